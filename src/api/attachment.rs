@@ -0,0 +1,103 @@
+//! Serves todo attachments to signed-in users of the todo's group.
+//!
+//! The blob container is private, so browsers cannot open attachment URLs
+//! directly; [`Attachment::href`](crate::domain::todo::Attachment::href) links
+//! here instead, and the file is read from Blob Storage with the SAS token.
+
+use axum::Router;
+use axum::extract::Path;
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+
+use crate::api::auth::{active_session, session_token_from_headers};
+use crate::domain::todo::{ATTACHMENT_ROUTE, Attachment, is_blob_path};
+use crate::services::blob::get_blob_service;
+use crate::services::todo_repository;
+
+/// Route of the attachment download, to be merged into the application router.
+pub fn attachment_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new().route(
+        &format!("{ATTACHMENT_ROUTE}/{{blob_id}}/{{file_name}}"),
+        get(download_attachment),
+    )
+}
+
+/// Streams the attachment with the content type stored on the todo; the file
+/// is never run as a page of this site, whatever it contains.
+async fn download_attachment(
+    headers: HeaderMap,
+    Path((blob_id, file_name)): Path<(String, String)>,
+) -> Response {
+    let Some(session) = session_token_from_headers(&headers).and_then(|t| active_session(&t))
+    else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let blob_path = format!("{blob_id}/{file_name}");
+    if !is_blob_path(&blob_path) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let Ok(blob) = get_blob_service() else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    // Only files on a todo of the group, or just uploaded for it, are served
+    let stored = match todo_repository() {
+        Ok(repository) => match repository.list(&session.group_id).await {
+            Ok(todos) => todos
+                .into_iter()
+                .flat_map(|todo| todo.attachments)
+                .find(|attachment| blob.blob_path(&attachment.url) == Some(blob_path.as_str())),
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to list todos for an attachment");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        },
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to get todo repository");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let Some(attachment) = stored.or_else(|| {
+        let url = format!("{}/{blob_path}", blob.container_url());
+        blob.uploaded(&url, &session.group_id)
+    }) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    match blob.download(&blob_path).await {
+        Ok(data) => (attachment_headers(&attachment), data).into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to download attachment {blob_path}");
+            StatusCode::BAD_GATEWAY.into_response()
+        }
+    }
+}
+
+fn attachment_headers(attachment: &Attachment) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    let content_type = HeaderValue::from_str(&attachment.content_type)
+        .unwrap_or(HeaderValue::from_static("application/octet-stream"));
+    headers.insert(header::CONTENT_TYPE, content_type);
+    headers.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+    headers.insert(
+        header::CONTENT_SECURITY_POLICY,
+        HeaderValue::from_static("sandbox"),
+    );
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static("private, max-age=3600"),
+    );
+    // The blob name only has letters, digits, '.', '-' and '_'
+    let file_name = attachment.url.rsplit('/').next().unwrap_or("attachment");
+    if let Ok(disposition) = HeaderValue::from_str(&format!("inline; filename=\"{file_name}\"")) {
+        headers.insert(header::CONTENT_DISPOSITION, disposition);
+    }
+    headers
+}
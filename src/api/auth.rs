@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 
-use crate::domain::auth::{LoginRequest, LoginResponse, UserInfo};
+use crate::domain::auth::{ChangePasswordRequest, LoginRequest, LoginResponse, UserInfo};
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -13,8 +13,35 @@ pub struct SessionInfo {
     pub created_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
     pub is_active: bool,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    /// Long-lived session that survives browser restarts
+    #[serde(default)]
+    pub remember_me: bool,
+    /// Household whose todos the session may access; taken from the server
+    /// configuration at login, never from the client
+    pub group_id: String,
 }
 
+/// Name of the HttpOnly cookie carrying the session token.
+///
+/// The cookie is set by `authenticate_user` and is not readable from JavaScript.
+/// Protected server functions read the token from the `Authorization: Bearer <token>`
+/// header first and fall back to this cookie, which the browser sends automatically.
+pub const SESSION_COOKIE_NAME: &str = "session_token";
+
+/// Message of the error returned when a request has no valid session.
+pub const UNAUTHORIZED_ERROR: &str = "Unauthorized: session is missing or expired";
+
+/// Returns true if a server function failed because the session is missing or expired.
+#[must_use]
+pub fn is_unauthorized(error: &ServerFnError) -> bool {
+    error.to_string().contains(UNAUTHORIZED_ERROR)
+}
+
+/// Message of the error returned when the session lacks a required permission.
+pub const FORBIDDEN_ERROR: &str = "Forbidden: missing permission";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthStatus {
     pub is_authenticated: bool,
@@ -26,92 +53,496 @@ pub struct AuthStatus {
 static SESSION_STORE: std::sync::LazyLock<Mutex<HashMap<String, SessionInfo>>> =
     std::sync::LazyLock::new(|| Mutex::new(HashMap::new()));
 
+/// Ensures the current request carries a valid session and returns it.
+///
+/// The token is read from the `Authorization: Bearer <token>` header or the
+/// `session_token` cookie (see [`SESSION_COOKIE_NAME`]).
+///
+/// # Errors
+///
+/// Returns a `ServerFnError` with [`UNAUTHORIZED_ERROR`] and sets the HTTP status to
+/// 401 if the token is missing, unknown, inactive or expired.
+#[cfg(feature = "ssr")]
+pub async fn require_session() -> Result<SessionInfo, ServerFnError> {
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+
+    let session = request_session_token()
+        .await?
+        .and_then(|token| active_session(&token));
+
+    session.ok_or_else(|| {
+        if let Some(response) = use_context::<ResponseOptions>() {
+            response.set_status(StatusCode::UNAUTHORIZED);
+        }
+        ServerFnError::new(UNAUTHORIZED_ERROR)
+    })
+}
+
+/// Looks up the session for `token`, if it exists and is active and unexpired.
+#[cfg(feature = "ssr")]
+#[must_use]
+pub fn active_session(token: &str) -> Option<SessionInfo> {
+    let sessions = SESSION_STORE.lock().ok()?;
+    sessions
+        .get(token)
+        .filter(|session_info| session_info.is_active && Utc::now() < session_info.expires_at)
+        .cloned()
+}
+
+/// Ensures the current request carries a valid session granting `permission`.
+///
+/// # Errors
+///
+/// Fails like [`require_session`] without a valid session, and returns a
+/// `ServerFnError` with [`FORBIDDEN_ERROR`] and HTTP status 403 if the session
+/// lacks the permission.
+#[cfg(feature = "ssr")]
+pub async fn require_permission(permission: &str) -> Result<SessionInfo, ServerFnError> {
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+
+    let session = require_session().await?;
+    if session.permissions.iter().any(|p| p == permission) {
+        return Ok(session);
+    }
+
+    if let Some(response) = use_context::<ResponseOptions>() {
+        response.set_status(StatusCode::FORBIDDEN);
+    }
+    Err(ServerFnError::new(format!(
+        "{FORBIDDEN_ERROR} {permission}"
+    )))
+}
+
+/// Reads the session token of the current request, if any.
+#[cfg(feature = "ssr")]
+async fn request_session_token() -> Result<Option<String>, ServerFnError> {
+    use axum::http::HeaderMap;
+    use leptos_axum::extract;
+
+    let headers: HeaderMap = extract()
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to read request headers: {}", e)))?;
+    Ok(session_token_from_headers(&headers))
+}
+
+/// Sets the HttpOnly session cookie on the response.
+///
+/// Without a max age the cookie is a browser-session cookie that is dropped when
+/// the browser closes.
+#[cfg(feature = "ssr")]
+fn set_session_cookie(token: &str, max_age_secs: Option<i64>) {
+    let max_age = max_age_secs
+        .map(|secs| format!("; Max-Age={secs}"))
+        .unwrap_or_default();
+    append_set_cookie(&format!(
+        "{SESSION_COOKIE_NAME}={token}; HttpOnly; Secure; SameSite=Strict; Path=/{max_age}"
+    ));
+}
+
+/// Lifetime of a new or extended session.
+#[cfg(feature = "ssr")]
+fn session_lifetime(
+    auth_config: &crate::config::AuthConfig,
+    remember_me: bool,
+) -> chrono::Duration {
+    if remember_me {
+        chrono::Duration::days(auth_config.remember_me_days as i64)
+    } else {
+        chrono::Duration::hours(auth_config.session_timeout_hours as i64)
+    }
+}
+
+/// Expires the session cookie in the browser.
+#[cfg(feature = "ssr")]
+fn clear_session_cookie() {
+    append_set_cookie(&format!(
+        "{SESSION_COOKIE_NAME}=; HttpOnly; Secure; SameSite=Strict; Path=/; Max-Age=0"
+    ));
+}
+
+#[cfg(feature = "ssr")]
+fn append_set_cookie(cookie: &str) {
+    use axum::http::{HeaderValue, header};
+    use leptos_axum::ResponseOptions;
+
+    let Some(response) = use_context::<ResponseOptions>() else {
+        tracing::warn!("No response options available to set the session cookie");
+        return;
+    };
+    match HeaderValue::from_str(cookie) {
+        Ok(value) => response.append_header(header::SET_COOKIE, value),
+        Err(e) => tracing::warn!(error = %e, "Invalid session cookie header"),
+    }
+}
+
+/// Reads the token of an `Authorization: Bearer <token>` header.
+#[cfg(feature = "ssr")]
+pub(crate) fn bearer_token(headers: &axum::http::HeaderMap) -> Option<String> {
+    headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|token| token.trim().to_string())
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn session_token_from_headers(headers: &axum::http::HeaderMap) -> Option<String> {
+    use axum::http::header;
+
+    let bearer = bearer_token(headers);
+    if bearer.is_some() {
+        return bearer;
+    }
+
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .find_map(|cookie| {
+            let (name, value) = cookie.trim().split_once('=')?;
+            (name == SESSION_COOKIE_NAME).then(|| value.to_string())
+        })
+}
+
+/// Username and client address a failed login is counted for; the address is
+/// `None` when it cannot be told.
+#[cfg(feature = "ssr")]
+type LoginKey = (String, Option<std::net::IpAddr>);
+
+/// Failed login attempts per username and client address: count and time of
+/// the latest failure.
+///
+/// Keying by address too means someone guessing passwords only locks the
+/// account for themselves, not for its owner.
+#[cfg(feature = "ssr")]
+static LOGIN_ATTEMPTS: std::sync::LazyLock<Mutex<HashMap<LoginKey, (u32, std::time::Instant)>>> =
+    std::sync::LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Failures older than this are forgotten.
+#[cfg(feature = "ssr")]
+const LOGIN_ATTEMPT_WINDOW: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+
+/// Username and address pairs whose failed logins are tracked at most.
+#[cfg(feature = "ssr")]
+const MAX_TRACKED_LOGINS: usize = 10_000;
+
+/// Upper bound for the exponential lockout multiplier (2^6 = 64x the base duration).
+#[cfg(feature = "ssr")]
+const MAX_LOCKOUT_DOUBLINGS: u32 = 6;
+
+/// Client address of a request: the last `X-Forwarded-For` entry, which the
+/// nearest proxy appended, if `trust_forwarded_for` is set, otherwise the
+/// address of the connection.
+#[cfg(feature = "ssr")]
+pub(crate) fn client_ip(
+    headers: &axum::http::HeaderMap,
+    connection: Option<std::net::SocketAddr>,
+    trust_forwarded_for: bool,
+) -> Option<std::net::IpAddr> {
+    if trust_forwarded_for {
+        return headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .next_back()
+            .and_then(|address| address.trim().parse().ok());
+    }
+    connection.map(|address| address.ip())
+}
+
+/// Client address of the current server function request, see [`client_ip`].
+#[cfg(feature = "ssr")]
+async fn request_client_ip(
+    server: &crate::config::ServerConfig,
+) -> Result<Option<std::net::IpAddr>, ServerFnError> {
+    use axum::extract::ConnectInfo;
+    use axum::http::HeaderMap;
+    use leptos_axum::extract;
+
+    let headers: HeaderMap = extract()
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to read request headers: {}", e)))?;
+    let connection = extract::<ConnectInfo<std::net::SocketAddr>>()
+        .await
+        .ok()
+        .map(|ConnectInfo(address)| address);
+    Ok(client_ip(&headers, connection, server.trust_forwarded_for))
+}
+
+/// Returns the remaining lockout for `username` from `ip`, if it is currently locked.
+#[cfg(feature = "ssr")]
+fn remaining_lockout(
+    username: &str,
+    ip: Option<std::net::IpAddr>,
+    auth: &crate::config::AuthConfig,
+) -> Option<std::time::Duration> {
+    let key = (username.to_string(), ip);
+    let mut attempts = LOGIN_ATTEMPTS.lock().ok()?;
+    let (failures, last_failure) = *attempts.get(&key)?;
+
+    let elapsed = last_failure.elapsed();
+    let lockout = lockout_duration(failures, auth);
+    if elapsed >= lockout.max(LOGIN_ATTEMPT_WINDOW) {
+        attempts.remove(&key);
+        return None;
+    }
+
+    lockout
+        .checked_sub(elapsed)
+        .filter(|remaining| !remaining.is_zero())
+}
+
+/// Lockout after `failures` failed attempts, doubling for every failure past the limit.
+#[cfg(feature = "ssr")]
+fn lockout_duration(failures: u32, auth: &crate::config::AuthConfig) -> std::time::Duration {
+    if failures < auth.max_login_attempts {
+        return std::time::Duration::ZERO;
+    }
+    let doublings = (failures - auth.max_login_attempts).min(MAX_LOCKOUT_DOUBLINGS);
+    std::time::Duration::from_secs(auth.lockout_duration_secs.saturating_mul(1 << doublings))
+}
+
+/// Counts a failed login for `username` from `ip`.
+///
+/// Entries whose lockout and window have passed are dropped first, and at most
+/// `MAX_TRACKED_LOGINS` entries are kept, evicting the oldest failure, so
+/// attempts with ever new usernames or addresses cannot grow the map without
+/// bound.
+#[cfg(feature = "ssr")]
+fn record_failed_login(
+    username: &str,
+    ip: Option<std::net::IpAddr>,
+    auth: &crate::config::AuthConfig,
+) {
+    let key = (username.to_string(), ip);
+    if let Ok(mut attempts) = LOGIN_ATTEMPTS.lock() {
+        let now = std::time::Instant::now();
+        attempts.retain(|_, (failures, last_failure)| {
+            now.duration_since(*last_failure)
+                < lockout_duration(*failures, auth).max(LOGIN_ATTEMPT_WINDOW)
+        });
+        if attempts.len() >= MAX_TRACKED_LOGINS && !attempts.contains_key(&key) {
+            let oldest = attempts
+                .iter()
+                .min_by_key(|(_, (_, last_failure))| *last_failure)
+                .map(|(oldest, _)| oldest.clone());
+            if let Some(oldest) = oldest {
+                attempts.remove(&oldest);
+            }
+        }
+
+        let entry = attempts.entry(key).or_insert((0, now));
+        entry.0 = entry.0.saturating_add(1);
+        entry.1 = now;
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn reset_failed_logins(username: &str, ip: Option<std::net::IpAddr>) {
+    if let Ok(mut attempts) = LOGIN_ATTEMPTS.lock() {
+        attempts.remove(&(username.to_string(), ip));
+    }
+}
+
+/// Password hash set with `change_password_server` since the server started.
+///
+/// The hash is also stored in `COSMIC_PASSWORD_HASH_FILE`, from which the
+/// configuration loads it after a restart.
+#[cfg(feature = "ssr")]
+static CHANGED_PASSWORD_HASH: std::sync::LazyLock<Mutex<Option<String>>> =
+    std::sync::LazyLock::new(|| Mutex::new(None));
+
+/// Verifies a submitted password against the changed password, or the
+/// configured one while it has not been changed.
+#[cfg(feature = "ssr")]
+fn verify_password(auth: &crate::config::AuthConfig, password: &str) -> bool {
+    let changed = CHANGED_PASSWORD_HASH
+        .lock()
+        .ok()
+        .and_then(|hash| hash.clone());
+    match changed {
+        Some(hash) => crate::config::AuthConfig::verify_password_hash(&hash, password),
+        None => auth.verify_password(password),
+    }
+}
+
+/// Why [`start_session`] refused a login, with the message for the user.
+#[cfg(feature = "ssr")]
+#[derive(Debug)]
+pub(crate) enum LoginRefusal {
+    /// Too many failed attempts; the account is locked for now
+    Locked(String),
+    /// Unknown username or wrong password
+    Invalid(String),
+}
+
+#[cfg(feature = "ssr")]
+impl LoginRefusal {
+    pub(crate) fn message(&self) -> &str {
+        match self {
+            Self::Locked(message) | Self::Invalid(message) => message,
+        }
+    }
+}
+
+/// Checks `credentials` sent from `client_ip` and opens a session for them,
+/// returning its token and the session; both the web login and the JSON API
+/// token route use it.
+///
+/// Failed attempts count towards the lockout of the username from that
+/// address and are answered after a delay.
+#[cfg(feature = "ssr")]
+pub(crate) async fn start_session(
+    auth: &crate::config::AuthConfig,
+    credentials: &LoginRequest,
+    client_ip: Option<std::net::IpAddr>,
+) -> Result<(String, SessionInfo), LoginRefusal> {
+    use uuid::Uuid;
+
+    if let Some(remaining) = remaining_lockout(&credentials.username, client_ip, auth) {
+        tracing::warn!(username = %credentials.username, "Login blocked for locked user");
+        return Err(LoginRefusal::Locked(format!(
+            "Too many failed attempts. Account is temporarily locked, try again in {} seconds",
+            remaining.as_secs().max(1)
+        )));
+    }
+
+    // Validate credentials against configuration
+    let is_valid =
+        credentials.username == auth.username && verify_password(auth, &credentials.password);
+    if !is_valid {
+        tracing::warn!(username = %credentials.username, ?client_ip, "Authentication failed");
+        record_failed_login(&credentials.username, client_ip, auth);
+
+        // Add delay to prevent brute force attacks
+        tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+
+        return Err(LoginRefusal::Invalid(
+            "Invalid username or password".to_string(),
+        ));
+    }
+    reset_failed_logins(&credentials.username, client_ip);
+
+    // Generate secure session token
+    let session_token = format!("session_{}", Uuid::new_v4());
+    let session_info = SessionInfo {
+        user_id: Uuid::new_v4().to_string(),
+        username: credentials.username.clone(),
+        created_at: Utc::now(),
+        expires_at: Utc::now() + session_lifetime(auth, credentials.remember_me),
+        is_active: true,
+        permissions: auth.permissions.clone(),
+        remember_me: credentials.remember_me,
+        group_id: auth.group_id.clone(),
+    };
+
+    // Store session in memory (use Azure Cache/Redis in production)
+    SESSION_STORE
+        .lock()
+        .expect("Failed to acquire session store lock")
+        .insert(session_token.clone(), session_info.clone());
+
+    tracing::info!(username = %credentials.username, "User authenticated");
+    Ok((session_token, session_info))
+}
+
+/// Ends the session of `token`; returns false if the token is unknown.
+#[cfg(feature = "ssr")]
+pub(crate) fn end_session(token: &str) -> bool {
+    let mut sessions = SESSION_STORE
+        .lock()
+        .expect("Failed to acquire session store lock");
+    sessions
+        .get_mut(token)
+        .map(|session_info| session_info.is_active = false)
+        .is_some()
+}
+
+/// Signs in from the web app, handing the session token to the browser as an
+/// HttpOnly cookie only; scripts get a token from `POST /api/v1/auth/token`.
 #[server(AuthenticateUser, "/api")]
 pub async fn authenticate_user(credentials: LoginRequest) -> Result<LoginResponse, ServerFnError> {
     // Extract the app config from Axum state
     use crate::config::AppConfig;
     use axum::extract::State;
-    use chrono::Duration;
     use leptos_axum::extract;
-    use uuid::Uuid;
     let State(app_config): State<AppConfig> = extract()
         .await
         .map_err(|e| ServerFnError::new(format!("Failed to extract app config: {}", e)))?;
 
-    // Validate credentials against configuration
-    let is_valid = credentials.username == app_config.auth.username
-        && credentials.password == app_config.auth.password;
-
-    if is_valid {
-        // Generate secure session token
-        let session_token = format!("session_{}", Uuid::new_v4());
-        let user_id = Uuid::new_v4().to_string();
-
-        // Calculate session expiration
-        let session_timeout_hours = app_config.auth.session_timeout_hours;
-        let expires_at = Utc::now() + Duration::hours(session_timeout_hours as i64);
-
-        // Create session info
-        let session_info = SessionInfo {
-            user_id: user_id.clone(),
-            username: credentials.username.clone(),
-            created_at: Utc::now(),
-            expires_at,
-            is_active: true,
+    let client_ip = request_client_ip(&app_config.server).await?;
+    let (session_token, session_info) =
+        match start_session(&app_config.auth, &credentials, client_ip).await {
+            Ok(session) => session,
+            Err(refusal) => {
+                return Ok(LoginResponse {
+                    success: false,
+                    message: refusal.message().to_string(),
+                    token: None,
+                    user_info: None,
+                    expires_in: None,
+                });
+            }
         };
 
-        // Store session in memory (use Azure Cache/Redis in production)
-        {
-            let mut sessions = SESSION_STORE
-                .lock()
-                .expect("Failed to acquire session store lock");
-            sessions.insert(session_token.clone(), session_info);
-        }
+    let expires_in = (session_info.expires_at - Utc::now()).num_seconds();
+    set_session_cookie(
+        &session_token,
+        credentials.remember_me.then_some(expires_in),
+    );
 
-        // Create user info
-        let user_info = UserInfo {
-            username: credentials.username.clone(),
-            display_name: credentials.username.clone(), // In real app, get from user profile
-            email: format!("{}@example.com", credentials.username), // Placeholder email
-        };
+    // Create user info
+    let user_info = UserInfo {
+        username: credentials.username.clone(),
+        display_name: credentials.username.clone(), // In real app, get from user profile
+        email: user_email(&app_config, &credentials.username),
+        permissions: session_info.permissions,
+        group_id: session_info.group_id,
+    };
 
-        leptos::logging::log!(
-            "User {} authenticated successfully with session {}",
-            credentials.username,
-            session_token
-        );
-
-        Ok(LoginResponse {
-            success: true,
-            message: "Authentication successful".to_string(),
-            token: Some(session_token),
-            user_info: Some(user_info),
-        })
-    } else {
-        leptos::logging::log!("Authentication failed for user: {}", credentials.username);
+    Ok(LoginResponse {
+        success: true,
+        message: "Authentication successful".to_string(),
+        token: None,
+        user_info: Some(user_info),
+        expires_in: Some(expires_in),
+    })
+}
 
-        // Add delay to prevent brute force attacks
-        #[cfg(feature = "ssr")]
-        {
-            tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
-        }
+/// Email shown for a signed-in user: the address configured for the assignee
+/// of the same name, or a placeholder for anyone else.
+///
+/// The placeholder is only used if it is a valid address, so a username that
+/// cannot form one leaves the email empty.
+#[cfg(feature = "ssr")]
+fn user_email(config: &crate::config::AppConfig, username: &str) -> String {
+    use crate::utils::validate_email;
 
-        Ok(LoginResponse {
-            success: false,
-            message: "Invalid username or password".to_string(),
-            token: None,
-            user_info: None,
-        })
+    if let Some(email) = config.emails.email_for(username) {
+        return email.to_string();
+    }
+    let placeholder = format!("{}@example.com", username.to_lowercase().replace(' ', "."));
+    if validate_email(&placeholder).is_ok() {
+        placeholder
+    } else {
+        String::new()
     }
 }
 
+/// Checks the session carried by the request's session cookie.
 #[server(ValidateSession, "/api")]
-pub async fn validate_session(session_token: String) -> Result<AuthStatus, ServerFnError> {
+pub async fn validate_session() -> Result<AuthStatus, ServerFnError> {
+    let session_token = request_session_token().await?;
+
     let sessions = SESSION_STORE
         .lock()
         .expect("Failed to acquire session store lock");
 
-    if let Some(session_info) = sessions.get(&session_token) {
+    if let Some(session_info) = session_token.and_then(|token| sessions.get(&token)) {
         // Check if session is still valid
         if session_info.is_active && Utc::now() < session_info.expires_at {
             let expires_in = (session_info.expires_at - Utc::now()).num_seconds();
@@ -119,7 +550,11 @@ pub async fn validate_session(session_token: String) -> Result<AuthStatus, Serve
             let user_info = UserInfo {
                 username: session_info.username.clone(),
                 display_name: session_info.username.clone(),
-                email: format!("{}@example.com", session_info.username), // Placeholder email
+                email: crate::config::get_config()
+                    .map(|config| user_email(config, &session_info.username))
+                    .unwrap_or_default(),
+                permissions: session_info.permissions.clone(),
+                group_id: session_info.group_id.clone(),
             };
 
             Ok(AuthStatus {
@@ -145,29 +580,152 @@ pub async fn validate_session(session_token: String) -> Result<AuthStatus, Serve
     }
 }
 
+/// Ends the session carried by the request's session cookie and clears the cookie.
 #[server(LogoutUser, "/api")]
-pub async fn logout_user(session_token: String) -> Result<bool, ServerFnError> {
+pub async fn logout_user() -> Result<bool, ServerFnError> {
+    let session_token = request_session_token().await?;
+    clear_session_cookie();
+
+    Ok(session_token.is_some_and(|token| end_session(&token)))
+}
+
+/// Invalidates every active session belonging to the owner of the current session.
+///
+/// Returns the number of sessions that were invalidated, including the current one.
+#[server(LogoutAllSessions, "/api")]
+pub async fn logout_all_sessions() -> Result<usize, ServerFnError> {
+    let session_token = request_session_token()
+        .await?
+        .ok_or_else(|| ServerFnError::new("Session not found".to_string()))?;
+    clear_session_cookie();
+
     let mut sessions = SESSION_STORE
         .lock()
-        .expect("Failed to acquire session store lock");
+        .map_err(|e| ServerFnError::new(format!("Failed to acquire session store lock: {e}")))?;
 
-    if let Some(session_info) = sessions.get_mut(&session_token) {
-        session_info.is_active = false;
-        Ok(true)
-    } else {
-        Ok(false)
+    let username = sessions
+        .get(&session_token)
+        .filter(|session_info| session_info.is_active)
+        .map(|session_info| session_info.username.clone())
+        .ok_or_else(|| ServerFnError::new("Session not found".to_string()))?;
+
+    let mut invalidated = 0;
+    for session_info in sessions.values_mut() {
+        if session_info.username == username && session_info.is_active {
+            session_info.is_active = false;
+            invalidated += 1;
+        }
     }
+
+    tracing::info!(username = %username, invalidated, "Invalidated sessions");
+    Ok(invalidated)
 }
 
+/// Changes the login password after verifying the current one.
+///
+/// The new password must pass [`crate::domain::auth::validate_password_strength`].
+/// Every other session of the user is invalidated; the current one stays
+/// signed in. Returns the number of sessions that were invalidated.
+///
+/// Failed attempts count towards the login lockout.
+#[server(ChangePassword, "/api")]
+pub async fn change_password_server(
+    request: ChangePasswordRequest,
+) -> Result<usize, ServerFnError> {
+    use crate::api::request_id::server_error;
+    use crate::config::{AppConfig, AuthConfig};
+    use axum::extract::State;
+    use leptos_axum::extract;
+    use validator::Validate;
+
+    let session = require_session().await?;
+    let session_token = request_session_token().await?;
+    let State(app_config): State<AppConfig> = extract()
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to extract app config: {}", e)))?;
+
+    let client_ip = request_client_ip(&app_config.server).await?;
+    if let Some(remaining) = remaining_lockout(&session.username, client_ip, &app_config.auth) {
+        return Err(ServerFnError::new(format!(
+            "Too many failed attempts, try again in {} seconds",
+            remaining.as_secs().max(1)
+        )));
+    }
+
+    request
+        .validate()
+        .map_err(|e| ServerFnError::new(format!("Validation error: {}", e)))?;
+
+    if !verify_password(&app_config.auth, &request.current_password) {
+        tracing::warn!(username = %session.username, "Password change failed");
+        record_failed_login(&session.username, client_ip, &app_config.auth);
+        tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+        return Err(ServerFnError::new(
+            "Current password is incorrect".to_string(),
+        ));
+    }
+    reset_failed_logins(&session.username, client_ip);
+
+    if request.new_password == request.current_password {
+        return Err(ServerFnError::new(
+            "New password must differ from the current one".to_string(),
+        ));
+    }
+
+    // Without somewhere to keep it, the old password would return on a restart
+    if app_config.auth.password_hash_file.is_empty() {
+        return Err(ServerFnError::new(
+            "Password changes are disabled: the server has no COSMIC_PASSWORD_HASH_FILE to keep them in",
+        ));
+    }
+
+    let hash = AuthConfig::hash_password(&request.new_password)
+        .map_err(|e| server_error(format!("Failed to hash password: {e}")))?;
+    app_config
+        .auth
+        .store_password_hash(&hash)
+        .map_err(|e| server_error(format!("Failed to store password: {e}")))?;
+    *CHANGED_PASSWORD_HASH
+        .lock()
+        .map_err(|e| server_error(format!("Failed to acquire password lock: {e}")))? = Some(hash);
+
+    let mut sessions = SESSION_STORE
+        .lock()
+        .map_err(|e| ServerFnError::new(format!("Failed to acquire session store lock: {e}")))?;
+
+    let mut invalidated = 0;
+    for (token, session_info) in sessions.iter_mut() {
+        if session_info.username == session.username
+            && session_info.is_active
+            && session_token.as_ref() != Some(token)
+        {
+            session_info.is_active = false;
+            invalidated += 1;
+        }
+    }
+
+    tracing::info!(
+        username = %session.username,
+        invalidated,
+        "Password changed, other sessions invalidated"
+    );
+    Ok(invalidated)
+}
+
+/// Extends the current session and renews the session cookie.
+///
+/// Returns the number of seconds until the extended session expires.
 #[server(RefreshSession, "/api")]
-pub async fn refresh_session(session_token: String) -> Result<String, ServerFnError> {
+pub async fn refresh_session() -> Result<i64, ServerFnError> {
     use crate::config::AppConfig;
     use axum::extract::State;
-    use chrono::Duration;
     use leptos_axum::extract;
     let State(app_config): State<AppConfig> = extract()
         .await
         .map_err(|e| ServerFnError::new(format!("Failed to extract app config: {}", e)))?;
+    let session_token = request_session_token()
+        .await?
+        .ok_or_else(|| ServerFnError::new("Session not found".to_string()))?;
 
     let mut sessions = SESSION_STORE
         .lock()
@@ -176,11 +734,16 @@ pub async fn refresh_session(session_token: String) -> Result<String, ServerFnEr
     if let Some(session_info) = sessions.get_mut(&session_token) {
         if session_info.is_active && Utc::now() < session_info.expires_at {
             // Extend session
-            let session_timeout_hours = app_config.auth.session_timeout_hours;
-            session_info.expires_at = Utc::now() + Duration::hours(session_timeout_hours as i64);
+            session_info.expires_at =
+                Utc::now() + session_lifetime(&app_config.auth, session_info.remember_me);
+            let expires_in = (session_info.expires_at - Utc::now()).num_seconds();
+            set_session_cookie(
+                &session_token,
+                session_info.remember_me.then_some(expires_in),
+            );
 
-            leptos::logging::log!("Session refreshed for user {}", session_info.username);
-            Ok(session_token)
+            tracing::info!(username = %session_info.username, "Session refreshed");
+            Ok(expires_in)
         } else {
             Err(ServerFnError::new("Session expired or invalid".to_string()))
         }
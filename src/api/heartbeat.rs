@@ -1,4 +1,5 @@
 use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[server(HeartbeatServer, "/api")]
 pub async fn heartbeat_server() -> Result<String, ServerFnError> {
@@ -7,3 +8,53 @@ pub async fn heartbeat_server() -> Result<String, ServerFnError> {
     let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string();
     Ok(format!("Server is running at {}", timestamp))
 }
+
+/// Interval between status checks until the server reports its configured one.
+pub const DEFAULT_HEARTBEAT_INTERVAL_SECS: u64 = 30;
+
+/// Health of the server and its database, as reported by [`readiness_server`].
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReadinessStatus {
+    pub server: bool,
+    /// Whether Cosmos DB answered a query
+    pub cosmos: bool,
+    /// Round trip time of the Cosmos DB query
+    pub latency_ms: u64,
+    /// Configured interval between status checks, `HEARTBEAT_INTERVAL_SECS`
+    pub heartbeat_interval_secs: u64,
+}
+
+/// Reports whether the server can reach Cosmos DB, served at `GET /api/health`.
+///
+/// Unlike [`heartbeat_server`], a database outage is reported with
+/// `cosmos: false` rather than as a failed request.
+#[server(name = ReadinessServer, prefix = "/api", endpoint = "health", input = GetUrl)]
+pub async fn readiness_server() -> Result<ReadinessStatus, ServerFnError> {
+    use crate::config::get_config;
+    use crate::services::cosmos::todo_repository::get_cosmos_service;
+    use std::time::Instant;
+
+    let started = Instant::now();
+    let cosmos = match get_cosmos_service() {
+        Ok(cosmos_service) => match cosmos_service.ping().await {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::error!(error = %e, "Cosmos DB readiness check failed");
+                false
+            }
+        },
+        Err(e) => {
+            tracing::error!(error = %e, "Cosmos service unavailable");
+            false
+        }
+    };
+
+    Ok(ReadinessStatus {
+        server: true,
+        cosmos,
+        latency_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        heartbeat_interval_secs: get_config().map_or(DEFAULT_HEARTBEAT_INTERVAL_SECS, |config| {
+            config.server.heartbeat_interval_secs
+        }),
+    })
+}
@@ -1,7 +1,21 @@
+#[cfg(feature = "ssr")]
+pub mod attachment;
 pub mod auth;
 pub mod heartbeat;
+pub mod notification;
+pub mod request_id;
+#[cfg(feature = "ssr")]
+pub mod rest;
+pub mod share;
 pub mod todo;
 
+#[cfg(feature = "ssr")]
+pub use attachment::*;
 pub use auth::*;
 pub use heartbeat::*;
+pub use notification::*;
+pub use request_id::*;
+#[cfg(feature = "ssr")]
+pub use rest::*;
+pub use share::*;
 pub use todo::*;
@@ -0,0 +1,27 @@
+use leptos::prelude::*;
+
+use crate::domain::todo::BulkItemResult;
+
+/// Emails reminders for the caller's group's todos due within the next 24 hours.
+///
+/// Meant to be called periodically by a scheduler; each todo is reminded of
+/// once. Returns the outcome of every reminder sent in this run.
+#[server(SendDueReminders, "/api")]
+pub async fn send_due_reminders_server() -> Result<Vec<BulkItemResult>, ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::api::request_id::server_error;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::notification::get_notification_service;
+    use crate::services::todo_repository;
+
+    let session = require_permission(WRITE_TODOS).await?;
+
+    let notification_service = get_notification_service()
+        .map_err(|e| server_error(format!("Failed to get notification service: {}", e)))?;
+    let repository = todo_repository()?;
+
+    notification_service
+        .send_due_reminders(repository.as_ref(), &session.group_id)
+        .await
+        .map_err(|e| server_error(format!("Failed to send reminders: {}", e)))
+}
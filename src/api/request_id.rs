@@ -0,0 +1,71 @@
+use leptos::prelude::*;
+
+/// Header carrying the request id, set on every response.
+///
+/// An id sent by a proxy in this header is kept, so logs can be followed
+/// across services.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Id of the current request, stored in the request extensions by
+/// [`request_id_layer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+/// Axum middleware giving each request an id and running it inside a
+/// `tracing` span carrying that id, so every log line of a server function can
+/// be found by the id shown to the user.
+#[cfg(feature = "ssr")]
+pub async fn request_id_layer(
+    mut request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    use axum::http::HeaderValue;
+    use tracing::Instrument;
+
+    let id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| {
+            !value.is_empty()
+                && value.len() <= 64
+                && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        .map_or_else(
+            || uuid::Uuid::new_v4().simple().to_string()[..12].to_string(),
+            str::to_string,
+        );
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %id,
+        method = %request.method(),
+        path = %request.uri().path(),
+    );
+    request.extensions_mut().insert(RequestId(id.clone()));
+
+    let mut response = next.run(request).instrument(span).await;
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+/// Id of the request being handled, if it passed through [`request_id_layer`].
+#[cfg(feature = "ssr")]
+#[must_use]
+pub fn current_request_id() -> Option<String> {
+    use_context::<axum::http::request::Parts>()
+        .and_then(|parts| parts.extensions.get::<RequestId>().map(|id| id.0.clone()))
+}
+
+/// Logs a server-side failure and returns it as a `ServerFnError` ending in
+/// "(ref: <request id>)", so an error shown in the UI can be found in the logs.
+#[cfg(feature = "ssr")]
+pub fn server_error(message: impl std::fmt::Display) -> ServerFnError {
+    tracing::error!("{message}");
+    match current_request_id() {
+        Some(id) => ServerFnError::new(format!("{message} (ref: {id})")),
+        None => ServerFnError::new(message.to_string()),
+    }
+}
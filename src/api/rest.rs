@@ -0,0 +1,365 @@
+//! Plain JSON API under `/api/v1`, for scripts and other clients that do not
+//! speak the Leptos server function protocol.
+//!
+//! Requests authenticate with an `Authorization: Bearer <token>` header carrying
+//! a session token from `POST /api/v1/auth/token`; the session cookie is not
+//! accepted here. Errors are answered as `{"error": "<message>"}` with a
+//! matching HTTP status.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use axum::extract::rejection::{ExtensionRejection, JsonRejection};
+use axum::extract::{ConnectInfo, Path};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use leptos::prelude::ServerFnError;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::api::auth::{
+    FORBIDDEN_ERROR, LoginRefusal, SessionInfo, UNAUTHORIZED_ERROR, active_session, bearer_token,
+    client_ip, end_session, start_session,
+};
+use crate::api::todo::{check_create_rate, ensure_due_date_allowed, ensure_within_size_limits};
+use crate::domain::auth::LoginRequest;
+use crate::domain::auth::WRITE_TODOS;
+use crate::domain::todo::{Todo, TodoAssignee, TodoPriority, TodoStatus};
+use crate::services::cosmos::metrics::OperationCharge;
+use crate::services::cosmos::{UpdateConflict, get_cosmos_service};
+use crate::services::webhook::notify_if_completed;
+use crate::services::{TodoRepository, todo_repository};
+use crate::utils::sanitize_string;
+
+/// Routes of the JSON API, to be merged into the application router.
+pub fn rest_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/api/v1/auth/token", post(issue_token).delete(revoke_token))
+        .route("/api/v1/todos", get(list_todos).post(create_todo))
+        .route(
+            "/api/v1/todos/{id}",
+            get(get_todo).put(update_todo).delete(delete_todo),
+        )
+        .route("/api/v1/debug/request-charges", get(request_charges))
+}
+
+/// Answer of `POST /api/v1/auth/token`.
+#[derive(Debug, Serialize)]
+pub struct TokenResponse {
+    /// Session token to send as `Authorization: Bearer <token>`
+    pub token: String,
+    /// Seconds until the session expires
+    pub expires_in: i64,
+}
+
+/// Body of `POST /api/v1/todos` and `PUT /api/v1/todos/{id}`.
+///
+/// A `PUT` replaces every field; only an omitted `status` or `priority` keeps
+/// the current value. Unknown fields are ignored, so a todo fetched with `GET`
+/// can be edited and sent back as it is.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TodoInput {
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Unix timestamp (seconds)
+    #[serde(default)]
+    pub due_date: Option<u64>,
+    pub assignee: String,
+    #[serde(default)]
+    pub status: Option<TodoStatus>,
+    #[serde(default)]
+    pub priority: Option<TodoPriority>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Confirms a due date in the past when creating; ignored by updates
+    #[serde(default)]
+    pub allow_past: bool,
+}
+
+impl TodoInput {
+    /// Applies the input to `existing`, or to a new todo, rejecting an unknown
+    /// assignee.
+    fn apply(self, existing: Option<Todo>) -> Result<Todo, ApiError> {
+        let assignee: TodoAssignee = self.assignee.parse().map_err(ApiError::unprocessable)?;
+        let is_configured = crate::config::get_config()
+            .map_err(|e| ApiError::internal("Failed to get app config", e))?
+            .emails
+            .email_for(assignee.as_str())
+            .is_some();
+        if !is_configured {
+            return Err(ApiError::unprocessable(format!(
+                "Unknown assignee: {assignee}"
+            )));
+        }
+
+        let todo = existing.unwrap_or_else(|| Todo::new(String::new(), assignee.clone()));
+        Ok(Todo {
+            title: self.title,
+            description: self.description,
+            due_date: self.due_date,
+            assignee,
+            status: self.status.unwrap_or(todo.status),
+            priority: self.priority.unwrap_or(todo.priority),
+            tags: self.tags,
+            ..todo
+        })
+    }
+}
+
+/// Error answered as `{"error": "<message>"}` with `status`.
+#[derive(Debug)]
+pub struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    fn unprocessable(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::UNPROCESSABLE_ENTITY, message)
+    }
+
+    fn not_found(todo_id: &str) -> Self {
+        Self::new(StatusCode::NOT_FOUND, format!("Todo not found: {todo_id}"))
+    }
+
+    /// Logs a server-side failure and hides its details from the client; the
+    /// log line carries the request id of the `X-Request-Id` response header.
+    fn internal(context: &str, error: impl Display) -> Self {
+        tracing::error!(error = %error, "{context}");
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, context)
+    }
+
+    /// Turns an error of the server function helpers into `status`.
+    fn from_server_fn(status: StatusCode, error: ServerFnError) -> Self {
+        let message = match error {
+            ServerFnError::ServerError(message) => message,
+            other => other.to_string(),
+        };
+        Self::new(status, message)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (
+            self.status,
+            Json(ErrorBody {
+                error: self.message,
+            }),
+        )
+            .into_response()
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::new(rejection.status(), rejection.body_text())
+    }
+}
+
+/// Returns the session of the bearer token, or 401.
+fn authenticate(headers: &HeaderMap) -> Result<SessionInfo, ApiError> {
+    bearer_token(headers)
+        .and_then(|token| active_session(&token))
+        .ok_or_else(|| ApiError::new(StatusCode::UNAUTHORIZED, UNAUTHORIZED_ERROR))
+}
+
+/// Returns the session of the bearer token if it grants `permission`, or 401/403.
+fn authorize(headers: &HeaderMap, permission: &str) -> Result<SessionInfo, ApiError> {
+    let session = authenticate(headers)?;
+    if session.permissions.iter().any(|p| p == permission) {
+        Ok(session)
+    } else {
+        Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            format!("{FORBIDDEN_ERROR} {permission}"),
+        ))
+    }
+}
+
+fn repository() -> Result<std::sync::Arc<dyn TodoRepository>, ApiError> {
+    todo_repository().map_err(|e| ApiError::internal("Failed to get todo repository", e))
+}
+
+/// Loads a todo of the session's group; malformed ids are answered like unknown ones.
+async fn find_todo(session: &SessionInfo, todo_id: &str) -> Result<Todo, ApiError> {
+    if crate::domain::todo::validation::validate_uuid(todo_id).is_err() {
+        return Err(ApiError::not_found(todo_id));
+    }
+    repository()?
+        .get(todo_id, &session.group_id)
+        .await
+        .map_err(|e| ApiError::internal("Failed to get todo", e))?
+        .ok_or_else(|| ApiError::not_found(todo_id))
+}
+
+/// Checks the limits and validation rules of the server functions and
+/// sanitizes the todo like they do.
+fn validated(todo: Todo) -> Result<Todo, ApiError> {
+    ensure_within_size_limits(&todo)
+        .map_err(|e| ApiError::from_server_fn(StatusCode::PAYLOAD_TOO_LARGE, e))?;
+    todo.validate()
+        .map_err(|e| ApiError::unprocessable(format!("Validation error: {e}")))?;
+
+    Ok(Todo {
+        title: sanitize_string(&todo.title),
+        description: todo.description.map(|desc| desc.trim().to_string()),
+        ..todo
+    })
+}
+
+/// `POST /api/v1/auth/token`: signs in with the body's `username`, `password`
+/// and optional `remember_me`, answering 200 with a session token.
+///
+/// Failed attempts count towards the same lockout as the web login.
+async fn issue_token(
+    connection: Result<ConnectInfo<std::net::SocketAddr>, ExtensionRejection>,
+    headers: HeaderMap,
+    input: Result<Json<LoginRequest>, JsonRejection>,
+) -> Result<Json<TokenResponse>, ApiError> {
+    let Json(credentials) = input?;
+    credentials
+        .validate()
+        .map_err(|e| ApiError::unprocessable(e.to_string()))?;
+    let config = crate::config::get_config()
+        .map_err(|e| ApiError::internal("Failed to get app config", e))?;
+
+    let client_ip = client_ip(
+        &headers,
+        connection.ok().map(|ConnectInfo(address)| address),
+        config.server.trust_forwarded_for,
+    );
+
+    match start_session(&config.auth, &credentials, client_ip).await {
+        Ok((token, session)) => Ok(Json(TokenResponse {
+            token,
+            expires_in: (session.expires_at - chrono::Utc::now()).num_seconds(),
+        })),
+        Err(LoginRefusal::Locked(message)) => {
+            Err(ApiError::new(StatusCode::TOO_MANY_REQUESTS, message))
+        }
+        Err(LoginRefusal::Invalid(message)) => {
+            Err(ApiError::new(StatusCode::UNAUTHORIZED, message))
+        }
+    }
+}
+
+/// `DELETE /api/v1/auth/token`: ends the session of the bearer token, answering 204.
+async fn revoke_token(headers: HeaderMap) -> Result<StatusCode, ApiError> {
+    authenticate(&headers)?;
+    if let Some(token) = bearer_token(&headers) {
+        end_session(&token);
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `GET /api/v1/todos`: every todo that is not in the trash, newest first.
+async fn list_todos(headers: HeaderMap) -> Result<Json<Vec<Todo>>, ApiError> {
+    let session = authenticate(&headers)?;
+    let todos = repository()?
+        .list(&session.group_id)
+        .await
+        .map_err(|e| ApiError::internal("Failed to get todos", e))?;
+    Ok(Json(todos))
+}
+
+/// `POST /api/v1/todos`: creates a todo, answering 201 with it.
+async fn create_todo(
+    headers: HeaderMap,
+    input: Result<Json<TodoInput>, JsonRejection>,
+) -> Result<(StatusCode, Json<Todo>), ApiError> {
+    let session = authorize(&headers, WRITE_TODOS)?;
+    let Json(input) = input?;
+
+    let allow_past = input.allow_past;
+    let todo = input.apply(None)?;
+    let todo = validated(todo)?;
+    ensure_due_date_allowed(&todo, allow_past)
+        .map_err(|e| ApiError::from_server_fn(StatusCode::UNPROCESSABLE_ENTITY, e))?;
+    check_create_rate(&session.username)
+        .map_err(|e| ApiError::from_server_fn(StatusCode::TOO_MANY_REQUESTS, e))?;
+
+    let created = repository()?
+        .create(todo, &session.group_id, &session.username)
+        .await
+        .map_err(|e| ApiError::internal("Failed to create todo", e))?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// `GET /api/v1/todos/{id}`
+async fn get_todo(headers: HeaderMap, Path(id): Path<String>) -> Result<Json<Todo>, ApiError> {
+    let session = authenticate(&headers)?;
+    Ok(Json(find_todo(&session, &id).await?))
+}
+
+/// `PUT /api/v1/todos/{id}`: replaces the editable fields, answering with the
+/// updated todo, or 409 if it changed concurrently.
+async fn update_todo(
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    input: Result<Json<TodoInput>, JsonRejection>,
+) -> Result<Json<Todo>, ApiError> {
+    let session = authorize(&headers, WRITE_TODOS)?;
+    let Json(input) = input?;
+
+    let existing = find_todo(&session, &id).await?;
+    let previous_status = existing.status;
+    let todo = validated(input.apply(Some(existing))?)?;
+
+    let updated = repository()?
+        .update(todo, &session.group_id, &session.username)
+        .await
+        .map_err(|e| {
+            if e.downcast_ref::<UpdateConflict>().is_some() {
+                ApiError::new(StatusCode::CONFLICT, e.to_string())
+            } else {
+                ApiError::internal("Failed to update todo", e)
+            }
+        })?;
+
+    if let Ok(config) = crate::config::get_config() {
+        notify_if_completed(&config.webhook_url, previous_status, &updated);
+    }
+    Ok(Json(updated))
+}
+
+/// `DELETE /api/v1/todos/{id}`: moves the todo to the trash, answering 204.
+async fn delete_todo(headers: HeaderMap, Path(id): Path<String>) -> Result<StatusCode, ApiError> {
+    let session = authorize(&headers, WRITE_TODOS)?;
+    find_todo(&session, &id).await?;
+
+    repository()?
+        .delete(&id, &session.group_id)
+        .await
+        .map_err(|e| ApiError::internal("Failed to delete todo", e))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `GET /api/v1/debug/request-charges`: request units Cosmos DB charged per
+/// operation since the server started, for cost tuning.
+async fn request_charges(
+    headers: HeaderMap,
+) -> Result<Json<BTreeMap<&'static str, OperationCharge>>, ApiError> {
+    authenticate(&headers)?;
+    let cosmos_service =
+        get_cosmos_service().map_err(|e| ApiError::internal("Failed to get Cosmos service", e))?;
+    Ok(Json(cosmos_service.request_charges()))
+}
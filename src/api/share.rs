@@ -0,0 +1,113 @@
+use leptos::prelude::*;
+
+#[cfg(feature = "ssr")]
+use crate::api::request_id::server_error;
+use crate::domain::todo::Todo;
+
+/// Message of the error returned for an unknown or revoked share token.
+pub const INVALID_SHARE_TOKEN_ERROR: &str = "This share link is invalid or has been revoked";
+
+/// Read-only access to a household's todos granted by a share link.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone)]
+struct ShareGrant {
+    username: String,
+    group_id: String,
+}
+
+/// Share tokens and what they grant; each user has at most one.
+///
+/// Kept apart from the session store: a share token never signs anyone in and
+/// only reaches [`get_shared_todos_server`].
+#[cfg(feature = "ssr")]
+static SHARE_TOKENS: std::sync::LazyLock<
+    std::sync::Mutex<std::collections::HashMap<String, ShareGrant>>,
+> = std::sync::LazyLock::new(|| std::sync::Mutex::new(std::collections::HashMap::new()));
+
+/// Returns the signed-in user's share token, if they have one.
+#[server(GetShareToken, "/api")]
+pub async fn get_share_token_server() -> Result<Option<String>, ServerFnError> {
+    use crate::api::auth::require_session;
+
+    let session = require_session().await?;
+    let tokens = SHARE_TOKENS
+        .lock()
+        .map_err(|_| server_error("Share tokens unavailable"))?;
+    Ok(tokens
+        .iter()
+        .find(|(_, grant)| grant.username == session.username)
+        .map(|(token, _)| token.clone()))
+}
+
+/// Creates a share token for the signed-in user, revoking their previous one.
+#[server(CreateShareToken, "/api")]
+pub async fn create_share_token_server() -> Result<String, ServerFnError> {
+    use crate::api::auth::require_session;
+    use uuid::Uuid;
+
+    let session = require_session().await?;
+    let token = format!("share_{}", Uuid::new_v4().simple());
+
+    let mut tokens = SHARE_TOKENS
+        .lock()
+        .map_err(|_| server_error("Share tokens unavailable"))?;
+    tokens.retain(|_, grant| grant.username != session.username);
+    tokens.insert(
+        token.clone(),
+        ShareGrant {
+            username: session.username,
+            group_id: session.group_id,
+        },
+    );
+    Ok(token)
+}
+
+/// Revokes the signed-in user's share token, so links using it stop working.
+#[server(RevokeShareToken, "/api")]
+pub async fn revoke_share_token_server() -> Result<(), ServerFnError> {
+    use crate::api::auth::require_session;
+
+    let session = require_session().await?;
+    SHARE_TOKENS
+        .lock()
+        .map_err(|_| server_error("Share tokens unavailable"))?
+        .retain(|_, grant| grant.username != session.username);
+    Ok(())
+}
+
+/// Returns the todos shared by `token`, without comments or attachments.
+///
+/// Needs no session; the token alone grants read access to the todos of the
+/// household of the user who created it.
+#[server(GetSharedTodos, "/api")]
+pub async fn get_shared_todos_server(token: String) -> Result<Vec<Todo>, ServerFnError> {
+    use crate::services::todo_repository;
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+
+    let grant = SHARE_TOKENS
+        .lock()
+        .map_err(|_| server_error("Share tokens unavailable"))?
+        .get(&token)
+        .cloned();
+    let Some(grant) = grant else {
+        if let Some(response) = use_context::<ResponseOptions>() {
+            response.set_status(StatusCode::NOT_FOUND);
+        }
+        return Err(ServerFnError::new(INVALID_SHARE_TOKEN_ERROR));
+    };
+
+    let todos = todo_repository()?
+        .list(&grant.group_id)
+        .await
+        .map_err(|e| server_error(format!("Failed to get todos: {}", e)))?;
+
+    Ok(todos
+        .into_iter()
+        .map(|todo| Todo {
+            comments: Vec::new(),
+            attachments: Vec::new(),
+            ..todo
+        })
+        .collect())
+}
@@ -1,62 +1,932 @@
 use leptos::prelude::*;
 
-use crate::domain::todo::Todo;
+#[cfg(feature = "ssr")]
+use crate::api::request_id::server_error;
+use crate::domain::todo::{
+    Attachment, BulkItemResult, ImportSummary, SortBy, Todo, TodoFilter, TodoPage, TodoStats,
+};
 
+/// Message prefix of the error returned when a todo exceeds the size limits.
+pub const TOO_LARGE_ERROR: &str = "Todo too large";
+
+/// Message prefix of the error returned when a user creates todos too quickly.
+pub const RATE_LIMITED_ERROR: &str = "Rate limited";
+
+/// Exact prefix of the message of the error returned when a todo was changed
+/// by someone else while it was being updated; shared by the server that
+/// raises it and the client that recognises it.
+pub const CONFLICT_ERROR: &str = "TODO_CONFLICT:";
+
+/// Returns true if a server function rejected a todo for exceeding the size limits.
+#[must_use]
+pub fn is_too_large(error: &ServerFnError) -> bool {
+    error.to_string().contains(TOO_LARGE_ERROR)
+}
+
+/// Returns true if a server function rejected a request for exceeding the rate limit.
+#[must_use]
+pub fn is_rate_limited(error: &ServerFnError) -> bool {
+    error.to_string().contains(RATE_LIMITED_ERROR)
+}
+
+/// Separates a conflict error's message from the stored version of the todo,
+/// which follows as JSON.
+const CONFLICT_CURRENT_MARKER: &str = "; current version: ";
+
+/// The message of a conflict error raised by [`conflict_error`], or `None` for
+/// any other error, even one that merely mentions a conflict.
+fn conflict_message(error: &ServerFnError) -> Option<&str> {
+    match error {
+        ServerFnError::ServerError(message) => message
+            .starts_with(CONFLICT_ERROR)
+            .then_some(message.as_str()),
+        _ => None,
+    }
+}
+
+/// Returns true if an update failed because the todo changed concurrently.
+#[must_use]
+pub fn is_conflict(error: &ServerFnError) -> bool {
+    conflict_message(error).is_some()
+}
+
+/// The stored version of the todo carried by a conflict error, or `None` if
+/// the error has none, e.g. because the todo was deleted meanwhile.
+#[must_use]
+pub fn conflict_current_todo(error: &ServerFnError) -> Option<Todo> {
+    let (_, current) = conflict_message(error)?.split_once(CONFLICT_CURRENT_MARKER)?;
+    serde_json::from_str(current).ok()
+}
+
+/// Maximum size of a title in bytes; 200 characters of up to 4 bytes each.
+#[cfg(feature = "ssr")]
+const MAX_TITLE_BYTES: usize = 800;
+
+/// Maximum size of a description in bytes; 1000 characters of up to 4 bytes each.
+#[cfg(feature = "ssr")]
+const MAX_DESCRIPTION_BYTES: usize = 4000;
+
+/// Maximum size of all tags together in bytes.
+#[cfg(feature = "ssr")]
+const MAX_TAGS_BYTES: usize = 2000;
+
+/// Todos a single user may create per `CREATE_RATE_WINDOW`.
+#[cfg(feature = "ssr")]
+const MAX_CREATES_PER_WINDOW: u32 = 30;
+
+#[cfg(feature = "ssr")]
+const CREATE_RATE_WINDOW: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Todos created per username: count and start of the current window.
+#[cfg(feature = "ssr")]
+static CREATE_COUNTS: std::sync::LazyLock<
+    std::sync::Mutex<std::collections::HashMap<String, (u32, std::time::Instant)>>,
+> = std::sync::LazyLock::new(|| std::sync::Mutex::new(std::collections::HashMap::new()));
+
+/// Rejects todos whose text fields exceed the byte limits, before validation
+/// walks over them.
+#[cfg(feature = "ssr")]
+pub(crate) fn ensure_within_size_limits(todo: &Todo) -> Result<(), ServerFnError> {
+    let too_large = |field: &str, len: usize, max: usize| {
+        ServerFnError::new(format!(
+            "{TOO_LARGE_ERROR}: {field} is {len} bytes, the limit is {max}"
+        ))
+    };
+
+    if todo.title.len() > MAX_TITLE_BYTES {
+        return Err(too_large("title", todo.title.len(), MAX_TITLE_BYTES));
+    }
+    if let Some(description) = &todo.description {
+        if description.len() > MAX_DESCRIPTION_BYTES {
+            return Err(too_large(
+                "description",
+                description.len(),
+                MAX_DESCRIPTION_BYTES,
+            ));
+        }
+    }
+    let tags_len: usize = todo.tags.iter().map(String::len).sum();
+    if tags_len > MAX_TAGS_BYTES {
+        return Err(too_large("tags", tags_len, MAX_TAGS_BYTES));
+    }
+    Ok(())
+}
+
+/// Counts a create for `username`, failing once the per-minute limit is reached.
+#[cfg(feature = "ssr")]
+pub(crate) fn check_create_rate(username: &str) -> Result<(), ServerFnError> {
+    let mut counts = CREATE_COUNTS
+        .lock()
+        .map_err(|_| ServerFnError::new("Rate limiter unavailable"))?;
+    let now = std::time::Instant::now();
+    counts.retain(|_, (_, started)| now.duration_since(*started) < CREATE_RATE_WINDOW);
+
+    let (count, started) = counts.entry(username.to_string()).or_insert((0, now));
+    if *count >= MAX_CREATES_PER_WINDOW {
+        let retry_in = CREATE_RATE_WINDOW.saturating_sub(now.duration_since(*started));
+        return Err(ServerFnError::new(format!(
+            "{RATE_LIMITED_ERROR}: at most {MAX_CREATES_PER_WINDOW} todos per minute, try again in {}s",
+            retry_in.as_secs().max(1)
+        )));
+    }
+    *count += 1;
+    Ok(())
+}
+
+/// Answers an update that lost to a concurrent change with [`CONFLICT_ERROR`],
+/// attaching the stored version of the todo if known.
+#[cfg(feature = "ssr")]
+fn conflict_error(message: impl std::fmt::Display, current: Option<&Todo>) -> ServerFnError {
+    match current.and_then(|todo| serde_json::to_string(todo).ok()) {
+        Some(current) => ServerFnError::new(format!(
+            "{CONFLICT_ERROR} {message}{CONFLICT_CURRENT_MARKER}{current}"
+        )),
+        None => ServerFnError::new(format!("{CONFLICT_ERROR} {message}")),
+    }
+}
+
+/// Checks, sanitizes and saves an edited todo of `group_id`, the same way for
+/// [`update_todo_server`] and every item of [`bulk_update_todos_server`].
+///
+/// Fails with a [`CONFLICT_ERROR`] if the todo changed after the client loaded
+/// it. Returns the saved todo and its status before the update, `None` if it
+/// was not stored before.
+#[cfg(feature = "ssr")]
+async fn save_todo_update(
+    repository: &dyn crate::services::TodoRepository,
+    todo: Todo,
+    group_id: &str,
+    username: &str,
+) -> Result<(Todo, Option<crate::domain::todo::TodoStatus>), ServerFnError> {
+    use crate::services::cosmos::UpdateConflict;
+    use crate::utils::sanitize_string;
+    use validator::Validate;
+
+    ensure_within_size_limits(&todo)?;
+
+    todo.validate()
+        .map_err(|e| ServerFnError::new(format!("Validation error: {}", e)))?;
+
+    // Sanitize like create_todo_server; clients send back the unsanitized title
+    let todo = Todo {
+        title: sanitize_string(&todo.title),
+        description: todo.description.map(|desc| desc.trim().to_string()),
+        ..todo
+    };
+
+    let todo_id = todo.id.clone();
+    let existing = repository
+        .get(&todo_id, group_id)
+        .await
+        .map_err(|e| server_error(format!("Failed to get todo: {}", e)))?;
+
+    // The edit started from an older version than the stored one, so saving it
+    // would silently undo a change made elsewhere; 0 means the client did not say
+    if let Some(existing) = existing
+        .as_ref()
+        .filter(|existing| todo.updated_at != 0 && existing.updated_at != todo.updated_at)
+    {
+        return Err(conflict_error(
+            format!("Todo {todo_id} was changed by someone else"),
+            Some(existing),
+        ));
+    }
+
+    let stored_attachments = existing
+        .as_ref()
+        .map(|existing| existing.attachments.as_slice())
+        .unwrap_or_default();
+    let todo = Todo {
+        attachments: verified_attachments(&todo.attachments, stored_attachments, group_id)?,
+        ..todo
+    };
+    let previous_status = existing.map(|existing| existing.status);
+
+    match repository.update(todo, group_id, username).await {
+        Ok(updated) => Ok((updated, previous_status)),
+        Err(e) if e.downcast_ref::<UpdateConflict>().is_some() => {
+            let current = repository.get(&todo_id, group_id).await.ok().flatten();
+            Err(conflict_error(&e, current.as_ref()))
+        }
+        Err(e) => Err(server_error(format!("Failed to update todo: {}", e))),
+    }
+}
+
+/// The attachments to save on a todo of `group_id` for the `requested` ones.
+///
+/// Clients may only keep attachments already on the todo (`existing`) or add
+/// ones [`upload_attachment_server`] stored for the group; the stored copies are
+/// used, so a client cannot point an attachment at another URL or change its
+/// name or content type.
+#[cfg(feature = "ssr")]
+pub(crate) fn verified_attachments(
+    requested: &[Attachment],
+    existing: &[Attachment],
+    group_id: &str,
+) -> Result<Vec<Attachment>, ServerFnError> {
+    use crate::services::blob::get_blob_service;
+
+    requested
+        .iter()
+        .map(|attachment| {
+            existing
+                .iter()
+                .find(|stored| stored.url == attachment.url)
+                .cloned()
+                .or_else(|| {
+                    get_blob_service()
+                        .ok()
+                        .and_then(|blob| blob.uploaded(&attachment.url, group_id))
+                })
+                .ok_or_else(|| {
+                    ServerFnError::new(format!(
+                        "Unknown attachment {}; upload the file again",
+                        attachment.name
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Rejects ids that are not well-formed UUIDs before they reach a query.
+#[cfg(feature = "ssr")]
+fn ensure_valid_id(todo_id: &str) -> Result<(), ServerFnError> {
+    crate::domain::todo::validation::validate_uuid(todo_id)
+        .map_err(|_| ServerFnError::new(format!("Invalid todo id: {todo_id}")))
+}
+
+/// Rejects a new todo due in the past when `REJECT_PAST_DUE_DATES` is set,
+/// unless the client confirmed the date with `allow_past`.
+#[cfg(feature = "ssr")]
+pub(crate) fn ensure_due_date_allowed(todo: &Todo, allow_past: bool) -> Result<(), ServerFnError> {
+    use crate::config::get_config;
+    use crate::domain::todo::validation::validate_future_date;
+
+    let config =
+        get_config().map_err(|e| server_error(format!("Failed to get app config: {}", e)))?;
+    if !config.server.reject_past_due_dates || allow_past {
+        return Ok(());
+    }
+    match todo.due_date {
+        Some(due_date) if validate_future_date(due_date).is_err() => Err(ServerFnError::new(
+            "Due date is in the past; confirm it to create the todo anyway",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Creates a todo. A due date in the past is only accepted with `allow_past`
+/// when the server rejects past due dates; editing a todo is never affected.
 #[server(CreateTodo, "/api")]
-pub async fn create_todo_server(todo: Todo) -> Result<Todo, ServerFnError> {
-    use crate::services::cosmos::todo_repository::get_cosmos_service;
+pub async fn create_todo_server(
+    todo: Todo,
+    allow_past: Option<bool>,
+) -> Result<Todo, ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::todo_repository;
+    use crate::utils::sanitize_string;
+    use validator::Validate;
+
+    let session = require_permission(WRITE_TODOS).await?;
+    ensure_within_size_limits(&todo)?;
+    check_create_rate(&session.username)?;
+
+    // Validate input
+    todo.validate()
+        .map_err(|e| ServerFnError::new(format!("Validation error: {}", e)))?;
+    ensure_due_date_allowed(&todo, allow_past.unwrap_or(false))?;
 
-    let cosmos_service = get_cosmos_service()
-        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {}", e)))?;
+    let attachments = verified_attachments(&todo.attachments, &[], &session.group_id)?;
 
-    cosmos_service
-        .create_todo(todo)
+    // Sanitize strings; descriptions are markdown and escaped when rendered
+    let sanitized_todo = Todo {
+        title: sanitize_string(&todo.title),
+        description: todo.description.map(|desc| desc.trim().to_string()),
+        attachments,
+        ..todo
+    };
+
+    let created_todo = todo_repository()?
+        .create(sanitized_todo, &session.group_id, &session.username)
         .await
-        .map_err(|e| ServerFnError::new(format!("Failed to create todo: {}", e)))
+        .map_err(|e| server_error(format!("Failed to create todo: {}", e)))?;
+
+    tracing::info!(todo_id = %created_todo.id, "Created todo");
+
+    Ok(created_todo)
 }
 
+/// Fetches a page of todos, newest first unless `sort` says otherwise.
+///
+/// Pass the `continuation` token from the previous `TodoPage` to fetch the next
+/// page; without a token, `page` selects the page index (default 0). `page_size`
+/// defaults to the configured `AZURE_COSMOS_MAX_ITEM_COUNT` and is at most
+/// [`MAX_PAGE_SIZE`](crate::domain::todo::MAX_PAGE_SIZE). Only todos matching
+/// `filter` are queried, and `sort` orders them by a criterion, ascending if its
+/// flag is set. `refresh` bypasses the server's todo cache.
 #[server(name=GetTodos, prefix="/api")]
-pub async fn get_todos_server() -> Result<Vec<Todo>, ServerFnError> {
-    use crate::services::cosmos::todo_repository::get_cosmos_service;
+pub async fn get_todos_server(
+    page: Option<u32>,
+    page_size: Option<u32>,
+    continuation: Option<String>,
+    filter: Option<TodoFilter>,
+    sort: Option<(SortBy, bool)>,
+    refresh: Option<bool>,
+) -> Result<TodoPage, ServerFnError> {
+    use crate::api::auth::require_session;
+    use crate::config::get_config;
+    use crate::domain::todo::MAX_PAGE_SIZE;
+    use crate::services::todo_repository;
+
+    let session = require_session().await?;
+
+    let config =
+        get_config().map_err(|e| server_error(format!("Failed to get app config: {}", e)))?;
+    let page_size = page_size
+        .unwrap_or(config.cosmos.max_item_count)
+        .clamp(1, MAX_PAGE_SIZE);
 
-    let cosmos_service = get_cosmos_service()
-        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {}", e)))?;
+    let mut filter = filter.unwrap_or_default();
+    filter.search = filter
+        .search
+        .map(|search| search.trim().to_string())
+        .filter(|search| !search.is_empty());
 
-    let cosmos_todos = cosmos_service
-        .get_todos()
+    let repository = todo_repository()?;
+    if refresh == Some(true) {
+        repository.invalidate(&session.group_id);
+    }
+
+    repository
+        .page(
+            &session.group_id,
+            &filter,
+            sort,
+            page.unwrap_or(0),
+            page_size,
+            continuation.as_deref(),
+        )
         .await
-        .map_err(|e| ServerFnError::new(format!("Failed to get todos: {}", e)))?;
-    let todos: Vec<Todo> = cosmos_todos.into_iter().map(Todo::from).collect();
+        .map_err(|e| server_error(format!("Failed to get todos: {}", e)))
+}
 
-    Ok(todos)
+/// Searches todo titles, descriptions, assignees and tags for `query`, ignoring
+/// case, and returns the first page of matches, newest first.
+///
+/// A blank query returns the first page of all todos. The home page passes its
+/// search box in the `filter` of [`get_todos_server`] instead, so the search
+/// combines with the other filters and pages like the rest of the list.
+#[server(SearchTodos, "/api")]
+pub async fn search_todos_server(query: String) -> Result<TodoPage, ServerFnError> {
+    let filter = TodoFilter {
+        search: Some(query),
+        ..TodoFilter::default()
+    };
+    get_todos_server(None, None, None, Some(filter), None, None).await
+}
+
+/// Fetches a single todo by id, or `None` if it does not exist or is in the trash.
+#[server(GetTodo, "/api")]
+pub async fn get_todo_server(id: String) -> Result<Option<Todo>, ServerFnError> {
+    use crate::api::auth::require_session;
+    use crate::services::todo_repository;
+
+    let session = require_session().await?;
+    ensure_valid_id(&id)?;
+
+    todo_repository()?
+        .get(&id, &session.group_id)
+        .await
+        .map_err(|e| server_error(format!("Failed to get todo: {}", e)))
 }
 
 #[server(UpdateTodo, "/api")]
 pub async fn update_todo_server(todo: Todo) -> Result<Todo, ServerFnError> {
-    use crate::services::cosmos::todo_repository::get_cosmos_service;
+    use crate::api::auth::require_permission;
+    use crate::config::get_config;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::todo_repository;
+    use crate::services::webhook::notify_if_completed;
+    use axum::http::StatusCode;
+    use leptos_axum::ResponseOptions;
+
+    let session = require_permission(WRITE_TODOS).await?;
+
+    let repository = todo_repository()?;
+    let (updated, previous_status) = save_todo_update(
+        repository.as_ref(),
+        todo,
+        &session.group_id,
+        &session.username,
+    )
+    .await
+    .inspect_err(|e| {
+        if is_conflict(e) {
+            if let Some(response) = use_context::<ResponseOptions>() {
+                response.set_status(StatusCode::CONFLICT);
+            }
+        }
+    })?;
+
+    if let Some(previous_status) = previous_status {
+        let webhook_url = get_config()
+            .map(|config| config.webhook_url.clone())
+            .unwrap_or_default();
+        notify_if_completed(&webhook_url, previous_status, &updated);
+    }
+    Ok(updated)
+}
+
+/// Counts todos by status and assignee, including how many are overdue.
+#[server(GetTodoStats, "/api")]
+pub async fn get_todo_stats_server() -> Result<TodoStats, ServerFnError> {
+    use crate::api::auth::require_session;
+    use crate::services::todo_repository;
+
+    let session = require_session().await?;
+
+    let todos = todo_repository()?
+        .list(&session.group_id)
+        .await
+        .map_err(|e| server_error(format!("Failed to get todos: {}", e)))?;
+
+    Ok(TodoStats::from_todos(&todos))
+}
+
+/// Exports every todo that is not in the trash as CSV.
+///
+/// Columns: id, title, description, due_date, assignee, status, created_at.
+#[server(ExportTodosCsv, "/api")]
+pub async fn export_todos_csv_server() -> Result<String, ServerFnError> {
+    use crate::api::auth::require_session;
+    use crate::services::todo_repository;
+    use crate::utils::todos_to_csv;
+
+    let session = require_session().await?;
+
+    let todos = todo_repository()?
+        .list(&session.group_id)
+        .await
+        .map_err(|e| server_error(format!("Failed to get todos: {}", e)))?;
+
+    Ok(todos_to_csv(&todos))
+}
+
+/// Exports the todos that have a due date as an iCalendar feed.
+#[server(ExportTodosIcal, "/api")]
+pub async fn export_todos_ical_server() -> Result<String, ServerFnError> {
+    use crate::api::auth::require_session;
+    use crate::services::todo_repository;
+    use crate::utils::todos_to_ical;
 
-    let cosmos_service = get_cosmos_service()
-        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {}", e)))?;
+    let session = require_session().await?;
 
-    let cosmos_todo = cosmos_service
-        .update_todo(todo)
+    let todos = todo_repository()?
+        .list(&session.group_id)
         .await
-        .map_err(|e| ServerFnError::new(format!("Failed to update todo: {}", e)))?;
+        .map_err(|e| server_error(format!("Failed to get todos: {}", e)))?;
 
-    Ok(Todo::from(cosmos_todo))
+    Ok(todos_to_ical(&todos))
+}
+
+/// Appends a comment by the signed-in user to a todo, returning the updated todo.
+#[server(AddComment, "/api")]
+pub async fn add_comment_server(todo_id: String, text: String) -> Result<Todo, ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::domain::todo::Comment;
+    use crate::services::todo_repository;
+    use validator::Validate;
+
+    let session = require_permission(WRITE_TODOS).await?;
+    ensure_valid_id(&todo_id)?;
+
+    let comment = Comment {
+        author: session.username,
+        text: text.trim().to_string(),
+        created_at: chrono::Utc::now()
+            .timestamp()
+            .max(0)
+            .try_into()
+            .unwrap_or(0),
+    };
+    comment
+        .validate()
+        .map_err(|e| ServerFnError::new(format!("Validation error: {}", e)))?;
+
+    todo_repository()?
+        .add_comment(&todo_id, &session.group_id, comment)
+        .await
+        .map_err(|e| server_error(format!("Failed to add comment: {}", e)))
+}
+
+/// Uploads a file to Azure Blob Storage and returns the attachment to add to a todo.
+///
+/// `data_base64` is the standard base64 encoding of the file. Size and content
+/// type limits are enforced by the blob service.
+#[server(name = UploadAttachment, prefix = "/api", input = Json)]
+pub async fn upload_attachment_server(
+    file_name: String,
+    content_type: String,
+    data_base64: String,
+) -> Result<Attachment, ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::blob::get_blob_service;
+    use base64::Engine;
+
+    let session = require_permission(WRITE_TODOS).await?;
+
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(data_base64)
+        .map_err(|e| ServerFnError::new(format!("Invalid file data: {}", e)))?;
+
+    let blob_service = get_blob_service()
+        .map_err(|e| ServerFnError::new(format!("Attachments are not available: {}", e)))?;
+
+    blob_service
+        .upload(&session.group_id, file_name.trim(), &content_type, data)
+        .await
+        .map_err(|e| server_error(format!("Failed to upload attachment: {}", e)))
+}
+
+/// Updates several todos one after another.
+///
+/// A failing item does not stop the rest; the outcome of each item is returned
+/// in the same order as the input. Every todo completed by the update triggers
+/// the completion webhook, as with a single update.
+#[server(BulkUpdateTodos, "/api")]
+pub async fn bulk_update_todos_server(
+    todos: Vec<Todo>,
+) -> Result<Vec<BulkItemResult>, ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::config::get_config;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::todo_repository;
+    use crate::services::webhook::notify_if_completed;
+
+    let session = require_permission(WRITE_TODOS).await?;
+
+    let repository = todo_repository()?;
+    let webhook_url = get_config()
+        .map(|config| config.webhook_url.clone())
+        .unwrap_or_default();
+
+    let mut results = Vec::with_capacity(todos.len());
+    for todo in todos {
+        let id = todo.id.clone();
+        let error = match save_todo_update(
+            repository.as_ref(),
+            todo,
+            &session.group_id,
+            &session.username,
+        )
+        .await
+        {
+            Ok((updated, previous_status)) => {
+                if let Some(previous_status) = previous_status {
+                    notify_if_completed(&webhook_url, previous_status, &updated);
+                }
+                None
+            }
+            Err(e) => Some(e.to_string()),
+        };
+        results.push(BulkItemResult { id, error });
+    }
+
+    Ok(results)
+}
+
+/// Creates todos from a JSON array, e.g. one produced by another instance.
+///
+/// Malformed JSON rejects the whole batch. Otherwise each todo is checked like a
+/// created one and saved on its own under a new id: invalid or too large todos
+/// are skipped, while save failures and todos over the create rate limit are
+/// counted as failed, without stopping the rest of the import.
+#[server(ImportTodos, "/api")]
+pub async fn import_todos_server(json: String) -> Result<ImportSummary, ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::todo_repository;
+    use crate::utils::sanitize_string;
+    use validator::Validate;
+
+    let session = require_permission(WRITE_TODOS).await?;
+
+    let todos: Vec<Todo> = serde_json::from_str(&json)
+        .map_err(|e| ServerFnError::new(format!("Invalid import file: {e}")))?;
+
+    let repository = todo_repository()?;
+
+    let mut summary = ImportSummary::default();
+    for todo in todos {
+        let id = todo.id.clone();
+        let checked = ensure_within_size_limits(&todo).and_then(|()| {
+            todo.validate()
+                .map_err(|e| ServerFnError::new(format!("Validation error: {e}")))
+        });
+        if let Err(e) = checked {
+            summary.skipped += 1;
+            summary.errors.push(BulkItemResult {
+                id,
+                error: Some(e.to_string()),
+            });
+            continue;
+        }
+        if let Err(e) = check_create_rate(&session.username) {
+            summary.failed += 1;
+            summary.errors.push(BulkItemResult {
+                id,
+                error: Some(e.to_string()),
+            });
+            continue;
+        }
+
+        // Only the content is imported: the todo gets a new id, and the fields
+        // the server maintains start over. Comments and attachments belong to
+        // another instance, and attachments may point at anything at all
+        let sanitized_todo = Todo {
+            description: todo.description.map(|desc| desc.trim().to_string()),
+            due_date: todo.due_date,
+            status: todo.status,
+            priority: todo.priority,
+            tags: todo.tags,
+            ..Todo::new(sanitize_string(&todo.title), todo.assignee)
+        };
+        match repository
+            .create(sanitized_todo, &session.group_id, &session.username)
+            .await
+        {
+            Ok(_) => summary.imported += 1,
+            Err(e) => {
+                summary.failed += 1;
+                summary.errors.push(BulkItemResult {
+                    id,
+                    error: Some(format!("Failed to create todo: {e}")),
+                });
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Moves several todos to the trash, reporting the outcome of each item.
+#[server(BulkDeleteTodos, "/api")]
+pub async fn bulk_delete_todos_server(
+    todo_ids: Vec<String>,
+) -> Result<Vec<BulkItemResult>, ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::todo_repository;
+
+    let session = require_permission(WRITE_TODOS).await?;
+
+    let repository = todo_repository()?;
+
+    let mut results = Vec::with_capacity(todo_ids.len());
+    for id in todo_ids {
+        let error = match ensure_valid_id(&id) {
+            Ok(()) => repository
+                .delete(&id, &session.group_id)
+                .await
+                .err()
+                .map(|e| format!("Failed to delete todo: {e}")),
+            Err(e) => Some(e.to_string()),
+        };
+        results.push(BulkItemResult { id, error });
+    }
+
+    Ok(results)
 }
 
 #[server(DeleteTodo, "/api")]
 pub async fn delete_todo_server(todo_id: String) -> Result<(), ServerFnError> {
-    use crate::services::cosmos::todo_repository::get_cosmos_service;
+    use crate::api::auth::require_permission;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::todo_repository;
 
-    let cosmos_service = get_cosmos_service()
-        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {}", e)))?;
+    let session = require_permission(WRITE_TODOS).await?;
+    ensure_valid_id(&todo_id)?;
 
-    cosmos_service
-        .delete_todo(&todo_id)
+    todo_repository()?
+        .delete(&todo_id, &session.group_id)
         .await
-        .map_err(|e| ServerFnError::new(format!("Failed to delete todo: {}", e)))?;
+        .map_err(|e| server_error(format!("Failed to delete todo: {}", e)))?;
 
     Ok(())
 }
+
+#[server(GetDeletedTodos, "/api")]
+pub async fn get_deleted_todos_server() -> Result<Vec<Todo>, ServerFnError> {
+    use crate::api::auth::require_session;
+    use crate::services::todo_repository;
+
+    let session = require_session().await?;
+
+    todo_repository()?
+        .list_deleted(&session.group_id)
+        .await
+        .map_err(|e| server_error(format!("Failed to get deleted todos: {}", e)))
+}
+
+#[server(RestoreTodo, "/api")]
+pub async fn restore_todo_server(todo_id: String) -> Result<(), ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::todo_repository;
+
+    let session = require_permission(WRITE_TODOS).await?;
+    ensure_valid_id(&todo_id)?;
+
+    todo_repository()?
+        .restore(&todo_id, &session.group_id)
+        .await
+        .map_err(|e| server_error(format!("Failed to restore todo: {}", e)))
+}
+
+#[server(PurgeTodo, "/api")]
+pub async fn purge_todo_server(todo_id: String) -> Result<(), ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::todo_repository;
+
+    let session = require_permission(WRITE_TODOS).await?;
+    ensure_valid_id(&todo_id)?;
+
+    todo_repository()?
+        .purge(&todo_id, &session.group_id)
+        .await
+        .map_err(|e| server_error(format!("Failed to permanently delete todo: {}", e)))
+}
+
+/// Reassigns every todo of `from`, including those in the trash, to `to`, e.g.
+/// when someone leaves the household, and returns how many were changed.
+///
+/// Both must be configured assignees, so reassign before removing someone
+/// from the configuration.
+#[server(ReassignTodos, "/api")]
+pub async fn reassign_todos_server(from: String, to: String) -> Result<usize, ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::config::get_config;
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::todo_repository;
+
+    let session = require_permission(WRITE_TODOS).await?;
+
+    let config =
+        get_config().map_err(|e| server_error(format!("Failed to get app config: {}", e)))?;
+    for name in [&from, &to] {
+        if !config
+            .emails
+            .names()
+            .any(|configured| configured == name.as_str())
+        {
+            return Err(ServerFnError::new(format!("Unknown assignee: {name}")));
+        }
+    }
+    if from == to {
+        return Err(ServerFnError::new(
+            "Todos can only be reassigned to someone else",
+        ));
+    }
+
+    todo_repository()?
+        .reassign(&session.group_id, &from, &to, &session.username)
+        .await
+        .map_err(|e| server_error(format!("Failed to reassign todos: {}", e)))
+}
+
+/// Permanently deletes every todo of the session's group, including the trash,
+/// and returns how many were deleted.
+///
+/// Only for clearing test data: refused unless the server runs in the
+/// development or staging environment.
+#[server(WipeAllTodos, "/api")]
+pub async fn wipe_all_todos_server() -> Result<usize, ServerFnError> {
+    use crate::api::auth::require_permission;
+    use crate::config::{Environment, get_config};
+    use crate::domain::auth::WRITE_TODOS;
+    use crate::services::todo_repository;
+
+    let session = require_permission(WRITE_TODOS).await?;
+
+    let config =
+        get_config().map_err(|e| server_error(format!("Failed to get app config: {}", e)))?;
+    tracing::warn!(
+        user = %session.username,
+        group_id = %session.group_id,
+        environment = ?config.server.environment,
+        "Wipe of all todos requested"
+    );
+    if !matches!(
+        config.server.environment,
+        Environment::Development | Environment::Staging
+    ) {
+        tracing::error!(
+            user = %session.username,
+            "Refused to wipe todos in production"
+        );
+        return Err(ServerFnError::new(
+            "Wiping all todos is disabled in production",
+        ));
+    }
+
+    todo_repository()?
+        .wipe(&session.group_id)
+        .await
+        .map_err(|e| server_error(format!("Failed to wipe todos: {}", e)))
+}
+
+#[server(GetAssignees, "/api")]
+pub async fn get_assignees_server() -> Result<Vec<String>, ServerFnError> {
+    use crate::api::auth::require_session;
+    use crate::config::get_config;
+
+    require_session().await?;
+
+    let config =
+        get_config().map_err(|e| server_error(format!("Failed to get app config: {}", e)))?;
+
+    Ok(config.emails.names().map(str::to_string).collect())
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_ids_are_accepted() {
+        assert!(ensure_valid_id("0f8fad5b-d9cb-469f-a165-70867728950e").is_ok());
+        assert!(ensure_valid_id(&uuid::Uuid::new_v4().to_string()).is_ok());
+    }
+
+    #[test]
+    fn empty_id_is_rejected() {
+        assert!(ensure_valid_id("").is_err());
+    }
+
+    #[test]
+    fn overlong_id_is_rejected() {
+        assert!(ensure_valid_id("0f8fad5b-d9cb-469f-a165-70867728950e0").is_err());
+        assert!(ensure_valid_id(&"a".repeat(10_000)).is_err());
+    }
+
+    #[test]
+    fn path_traversal_is_rejected() {
+        assert!(ensure_valid_id("../0f8fad5b-d9cb-469f-a165-70867728950e").is_err());
+        assert!(ensure_valid_id("0f8fad5b-d9cb-469f-a165-70867728950e/../x").is_err());
+    }
+
+    #[test]
+    fn quotes_are_rejected() {
+        assert!(ensure_valid_id("0f8fad5b-d9cb-469f-a165-70867728950e'").is_err());
+        assert!(ensure_valid_id("\"0f8fad5b-d9cb-469f-a165-70867728950e\"").is_err());
+    }
+
+    #[test]
+    fn sql_metacharacters_are_rejected() {
+        for id in [
+            "' OR 1=1 --",
+            "0f8fad5b-d9cb-469f-a165-70867728950e' OR '1'='1",
+            "0f8fad5b-d9cb-469f-a165-70867728950e; DROP",
+            "0f8fad5b-d9cb-469f-a165-7086772895%",
+        ] {
+            assert!(ensure_valid_id(id).is_err(), "{id} was accepted");
+        }
+    }
+
+    #[test]
+    fn rejection_names_the_id() {
+        let error = ensure_valid_id("nope").unwrap_err();
+
+        assert!(error.to_string().contains("Invalid todo id: nope"));
+    }
+
+    #[test]
+    fn conflicts_carry_the_stored_todo() {
+        let stored = Todo::new("Buy milk".to_string(), "Mike".parse().unwrap());
+        let error = conflict_error("Todo was changed by someone else", Some(&stored));
+
+        assert!(is_conflict(&error));
+        assert_eq!(conflict_current_todo(&error), Some(stored));
+        assert!(is_conflict(&conflict_error("Todo was deleted", None)));
+        assert_eq!(
+            conflict_current_todo(&conflict_error("Todo was deleted", None)),
+            None
+        );
+    }
+
+    #[test]
+    fn only_conflict_errors_are_conflicts() {
+        for error in [
+            ServerFnError::new("Failed to update todo: Conflict (409)"),
+            ServerFnError::new(format!("Failed to update todo: {CONFLICT_ERROR} nested")),
+            ServerFnError::Request(format!("{CONFLICT_ERROR} not from the server")),
+        ] {
+            assert!(!is_conflict(&error), "{error} was taken for a conflict");
+            assert_eq!(conflict_current_todo(&error), None);
+        }
+    }
+}
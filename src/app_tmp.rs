@@ -2,16 +2,19 @@
 use leptos::prelude::*;
 use leptos_meta::{Link, MetaTags, Stylesheet, Title, provide_meta_context};
 use leptos_router::{
-    StaticSegment,
+    ParamSegment, StaticSegment,
     components::{Route, Router, Routes},
 };
 
 use crate::{
-    domain::{
-        auth::{AuthProvider, use_auth},
-        todo::Todo,
+    components::{
+        session_warning::SessionExpiryWarning,
+        theme::ThemeProvider,
+        toast::{ToastProvider, Toasts},
     },
-    pages::{home::HomePage, login::LoginPage},
+    domain::auth::{AuthProvider, use_auth},
+    i18n::LangProvider,
+    pages::{home::HomePage, login::LoginPage, print::PrintPage, todo_detail::TodoDetail},
 };
 
 // Static configuration loaded once at startup
@@ -28,7 +31,7 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
                 <HydrationScripts options />
                 <MetaTags />
             </head>
-            <body class="bg-gray-100 min-h-screen">
+            <body class="bg-gray-100 min-h-screen dark:bg-gray-900 dark:text-gray-100">
                 <App />
             </body>
         </html>
@@ -59,9 +62,16 @@ pub fn App() -> impl IntoView {
         />
 
         // content for this welcome page
-        <AuthProvider>
-            <AppRoutes />
-        </AuthProvider>
+        <ThemeProvider>
+            <LangProvider>
+                <ToastProvider>
+                    <AuthProvider>
+                        <AppRoutes />
+                    </AuthProvider>
+                    <Toasts />
+                </ToastProvider>
+            </LangProvider>
+        </ThemeProvider>
     }
 }
 
@@ -80,6 +90,7 @@ fn AppRoutes() -> impl IntoView {
                 }
             }
         >
+            <SessionExpiryWarning />
             <Router>
                 <main>
                     <Routes fallback=|| "Page not found.">
@@ -94,6 +105,8 @@ fn AppRoutes() -> impl IntoView {
                             }
                         />
                         <Route path=StaticSegment("login") view=move || view! { <LoginPage /> } />
+                        // Works without a login when opened with a share token
+                        <Route path=StaticSegment("print") view=PrintPage />
                         <Route
                             path=StaticSegment("todo")
                             view=move || {
@@ -104,6 +117,16 @@ fn AppRoutes() -> impl IntoView {
                                 }
                             }
                         />
+                        <Route
+                            path=(StaticSegment("todo"), ParamSegment("id"))
+                            view=move || {
+                                if auth.is_authenticated.get() {
+                                    view! { <TodoDetail /> }.into_any()
+                                } else {
+                                    view! { <LoginPage /> }.into_any()
+                                }
+                            }
+                        />
                     </Routes>
                 </main>
             </Router>
@@ -120,108 +143,6 @@ fn Redirect(path: &'static str) -> impl IntoView {
         navigate(path, NavigateOptions::default());
     });
 } */
-// Server functions for Cosmos DB operations
-#[server(CreateTodo, "/api")]
-pub async fn create_todo_server(todo: Todo) -> Result<Todo, ServerFnError> {
-    use crate::services::cosmos::todo_repository::get_cosmos_service;
-    use leptos::logging;
-    use validator::Validate;
-
-    // Validate input
-    todo.validate()
-        .map_err(|e| ServerFnError::new(format!("Validation error: {}", e)))?;
-
-    // Sanitize strings
-    #[allow(unused_variables)]
-    let sanitized_todo = Todo {
-        title: sanitize_string(&todo.title),
-        description: todo.description.map(|desc| sanitize_string(&desc)),
-        ..todo
-    };
-
-    // Initialize DB on first access
-    let cosmos_service = get_cosmos_service()
-        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
-
-    let cosmos_todo = cosmos_service
-        .create_todo(sanitized_todo)
-        .await
-        .map_err(|e| ServerFnError::new(format!("Failed to create todo: {e}")))?;
-
-    logging::log!("Created todo in Cosmos DB: {:?}", cosmos_todo);
-
-    Ok(Todo::from(cosmos_todo))
-}
-
-#[server(name=GetTodos, prefix="/api")]
-pub async fn get_todos_server() -> Result<Vec<Todo>, ServerFnError> {
-    use crate::services::cosmos::todo_repository::get_cosmos_service;
-    use leptos::logging;
-
-    let cosmos_service = get_cosmos_service()
-        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
-
-    let cosmos_todos = cosmos_service
-        .get_todos()
-        .await
-        .map_err(|e| ServerFnError::new(format!("Failed to get todos: {e}")))?;
-
-    let todos: Vec<Todo> = cosmos_todos.into_iter().map(Todo::from).collect();
-
-    logging::log!("Retrieved {} todos from Cosmos DB", todos.len());
-
-    Ok(todos)
-}
-
-#[server(UpdateTodo, "/api")]
-pub async fn update_todo_server(todo: Todo) -> Result<Todo, ServerFnError> {
-    use crate::services::cosmos::todo_repository::get_cosmos_service;
-    use leptos::logging;
-
-    let cosmos_service = get_cosmos_service()
-        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
-
-    let cosmos_todo = cosmos_service
-        .update_todo(todo)
-        .await
-        .map_err(|e| ServerFnError::new(format!("Failed to update todo: {e}")))?;
-
-    logging::log!("Updated todo in Cosmos DB: {:?}", cosmos_todo);
-
-    Ok(Todo::from(cosmos_todo))
-}
-
-#[server(DeleteTodo, "/api")]
-pub async fn delete_todo_server(todo_id: String) -> Result<(), ServerFnError> {
-    use crate::services::cosmos::todo_repository::get_cosmos_service;
-    use leptos::logging;
-
-    let cosmos_service = get_cosmos_service()
-        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
-
-    cosmos_service
-        .delete_todo(&todo_id)
-        .await
-        .map_err(|e| ServerFnError::new(format!("Failed to delete todo: {e}")))?;
-
-    logging::log!("Deleted todo from Cosmos DB: {todo_id}");
-
-    Ok(())
-}
-
-#[allow(dead_code)]
-fn sanitize_string(input: &str) -> String {
-    // Remove potential HTML/script tags and normalize whitespace
-    input
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('&', "&amp;")
-        .replace('"', "&quot;")
-        .replace('\'', "&#x27;")
-        .trim()
-        .to_string()
-}
-
 #[server(HeartbeatServer, "/api")]
 pub async fn heartbeat_server() -> Result<String, ServerFnError> {
     Ok("alive".to_string())
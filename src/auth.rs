@@ -34,7 +34,7 @@ pub fn get_auth_state() -> Option<AuthState> {
     let window = window()?;
     let storage = window.local_storage().ok()??;
 
-    match storage.get_item("auth_state") {
+    match storage.get_item(&crate::config::storage_key("auth_state")) {
         Ok(Some(auth_state_str)) => {
             match serde_json::from_str(&auth_state_str) {
                 Ok(auth_state) => {
@@ -47,7 +47,7 @@ pub fn get_auth_state() -> Option<AuthState> {
                         e
                     ));
                     // Clear corrupted data
-                    let _ = storage.remove_item("auth_state");
+                    let _ = storage.remove_item(&crate::config::storage_key("auth_state"));
                     None
                 }
             }
@@ -69,7 +69,7 @@ pub fn store_auth_state(auth_state: &AuthState) {
     if let Some(window) = web_sys::window() {
         if let Ok(Some(storage)) = window.local_storage() {
             match serde_json::to_string(auth_state) {
-                Ok(auth_state_str) => match storage.set_item("auth_state", &auth_state_str) {
+                Ok(auth_state_str) => match storage.set_item(&crate::config::storage_key("auth_state"), &auth_state_str) {
                     Ok(_) => {
                         logging::console_log(&format!(
                             "Successfully stored auth state in localStorage: authenticated={:#?}",
@@ -95,7 +95,7 @@ pub fn store_auth_state(auth_state: &AuthState) {
 pub fn remove_auth_state() {
     if let Some(window) = web_sys::window() {
         if let Ok(Some(storage)) = window.local_storage() {
-            match storage.remove_item("auth_state") {
+            match storage.remove_item(&crate::config::storage_key("auth_state")) {
                 Ok(_) => {
                     leptos::logging::log!("Successfully removed auth state from localStorage");
                 }
@@ -0,0 +1,26 @@
+use leptos::prelude::*;
+
+use crate::domain::todo::TodoAssignee;
+
+/// Small circle with an assignee's initials on their color, see
+/// [`TodoAssignee::avatar_color`].
+///
+/// The full name is the tooltip and is read out to screen readers.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn Avatar(assignee: TodoAssignee) -> impl IntoView {
+    let name = assignee.as_str().to_string();
+
+    view! {
+        <span
+            class=format!(
+                "inline-flex items-center justify-center w-7 h-7 rounded-full text-xs font-semibold shrink-0 {}",
+                assignee.avatar_color(),
+            )
+            title=name.clone()
+        >
+            <span aria-hidden="true">{assignee.initials()}</span>
+            <span class="sr-only">{name}</span>
+        </span>
+    }
+}
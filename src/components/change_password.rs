@@ -0,0 +1,172 @@
+use leptos::prelude::*;
+use validator::Validate;
+
+use crate::api::change_password_server;
+use crate::components::modal::Modal;
+use crate::components::toast::use_toasts;
+use crate::domain::auth::ChangePasswordRequest;
+use crate::i18n::use_lang;
+
+/// Id of the dialog's heading, which names it.
+const TITLE_ID: &str = "change-password-title";
+
+const INPUT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent";
+
+/// Dialog for changing the login password.
+///
+/// On success the dialog closes and a toast reports how many other sessions
+/// were signed out; the current session stays signed in.
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+pub fn ChangePasswordDialog(#[prop(into)] on_close: Callback<()>) -> impl IntoView {
+    let lang = use_lang();
+    let toasts = use_toasts();
+
+    let (current_password, set_current_password) = signal(String::new());
+    let (new_password, set_new_password) = signal(String::new());
+    let (confirm_password, set_confirm_password) = signal(String::new());
+    let (error_message, set_error_message) = signal(String::new());
+
+    let change_action = Action::new(move |request: &ChangePasswordRequest| {
+        let request = request.clone();
+        async move { change_password_server(request).await }
+    });
+
+    Effect::new(move |_| {
+        let Some(result) = change_action.value().get() else {
+            return;
+        };
+        let locale = lang.locale.get_untracked();
+        match result {
+            Ok(invalidated) => {
+                toasts.show(locale.t_with("password.changed", &[("count", &invalidated)]));
+                on_close.run(());
+            }
+            Err(e) => {
+                set_error_message.set(locale.t_with("password.failed", &[("error", &e)]));
+            }
+        }
+    });
+
+    let handle_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let locale = lang.locale.get_untracked();
+
+        let request = ChangePasswordRequest {
+            current_password: current_password.get_untracked(),
+            new_password: new_password.get_untracked(),
+        };
+        if request.new_password != confirm_password.get_untracked() {
+            set_error_message.set(locale.t("password.mismatch").to_string());
+            return;
+        }
+        if request.validate().is_err() {
+            set_error_message.set(locale.t("password.too_weak").to_string());
+            return;
+        }
+
+        set_error_message.set(String::new());
+        change_action.dispatch(request);
+    };
+
+    view! {
+        <Modal
+            labelled_by=TITLE_ID
+            class="bg-white dark:bg-gray-800 rounded-2xl shadow-2xl w-full max-w-md p-6"
+            on_close=on_close
+        >
+            <div class="flex justify-between items-center mb-6">
+                <h2 id=TITLE_ID class="text-2xl font-bold text-gray-900 dark:text-gray-100">
+                    {move || lang.t("password.change")}
+                </h2>
+                <button
+                    type="button"
+                    on:click=move |_| on_close.run(())
+                    class="text-gray-500 dark:text-gray-400 hover:text-gray-700 text-2xl leading-none"
+                    aria-label=move || lang.t("common.close")
+                >
+                    "×"
+                </button>
+            </div>
+
+            <form on:submit=handle_submit>
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("password.current")}
+                    </label>
+                    <input
+                        type="password"
+                        autocomplete="current-password"
+                        prop:value=move || current_password.get()
+                        on:input=move |ev| set_current_password.set(event_target_value(&ev))
+                        class=INPUT_CLASS
+                        required
+                    />
+                </div>
+
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("password.new")}
+                    </label>
+                    <input
+                        type="password"
+                        autocomplete="new-password"
+                        prop:value=move || new_password.get()
+                        on:input=move |ev| set_new_password.set(event_target_value(&ev))
+                        class=INPUT_CLASS
+                        required
+                    />
+                    <p class="mt-1 text-xs text-gray-500 dark:text-gray-400">
+                        {move || lang.t("password.requirements")}
+                    </p>
+                </div>
+
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("password.confirm")}
+                    </label>
+                    <input
+                        type="password"
+                        autocomplete="new-password"
+                        prop:value=move || confirm_password.get()
+                        on:input=move |ev| set_confirm_password.set(event_target_value(&ev))
+                        class=INPUT_CLASS
+                        required
+                    />
+                </div>
+
+                <Show when=move || !error_message.get().is_empty()>
+                    <p class="mb-4 text-sm text-red-600 dark:text-red-400">
+                        {move || error_message.get()}
+                    </p>
+                </Show>
+
+                <div class="flex justify-end gap-3">
+                    <button
+                        type="button"
+                        on:click=move |_| on_close.run(())
+                        class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                    >
+                        {move || lang.t("common.cancel")}
+                    </button>
+                    <button
+                        type="submit"
+                        class="px-4 py-2 bg-gradient-to-r from-purple-500 to-fuchsia-500 text-white rounded-lg hover:from-purple-600 hover:to-fuchsia-600 transition-all duration-200 disabled:opacity-50 disabled:cursor-not-allowed"
+                        disabled=move || change_action.pending().get()
+                    >
+                        {move || {
+                            lang.t(
+                                if change_action.pending().get() {
+                                    "password.changing"
+                                } else {
+                                    "password.change"
+                                },
+                            )
+                        }}
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
@@ -0,0 +1,61 @@
+use leptos::prelude::*;
+use leptos::web_sys;
+
+use crate::api::wipe_all_todos_server;
+use crate::components::toast::use_toasts;
+use crate::i18n::use_lang;
+
+/// Panel of development helpers, rendered only in debug builds.
+///
+/// "Wipe all todos" permanently deletes every todo of the group after a
+/// confirmation; the server refuses it in production. `on_wiped` is called
+/// afterwards so the list can be reloaded.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn DevTools(#[prop(into)] on_wiped: Callback<()>) -> impl IntoView {
+    let lang = use_lang();
+    let toasts = use_toasts();
+
+    let wipe_action = Action::new(move |(): &()| async move { wipe_all_todos_server().await });
+
+    Effect::new(move |_| {
+        let Some(result) = wipe_action.value().get() else {
+            return;
+        };
+        let locale = lang.locale.get_untracked();
+        match result {
+            Ok(count) => {
+                toasts.show(locale.t_with("dev.wiped", &[("count", &count)]));
+                on_wiped.run(());
+            }
+            Err(e) => toasts.show(locale.t_with("dev.wipe_failed", &[("error", &e)])),
+        }
+    });
+
+    let wipe = move |_| {
+        let message = lang.locale.get_untracked().t("dev.confirm_wipe");
+        let confirmed = web_sys::window()
+            .is_some_and(|window| window.confirm_with_message(message).unwrap_or(false));
+        if confirmed {
+            wipe_action.dispatch(());
+        }
+    };
+
+    view! {
+        <section class="mt-6 p-4 rounded-xl border-2 border-dashed border-red-300 dark:border-red-700">
+            <h2 class="mb-2 text-sm font-semibold text-red-700 dark:text-red-400">
+                {move || lang.t("dev.title")}
+            </h2>
+            <button
+                type="button"
+                on:click=wipe
+                class="px-3 py-1.5 text-sm bg-red-600 text-white rounded-lg hover:bg-red-700 transition-colors disabled:opacity-50 disabled:cursor-not-allowed"
+                disabled=move || wipe_action.pending().get()
+            >
+                {move || {
+                    lang.t(if wipe_action.pending().get() { "dev.wiping" } else { "dev.wipe" })
+                }}
+            </button>
+        </section>
+    }
+}
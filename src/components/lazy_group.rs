@@ -0,0 +1,106 @@
+use leptos::html;
+use leptos::prelude::*;
+
+/// How far outside the viewport a group stays mounted, so its cards are in
+/// place before they scroll into view.
+#[cfg(feature = "hydrate")]
+const MOUNT_MARGIN: &str = "800px 0px";
+
+/// Renders its children only while they are on or near the screen.
+///
+/// Off screen the children are unmounted and replaced by empty space of the
+/// height they last had, or `estimated_height` before their first render, so
+/// the page keeps its scroll position. Visibility is tracked with an
+/// `IntersectionObserver` on the viewport, which is the page's scroll container.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn LazyGroup(
+    /// Height in pixels to reserve until the children have been rendered once
+    estimated_height: f64,
+    /// Render the children right away, e.g. for the groups at the top of the page,
+    /// which the server renders too
+    #[prop(optional)]
+    eager: bool,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let node_ref = NodeRef::<html::Div>::new();
+    let (is_near, set_near) = signal(eager);
+    let (height, set_height) = signal(estimated_height);
+
+    observe_viewport(node_ref, set_near, set_height);
+
+    view! {
+        <div node_ref=node_ref>
+            <Show
+                when=move || is_near.get()
+                fallback=move || view! { <div style=move || format!("height: {}px", height.get())></div> }
+            >
+                {children()}
+            </Show>
+        </div>
+    }
+}
+
+/// Keeps `set_near` in sync with whether the element is within
+/// [`MOUNT_MARGIN`] of the viewport, measuring its height as it leaves.
+#[cfg(feature = "hydrate")]
+fn observe_viewport(
+    node_ref: NodeRef<html::Div>,
+    set_near: WriteSignal<bool>,
+    set_height: WriteSignal<f64>,
+) {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen::closure::Closure;
+    use web_sys::js_sys::Array;
+    use web_sys::{IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+    let observer = StoredValue::new_local(None::<(IntersectionObserver, Closure<dyn Fn(Array)>)>);
+
+    Effect::new(move |_| {
+        let Some(element) = node_ref.get() else {
+            return;
+        };
+
+        let callback = Closure::<dyn Fn(Array)>::new(move |entries: Array| {
+            for entry in entries.iter() {
+                let entry: IntersectionObserverEntry = entry.unchecked_into();
+                if !entry.is_intersecting() {
+                    // Measured while still mounted, so the placeholder matches
+                    set_height.set(entry.bounding_client_rect().height());
+                }
+                set_near.set(entry.is_intersecting());
+            }
+        });
+        let options = IntersectionObserverInit::new();
+        options.set_root_margin(MOUNT_MARGIN);
+
+        match IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &options) {
+            Ok(new_observer) => {
+                new_observer.observe(&element);
+                observer.set_value(Some((new_observer, callback)));
+            }
+            Err(e) => {
+                // Without an observer, render everything as before
+                leptos::logging::warn!("Failed to observe todo group: {:?}", e);
+                set_near.set(true);
+            }
+        }
+    });
+
+    on_cleanup(move || {
+        observer.try_update_value(|observer| {
+            if let Some((observer, _callback)) = observer.take() {
+                observer.disconnect();
+            }
+        });
+    });
+}
+
+// Server-side stub; the server renders the eager groups only
+#[cfg(not(feature = "hydrate"))]
+fn observe_viewport(
+    _node_ref: NodeRef<html::Div>,
+    _set_near: WriteSignal<bool>,
+    _set_height: WriteSignal<f64>,
+) {
+}
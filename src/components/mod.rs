@@ -1 +1,11 @@
+pub mod avatar;
+pub mod change_password;
+pub mod dev_tools;
+pub mod lazy_group;
+pub mod modal;
+pub mod reassign;
+pub mod session_warning;
 pub mod status_bar;
+pub mod theme;
+pub mod toast;
+pub mod todo_dialog;
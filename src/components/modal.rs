@@ -0,0 +1,153 @@
+use leptos::html;
+use leptos::prelude::*;
+
+/// Elements that can take keyboard focus inside a dialog.
+#[cfg(feature = "hydrate")]
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+    select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+/// Modal dialog over a dimmed backdrop.
+///
+/// Focus moves into the dialog when it opens and Tab and Shift+Tab cycle
+/// within it; Escape calls `on_close`. When the dialog is removed, focus goes
+/// back to the element that had it before, usually the button that opened it.
+/// The dialog is announced to screen readers by the element `labelled_by`
+/// names, normally its heading.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn Modal(
+    /// Id of the element that names the dialog
+    labelled_by: &'static str,
+    /// Classes of the dialog panel
+    class: &'static str,
+    #[prop(into)] on_close: Callback<()>,
+    children: Children,
+) -> impl IntoView {
+    let dialog_ref = NodeRef::<html::Div>::new();
+
+    trap_focus(dialog_ref);
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| match ev.key().as_str() {
+        "Escape" => {
+            ev.prevent_default();
+            on_close.run(());
+        }
+        "Tab" => cycle_focus(dialog_ref, &ev),
+        _ => {}
+    };
+
+    view! {
+        <div class="fixed inset-0 bg-black/50 flex items-center justify-center z-50 p-4">
+            <div
+                node_ref=dialog_ref
+                role="dialog"
+                aria-modal="true"
+                aria-labelledby=labelled_by
+                tabindex="-1"
+                on:keydown=on_keydown
+                class=class
+            >
+                {children()}
+            </div>
+        </div>
+    }
+}
+
+/// Focuses the first focusable element of the dialog once it is mounted, and
+/// gives focus back to the previously focused element when it is removed.
+#[cfg(feature = "hydrate")]
+fn trap_focus(dialog_ref: NodeRef<html::Div>) {
+    use wasm_bindgen::JsCast;
+    use web_sys::HtmlElement;
+
+    // Still the opening button: the dialog is not in the document yet
+    let previous_focus = StoredValue::new_local(
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.active_element())
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok()),
+    );
+
+    Effect::new(move |_| {
+        let Some(dialog) = dialog_ref.get() else {
+            return;
+        };
+        let target = focusable_elements(&dialog)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| dialog.clone().into());
+        if let Err(e) = target.focus() {
+            leptos::logging::warn!("Failed to focus the dialog: {:?}", e);
+        }
+    });
+
+    on_cleanup(move || {
+        if let Some(Some(element)) = previous_focus.try_get_value() {
+            // The opener may have been re-rendered meanwhile; then this does nothing
+            let _ = element.focus();
+        }
+    });
+}
+
+/// Keeps Tab within the dialog by wrapping around at its first and last
+/// focusable elements.
+#[cfg(feature = "hydrate")]
+fn cycle_focus(dialog_ref: NodeRef<html::Div>, ev: &leptos::ev::KeyboardEvent) {
+    use web_sys::{Element, HtmlElement, Node};
+
+    let Some(dialog) = dialog_ref.get_untracked() else {
+        return;
+    };
+    let elements = focusable_elements(&dialog);
+    let (Some(first), Some(last)) = (elements.first(), elements.last()) else {
+        // Nothing to move to; keep focus on the dialog itself
+        ev.prevent_default();
+        return;
+    };
+
+    let active = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.active_element());
+    let is_active = |element: &HtmlElement| {
+        let element: &Element = element.as_ref();
+        active.as_ref() == Some(element)
+    };
+    let dialog_element: &Element = dialog.as_ref();
+    let is_outside = active.as_ref().is_none_or(|active| {
+        active == dialog_element || !dialog.contains(Some(AsRef::<Node>::as_ref(active)))
+    });
+
+    let wrap_to = if ev.shift_key() && (is_active(first) || is_outside) {
+        Some(last)
+    } else if !ev.shift_key() && (is_active(last) || is_outside) {
+        Some(first)
+    } else {
+        None
+    };
+    if let Some(element) = wrap_to {
+        ev.prevent_default();
+        let _ = element.focus();
+    }
+}
+
+#[cfg(feature = "hydrate")]
+fn focusable_elements(dialog: &web_sys::HtmlDivElement) -> Vec<web_sys::HtmlElement> {
+    use wasm_bindgen::JsCast;
+
+    let Ok(nodes) = dialog.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        // Skip elements hidden with `display: none`, which cannot take focus
+        .filter(|element| element.offset_parent().is_some())
+        .collect()
+}
+
+// Server-side stubs; focus only exists in the browser
+#[cfg(not(feature = "hydrate"))]
+fn trap_focus(_dialog_ref: NodeRef<html::Div>) {}
+
+#[cfg(not(feature = "hydrate"))]
+fn cycle_focus(_dialog_ref: NodeRef<html::Div>, _ev: &leptos::ev::KeyboardEvent) {}
@@ -0,0 +1,184 @@
+use leptos::prelude::*;
+use leptos::web_sys;
+
+use crate::api::reassign_todos_server;
+use crate::components::modal::Modal;
+use crate::components::toast::use_toasts;
+use crate::i18n::use_lang;
+
+/// Id of the dialog's heading, which names it.
+const TITLE_ID: &str = "reassign-todos-title";
+
+const SELECT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent";
+
+/// Maintenance dialog moving every todo of one assignee to another, e.g. when
+/// someone leaves the household.
+///
+/// Asks for confirmation before running. On success a toast reports how many
+/// todos were moved, `on_reassigned` is called so the list can be reloaded and
+/// the dialog closes.
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+pub fn ReassignDialog(
+    assignees: ReadSignal<Vec<String>>,
+    #[prop(into)] on_reassigned: Callback<()>,
+    #[prop(into)] on_close: Callback<()>,
+) -> impl IntoView {
+    let lang = use_lang();
+    let toasts = use_toasts();
+
+    let (from, set_from) = signal(String::new());
+    let (to, set_to) = signal(String::new());
+    let (error_message, set_error_message) = signal(String::new());
+
+    let reassign_action = Action::new(move |(from, to): &(String, String)| {
+        let from = from.clone();
+        let to = to.clone();
+        async move {
+            let result = reassign_todos_server(from.clone(), to.clone()).await;
+            (from, to, result)
+        }
+    });
+
+    Effect::new(move |_| {
+        let Some((from, to, result)) = reassign_action.value().get() else {
+            return;
+        };
+        let locale = lang.locale.get_untracked();
+        match result {
+            Ok(count) => {
+                toasts.show(locale.t_with(
+                    "reassign.done",
+                    &[("count", &count), ("from", &from), ("to", &to)],
+                ));
+                on_reassigned.run(());
+                on_close.run(());
+            }
+            Err(e) => {
+                set_error_message.set(locale.t_with("reassign.failed", &[("error", &e)]));
+            }
+        }
+    });
+
+    let handle_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let locale = lang.locale.get_untracked();
+        let from = from.get_untracked();
+        let to = to.get_untracked();
+
+        if from.is_empty() || to.is_empty() {
+            set_error_message.set(locale.t("reassign.choose").to_string());
+            return;
+        }
+        if from == to {
+            set_error_message.set(locale.t("reassign.same").to_string());
+            return;
+        }
+        let message = locale.t_with("reassign.confirm", &[("from", &from), ("to", &to)]);
+        let confirmed = web_sys::window()
+            .is_some_and(|window| window.confirm_with_message(&message).unwrap_or(false));
+        if !confirmed {
+            return;
+        }
+
+        set_error_message.set(String::new());
+        reassign_action.dispatch((from, to));
+    };
+
+    let options = move || {
+        assignees
+            .get()
+            .into_iter()
+            .map(|name| view! { <option value=name.clone()>{name.clone()}</option> })
+            .collect_view()
+    };
+
+    view! {
+        <Modal
+            labelled_by=TITLE_ID
+            class="bg-white dark:bg-gray-800 rounded-2xl shadow-2xl w-full max-w-md p-6"
+            on_close=on_close
+        >
+            <div class="flex justify-between items-center mb-2">
+                <h2 id=TITLE_ID class="text-2xl font-bold text-gray-900 dark:text-gray-100">
+                    {move || lang.t("reassign.title")}
+                </h2>
+                <button
+                    type="button"
+                    on:click=move |_| on_close.run(())
+                    class="text-gray-500 dark:text-gray-400 hover:text-gray-700 text-2xl leading-none"
+                    aria-label=move || lang.t("common.close")
+                >
+                    "×"
+                </button>
+            </div>
+            <p class="mb-6 text-sm text-gray-600 dark:text-gray-300">
+                {move || lang.t("reassign.description")}
+            </p>
+
+            <form on:submit=handle_submit>
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("reassign.from")}
+                    </label>
+                    <select
+                        prop:value=move || from.get()
+                        on:change=move |ev| set_from.set(event_target_value(&ev))
+                        class=SELECT_CLASS
+                        required
+                    >
+                        <option value="">{move || lang.t("reassign.select")}</option>
+                        {options}
+                    </select>
+                </div>
+
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("reassign.to")}
+                    </label>
+                    <select
+                        prop:value=move || to.get()
+                        on:change=move |ev| set_to.set(event_target_value(&ev))
+                        class=SELECT_CLASS
+                        required
+                    >
+                        <option value="">{move || lang.t("reassign.select")}</option>
+                        {options}
+                    </select>
+                </div>
+
+                <Show when=move || !error_message.get().is_empty()>
+                    <p class="mb-4 text-sm text-red-600 dark:text-red-400">
+                        {move || error_message.get()}
+                    </p>
+                </Show>
+
+                <div class="flex justify-end gap-3">
+                    <button
+                        type="button"
+                        on:click=move |_| on_close.run(())
+                        class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                    >
+                        {move || lang.t("common.cancel")}
+                    </button>
+                    <button
+                        type="submit"
+                        class="px-4 py-2 bg-gradient-to-r from-purple-500 to-fuchsia-500 text-white rounded-lg hover:from-purple-600 hover:to-fuchsia-600 transition-all duration-200 disabled:opacity-50 disabled:cursor-not-allowed"
+                        disabled=move || reassign_action.pending().get()
+                    >
+                        {move || {
+                            lang.t(
+                                if reassign_action.pending().get() {
+                                    "reassign.running"
+                                } else {
+                                    "reassign.submit"
+                                },
+                            )
+                        }}
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
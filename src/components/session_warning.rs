@@ -0,0 +1,48 @@
+use leptos::prelude::*;
+
+use crate::domain::auth::use_auth;
+use crate::i18n::use_lang;
+
+/// Seconds before expiry at which the warning banner is shown.
+const WARNING_THRESHOLD_SECS: i64 = 5 * 60;
+
+/// Banner warning that the session is about to expire, with a button to extend it.
+#[component]
+#[must_use]
+#[allow(clippy::must_use_candidate)]
+pub fn SessionExpiryWarning() -> impl IntoView {
+    let auth = use_auth();
+    let lang = use_lang();
+
+    let show_warning = move || {
+        auth.is_authenticated.get()
+            && auth
+                .session_remaining
+                .get()
+                .is_some_and(|remaining| remaining < WARNING_THRESHOLD_SECS)
+    };
+
+    let format_remaining = move || {
+        let remaining = auth.session_remaining.get().unwrap_or(0);
+        format!("{}:{:02}", remaining / 60, remaining % 60)
+    };
+
+    view! {
+        <Show when=show_warning>
+            <div class="fixed top-4 left-1/2 -translate-x-1/2 z-50 bg-amber-50 border border-amber-200 rounded-xl shadow-lg px-4 py-3 flex items-center gap-4">
+                <p class="text-sm font-medium text-amber-800">
+                    {move || lang.t_with("session.expires_in", &[("time", &format_remaining())])}
+                </p>
+                <button
+                    on:click=move |_| {
+                        auth.refresh.dispatch(());
+                    }
+                    class="px-3 py-1 text-sm text-white bg-amber-600 rounded-lg hover:bg-amber-700 transition-colors disabled:opacity-50"
+                    disabled=move || auth.refresh.pending().get()
+                >
+                    {move || lang.t("session.stay_signed_in")}
+                </button>
+            </div>
+        </Show>
+    }
+}
@@ -1,28 +1,81 @@
-use chrono::Local;
+use chrono::{Local, Utc};
 use leptos::prelude::*;
+
+use crate::i18n::use_lang;
 use std::time::Duration;
 
+/// Polling intervals after one and after more consecutive failed checks, so a
+/// server that is down is not polled at the normal rate. A longer configured
+/// interval is kept as it is.
+const BACKOFF_INTERVALS: [Duration; 2] = [Duration::from_secs(60), Duration::from_secs(5 * 60)];
+
+/// Interval between checks after `failures` consecutive failed ones.
+fn backoff_interval(interval: Duration, failures: u32) -> Duration {
+    let step = usize::try_from(failures).unwrap_or(usize::MAX);
+    match step.checked_sub(1) {
+        None => interval,
+        Some(index) => interval.max(BACKOFF_INTERVALS[index.min(BACKOFF_INTERVALS.len() - 1)]),
+    }
+}
+
+/// Short form of a polling interval, e.g. "30 s" or "5 min".
+fn format_interval(interval: Duration) -> String {
+    let secs = interval.as_secs();
+    if secs >= 60 && secs.is_multiple_of(60) {
+        format!("{} min", secs / 60)
+    } else {
+        format!("{secs} s")
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ServerStatus {
     Online,
+    /// The server is up but cannot reach Cosmos DB
+    Degraded,
     Offline,
     Checking,
 }
 
+/// Floating indicator of server and database health.
+///
+/// The server is polled every `interval`; without the prop, the interval
+/// configured on the server with `HEARTBEAT_INTERVAL_SECS` is used. While the
+/// server is unreachable the interval backs off to 1 and then 5 minutes, and
+/// it returns to normal after the next successful check. Automatic checks
+/// can be paused; "check now" works either way.
+/// `on_status_change` is called with the result of every check.
 #[component]
 #[allow(clippy::too_many_lines)]
 #[must_use]
 #[allow(clippy::must_use_candidate)]
-pub fn StatusBar() -> impl IntoView {
+pub fn StatusBar(
+    #[prop(optional, into)] interval: Option<Signal<Duration>>,
+    #[prop(optional, into)] on_status_change: Option<Callback<ServerStatus>>,
+) -> impl IntoView {
+    use crate::api::heartbeat::DEFAULT_HEARTBEAT_INTERVAL_SECS;
+
+    let lang = use_lang();
     let (server_status, set_server_status) = signal(ServerStatus::Checking);
     let (last_successful_check, set_last_successful_check) = signal(Local::now());
     let (last_attempt, set_last_attempt) = signal(Local::now());
     let (is_mounted, set_is_mounted) = signal(true);
+    let (last_latency_ms, set_last_latency_ms) = signal(None::<i64>);
+    let check_started = StoredValue::new(Utc::now());
+    let (server_interval, set_server_interval) =
+        signal(Duration::from_secs(DEFAULT_HEARTBEAT_INTERVAL_SECS));
+    let (consecutive_failures, set_consecutive_failures) = signal(0_u32);
+    let (paused, set_paused) = signal(false);
+    // Memoized so that re-reporting the same interval does not restart polling
+    let interval = Memo::new(move |_| {
+        let interval = interval.map_or_else(|| server_interval.get(), |interval| interval.get());
+        backoff_interval(interval, consecutive_failures.get())
+    });
 
-    // Create a heartbeat action
+    // Create a heartbeat action that also checks the database
     let heartbeat_action = Action::new(move |(): &()| async move {
-        use crate::api::heartbeat::heartbeat_server;
-        heartbeat_server().await
+        use crate::api::heartbeat::readiness_server;
+        readiness_server().await
     });
 
     // Safe signal update function that checks if component is still mounted
@@ -33,6 +86,15 @@ pub fn StatusBar() -> impl IntoView {
             if status == ServerStatus::Online {
                 set_last_successful_check.set(Local::now());
             }
+            // Only an unreachable server backs off; a degraded one still answers
+            if status == ServerStatus::Offline {
+                set_consecutive_failures.update(|failures| *failures = failures.saturating_add(1));
+            } else if consecutive_failures.get_untracked() != 0 {
+                set_consecutive_failures.set(0);
+            }
+            if let Some(on_status_change) = on_status_change {
+                on_status_change.run(status);
+            }
         }
     };
 
@@ -40,6 +102,7 @@ pub fn StatusBar() -> impl IntoView {
     let check_heartbeat = move || {
         if is_mounted.get_untracked() {
             set_server_status.set(ServerStatus::Checking);
+            check_started.set_value(Utc::now());
             heartbeat_action.dispatch(());
         }
     };
@@ -49,33 +112,53 @@ pub fn StatusBar() -> impl IntoView {
         if let Some(result) = heartbeat_action.value().get() {
             // Only update if component is still mounted
             if is_mounted.get_untracked() {
+                let elapsed = Utc::now() - check_started.get_value();
+                set_last_latency_ms.set(Some(elapsed.num_milliseconds()));
+                if let Ok(readiness) = &result {
+                    set_server_interval.set(Duration::from_secs(readiness.heartbeat_interval_secs));
+                }
                 match result {
-                    Ok(_) => safe_update_status(ServerStatus::Online),
+                    Ok(readiness) if readiness.cosmos => safe_update_status(ServerStatus::Online),
+                    Ok(_) => safe_update_status(ServerStatus::Degraded),
                     Err(_) => safe_update_status(ServerStatus::Offline),
                 }
             }
         }
     });
 
-    // Periodic heartbeat check with proper cleanup
+    // Periodic heartbeat check, restarted whenever the interval changes
+    let interval_id = StoredValue::new(None::<i32>);
     Effect::new(move |_| {
-        let Ok(interval_id) = set_interval_with_handle(
+        let period = interval.get();
+        if let Some(previous) = interval_id.get_value() {
+            clear_interval(previous);
+            interval_id.set_value(None);
+        }
+        if paused.get() {
+            return;
+        }
+
+        let Ok(new_interval_id) = set_interval_with_handle(
             move || {
                 if is_mounted.get_untracked() {
                     check_heartbeat();
                 }
             },
-            Duration::from_secs(30),
+            period,
         ) else {
             leptos::logging::warn!("Failed to set up interval for heartbeat check");
+            interval_id.set_value(None);
             return; // Exit the effect if we can't set up the interval
         };
+        interval_id.set_value(Some(new_interval_id));
+    });
 
-        // Cleanup function with the extracted interval ID
-        on_cleanup(move || {
-            set_is_mounted.set(false);
-            clear_interval(interval_id);
-        });
+    // Stop polling when the component is removed
+    on_cleanup(move || {
+        set_is_mounted.set(false);
+        if let Some(Some(id)) = interval_id.try_get_value() {
+            clear_interval(id);
+        }
     });
 
     // Initial heartbeat check
@@ -85,32 +168,34 @@ pub fn StatusBar() -> impl IntoView {
 
     // Status display helpers
     let status_color = move || {
-        if !is_mounted.get_untracked() {
+        if !is_mounted.get() {
             return "bg-gray-500".to_string();
         }
 
         match server_status.get() {
             ServerStatus::Online => "bg-green-500".to_string(),
+            ServerStatus::Degraded => "bg-orange-500".to_string(),
             ServerStatus::Offline => "bg-red-500".to_string(),
             ServerStatus::Checking => "bg-yellow-500".to_string(),
         }
     };
 
     let status_text = move || {
-        if !is_mounted.get_untracked() {
-            return "Disconnected".to_string();
+        if !is_mounted.get() {
+            return lang.t("server.disconnected");
         }
 
         match server_status.get() {
-            ServerStatus::Online => "Server Online".to_string(),
-            ServerStatus::Offline => "Server Offline".to_string(),
-            ServerStatus::Checking => "Checking...".to_string(),
+            ServerStatus::Online => lang.t("server.online"),
+            ServerStatus::Degraded => lang.t("server.degraded"),
+            ServerStatus::Offline => lang.t("server.offline"),
+            ServerStatus::Checking => lang.t("server.checking"),
         }
     };
 
     let format_last_check = move || {
-        if !is_mounted.get_untracked() {
-            return "Unknown".to_string();
+        if !is_mounted.get() {
+            return lang.t("server.unknown").to_string();
         }
 
         last_successful_check.get().format("%H:%M:%S").to_string()
@@ -118,13 +203,13 @@ pub fn StatusBar() -> impl IntoView {
 
     view! {
         <div class="fixed bottom-4 right-4 z-40">
-            <div class="bg-white rounded-lg shadow-lg border border-gray-200 p-3 min-w-[200px]">
+            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-lg border border-gray-200 dark:border-gray-700 p-3 min-w-[200px]">
                 <div class="flex items-center gap-3">
                     // Status indicator dot with pulse animation for checking state
                     <div class="relative">
                         <div class=move || format!("w-3 h-3 rounded-full {}", status_color())></div>
                         <Show when=move || {
-                            is_mounted.get_untracked()
+                            is_mounted.get()
                                 && server_status.get() == ServerStatus::Checking
                         }>
                             <div class="absolute inset-0 w-3 h-3 rounded-full bg-yellow-500 animate-ping opacity-75"></div>
@@ -132,12 +217,26 @@ pub fn StatusBar() -> impl IntoView {
                     </div>
 
                     <div class="flex-1">
-                        <p class="text-sm font-medium text-gray-900">{status_text}</p>
+                        <p class="text-sm font-medium text-gray-900 dark:text-gray-100">{status_text}</p>
                         <Show when=move || {
-                            is_mounted.get_untracked()
+                            is_mounted.get()
                                 && server_status.get() != ServerStatus::Checking
                         }>
-                            <p class="text-xs text-gray-500">"Last check: " {format_last_check}</p>
+                            <p class="text-xs text-gray-500 dark:text-gray-400">
+                                {move || lang.t("server.last_check")}
+                                " "
+                                {format_last_check}
+                            </p>
+                            <Show when=move || last_latency_ms.get().is_some()>
+                                <p class="text-xs text-gray-500 dark:text-gray-400">
+                                    {move || {
+                                        lang.t_with(
+                                            "server.latency",
+                                            &[("ms", &last_latency_ms.get().unwrap_or_default())],
+                                        )
+                                    }}
+                                </p>
+                            </Show>
                         </Show>
                     </div>
 
@@ -150,9 +249,9 @@ pub fn StatusBar() -> impl IntoView {
                             }
                         }
                         class="p-1 text-gray-400 hover:text-gray-600 rounded transition-colors"
-                        title="Check server status"
+                        title=move || lang.t("server.check_now")
                         disabled=move || {
-                            !is_mounted.get_untracked()
+                            !is_mounted.get()
                                 || server_status.get() == ServerStatus::Checking
                         }
                     >
@@ -160,7 +259,7 @@ pub fn StatusBar() -> impl IntoView {
                             class=move || {
                                 format!(
                                     "w-4 h-4 {}",
-                                    if is_mounted.get_untracked()
+                                    if is_mounted.get()
                                         && server_status.get() == ServerStatus::Checking
                                     {
                                         "animate-spin"
@@ -185,43 +284,79 @@ pub fn StatusBar() -> impl IntoView {
 
                 // Additional info when offline
                 <Show when=move || {
-                    is_mounted.get_untracked() && server_status.get() == ServerStatus::Offline
+                    is_mounted.get() && server_status.get() == ServerStatus::Offline
                 }>
-                    <div class="mt-2 pt-2 border-t border-gray-100">
+                    <div class="mt-2 pt-2 border-t border-gray-100 dark:border-gray-700">
                         <p class="text-xs text-red-600">
-                            "Server connection lost. Some features may not work."
+                            {move || lang.t("server.connection_lost")}
+                        </p>
+                        <Show when=move || !paused.get() && consecutive_failures.get() > 0>
+                            <p class="text-xs text-gray-500 dark:text-gray-400">
+                                {move || {
+                                    lang.t_with(
+                                        "server.retry_every",
+                                        &[("interval", &format_interval(interval.get()))],
+                                    )
+                                }}
+                            </p>
+                        </Show>
+                    </div>
+                </Show>
+
+                // Automatic checks can be paused, e.g. while the server is known to be down
+                <div class="mt-2 pt-2 border-t border-gray-100 dark:border-gray-700 flex items-center justify-between gap-2">
+                    <p class="text-xs text-gray-500 dark:text-gray-400">
+                        {move || paused.get().then(|| lang.t("server.paused"))}
+                    </p>
+                    <button
+                        type="button"
+                        on:click=move |_| set_paused.update(|paused| *paused = !*paused)
+                        class="text-xs text-purple-600 hover:text-purple-800 dark:text-purple-300 dark:hover:text-purple-200"
+                        aria-pressed=move || paused.get().to_string()
+                    >
+                        {move || lang.t(if paused.get() { "server.resume" } else { "server.pause" })}
+                    </button>
+                </div>
+
+                // Additional info when the database is down
+                <Show when=move || {
+                    is_mounted.get() && server_status.get() == ServerStatus::Degraded
+                }>
+                    <div class="mt-2 pt-2 border-t border-gray-100 dark:border-gray-700">
+                        <p class="text-xs text-orange-600">
+                            {move || lang.t("server.database_down")}
                         </p>
                     </div>
                 </Show>
 
                 // Debug info (remove in production) - Now displayed vertically
-                <Show when=move || is_mounted.get_untracked() && cfg!(debug_assertions)>
-                    <div class="mt-2 pt-2 border-t border-gray-100">
+                <Show when=move || is_mounted.get() && cfg!(debug_assertions)>
+                    <div class="mt-2 pt-2 border-t border-gray-100 dark:border-gray-700">
                         <div class="space-y-1">
-                            <p class="text-xs text-gray-500">
+                            <p class="text-xs text-gray-500 dark:text-gray-400">
                                 "Status: "
                                 {move || {
-                                    if is_mounted.get_untracked() {
+                                    if is_mounted.get() {
                                         format!("{:?}", server_status.get())
                                     } else {
                                         "Unmounted".to_string()
                                     }
                                 }}
                             </p>
-                            <p class="text-xs text-gray-500">
+                            <p class="text-xs text-gray-500 dark:text-gray-400">
                                 "Last successful: "
                                 {move || {
-                                    if is_mounted.get_untracked() {
+                                    if is_mounted.get() {
                                         last_successful_check.get().format("%H:%M:%S").to_string()
                                     } else {
                                         "N/A".to_string()
                                     }
                                 }}
                             </p>
-                            <p class="text-xs text-gray-500">
+                            <p class="text-xs text-gray-500 dark:text-gray-400">
                                 "Last attempt: "
                                 {move || {
-                                    if is_mounted.get_untracked() {
+                                    if is_mounted.get() {
                                         last_attempt.get().format("%H:%M:%S").to_string()
                                     } else {
                                         "N/A".to_string()
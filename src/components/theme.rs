@@ -0,0 +1,103 @@
+use leptos::prelude::*;
+
+use crate::utils::{get_preference, set_preference};
+
+/// localStorage key of the chosen color scheme.
+pub const DARK_MODE_KEY: &str = "pref_dark_mode";
+
+/// Light/dark color scheme shared by every page.
+///
+/// The scheme is applied by toggling the `dark` class on the document element,
+/// which Tailwind's `dark:` variants key off.
+#[derive(Clone, Copy)]
+pub struct ThemeContext {
+    pub is_dark: ReadSignal<bool>,
+    set_dark: WriteSignal<bool>,
+}
+
+impl ThemeContext {
+    /// Switches between light and dark mode and remembers the choice.
+    pub fn toggle(&self) {
+        let is_dark = !self.is_dark.get_untracked();
+        self.set_dark.set(is_dark);
+        set_preference(DARK_MODE_KEY, &is_dark);
+    }
+}
+
+/// Provides the `ThemeContext`, restoring a stored choice or, on a first
+/// visit, following the system's `prefers-color-scheme`.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn ThemeProvider(children: Children) -> impl IntoView {
+    let (is_dark, set_dark) = signal(false);
+
+    // Restore after mount, so the server-rendered markup hydrates unchanged
+    Effect::new(move |_| {
+        set_dark.set(get_preference(DARK_MODE_KEY).unwrap_or_else(prefers_dark_scheme));
+    });
+
+    Effect::new(move |_| apply_dark_class(is_dark.get()));
+
+    provide_context(ThemeContext { is_dark, set_dark });
+
+    view! { {children()} }
+}
+
+#[must_use]
+pub fn use_theme() -> ThemeContext {
+    expect_context::<ThemeContext>()
+}
+
+/// Sun/moon button switching the color scheme.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn ThemeToggle() -> impl IntoView {
+    let theme = use_theme();
+
+    view! {
+        <button
+            type="button"
+            on:click=move |_| theme.toggle()
+            class="p-2 rounded-lg border border-gray-300 text-gray-700 hover:bg-gray-50 transition-colors dark:border-gray-600 dark:text-gray-200 dark:hover:bg-gray-700"
+            title=move || {
+                if theme.is_dark.get() { "Switch to light mode" } else { "Switch to dark mode" }
+            }
+        >
+            {move || if theme.is_dark.get() { "☀️" } else { "🌙" }}
+        </button>
+    }
+}
+
+#[cfg(feature = "hydrate")]
+fn prefers_dark_scheme() -> bool {
+    web_sys::window()
+        .and_then(|window| {
+            window
+                .match_media("(prefers-color-scheme: dark)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|query| query.matches())
+}
+
+#[cfg(feature = "hydrate")]
+fn apply_dark_class(is_dark: bool) {
+    let Some(root) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element())
+    else {
+        return;
+    };
+    if let Err(e) = root.class_list().toggle_with_force("dark", is_dark) {
+        leptos::logging::warn!("Failed to apply color scheme: {:?}", e);
+    }
+}
+
+// Server-side stubs
+#[cfg(not(feature = "hydrate"))]
+fn prefers_dark_scheme() -> bool {
+    false
+}
+
+#[cfg(not(feature = "hydrate"))]
+fn apply_dark_class(_is_dark: bool) {}
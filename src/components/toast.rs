@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+
+use crate::i18n::use_lang;
+
+/// How long a toast stays on screen before dismissing itself.
+const TOAST_TIMEOUT: Duration = Duration::from_secs(4);
+
+/// A short-lived confirmation shown over the page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Toast {
+    pub id: usize,
+    pub message: String,
+}
+
+/// Queue of toasts shared by every page.
+///
+/// Toasts stack in the order they were shown and each one dismisses itself
+/// after `TOAST_TIMEOUT`, or earlier when closed.
+#[derive(Clone, Copy)]
+pub struct ToastContext {
+    pub toasts: ReadSignal<Vec<Toast>>,
+    set_toasts: WriteSignal<Vec<Toast>>,
+    next_id: StoredValue<usize>,
+}
+
+impl ToastContext {
+    /// Shows `message` until it times out or is closed.
+    pub fn show(&self, message: impl Into<String>) {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        self.set_toasts.update(|toasts| {
+            toasts.push(Toast {
+                id,
+                message: message.into(),
+            });
+        });
+
+        #[cfg(feature = "hydrate")]
+        {
+            use leptos::leptos_dom::helpers::set_timeout;
+
+            let context = *self;
+            set_timeout(move || context.dismiss(id), TOAST_TIMEOUT);
+        }
+    }
+
+    /// Removes a toast; one already gone is ignored.
+    pub fn dismiss(&self, id: usize) {
+        self.set_toasts
+            .update(|toasts| toasts.retain(|toast| toast.id != id));
+    }
+}
+
+/// Provides the `ToastContext`; render [`Toasts`] once inside it.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn ToastProvider(children: Children) -> impl IntoView {
+    let (toasts, set_toasts) = signal(Vec::new());
+
+    provide_context(ToastContext {
+        toasts,
+        set_toasts,
+        next_id: StoredValue::new(0),
+    });
+
+    view! { {children()} }
+}
+
+#[must_use]
+pub fn use_toasts() -> ToastContext {
+    expect_context::<ToastContext>()
+}
+
+/// Stack of the current toasts in the top right corner, announced to screen
+/// readers as they appear.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn Toasts() -> impl IntoView {
+    let toasts = use_toasts();
+    let lang = use_lang();
+
+    view! {
+        <div
+            role="status"
+            aria-live="polite"
+            class="fixed top-4 right-4 z-50 flex flex-col items-end gap-2 pointer-events-none"
+        >
+            <For
+                each=move || toasts.toasts.get()
+                key=|toast| toast.id
+                children=move |toast| {
+                    let id = toast.id;
+                    view! {
+                        <div class="pointer-events-auto bg-green-50 border border-green-200 text-green-800 rounded-xl shadow-lg px-4 py-3 flex items-center gap-3 dark:bg-green-900 dark:border-green-700 dark:text-green-100">
+                            <span class="text-sm">{toast.message}</span>
+                            <button
+                                type="button"
+                                on:click=move |_| toasts.dismiss(id)
+                                class="text-green-600 hover:text-green-800 transition-colors dark:text-green-300 dark:hover:text-green-100"
+                                aria-label=move || lang.t("common.close")
+                            >
+                                "✕"
+                            </button>
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
+}
@@ -0,0 +1,682 @@
+use std::collections::HashMap;
+
+use chrono_tz::Tz;
+use leptos::web_sys;
+use leptos::{ev, prelude::*};
+
+use crate::components::modal::Modal;
+use crate::domain::todo::validation::validate_no_html;
+use crate::domain::todo::{
+    ATTACHMENT_CONTENT_TYPES, Attachment, MAX_DESCRIPTION_LENGTH, MAX_TITLE_LENGTH, Todo,
+    TodoPriority, TodoStatus,
+};
+use crate::i18n::{DateFormat, use_lang};
+use crate::utils::{
+    DuePreset, SelectedFile, from_user_time, read_file_base64, to_user_time, user_today,
+};
+
+/// Id of the dialog's heading, which names it.
+const TITLE_ID: &str = "todo-dialog-title";
+
+/// Input classes of a form field, outlined in red while it has an error.
+const fn field_class(invalid: bool) -> &'static str {
+    if invalid {
+        "w-full px-3 py-2 border border-red-500 dark:border-red-400 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-red-500 focus:border-transparent"
+    } else {
+        "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
+    }
+}
+
+/// Length of a form field as validation counts it, without surrounding whitespace.
+fn char_count(text: &str) -> u64 {
+    u64::try_from(text.trim().chars().count()).unwrap_or(u64::MAX)
+}
+
+/// Fields of the create/edit form.
+///
+/// The page owns them, so that opening a todo can fill them in and submitting
+/// can read them; [`TodoDialog`] only edits them.
+#[derive(Clone, Copy)]
+pub struct TodoFormFields {
+    pub title: RwSignal<String>,
+    pub description: RwSignal<String>,
+    pub due_date: RwSignal<String>,
+    pub due_time: RwSignal<String>,
+    /// Set when the todo is explicitly left without a due date; the date fields are cleared and locked
+    pub no_due_date: RwSignal<bool>,
+    pub assignee: RwSignal<String>,
+    pub status: RwSignal<String>,
+    pub priority: RwSignal<String>,
+    pub tags: RwSignal<String>,
+    pub comment: RwSignal<String>,
+    pub attachments: RwSignal<Vec<Attachment>>,
+    /// Validation errors shown beneath the form fields; a field's error clears once it is edited
+    pub errors: RwSignal<HashMap<&'static str, String>>,
+}
+
+impl Default for TodoFormFields {
+    fn default() -> Self {
+        Self {
+            title: RwSignal::new(String::new()),
+            description: RwSignal::new(String::new()),
+            due_date: RwSignal::new(String::new()),
+            due_time: RwSignal::new(String::new()),
+            no_due_date: RwSignal::new(false),
+            assignee: RwSignal::new(String::new()),
+            status: RwSignal::new("Pending".to_string()),
+            priority: RwSignal::new(TodoPriority::default().as_str().to_string()),
+            tags: RwSignal::new(String::new()),
+            comment: RwSignal::new(String::new()),
+            attachments: RwSignal::new(Vec::new()),
+            errors: RwSignal::new(HashMap::new()),
+        }
+    }
+}
+
+/// "12 / 200" beneath a form field, amber from 90% of `max` and red past it.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn CharCounter(#[prop(into)] count: Signal<u64>, max: u64) -> impl IntoView {
+    let class = move || {
+        let count = count.get();
+        if count > max {
+            "mt-1 text-xs text-right text-red-600 dark:text-red-400 font-medium"
+        } else if count * 10 >= max * 9 {
+            "mt-1 text-xs text-right text-amber-600 dark:text-amber-400"
+        } else {
+            "mt-1 text-xs text-right text-gray-500 dark:text-gray-400"
+        }
+    };
+
+    view! {
+        <p class=class aria-live="polite">
+            {move || format!("{} / {max}", count.get())}
+        </p>
+    }
+}
+
+/// Dialog for creating a todo, or editing and commenting on `editing`.
+///
+/// Saving, uploads and comments are left to the page through the callbacks;
+/// the form only checks what it can show next to its fields. Submitting is
+/// blocked while a field is over its length limit.
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+#[allow(clippy::too_many_arguments)]
+pub fn TodoDialog(
+    fields: TodoFormFields,
+    /// The todo being edited, `None` while creating one
+    #[prop(into)]
+    editing: Signal<Option<Todo>>,
+    #[prop(into)] assignees: Signal<Vec<String>>,
+    #[prop(into)] can_write: Signal<bool>,
+    /// Whether the todo is being saved
+    #[prop(into)]
+    saving: Signal<bool>,
+    #[prop(into)] uploading: Signal<bool>,
+    #[prop(into)] commenting: Signal<bool>,
+    /// Time zone the due date and time are entered in
+    tz: Tz,
+    #[prop(into)] on_submit: Callback<ev::SubmitEvent>,
+    /// Stores a picked file; the page adds it to `fields.attachments` once saved
+    #[prop(into)]
+    on_upload: Callback<SelectedFile>,
+    /// Posts a comment on the edited todo
+    #[prop(into)]
+    on_comment: Callback<String>,
+    #[prop(into)] on_error: Callback<String>,
+    /// Discards the form and closes the dialog
+    #[prop(into)]
+    on_cancel: Callback<()>,
+    #[prop(into)] on_close: Callback<()>,
+) -> impl IntoView {
+    let lang = use_lang();
+
+    let clear_field_error = move |field: &'static str| {
+        if fields
+            .errors
+            .with_untracked(|errors| errors.contains_key(field))
+        {
+            fields.errors.update(|errors| {
+                errors.remove(field);
+            });
+        }
+    };
+    let field_error =
+        move |field: &'static str| fields.errors.with(|errors| errors.get(field).cloned());
+
+    let title_length = Signal::derive(move || fields.title.with(|title| char_count(title)));
+    let description_length = Signal::derive(move || {
+        fields
+            .description
+            .with(|description| char_count(description))
+    });
+    let over_length_limit = move || {
+        title_length.get() > MAX_TITLE_LENGTH || description_length.get() > MAX_DESCRIPTION_LENGTH
+    };
+
+    let is_past_date = move || {
+        let date_str = fields.due_date.get();
+        let time_str = fields.due_time.get();
+
+        if date_str.is_empty() {
+            return false;
+        }
+
+        let time_str = if time_str.is_empty() {
+            "00:00"
+        } else {
+            &time_str
+        };
+        let datetime_str = format!("{date_str} {time_str}");
+
+        chrono::NaiveDateTime::parse_from_str(&datetime_str, "%Y-%m-%d %H:%M")
+            .ok()
+            .and_then(|datetime| from_user_time(datetime, tz))
+            .and_then(|timestamp| i64::try_from(timestamp).ok())
+            .is_some_and(|timestamp| timestamp < chrono::Utc::now().timestamp())
+    };
+
+    // Fills in the due date of a preset, keeping the entered time of day unless
+    // there is none or it has already passed on that day
+    let apply_due_preset = move |preset: DuePreset| {
+        let now = chrono::Utc::now().with_timezone(&tz).naive_local();
+        let entered =
+            chrono::NaiveTime::parse_from_str(&fields.due_time.get_untracked(), "%H:%M").ok();
+        let Some(due) = preset.due(now, entered) else {
+            return;
+        };
+        fields.no_due_date.set(false);
+        fields.due_date.set(due.format("%Y-%m-%d").to_string());
+        fields.due_time.set(due.format("%H:%M").to_string());
+    };
+
+    // Turning "No due date" on drops whatever date and time were entered
+    let toggle_no_due_date = move |_| {
+        let undated = !fields.no_due_date.get_untracked();
+        if undated {
+            fields.due_date.set(String::new());
+            fields.due_time.set(String::new());
+        }
+        fields.no_due_date.set(undated);
+    };
+
+    view! {
+        <Modal
+            labelled_by=TITLE_ID
+            class="bg-white dark:bg-gray-800 rounded-2xl p-6 w-full max-w-md shadow-2xl"
+            on_close=on_close
+        >
+            <div class="flex justify-between items-center mb-4">
+                <h2
+                    id=TITLE_ID
+                    class="text-xl font-bold text-gray-800 dark:text-gray-100"
+                >
+                    {move || {
+                        if editing.get().is_some() {
+                            lang.t("form.edit_title")
+                        } else {
+                            lang.t("form.create_title")
+                        }
+                    }}
+                </h2>
+                <button
+                    type="button"
+                    on:click=move |_| on_close.run(())
+                    class="text-gray-500 dark:text-gray-400 hover:text-gray-700 text-2xl leading-none"
+                    aria-label=move || lang.t("common.close")
+                >
+                    "×"
+                </button>
+            </div>
+
+            <form on:submit=move |ev| on_submit.run(ev)>
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("form.title_label")}
+                    </label>
+                    <input
+                        type="text"
+                        prop:value=move || fields.title.get()
+                        on:input=move |ev| {
+                            fields.title.set(event_target_value(&ev));
+                            clear_field_error("title");
+                        }
+                        class=move || field_class(field_error("title").is_some())
+                        aria-invalid=move || field_error("title").is_some().to_string()
+                        aria-describedby=move || field_error("title").map(|_| "title-error")
+                        placeholder=move || lang.t("form.title_placeholder")
+                        required
+                    />
+                    <CharCounter count=title_length max=MAX_TITLE_LENGTH />
+                    {move || {
+                        field_error("title")
+                            .map(|message| {
+                                view! {
+                                    <p
+                                        id="title-error"
+                                        class="mt-1 text-sm text-red-600 dark:text-red-400"
+                                    >
+                                        {message}
+                                    </p>
+                                }
+                            })
+                    }}
+                </div>
+
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("field.description")}
+                    </label>
+                    <textarea
+                        prop:value=move || fields.description.get()
+                        on:input=move |ev| {
+                            fields.description.set(event_target_value(&ev));
+                            clear_field_error("description");
+                        }
+                        class=move || field_class(field_error("description").is_some())
+                        aria-invalid=move || {
+                            field_error("description").is_some().to_string()
+                        }
+                        aria-describedby=move || {
+                            field_error("description").map(|_| "description-error")
+                        }
+                        placeholder=move || lang.t("form.description_placeholder")
+                        rows="3"
+                    />
+                    <CharCounter count=description_length max=MAX_DESCRIPTION_LENGTH />
+                    {move || {
+                        field_error("description")
+                            .map(|message| {
+                                view! {
+                                    <p
+                                        id="description-error"
+                                        class="mt-1 text-sm text-red-600 dark:text-red-400"
+                                    >
+                                        {message}
+                                    </p>
+                                }
+                            })
+                    }}
+                </div>
+
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("field.tags")}
+                    </label>
+                    <input
+                        type="text"
+                        prop:value=move || fields.tags.get()
+                        on:input=move |ev| fields.tags.set(event_target_value(&ev))
+                        class="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
+                        placeholder=move || lang.t("form.tags_placeholder")
+                    />
+                </div>
+
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("field.attachments")}
+                    </label>
+                    <ul class="space-y-1 mb-2">
+                        <For
+                            each=move || fields.attachments.get()
+                            key=|attachment| attachment.url.clone()
+                            let:attachment
+                        >
+                            {
+                                let remove_url = attachment.url.clone();
+                                view! {
+                                    <li class="flex justify-between items-center text-sm">
+                                        <a
+                                            href=attachment.href()
+                                            target="_blank"
+                                            rel="noopener noreferrer"
+                                            class="text-purple-600 hover:underline truncate"
+                                        >
+                                            {format!(
+                                                "{} ({} KB)",
+                                                attachment.name,
+                                                attachment.size.div_ceil(1024),
+                                            )}
+                                        </a>
+                                        <button
+                                            type="button"
+                                            on:click=move |_| {
+                                                fields
+                                                    .attachments
+                                                    .update(|attachments| {
+                                                        attachments.retain(|a| a.url != remove_url);
+                                                    });
+                                            }
+                                            class="text-xs text-red-600 hover:text-red-800"
+                                        >
+                                            {move || lang.t("common.remove")}
+                                        </button>
+                                    </li>
+                                }
+                            }
+                        </For>
+                    </ul>
+                    <input
+                        type="file"
+                        accept=ATTACHMENT_CONTENT_TYPES.join(",")
+                        disabled=move || uploading.get()
+                        on:change=move |ev| {
+                            let input = event_target::<web_sys::HtmlInputElement>(&ev);
+                            let read = read_file_base64(
+                                &input,
+                                move |file| match file {
+                                    Ok(file) => on_upload.run(file),
+                                    Err(e) => on_error.run(e),
+                                },
+                            );
+                            if let Err(e) = read {
+                                on_error.run(e);
+                            }
+                            input.set_value("");
+                        }
+                        class="block w-full text-sm text-gray-600 dark:text-gray-300"
+                    />
+                    <Show when=move || uploading.get()>
+                        <p class="text-xs text-gray-500 dark:text-gray-400 mt-1">
+                            {move || lang.t("form.uploading")}
+                        </p>
+                    </Show>
+                </div>
+
+                <div class="flex flex-wrap gap-2 mb-2">
+                    {DuePreset::ALL
+                        .into_iter()
+                        .map(|preset| {
+                            let is_active = move || {
+                                preset
+                                    .date(user_today(tz))
+                                    .is_some_and(|date| {
+                                        fields.due_date.get() == date.format("%Y-%m-%d").to_string()
+                                    })
+                            };
+                            view! {
+                                <button
+                                    type="button"
+                                    on:click=move |_| apply_due_preset(preset)
+                                    class=move || {
+                                        if is_active() {
+                                            "px-3 py-1 text-xs rounded-full border border-purple-500 bg-purple-500 text-white"
+                                        } else {
+                                            "px-3 py-1 text-xs rounded-full border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 hover:bg-purple-50 dark:hover:bg-gray-700 transition-colors"
+                                        }
+                                    }
+                                    aria-pressed=move || is_active().to_string()
+                                >
+                                    {move || lang.t(preset.label_key())}
+                                </button>
+                            }
+                        })
+                        .collect_view()}
+                    <button
+                        type="button"
+                        on:click=toggle_no_due_date
+                        class=move || {
+                            if fields.no_due_date.get() {
+                                "px-3 py-1 text-xs rounded-full border border-gray-500 bg-gray-500 text-white"
+                            } else {
+                                "px-3 py-1 text-xs rounded-full border border-dashed border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                            }
+                        }
+                        aria-pressed=move || fields.no_due_date.get().to_string()
+                    >
+                        {move || lang.t("form.no_due_date")}
+                    </button>
+                </div>
+                <Show when=move || fields.no_due_date.get()>
+                    <p class="mb-2 text-xs text-gray-500 dark:text-gray-400">
+                        {move || lang.t("form.no_due_date_hint")}
+                    </p>
+                </Show>
+                <div class="grid grid-cols-2 gap-4 mb-4">
+                    <div>
+                        <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                            {move || lang.t("field.due_date")}
+                        </label>
+                        <input
+                            type="date"
+                            disabled=move || fields.no_due_date.get()
+                            prop:value=move || fields.due_date.get()
+                            on:input=move |ev| {
+                                fields.due_date.set(event_target_value(&ev));
+                            }
+                            class=move || {
+                                if is_past_date() {
+                                    "w-full px-3 py-2 border border-orange-300 rounded-lg focus:ring-2 focus:ring-orange-500 focus:border-transparent bg-orange-50"
+                                } else {
+                                    "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent disabled:opacity-50 disabled:cursor-not-allowed"
+                                }
+                            }
+                        />
+                    </div>
+                    <div>
+                        <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                            {move || lang.t("field.due_time")}
+                        </label>
+                        <input
+                            type="time"
+                            disabled=move || fields.no_due_date.get()
+                            prop:value=move || fields.due_time.get()
+                            on:input=move |ev| {
+                                fields.due_time.set(event_target_value(&ev));
+                            }
+                            class=move || {
+                                if is_past_date() {
+                                    "w-full px-3 py-2 border border-orange-300 rounded-lg focus:ring-2 focus:ring-orange-500 focus:border-transparent bg-orange-50"
+                                } else {
+                                    "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent disabled:opacity-50 disabled:cursor-not-allowed"
+                                }
+                            }
+                        />
+                    </div>
+                </div>
+                <Show when=move || is_past_date() && editing.get().is_none()>
+                    <div class="mb-4 p-2 rounded-lg bg-orange-50 border border-orange-200">
+                        <div class="flex items-center gap-2">
+                            <svg
+                                class="w-4 h-4 text-orange-500 flex-shrink-0"
+                                fill="currentColor"
+                                viewBox="0 0 20 20"
+                            >
+                                <path
+                                    fill-rule="evenodd"
+                                    d="M8.257 3.099c.765-1.36 2.722-1.36 3.486 0l5.58 9.92c.75 1.334-.213 2.98-1.742 2.98H4.42c-1.53 0-2.493-1.646-1.743-2.98l5.58-9.92zM11 13a1 1 0 11-2 0 1 1 0 012 0zm-1-8a1 1 0 00-1 1v3a1 1 0 002 0V6a1 1 0 00-1-1z"
+                                    clip-rule="evenodd"
+                                />
+                            </svg>
+                            <p class="text-sm text-orange-700">
+                                {move || lang.t("form.past_warning")}
+                            </p>
+                        </div>
+                    </div>
+                </Show>
+
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("field.assignee")}
+                    </label>
+                    <select
+                        prop:value=move || fields.assignee.get()
+                        on:change=move |ev| {
+                            fields.assignee.set(event_target_value(&ev));
+                        }
+                        class="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
+                    >
+                        {move || {
+                            let mut names = assignees.get();
+                            let current = fields.assignee.get();
+                            // Keep the stored name selectable even if it is no longer configured
+                            if !current.is_empty() && !names.contains(&current) {
+                                names.push(current);
+                            }
+                            names
+                                .into_iter()
+                                .map(|name| {
+                                    view! { <option value=name.clone()>{name.clone()}</option> }
+                                })
+                                .collect_view()
+                        }}
+                    </select>
+                </div>
+
+                <div class="mb-4">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("field.priority")}
+                    </label>
+                    <select
+                        prop:value=move || fields.priority.get()
+                        on:change=move |ev| {
+                            fields.priority.set(event_target_value(&ev));
+                        }
+                        class="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
+                    >
+                        <option value="Low">{move || lang.priority(TodoPriority::Low)}</option>
+                        <option value="Medium">
+                            {move || lang.priority(TodoPriority::Medium)}
+                        </option>
+                        <option value="High">{move || lang.priority(TodoPriority::High)}</option>
+                    </select>
+                </div>
+
+                <div class="mb-6">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("field.status")}
+                    </label>
+                    <select
+                        prop:value=move || fields.status.get()
+                        on:change=move |ev| {
+                            fields.status.set(event_target_value(&ev));
+                        }
+                        class="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
+                    >
+                        <option value="Pending">
+                            {move || lang.status(TodoStatus::Pending)}
+                        </option>
+                        <option value="In Progress">
+                            {move || lang.status(TodoStatus::InProgress)}
+                        </option>
+                        <option value="Completed">
+                            {move || lang.status(TodoStatus::Completed)}
+                        </option>
+                    </select>
+                </div>
+
+                <div class="flex gap-3">
+                    <button
+                        type="button"
+                        on:click=move |_| on_cancel.run(())
+                        class="flex-1 px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                        disabled=move || saving.get()
+                    >
+                        {move || lang.t("common.cancel")}
+                    </button>
+                    <button
+                        type="submit"
+                        class="flex-1 px-4 py-2 bg-gradient-to-r from-purple-500 to-fuchsia-500 text-white rounded-lg hover:from-purple-600 hover:to-fuchsia-600 transition-all duration-200 disabled:opacity-50 disabled:cursor-not-allowed"
+                        disabled=move || saving.get() || over_length_limit()
+                    >
+                        <Show
+                            when=move || saving.get()
+                            fallback=move || {
+                                if editing.get().is_some() {
+                                    lang.t("form.update")
+                                } else {
+                                    lang.t("form.create")
+                                }
+                            }
+                        >
+                            {move || {
+                                if editing.get().is_some() {
+                                    lang.t("form.updating")
+                                } else {
+                                    lang.t("form.creating")
+                                }
+                            }}
+                        </Show>
+                    </button>
+                </div>
+            </form>
+
+            // Comments, oldest first
+            <Show when=move || editing.get().is_some()>
+                <div class="mt-6 pt-4 border-t border-gray-100 dark:border-gray-700">
+                    <h3 class="text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">
+                        {move || lang.t("comments.title")}
+                    </h3>
+                    <div class="space-y-2 max-h-48 overflow-y-auto mb-3">
+                        {move || {
+                            let comments = editing
+                                .get()
+                                .map(|todo| todo.comments)
+                                .unwrap_or_default();
+                            if comments.is_empty() {
+                                view! {
+                                    <p class="text-sm text-gray-400">{lang.t("comments.empty")}</p>
+                                }
+                                    .into_any()
+                            } else {
+                                comments
+                                    .into_iter()
+                                    .map(|comment| {
+                                        let posted = to_user_time(comment.created_at, tz)
+                                            .map(|datetime| {
+                                                lang.locale.get().format_datetime(&datetime, DateFormat::Timestamp)
+                                            })
+                                            .unwrap_or_default();
+                                        view! {
+                                            <div class="p-2 bg-gray-50 dark:bg-gray-700 rounded-lg">
+                                                <p class="text-xs text-gray-500 dark:text-gray-400">
+                                                    {format!("{} · {posted}", comment.author)}
+                                                </p>
+                                                <p class="text-sm text-gray-800 dark:text-gray-100 whitespace-pre-wrap">
+                                                    {comment.text}
+                                                </p>
+                                            </div>
+                                        }
+                                    })
+                                    .collect_view()
+                                    .into_any()
+                            }
+                        }}
+                    </div>
+                    <div class="flex gap-2" class:hidden=move || !can_write.get()>
+                        <input
+                            type="text"
+                            prop:value=move || fields.comment.get()
+                            on:input=move |ev| fields.comment.set(event_target_value(&ev))
+                            class="flex-1 px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent text-sm"
+                            placeholder=move || lang.t("comments.placeholder")
+                        />
+                        <button
+                            type="button"
+                            on:click=move |_| {
+                                let text = fields.comment.get_untracked().trim().to_string();
+                                if text.is_empty() {
+                                    return;
+                                }
+                                if validate_no_html(&text).is_err() {
+                                    on_error
+                                        .run(
+                                            lang.locale.get_untracked().t("comments.no_html").to_string(),
+                                        );
+                                    return;
+                                }
+                                on_comment.run(text);
+                            }
+                            class="px-3 py-2 text-sm bg-purple-50 text-purple-700 border border-purple-200 rounded-lg hover:bg-purple-100 transition-colors disabled:opacity-50"
+                            disabled=move || commenting.get()
+                        >
+                            {move || lang.t("common.add")}
+                        </button>
+                    </div>
+                </div>
+            </Show>
+        </Modal>
+    }
+}
@@ -0,0 +1,14 @@
+/// Name of this deployment, set at build time with `COSMIC_APP_NAME`.
+///
+/// It namespaces what the app keeps in browser storage, so two instances served
+/// from the same origin do not share or overwrite each other's logins.
+pub const APP_NAME: &str = match option_env!("COSMIC_APP_NAME") {
+    Some(name) => name,
+    None => "cosmic_todos",
+};
+
+/// Browser storage key of `name`, namespaced by [`APP_NAME`].
+#[must_use]
+pub fn storage_key(name: &str) -> String {
+    format!("{APP_NAME}:{name}")
+}
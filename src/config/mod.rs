@@ -1,3 +1,5 @@
+pub mod branding;
 pub mod settings;
 
+pub use branding::*;
 pub use settings::*;
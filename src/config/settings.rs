@@ -1,52 +1,55 @@
 use leptos::leptos_dom::logging;
-use miette::{Diagnostic, SourceSpan};
+use miette::{Diagnostic, NamedSource, SourceSpan};
 use serde::{Deserialize, Serialize};
 use std::{
     env,
     fmt::{self, Formatter},
+    path::Path,
 };
 use thiserror::Error;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmailConfig {
-    pub mikko: String,
-    pub niina: String,
+    /// Configured assignees as `(name, email)` pairs, sorted by name.
+    pub assignees: Vec<(String, String)>,
 }
 
 impl EmailConfig {
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.mikko.is_empty() && self.niina.is_empty()
+        self.assignees.is_empty()
     }
 
     #[must_use]
     pub fn get(&self, assignee: &TodoAssignee) -> Option<String> {
-        let config = get_config().ok()?;
-        match assignee {
-            TodoAssignee::Mikko => Some(config.emails.mikko.clone()),
-            TodoAssignee::Niina => Some(config.emails.niina.clone()),
-        }
+        self.email_for(assignee.as_str()).map(str::to_string)
+    }
+
+    /// Look up the email address configured for the given assignee name.
+    #[must_use]
+    pub fn email_for(&self, name: &str) -> Option<&str> {
+        self.assignees
+            .iter()
+            .find(|(assignee, _)| assignee == name)
+            .map(|(_, email)| email.as_str())
+    }
+
+    /// Names of all configured assignees, sorted by name.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.assignees.iter().map(|(name, _)| name.as_str())
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = (TodoAssignee, String)> {
-        vec![
-            (TodoAssignee::Mikko, self.mikko.clone()),
-            (TodoAssignee::Niina, self.niina.clone()),
-        ]
-        .into_iter()
+    pub fn iter(&self) -> impl Iterator<Item = &(String, String)> {
+        self.assignees.iter()
     }
 }
 
-impl IntoIterator for &EmailConfig {
-    type Item = (TodoAssignee, String);
-    type IntoIter = std::vec::IntoIter<(TodoAssignee, String)>;
+impl<'a> IntoIterator for &'a EmailConfig {
+    type Item = &'a (String, String);
+    type IntoIter = std::slice::Iter<'a, (String, String)>;
 
     fn into_iter(self) -> Self::IntoIter {
-        vec![
-            (TodoAssignee::Mikko, self.mikko.clone()),
-            (TodoAssignee::Niina, self.niina.clone()),
-        ]
-        .into_iter()
+        self.assignees.iter()
     }
 }
 
@@ -66,13 +69,27 @@ pub struct AppConfig {
 
     // Email Configuration
     pub emails: EmailConfig, // Uncomment if email config is needed
-                             // Add more configuration sections as needed
+    // Add more configuration sections as needed
+
+    // SMTP server used for due date reminders
+    pub smtp: SmtpConfig,
+
+    // Azure Blob Storage for todo attachments
+    pub blob: BlobConfig,
+
+    /// URL receiving a POST when a todo is completed; disabled when empty
+    #[serde(default)]
+    pub webhook_url: String,
 }
 
 #[cfg(feature = "ssr")]
 use axum::extract::FromRef;
 
+use chrono_tz::Tz;
+
+use crate::domain::auth::{DEFAULT_GROUP_ID, KNOWN_PERMISSIONS};
 use crate::domain::todo::TodoAssignee;
+use crate::utils::validate_email;
 #[cfg(feature = "ssr")]
 impl FromRef<()> for AppConfig {
     fn from_ref(_: &()) -> Self {
@@ -94,21 +111,43 @@ impl fmt::Display for AppConfig {
         writeln!(f, "   Container: {}", self.cosmos.container_name)?;
         writeln!(f, "   Max Items: {}", self.cosmos.max_item_count)?;
         writeln!(f, "   Throughput: {} RU/s", self.cosmos.throughput)?;
+        writeln!(f, "   Max Retries: {}", self.cosmos.max_retries)?;
+        writeln!(f, "   Cache TTL: {}s", self.cosmos.cache_ttl_secs)?;
         writeln!(f, "   URI: {}", self.cosmos.mask_uri())?;
-        writeln!(
-            f,
-            "   Connection: {}",
-            if self.cosmos.connection_string.is_empty() {
-                "❌ Not Set"
-            } else {
-                "✅ Configured"
+        writeln!(f, "   Auth: {}", self.cosmos.auth_method)?;
+        match self.cosmos.auth_method {
+            CosmosAuthMethod::ConnectionString => writeln!(
+                f,
+                "   Connection: {}",
+                if self.cosmos.connection_string.is_empty() {
+                    "❌ Not Set"
+                } else {
+                    "✅ Configured"
+                }
+            )?,
+            CosmosAuthMethod::AzureAD => {
+                writeln!(
+                    f,
+                    "   Connection: ✅ Managed identity / Azure AD credential"
+                )?;
             }
-        )?;
+        }
 
         // Authentication Configuration
         writeln!(f, "🔐 Authentication:")?;
         writeln!(f, "   Username: {}", self.auth.username)?;
         writeln!(f, "   Password: {}", self.auth.mask_password())?;
+        if !self.auth.password_hash_file.is_empty() {
+            writeln!(f, "   Password File: {}", self.auth.password_hash_file)?;
+        }
+        writeln!(
+            f,
+            "   Lockout: {}s after {} failed attempts",
+            self.auth.lockout_duration_secs, self.auth.max_login_attempts
+        )?;
+        writeln!(f, "   Permissions: {}", self.auth.permissions.join(", "))?;
+        writeln!(f, "   Remember Me: {} days", self.auth.remember_me_days)?;
+        writeln!(f, "   Group: {}", self.auth.group_id)?;
         writeln!(f)?;
 
         // Server Configuration
@@ -116,6 +155,30 @@ impl fmt::Display for AppConfig {
         writeln!(f, "   Address: {}", self.server_address())?;
         writeln!(f, "   Environment: {}", self.server.environment)?;
         writeln!(f, "   Site Root: {}", self.server.site_root)?;
+        writeln!(
+            f,
+            "   Heartbeat: every {}s",
+            self.server.heartbeat_interval_secs
+        )?;
+        writeln!(
+            f,
+            "   Past Due Dates: {}",
+            if self.server.reject_past_due_dates {
+                "rejected unless confirmed"
+            } else {
+                "allowed"
+            }
+        )?;
+        writeln!(f, "   Time Zone: {}", self.server.timezone())?;
+        writeln!(
+            f,
+            "   Client Address: {}",
+            if self.server.trust_forwarded_for {
+                "X-Forwarded-For"
+            } else {
+                "connection"
+            }
+        )?;
         writeln!(f)?;
 
         // Logging Configuration
@@ -164,6 +227,34 @@ impl fmt::Display for AppConfig {
             }
         }
 
+        // SMTP Configuration
+        writeln!(f, "📮 SMTP:")?;
+        if self.smtp.is_configured() {
+            writeln!(f, "   Server: {}:{}", self.smtp.host, self.smtp.port)?;
+            writeln!(f, "   From: {}", self.smtp.from_address)?;
+            writeln!(f, "   Username: {}", self.smtp.username)?;
+            writeln!(f, "   Password: {}", self.smtp.mask_password())?;
+        } else {
+            writeln!(f, "   ❌ Not Set (due date reminders disabled)")?;
+        }
+
+        // Blob Storage Configuration
+        writeln!(f, "📎 Attachments:")?;
+        if self.blob.is_configured() {
+            writeln!(f, "   Container: {}", self.blob.container_url())?;
+            writeln!(f, "   Max Size: {} bytes", self.blob.max_attachment_bytes)?;
+        } else {
+            writeln!(f, "   ❌ Not Set (attachments disabled)")?;
+        }
+
+        // Completion Webhook
+        writeln!(f, "🪝 Webhook:")?;
+        if self.webhook_url.is_empty() {
+            writeln!(f, "   ❌ Not Set (completion webhook disabled)")?;
+        } else {
+            writeln!(f, "   URL: {}", self.webhook_url)?;
+        }
+
         writeln!(f, "═══════════════════════════════")?;
         writeln!(f, "🌌 Cosmic Todos is ready to rock!")?;
         writeln!(f, "═══════════════════════════════")?;
@@ -227,12 +318,139 @@ impl CosmosConfig {
 }
 
 impl AuthConfig {
+    /// Minimum length of a plaintext `COSMIC_PASSWORD`.
+    pub const MIN_PASSWORD_LENGTH: usize = 8;
+
     fn mask_password(&self) -> String {
-        if self.password.is_empty() {
+        if self.password_hash.is_empty() {
             "❌ Not Set".to_string()
         } else {
-            format!("✅ Set ({} characters)", self.password.len())
+            "✅ Set (argon2 hash)".to_string()
+        }
+    }
+
+    /// Hashes a plaintext password into an argon2 PHC string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the password could not be hashed.
+    pub fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+        use argon2::password_hash::{PasswordHasher, SaltString};
+
+        let salt = SaltString::encode_b64(uuid::Uuid::new_v4().as_bytes())?;
+        argon2::Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+    }
+
+    /// The password hash stored in `password_hash_file` by a password change,
+    /// if the file is configured and holds one.
+    #[must_use]
+    pub fn stored_password_hash(&self) -> Option<String> {
+        if self.password_hash_file.is_empty() {
+            return None;
+        }
+        std::fs::read_to_string(&self.password_hash_file)
+            .ok()
+            .map(|hash| hash.trim().to_string())
+            .filter(|hash| !hash.is_empty())
+    }
+
+    /// Writes a changed password's hash to `password_hash_file`, replacing the
+    /// file at once so a crash never leaves half a hash behind.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no file is configured or it cannot be written.
+    pub fn store_password_hash(&self, password_hash: &str) -> std::io::Result<()> {
+        if self.password_hash_file.is_empty() {
+            return Err(std::io::Error::other(
+                "COSMIC_PASSWORD_HASH_FILE is not set",
+            ));
+        }
+        let path = Path::new(&self.password_hash_file);
+        let temporary = path.with_extension("tmp");
+        std::fs::write(&temporary, format!("{password_hash}\n"))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&temporary, std::fs::Permissions::from_mode(0o600))?;
         }
+        std::fs::rename(&temporary, path)
+    }
+
+    /// Verifies a submitted password against the configured hash.
+    ///
+    /// The comparison is done by argon2 in constant time.
+    #[must_use]
+    pub fn verify_password(&self, password: &str) -> bool {
+        Self::verify_password_hash(&self.password_hash, password)
+    }
+
+    /// Verifies a submitted password against an argon2 PHC string.
+    #[must_use]
+    pub fn verify_password_hash(password_hash: &str, password: &str) -> bool {
+        use argon2::password_hash::{PasswordHash, PasswordVerifier};
+
+        PasswordHash::new(password_hash).is_ok_and(|hash| {
+            argon2::Argon2::default()
+                .verify_password(password.as_bytes(), &hash)
+                .is_ok()
+        })
+    }
+
+    /// Reads the comma-separated `COSMIC_USER_PERMISSIONS`, granting full
+    /// access when unset.
+    fn permissions_from_env() -> Vec<String> {
+        env::var("COSMIC_USER_PERMISSIONS").map_or_else(
+            |_| KNOWN_PERMISSIONS.iter().map(ToString::to_string).collect(),
+            |value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|perm| !perm.is_empty())
+                    .map(ToString::to_string)
+                    .collect()
+            },
+        )
+    }
+
+    /// Reads the password hash from `COSMIC_PASSWORD_HASH`, falling back to
+    /// hashing a plaintext `COSMIC_PASSWORD` for existing deployments.
+    fn password_hash_from_env() -> Result<String, ConfigError> {
+        if let Ok(hash) = env::var("COSMIC_PASSWORD_HASH") {
+            return Ok(hash);
+        }
+
+        let password = env::var("COSMIC_PASSWORD").map_err(|_| {
+            let config_line = "COSMIC_PASSWORD_HASH=<missing>".to_string();
+            ConfigError::MissingRequired {
+                name: "COSMIC_PASSWORD_HASH".to_string(),
+                src: config_line.clone(),
+                span: (0, config_line.len()).into(),
+            }
+        })?;
+
+        if password.len() < Self::MIN_PASSWORD_LENGTH {
+            let config_line = format!("COSMIC_PASSWORD={}", "*".repeat(password.len()));
+            return Err(ConfigError::InvalidValue {
+                value: format!("{} characters", password.len()),
+                expected: format!("at least {} characters", Self::MIN_PASSWORD_LENGTH),
+                src: config_line.clone(),
+                span: (16, config_line.len() - 16).into(),
+            });
+        }
+
+        logging::console_warn(
+            "Warning: COSMIC_PASSWORD is deprecated, set COSMIC_PASSWORD_HASH to an argon2 hash instead",
+        );
+
+        Self::hash_password(&password).map_err(|e| ConfigError::ParseError {
+            var_name: "COSMIC_PASSWORD".to_string(),
+            value: "*".repeat(password.len()),
+            expected_type: "password that can be hashed with argon2".to_string(),
+            source: e.to_string().into(),
+        })
     }
 }
 
@@ -242,15 +460,96 @@ pub struct CosmosConfig {
     pub connection_string: String,
     pub database_name: String,
     pub container_name: String,
+    /// How the client authenticates; the primary key is only used with `ConnectionString`
+    pub auth_method: CosmosAuthMethod,
     pub max_item_count: u32,
     pub throughput: u32,
+    /// Retries of an operation that failed with a transient error (throttling, timeouts)
+    pub max_retries: u32,
+    /// How long the todo list of a group is served from memory; 0 disables the cache
+    #[serde(default = "default_cache_ttl_secs")]
+    pub cache_ttl_secs: u64,
+}
+
+fn default_cache_ttl_secs() -> u64 {
+    5
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthConfig {
     pub username: String,
-    pub password: String,
+    /// Argon2 PHC string of the application password
+    pub password_hash: String,
+    /// File keeping the password changed in the app across restarts, which
+    /// then replaces `password_hash`; password changes are refused when empty
+    #[serde(default)]
+    pub password_hash_file: String,
     pub session_timeout_hours: u64, // in seconds
+    /// Failed logins allowed before the account is temporarily locked
+    pub max_login_attempts: u32,
+    /// Base lockout duration in seconds, doubled for every further failure
+    pub lockout_duration_secs: u64,
+    /// Permissions granted to the user, e.g. `read_todos` and `write_todos`
+    pub permissions: Vec<String>,
+    /// Session lifetime in days when the user ticks "remember me"
+    pub remember_me_days: u64,
+    /// Household the user belongs to; todos are only visible within a group
+    pub group_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpConfig {
+    /// SMTP server host; reminders are not sent when empty
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    /// Sender address of reminder emails
+    pub from_address: String,
+}
+
+impl SmtpConfig {
+    #[must_use]
+    pub fn is_configured(&self) -> bool {
+        !self.host.is_empty()
+    }
+
+    fn mask_password(&self) -> String {
+        if self.password.is_empty() {
+            "❌ Not Set".to_string()
+        } else {
+            "✅ Set".to_string()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlobConfig {
+    /// Blob service endpoint, e.g. `https://<account>.blob.core.windows.net`;
+    /// attachments are disabled when empty
+    pub account_url: String,
+    pub container_name: String,
+    /// SAS token with read, create and write permissions on the container
+    pub sas_token: String,
+    /// Largest attachment accepted for upload
+    pub max_attachment_bytes: u64,
+}
+
+impl BlobConfig {
+    #[must_use]
+    pub fn is_configured(&self) -> bool {
+        !self.account_url.is_empty()
+    }
+
+    /// URL of the attachment container, without the SAS token.
+    #[must_use]
+    pub fn container_url(&self) -> String {
+        format!(
+            "{}/{}",
+            self.account_url.trim_end_matches('/'),
+            self.container_name
+        )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -258,7 +557,27 @@ pub struct ServerConfig {
     pub host: String,
     pub port: u16,
     pub site_root: String,
+    /// How often the status bar checks the server, in seconds
+    pub heartbeat_interval_secs: u64,
     pub environment: Environment,
+    /// Whether new todos due in the past are refused unless the client confirms them
+    #[serde(default)]
+    pub reject_past_due_dates: bool,
+    /// IANA name of the household's time zone, e.g. `Europe/Helsinki`; UTC when empty
+    #[serde(default)]
+    pub timezone: String,
+    /// Whether the client address is taken from `X-Forwarded-For`, as set by a
+    /// reverse proxy, instead of the connection
+    #[serde(default)]
+    pub trust_forwarded_for: bool,
+}
+
+impl ServerConfig {
+    /// The configured time zone, UTC when unset or unknown.
+    #[must_use]
+    pub fn timezone(&self) -> Tz {
+        self.timezone.parse().unwrap_or(Tz::UTC)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -268,6 +587,7 @@ pub struct LoggingConfig {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Environment {
     Development,
     Staging,
@@ -275,14 +595,17 @@ pub enum Environment {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum LogFormat {
     Json,
     Pretty,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CosmosAuthMethod {
+    #[serde(rename = "key")]
     ConnectionString,
+    #[serde(rename = "azure_ad")]
     AzureAD,
 }
 
@@ -330,11 +653,26 @@ pub enum ConfigError {
         source: Box<dyn std::error::Error + Send + Sync>,
     },
 
+    #[error("Invalid configuration file {path}")]
+    #[diagnostic(
+        code(config::file_error),
+        help("{message}"),
+        url("https://familyleppanen.net/docs/configuration")
+    )]
+    FileError {
+        path: String,
+        message: String,
+        #[source_code]
+        src: NamedSource<String>,
+        #[label("{message}")]
+        span: Option<SourceSpan>,
+    },
+
     #[error("Azure Cosmos DB configuration error")]
     #[diagnostic(
         code(config::cosmos_config_error),
         help(
-            "Either set AZURE_COSMOS_CONNECTION_STRING or both AZURE_COSMOS_ENDPOINT with proper Azure AD authentication"
+            "Either set AZURE_COSMOS_DB_PRIMARY_KEY, or set AZURE_COSMOS_AUTH_METHOD=azure_ad on a host with a managed identity, Azure AD environment credentials or an Azure CLI login"
         ),
         url("https://docs.microsoft.com/en-us/azure/cosmos-db/")
     )]
@@ -346,6 +684,101 @@ pub enum ConfigError {
     },
 }
 
+/// Variables that configured the two fixed assignees before `ASSIGNEE_<NAME>`.
+const LEGACY_ASSIGNEE_VARS: [&str; 2] = ["EMAIL_MIKKO", "EMAIL_NIINA"];
+
+/// Config file read by [`AppConfig::load`] when `COSMIC_CONFIG_FILE` is not set.
+pub const DEFAULT_CONFIG_FILE: &str = "cosmic.toml";
+
+/// Settings read from a TOML config file, with the same sections as
+/// [`AppConfig`]. Every field is optional: values missing from the file are
+/// taken from the environment or the defaults, so secrets can stay out of it.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ConfigFile {
+    pub cosmos: CosmosFile,
+    pub auth: AuthFile,
+    pub server: ServerFile,
+    pub logging: LoggingFile,
+    pub emails: EmailFile,
+    pub smtp: SmtpFile,
+    pub blob: BlobFile,
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CosmosFile {
+    pub uri: Option<String>,
+    pub connection_string: Option<String>,
+    pub database_name: Option<String>,
+    pub container_name: Option<String>,
+    pub auth_method: Option<CosmosAuthMethod>,
+    pub max_item_count: Option<u32>,
+    pub throughput: Option<u32>,
+    pub max_retries: Option<u32>,
+    pub cache_ttl_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AuthFile {
+    pub username: Option<String>,
+    pub password_hash: Option<String>,
+    pub password_hash_file: Option<String>,
+    pub session_timeout_hours: Option<u64>,
+    pub max_login_attempts: Option<u32>,
+    pub lockout_duration_secs: Option<u64>,
+    pub permissions: Option<Vec<String>>,
+    pub remember_me_days: Option<u64>,
+    pub group_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ServerFile {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub site_root: Option<String>,
+    pub heartbeat_interval_secs: Option<u64>,
+    pub environment: Option<Environment>,
+    pub reject_past_due_dates: Option<bool>,
+    pub timezone: Option<String>,
+    pub trust_forwarded_for: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LoggingFile {
+    pub level: Option<String>,
+    pub format: Option<LogFormat>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct EmailFile {
+    pub assignees: Option<Vec<(String, String)>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SmtpFile {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BlobFile {
+    pub account_url: Option<String>,
+    pub container_name: Option<String>,
+    pub sas_token: Option<String>,
+    pub max_attachment_bytes: Option<u64>,
+}
+
 impl AppConfig {
     /// Load configuration from environment variables with fallback defaults
     ///
@@ -354,91 +787,277 @@ impl AppConfig {
     /// Returns a `ConfigError` if required environment variables are missing,
     /// contain invalid values, or if parsing of numeric values fails.
     pub fn from_env() -> Result<Self, ConfigError> {
-        {
-            // Try to load .env file, but don't fail if it doesn't exist
-            if let Err(e) = dotenv::dotenv() {
-                // Only log if the error is NOT "file not found"
-                if !e.to_string().contains("No such file or directory") {
-                    logging::console_log(&format!("Warning: Could not load .env file: {e}"));
-                }
-            } else {
-                logging::console_log("Loaded environment variables from .env file");
+        Self::load_dotenv();
+        Self::from_env_over(&ConfigFile::default())
+    }
+
+    /// Read a TOML config file with the same sections as `AppConfig`, any of
+    /// whose fields may be left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConfigError` if the file cannot be read, or pointing at the
+    /// offending line if a field has an invalid value.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<ConfigFile, ConfigError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| ConfigError::ParseError {
+            var_name: "COSMIC_CONFIG_FILE".to_string(),
+            value: path.display().to_string(),
+            expected_type: "readable TOML file".to_string(),
+            source: Box::new(e),
+        })?;
+
+        toml::from_str(&contents).map_err(|e| ConfigError::FileError {
+            path: path.display().to_string(),
+            message: e.message().to_string(),
+            span: e.span().map(|span| (span.start, span.len()).into()),
+            src: NamedSource::new(path.display().to_string(), contents),
+        })
+    }
+
+    /// Load configuration from environment variables layered over a TOML file.
+    ///
+    /// The file is `COSMIC_CONFIG_FILE`, or `cosmic.toml` in the working directory
+    /// if present. Environment variables (including `.env`) win over the file, so
+    /// CI and deployments can override single values; without a file this is the
+    /// same as [`AppConfig::from_env`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConfigError` if the file is invalid, or if a value is missing
+    /// from both the environment and the file or cannot be parsed.
+    pub fn load() -> Result<Self, ConfigError> {
+        Self::load_dotenv();
+
+        let file = match env::var("COSMIC_CONFIG_FILE") {
+            Ok(path) => Some(Self::from_file(path)?),
+            Err(_) if Path::new(DEFAULT_CONFIG_FILE).exists() => {
+                Some(Self::from_file(DEFAULT_CONFIG_FILE)?)
+            }
+            Err(_) => None,
+        };
+        if file.is_some() {
+            logging::console_log(
+                "Loaded configuration file, environment variables take precedence",
+            );
+        }
+
+        Self::from_env_over(&file.unwrap_or_default())
+    }
+
+    fn load_dotenv() {
+        // Try to load .env file, but don't fail if it doesn't exist
+        if let Err(e) = dotenv::dotenv() {
+            // Only log if the error is NOT "file not found"
+            if !e.to_string().contains("No such file or directory") {
+                logging::console_log(&format!("Warning: Could not load .env file: {e}"));
             }
+        } else {
+            logging::console_log("Loaded environment variables from .env file");
         }
+    }
+
+    /// Reads the configuration from environment variables, taking values that
+    /// are not set from `file` before falling back to the defaults.
+    #[allow(clippy::too_many_lines)]
+    fn from_env_over(file: &ConfigFile) -> Result<Self, ConfigError> {
         let env_vars = Self::collect_env_vars();
+        let env_or_file = |name: &str, file_value: &Option<String>| {
+            env_vars.get(name).cloned().or_else(|| file_value.clone())
+        };
+        let required = |name: &str, file_value: &Option<String>| {
+            env_or_file(name, file_value).map_or_else(|| Self::get_required_env_var(name), Ok)
+        };
 
         // Cosmos DB Configuration
+        let auth_method = match env_vars.get("AZURE_COSMOS_AUTH_METHOD") {
+            Some(method) => Self::parse_cosmos_auth_method(method)?,
+            None => file
+                .cosmos
+                .auth_method
+                .unwrap_or(CosmosAuthMethod::ConnectionString),
+        };
         let cosmos = CosmosConfig {
-            uri: Self::get_required_env_var("AZURE_COSMOS_DB_URI")?,
-            connection_string: Self::get_required_env_var("AZURE_COSMOS_DB_PRIMARY_KEY")?,
-            database_name: Self::get_required_env_var("AZURE_COSMOS_DATABASE_NAME")?,
-            container_name: Self::get_required_env_var("AZURE_COSMOS_CONTAINER_NAME")?,
-            max_item_count: Self::parse_env_var_with_default("AZURE_COSMOS_MAX_ITEM_COUNT", 100)?,
-            throughput: Self::parse_env_var_with_default("AZURE_COSMOS_THROUGHPUT", 400)?,
+            uri: required("AZURE_COSMOS_DB_URI", &file.cosmos.uri)?,
+            // The primary key is only needed for key-based authentication
+            connection_string: match auth_method {
+                CosmosAuthMethod::ConnectionString => required(
+                    "AZURE_COSMOS_DB_PRIMARY_KEY",
+                    &file.cosmos.connection_string,
+                )?,
+                CosmosAuthMethod::AzureAD => env_or_file(
+                    "AZURE_COSMOS_DB_PRIMARY_KEY",
+                    &file.cosmos.connection_string,
+                )
+                .unwrap_or_default(),
+            },
+            database_name: required("AZURE_COSMOS_DATABASE_NAME", &file.cosmos.database_name)?,
+            container_name: required("AZURE_COSMOS_CONTAINER_NAME", &file.cosmos.container_name)?,
+            auth_method,
+            max_item_count: Self::parse_env_var_with_default(
+                "AZURE_COSMOS_MAX_ITEM_COUNT",
+                file.cosmos.max_item_count.unwrap_or(100),
+            )?,
+            throughput: Self::parse_env_var_with_default(
+                "AZURE_COSMOS_THROUGHPUT",
+                file.cosmos.throughput.unwrap_or(400),
+            )?,
+            max_retries: Self::parse_env_var_with_default(
+                "AZURE_COSMOS_MAX_RETRIES",
+                file.cosmos.max_retries.unwrap_or(3),
+            )?,
+            cache_ttl_secs: Self::parse_env_var_with_default(
+                "AZURE_COSMOS_CACHE_TTL_SECS",
+                file.cosmos
+                    .cache_ttl_secs
+                    .unwrap_or_else(default_cache_ttl_secs),
+            )?,
         };
 
         // Authentication Configuration
-        let auth = AuthConfig {
-            username: Self::get_required_env_var("COSMIC_USERNAME")?,
-            password: Self::get_required_env_var("COSMIC_PASSWORD")?,
+        let mut auth = AuthConfig {
+            username: required("COSMIC_USERNAME", &file.auth.username)?,
+            password_hash: match &file.auth.password_hash {
+                Some(password_hash)
+                    if !env_vars.contains_key("COSMIC_PASSWORD_HASH")
+                        && !env_vars.contains_key("COSMIC_PASSWORD") =>
+                {
+                    password_hash.clone()
+                }
+                _ => AuthConfig::password_hash_from_env()?,
+            },
             session_timeout_hours: Self::parse_env_var_with_default(
                 "COSMIC_SESSION_TIMEOUT_HOURS",
-                1,
+                file.auth.session_timeout_hours.unwrap_or(1),
+            )?,
+            max_login_attempts: Self::parse_env_var_with_default(
+                "COSMIC_MAX_LOGIN_ATTEMPTS",
+                file.auth.max_login_attempts.unwrap_or(5),
+            )?,
+            lockout_duration_secs: Self::parse_env_var_with_default(
+                "COSMIC_LOCKOUT_DURATION_SECS",
+                file.auth.lockout_duration_secs.unwrap_or(60),
+            )?,
+            permissions: match &file.auth.permissions {
+                Some(permissions) if !env_vars.contains_key("COSMIC_USER_PERMISSIONS") => {
+                    permissions.clone()
+                }
+                _ => AuthConfig::permissions_from_env(),
+            },
+            remember_me_days: Self::parse_env_var_with_default(
+                "COSMIC_REMEMBER_ME_DAYS",
+                file.auth.remember_me_days.unwrap_or(30),
             )?,
+            group_id: env_or_file("COSMIC_GROUP_ID", &file.auth.group_id)
+                .unwrap_or_else(|| DEFAULT_GROUP_ID.to_string()),
+            password_hash_file: env_or_file(
+                "COSMIC_PASSWORD_HASH_FILE",
+                &file.auth.password_hash_file,
+            )
+            .unwrap_or_default(),
         };
+        // A password changed in the app wins over the configured one
+        if let Some(password_hash) = auth.stored_password_hash() {
+            auth.password_hash = password_hash;
+        }
 
         // Server Configuration
-        let server_addr = env_vars
-            .get("LEPTOS_SITE_ADDR")
-            .cloned()
-            .unwrap_or_else(|| "0.0.0.0:3000".to_string());
-
-        let (host, port) = Self::parse_server_address(&server_addr)?;
+        let (host, port) = match env_vars.get("LEPTOS_SITE_ADDR") {
+            Some(server_addr) => Self::parse_server_address(server_addr)?,
+            None => (
+                file.server
+                    .host
+                    .clone()
+                    .unwrap_or_else(|| "0.0.0.0".to_string()),
+                file.server.port.unwrap_or(3000),
+            ),
+        };
 
         let server = ServerConfig {
             host,
             port,
-            site_root: env_vars
-                .get("LEPTOS_SITE_ROOT")
-                .cloned()
+            site_root: env_or_file("LEPTOS_SITE_ROOT", &file.server.site_root)
                 .unwrap_or_else(|| "site".to_string()),
-            environment: Self::parse_environment(
-                &env_vars
-                    .get("ENVIRONMENT")
-                    .cloned()
-                    .unwrap_or_else(|| "development".to_string()),
+            environment: match env_vars.get("ENVIRONMENT") {
+                Some(environment) => Self::parse_environment(environment)?,
+                None => file
+                    .server
+                    .environment
+                    .clone()
+                    .unwrap_or(Environment::Development),
+            },
+            heartbeat_interval_secs: Self::parse_env_var_with_default(
+                "HEARTBEAT_INTERVAL_SECS",
+                file.server.heartbeat_interval_secs.unwrap_or(30),
+            )?,
+            reject_past_due_dates: Self::parse_env_var_with_default(
+                "REJECT_PAST_DUE_DATES",
+                file.server.reject_past_due_dates.unwrap_or(false),
+            )?,
+            timezone: env_or_file("TIMEZONE", &file.server.timezone).unwrap_or_default(),
+            trust_forwarded_for: Self::parse_env_var_with_default(
+                "TRUST_FORWARDED_FOR",
+                file.server.trust_forwarded_for.unwrap_or(false),
             )?,
         };
 
         // Logging Configuration
         let logging = LoggingConfig {
-            level: env_vars
-                .get("RUST_LOG")
-                .cloned()
-                .unwrap_or_else(|| match server.environment {
+            level: env_or_file("RUST_LOG", &file.logging.level).unwrap_or_else(|| {
+                match server.environment {
                     Environment::Production => "info".to_string(),
                     Environment::Staging | Environment::Development => "debug".to_string(),
-                }),
-            format: Self::parse_log_format(
-                &env_vars
-                    .get("LOG_FORMAT")
-                    .cloned()
-                    .unwrap_or_else(|| "pretty".to_string()),
-            )?,
+                }
+            }),
+            format: match env_vars.get("LOG_FORMAT") {
+                Some(format) => Self::parse_log_format(format)?,
+                None => file.logging.format.clone().unwrap_or(LogFormat::Pretty),
+            },
         };
 
-        // email is specified in env varialbles as EMAIL_<assignee>=<email>
+        // assignees are specified in env variables as ASSIGNEE_<name>=<email>
         let emails = EmailConfig {
-            mikko: Self::get_required_env_var("EMAIL_MIKKO")?,
-            niina: Self::get_required_env_var("EMAIL_NIINA")?,
+            assignees: Self::parse_assignees(&env_vars, file.emails.assignees.as_deref())?,
+        };
+
+        // SMTP Configuration, optional
+        let smtp_username = env_or_file("SMTP_USERNAME", &file.smtp.username).unwrap_or_default();
+        let smtp = SmtpConfig {
+            host: env_or_file("SMTP_HOST", &file.smtp.host).unwrap_or_default(),
+            port: Self::parse_env_var_with_default("SMTP_PORT", file.smtp.port.unwrap_or(587))?,
+            password: env_or_file("SMTP_PASSWORD", &file.smtp.password).unwrap_or_default(),
+            from_address: env_or_file("SMTP_FROM", &file.smtp.from_address)
+                .unwrap_or_else(|| smtp_username.clone()),
+            username: smtp_username,
         };
 
+        // Blob Storage Configuration, optional
+        let blob = BlobConfig {
+            account_url: env_or_file("AZURE_STORAGE_ACCOUNT_URL", &file.blob.account_url)
+                .unwrap_or_default(),
+            container_name: env_or_file("AZURE_STORAGE_CONTAINER_NAME", &file.blob.container_name)
+                .unwrap_or_else(|| "attachments".to_string()),
+            sas_token: env_or_file("AZURE_STORAGE_SAS_TOKEN", &file.blob.sas_token)
+                .map(|token| token.trim_start_matches('?').to_string())
+                .unwrap_or_default(),
+            max_attachment_bytes: Self::parse_env_var_with_default(
+                "ATTACHMENT_MAX_BYTES",
+                file.blob.max_attachment_bytes.unwrap_or(5 * 1024 * 1024),
+            )?,
+        };
+
+        // Completion webhook, optional
+        let webhook_url = env_or_file("WEBHOOK_URL", &file.webhook_url).unwrap_or_default();
+
         Ok(AppConfig {
             cosmos,
             auth,
             server,
             logging,
             emails,
+            smtp,
+            blob,
+            webhook_url,
         })
     }
 
@@ -446,6 +1065,79 @@ impl AppConfig {
         env::vars().collect()
     }
 
+    /// Collect assignees from `ASSIGNEE_<NAME>=<email>` variables, sorted by name.
+    ///
+    /// Falls back to the legacy `EMAIL_MIKKO` and `EMAIL_NIINA` variables when
+    /// no `ASSIGNEE_` variables are set, and then to the assignees of the config
+    /// file. Underscores in the name become spaces and each word is capitalized,
+    /// so `ASSIGNEE_ANNA_LIISA` becomes "Anna Liisa".
+    fn parse_assignees(
+        env_vars: &std::collections::HashMap<String, String>,
+        file_assignees: Option<&[(String, String)]>,
+    ) -> Result<Vec<(String, String)>, ConfigError> {
+        const PREFIX: &str = "ASSIGNEE_";
+
+        let mut assignees = Vec::new();
+        for (key, email) in env_vars {
+            let Some(raw_name) = key.strip_prefix(PREFIX) else {
+                continue;
+            };
+            let name = Self::normalize_assignee_name(raw_name);
+            if name.parse::<TodoAssignee>().is_err() {
+                let config_line = format!("{key}={email}");
+                return Err(ConfigError::InvalidValue {
+                    value: raw_name.to_string(),
+                    expected: "an assignee name made of letters, spaces or hyphens".to_string(),
+                    src: config_line,
+                    span: (PREFIX.len(), raw_name.len()).into(),
+                });
+            }
+            assignees.push((name, email.clone()));
+        }
+        // Only the variables of the former fixed assignees, not every EMAIL_*
+        // variable, which other settings such as EMAIL_SMTP_HOST may use
+        if assignees.is_empty() {
+            assignees = LEGACY_ASSIGNEE_VARS
+                .iter()
+                .filter_map(|key| {
+                    let email = env_vars.get(*key)?;
+                    let raw_name = key.trim_start_matches("EMAIL_");
+                    Some((Self::normalize_assignee_name(raw_name), email.clone()))
+                })
+                .collect();
+        }
+        if assignees.is_empty() {
+            assignees = file_assignees.unwrap_or_default().to_vec();
+        }
+        assignees.sort();
+
+        if assignees.is_empty() {
+            let config_line = "ASSIGNEE_<NAME>=<missing>".to_string();
+            return Err(ConfigError::MissingRequired {
+                name: "ASSIGNEE_<NAME>".to_string(),
+                src: config_line.clone(),
+                span: (0, config_line.len()).into(),
+            });
+        }
+
+        Ok(assignees)
+    }
+
+    fn normalize_assignee_name(raw_name: &str) -> String {
+        raw_name
+            .split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let lower = part.to_lowercase();
+                let mut chars = lower.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_uppercase().chain(chars).collect::<String>()
+                })
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     fn get_required_env_var(name: &str) -> Result<String, ConfigError> {
         env::var(name).map_err(|_| {
             let config_line = format!("{name}=<missing>");
@@ -524,6 +1216,34 @@ impl AppConfig {
         }
     }
 
+    fn parse_cosmos_auth_method(method_str: &str) -> Result<CosmosAuthMethod, ConfigError> {
+        match method_str.to_lowercase().as_str() {
+            "key" | "connection_string" => Ok(CosmosAuthMethod::ConnectionString),
+            "azure_ad" | "aad" | "managed_identity" => Ok(CosmosAuthMethod::AzureAD),
+            _ => Err(ConfigError::InvalidValue {
+                value: method_str.to_string(),
+                expected: "key or azure_ad".to_string(),
+                src: format!("AZURE_COSMOS_AUTH_METHOD={method_str}"),
+                span: (25, method_str.len()).into(),
+            }),
+        }
+    }
+
+    /// Returns true for an `https://` URL with a host, e.g. `https://host:443/path`.
+    fn is_https_url(value: &str) -> bool {
+        value.strip_prefix("https://").is_some_and(|rest| {
+            let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+            let (host, port) = authority
+                .rsplit_once(':')
+                .map_or((authority, None), |(host, port)| (host, Some(port)));
+            !host.is_empty()
+                && host
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.'))
+                && port.is_none_or(|port| port.parse::<u16>().is_ok())
+        })
+    }
+
     /// Get the full server address
     #[must_use]
     pub fn server_address(&self) -> String {
@@ -547,8 +1267,10 @@ impl AppConfig {
     /// # Errors
     ///
     /// Returns a `ConfigError` if any configuration values are invalid,
-    /// such as empty username, password too short, invalid port number,
-    /// or insufficient Cosmos DB throughput.
+    /// such as empty username, malformed password hash, session timeout outside
+    /// 1 to 720 hours, invalid port number, Cosmos DB URI that is not an
+    /// `https://` URL, insufficient Cosmos DB throughput or malformed assignee
+    /// email address.
     pub fn validate(&self) -> Result<(), ConfigError> {
         // Validate authentication
         if self.auth.username.is_empty() {
@@ -561,13 +1283,81 @@ impl AppConfig {
             });
         }
 
-        if self.auth.password.len() < 8 {
-            let config_line = format!("COSMIC_PASSWORD={}", "*".repeat(self.auth.password.len()));
+        if argon2::password_hash::PasswordHash::new(&self.auth.password_hash).is_err() {
+            let config_line = format!("COSMIC_PASSWORD_HASH={}", self.auth.password_hash);
             return Err(ConfigError::InvalidValue {
-                value: format!("{} characters", self.auth.password.len()),
-                expected: "at least 8 characters".to_string(),
+                value: self.auth.password_hash.clone(),
+                expected: "argon2 hash in PHC string format ($argon2id$...)".to_string(),
                 src: config_line.clone(),
-                span: (15, config_line.len()).into(),
+                span: (21, config_line.len() - 21).into(),
+            });
+        }
+
+        if !(1..=720).contains(&self.auth.session_timeout_hours) {
+            let config_line = format!(
+                "COSMIC_SESSION_TIMEOUT_HOURS={}",
+                self.auth.session_timeout_hours
+            );
+            return Err(ConfigError::InvalidValue {
+                value: self.auth.session_timeout_hours.to_string(),
+                expected: "between 1 and 720 hours".to_string(),
+                src: config_line.clone(),
+                span: (29, config_line.len() - 29).into(),
+            });
+        }
+
+        if self.auth.max_login_attempts == 0 {
+            let config_line = format!("COSMIC_MAX_LOGIN_ATTEMPTS={}", self.auth.max_login_attempts);
+            return Err(ConfigError::InvalidValue {
+                value: self.auth.max_login_attempts.to_string(),
+                expected: "at least 1 attempt".to_string(),
+                src: config_line.clone(),
+                span: (26, config_line.len() - 26).into(),
+            });
+        }
+
+        if !(1..=365).contains(&self.auth.remember_me_days) {
+            let config_line = format!("COSMIC_REMEMBER_ME_DAYS={}", self.auth.remember_me_days);
+            return Err(ConfigError::InvalidValue {
+                value: self.auth.remember_me_days.to_string(),
+                expected: "between 1 and 365 days".to_string(),
+                src: config_line.clone(),
+                span: (24, config_line.len() - 24).into(),
+            });
+        }
+
+        if self.auth.group_id.is_empty()
+            || self.auth.group_id.len() > 64
+            || !self
+                .auth
+                .group_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+        {
+            let config_line = format!("COSMIC_GROUP_ID={}", self.auth.group_id);
+            return Err(ConfigError::InvalidValue {
+                value: self.auth.group_id.clone(),
+                expected: "1-64 letters, digits, '-' or '_'".to_string(),
+                src: config_line.clone(),
+                span: (16, config_line.len() - 16).into(),
+            });
+        }
+
+        if let Some(unknown) = self
+            .auth
+            .permissions
+            .iter()
+            .find(|perm| !KNOWN_PERMISSIONS.contains(&perm.as_str()))
+        {
+            let config_line = format!(
+                "COSMIC_USER_PERMISSIONS={}",
+                self.auth.permissions.join(",")
+            );
+            return Err(ConfigError::InvalidValue {
+                value: unknown.clone(),
+                expected: format!("one of: {}", KNOWN_PERMISSIONS.join(", ")),
+                src: config_line.clone(),
+                span: (24, config_line.len() - 24).into(),
             });
         }
 
@@ -582,7 +1372,52 @@ impl AppConfig {
             });
         }
 
+        if !(5..=3600).contains(&self.server.heartbeat_interval_secs) {
+            let config_line = format!(
+                "HEARTBEAT_INTERVAL_SECS={}",
+                self.server.heartbeat_interval_secs
+            );
+            return Err(ConfigError::InvalidValue {
+                value: self.server.heartbeat_interval_secs.to_string(),
+                expected: "between 5 and 3600 seconds".to_string(),
+                src: config_line.clone(),
+                span: (24, config_line.len() - 24).into(),
+            });
+        }
+
         // Validate Cosmos DB configuration
+        if !Self::is_https_url(&self.cosmos.uri) {
+            let config_line = format!("AZURE_COSMOS_DB_URI={}", self.cosmos.uri);
+            return Err(ConfigError::InvalidValue {
+                value: self.cosmos.uri.clone(),
+                expected:
+                    "https:// URL of the account, e.g. https://<account>.documents.azure.com:443/"
+                        .to_string(),
+                src: config_line.clone(),
+                span: (20, config_line.len() - 20).into(),
+            });
+        }
+
+        if self.cosmos.auth_method == CosmosAuthMethod::ConnectionString
+            && self.cosmos.connection_string.is_empty()
+        {
+            let config_line = "AZURE_COSMOS_DB_PRIMARY_KEY=".to_string();
+            return Err(ConfigError::CosmosConfigError {
+                src: config_line.clone(),
+                span: (0, config_line.len()).into(),
+            });
+        }
+
+        if !self.server.timezone.is_empty() && self.server.timezone.parse::<Tz>().is_err() {
+            let config_line = format!("TIMEZONE={}", self.server.timezone);
+            return Err(ConfigError::InvalidValue {
+                value: self.server.timezone.clone(),
+                expected: "IANA time zone name such as Europe/Helsinki".to_string(),
+                src: config_line.clone(),
+                span: (9, config_line.len() - 9).into(),
+            });
+        }
+
         if self.cosmos.throughput < 400 {
             let config_line = format!("AZURE_COSMOS_THROUGHPUT={}", self.cosmos.throughput);
             return Err(ConfigError::InvalidValue {
@@ -593,6 +1428,78 @@ impl AppConfig {
             });
         }
 
+        if self.cosmos.max_retries > 10 {
+            let config_line = format!("AZURE_COSMOS_MAX_RETRIES={}", self.cosmos.max_retries);
+            return Err(ConfigError::InvalidValue {
+                value: self.cosmos.max_retries.to_string(),
+                expected: "at most 10 retries".to_string(),
+                src: config_line.clone(),
+                span: (25, config_line.len() - 25).into(),
+            });
+        }
+
+        if self.cosmos.cache_ttl_secs > 300 {
+            let config_line = format!("AZURE_COSMOS_CACHE_TTL_SECS={}", self.cosmos.cache_ttl_secs);
+            return Err(ConfigError::InvalidValue {
+                value: self.cosmos.cache_ttl_secs.to_string(),
+                expected: "at most 300 seconds".to_string(),
+                src: config_line.clone(),
+                span: (28, config_line.len() - 28).into(),
+            });
+        }
+
+        // Validate assignee emails
+        if let Some((name, email)) = self
+            .emails
+            .iter()
+            .find(|(_, email)| validate_email(email).is_err())
+        {
+            let key = format!("ASSIGNEE_{}", name.to_uppercase().replace(' ', "_"));
+            let config_line = format!("{key}={email}");
+            return Err(ConfigError::InvalidValue {
+                value: email.clone(),
+                expected: format!("email address of {name}"),
+                src: config_line.clone(),
+                span: (key.len() + 1, email.len()).into(),
+            });
+        }
+
+        // Validate SMTP configuration
+        if self.smtp.is_configured() && validate_email(&self.smtp.from_address).is_err() {
+            let config_line = format!("SMTP_FROM={}", self.smtp.from_address);
+            return Err(ConfigError::InvalidValue {
+                value: self.smtp.from_address.clone(),
+                expected: "sender email address (set SMTP_FROM or SMTP_USERNAME)".to_string(),
+                src: config_line.clone(),
+                span: (10, config_line.len() - 10).into(),
+            });
+        }
+
+        // Validate Blob Storage configuration
+        if self.blob.is_configured() && !self.blob.account_url.starts_with("https://") {
+            let config_line = format!("AZURE_STORAGE_ACCOUNT_URL={}", self.blob.account_url);
+            return Err(ConfigError::InvalidValue {
+                value: self.blob.account_url.clone(),
+                expected: "https:// URL of the blob service".to_string(),
+                src: config_line.clone(),
+                span: (26, config_line.len() - 26).into(),
+            });
+        }
+
+        // Validate the completion webhook
+        if !self.webhook_url.is_empty()
+            && !self.webhook_url.starts_with("https://")
+            && !self.webhook_url.starts_with("http://")
+        {
+            let config_line = format!("WEBHOOK_URL={}", self.webhook_url);
+            return Err(ConfigError::InvalidValue {
+                value: self.webhook_url.clone(),
+                expected: "http:// or https:// URL of the webhook".to_string(),
+                src: config_line.clone(),
+                span: (12, config_line.len() - 12).into(),
+            });
+        }
+
         Ok(())
     }
 }
@@ -600,7 +1507,7 @@ impl AppConfig {
 // Global configuration instance
 static APP_CONFIG: std::sync::LazyLock<Result<AppConfig, ConfigError>> =
     std::sync::LazyLock::new(|| {
-        let config = AppConfig::from_env()?;
+        let config = AppConfig::load()?;
         config.validate()?;
         Ok(config)
     });
@@ -638,3 +1545,197 @@ pub fn initialize_config() -> miette::Result<()> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_config() -> AppConfig {
+        AppConfig {
+            cosmos: CosmosConfig {
+                uri: "https://family.documents.azure.com:443/".to_string(),
+                connection_string: "primary-key".to_string(),
+                database_name: "cosmic".to_string(),
+                container_name: "todos".to_string(),
+                auth_method: CosmosAuthMethod::ConnectionString,
+                max_item_count: 100,
+                throughput: 400,
+                max_retries: 3,
+                cache_ttl_secs: 5,
+            },
+            auth: AuthConfig {
+                username: "family".to_string(),
+                password_hash: AuthConfig::hash_password("correct horse").unwrap(),
+                password_hash_file: String::new(),
+                session_timeout_hours: 1,
+                max_login_attempts: 5,
+                lockout_duration_secs: 60,
+                permissions: KNOWN_PERMISSIONS.iter().map(ToString::to_string).collect(),
+                remember_me_days: 30,
+                group_id: DEFAULT_GROUP_ID.to_string(),
+            },
+            server: ServerConfig {
+                host: "127.0.0.1".to_string(),
+                port: 3000,
+                site_root: "site".to_string(),
+                heartbeat_interval_secs: 30,
+                environment: Environment::Development,
+                reject_past_due_dates: false,
+                timezone: "Europe/Helsinki".to_string(),
+                trust_forwarded_for: false,
+            },
+            logging: LoggingConfig {
+                level: "info".to_string(),
+                format: LogFormat::Pretty,
+            },
+            emails: EmailConfig {
+                assignees: vec![("Mikko".to_string(), "mikko@example.com".to_string())],
+            },
+            smtp: SmtpConfig {
+                host: String::new(),
+                port: 587,
+                username: String::new(),
+                password: String::new(),
+                from_address: String::new(),
+            },
+            blob: BlobConfig {
+                account_url: String::new(),
+                container_name: "attachments".to_string(),
+                sas_token: String::new(),
+                max_attachment_bytes: 10 * 1024 * 1024,
+            },
+            webhook_url: String::new(),
+        }
+    }
+
+    fn rejected_value(config: &AppConfig) -> Option<String> {
+        match config.validate() {
+            Err(ConfigError::InvalidValue { value, .. }) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn valid_config_passes() {
+        assert!(valid_config().validate().is_ok());
+    }
+
+    #[test]
+    fn assignee_emails_must_be_email_addresses() {
+        let mut config = valid_config();
+        config
+            .emails
+            .assignees
+            .push(("Anna".to_string(), "anna.example.com".to_string()));
+        assert_eq!(rejected_value(&config).as_deref(), Some("anna.example.com"));
+
+        config.emails.assignees[1].1 = "anna@example.com".to_string();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn cosmos_uri_must_be_https() {
+        let mut config = valid_config();
+        for uri in [
+            "http://family.documents.azure.com:443/",
+            "family.documents.azure.com",
+            "https://",
+            "https://family.documents.azure.com:port/",
+        ] {
+            config.cosmos.uri = uri.to_string();
+            assert_eq!(rejected_value(&config).as_deref(), Some(uri), "{uri}");
+        }
+
+        for uri in [
+            "https://family.documents.azure.com:443/",
+            "https://localhost:8081",
+        ] {
+            config.cosmos.uri = uri.to_string();
+            assert!(config.validate().is_ok(), "{uri}");
+        }
+    }
+
+    fn env(vars: &[(&str, &str)]) -> std::collections::HashMap<String, String> {
+        vars.iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn assignees_are_read_from_assignee_variables_sorted_by_name() {
+        let vars = env(&[
+            ("ASSIGNEE_NIINA", "niina@example.com"),
+            ("ASSIGNEE_ANNA_LIISA", "anna@example.com"),
+            ("EMAIL_MIKKO", "mikko@example.com"),
+        ]);
+
+        assert_eq!(
+            AppConfig::parse_assignees(&vars, None).unwrap(),
+            vec![
+                ("Anna Liisa".to_string(), "anna@example.com".to_string()),
+                ("Niina".to_string(), "niina@example.com".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn legacy_fallback_only_reads_the_former_assignees() {
+        let vars = env(&[
+            ("EMAIL_NIINA", "niina@example.com"),
+            ("EMAIL_MIKKO", "mikko@example.com"),
+            ("EMAIL_SMTP_HOST", "smtp.example.com"),
+        ]);
+
+        assert_eq!(
+            AppConfig::parse_assignees(&vars, None).unwrap(),
+            vec![
+                ("Mikko".to_string(), "mikko@example.com".to_string()),
+                ("Niina".to_string(), "niina@example.com".to_string()),
+            ]
+        );
+
+        let vars = env(&[("EMAIL_SMTP_HOST", "smtp.example.com")]);
+        assert!(matches!(
+            AppConfig::parse_assignees(&vars, None),
+            Err(ConfigError::MissingRequired { .. })
+        ));
+    }
+
+    #[test]
+    fn config_file_fields_are_optional() {
+        let file: ConfigFile = toml::from_str(
+            r#"
+            [cosmos]
+            uri = "https://family.documents.azure.com:443/"
+
+            [server]
+            port = 8080
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            file.cosmos.uri.as_deref(),
+            Some("https://family.documents.azure.com:443/")
+        );
+        assert!(file.cosmos.connection_string.is_none());
+        assert_eq!(file.server.port, Some(8080));
+        assert!(file.auth.password_hash.is_none());
+        assert!(file.emails.assignees.is_none());
+        assert!(toml::from_str::<ConfigFile>("").is_ok());
+    }
+
+    #[test]
+    fn session_timeout_must_be_within_a_month() {
+        let mut config = valid_config();
+        for hours in [0, 721] {
+            config.auth.session_timeout_hours = hours;
+            assert_eq!(rejected_value(&config), Some(hours.to_string()));
+        }
+
+        for hours in [1, 720] {
+            config.auth.session_timeout_hours = hours;
+            assert!(config.validate().is_ok(), "{hours}");
+        }
+    }
+}
@@ -8,9 +8,17 @@ use super::model::{AuthState, LoginRequest, LoginResponse, UserInfo};
 pub struct AuthContext {
     pub is_authenticated: ReadSignal<bool>,
     pub user_info: ReadSignal<Option<UserInfo>>,
+    /// Permissions of the signed-in user, empty when signed out
+    pub permissions: Signal<Vec<String>>,
     pub logout: Action<(), Result<(), String>>,
     pub login: Action<LoginRequest, Result<LoginResponse, ServerFnError>>,
+    /// Signs out of every session of the current user, returning how many were invalidated
+    pub logout_all: Action<(), Result<usize, String>>,
+    /// Extends the current session
+    pub refresh: Action<(), Result<(), String>>,
     pub is_loading: ReadSignal<bool>,
+    /// Seconds left before the session expires, ticking down client-side
+    pub session_remaining: ReadSignal<Option<i64>>,
 }
 
 #[component]
@@ -21,19 +29,11 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
     let initial_auth_state = {
         #[cfg(feature = "hydrate")]
         {
-            get_auth_state().unwrap_or(AuthState {
-                is_authenticated: false,
-                user_info: None,
-                session_token: None,
-            })
+            get_auth_state().unwrap_or_default()
         }
         #[cfg(not(feature = "hydrate"))]
         {
-            AuthState {
-                is_authenticated: false,
-                user_info: None,
-                session_token: None,
-            }
+            AuthState::new()
         }
     };
 
@@ -45,6 +45,8 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
     let (is_authenticated, set_is_authenticated) = signal(initial_auth_state.is_authenticated);
     let (user_info, set_user_info) = signal(initial_auth_state.user_info.clone());
     let (is_loading, set_is_loading) = signal(true);
+    // Seconds until the current session expires, as last reported by the server
+    let (session_expires_in, set_session_expires_in) = signal(None::<i64>);
 
     // Only validate session if we have a stored session, don't clear state immediately
     let validate_auth: Resource<Option<AuthStatus>> = Resource::new(
@@ -56,11 +58,10 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
                 if let Some(auth_state) = get_auth_state() {
                     leptos::logging::log!("Found stored auth state, validating...");
 
-                    if auth_state.is_authenticated && auth_state.session_token.is_some() {
-                        let token = auth_state.session_token.unwrap();
-                        leptos::logging::log!("Validating session token...");
+                    if auth_state.is_authenticated {
+                        leptos::logging::log!("Validating session cookie...");
 
-                        match validate_session(token.clone()).await {
+                        match validate_session().await {
                             Ok(validation_result) => {
                                 leptos::logging::log!(
                                     "Session validation result: authenticated={}",
@@ -72,7 +73,7 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
                                     let updated_auth_state = AuthState {
                                         is_authenticated: true,
                                         user_info: validation_result.user_info.clone(),
-                                        session_token: Some(token),
+                                        remember_me: auth_state.remember_me,
                                     };
                                     store_auth_state(&updated_auth_state);
                                     return Some(validation_result);
@@ -95,7 +96,7 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
                             }
                         }
                     } else {
-                        leptos::logging::log!("No session token found in stored state");
+                        leptos::logging::log!("Stored auth state is not authenticated");
                     }
                 } else {
                     leptos::logging::log!("No stored auth state found");
@@ -118,6 +119,7 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
 
                 set_is_authenticated.set(auth_status.is_authenticated);
                 set_user_info.set(auth_status.user_info.clone());
+                set_session_expires_in.set(auth_status.session_expires_in);
                 set_is_loading.set(false);
 
                 // Only update localStorage if session is valid
@@ -127,7 +129,7 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
                         let auth_state = AuthState {
                             is_authenticated: auth_status.is_authenticated,
                             user_info: auth_status.user_info,
-                            session_token: get_session_token(),
+                            remember_me: get_auth_state().is_some_and(|state| state.remember_me),
                         };
                         store_auth_state(&auth_state);
                     } else {
@@ -166,31 +168,31 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
         let credentials = credentials.clone();
         async move {
             leptos::logging::log!("Login action started for user: {}", credentials.username);
+            #[cfg(feature = "hydrate")]
+            let remember_me = credentials.remember_me;
 
             match authenticate_user(credentials).await {
                 Ok(response) => {
                     leptos::logging::log!("Login response received: success={}", response.success);
 
                     if response.success {
-                        #[allow(unused_variables)]
-                        if let Some(token) = &response.token {
-                            // Update signals immediately
-                            set_is_authenticated.set(true);
-                            set_user_info.set(response.user_info.clone());
-
-                            // Store complete auth state in localStorage
-                            #[cfg(feature = "hydrate")]
-                            {
-                                let auth_state = AuthState {
-                                    is_authenticated: true,
-                                    user_info: response.user_info.clone(),
-                                    session_token: Some(token.clone()),
-                                };
-                                store_auth_state(&auth_state);
-                                leptos::logging::log!(
-                                    "Auth state stored in localStorage after successful login"
-                                );
-                            }
+                        // The session token arrives as an HttpOnly cookie; only the
+                        // non-sensitive user info is kept client-side
+                        set_is_authenticated.set(true);
+                        set_user_info.set(response.user_info.clone());
+                        set_session_expires_in.set(response.expires_in);
+
+                        #[cfg(feature = "hydrate")]
+                        {
+                            let auth_state = AuthState {
+                                is_authenticated: true,
+                                user_info: response.user_info.clone(),
+                                remember_me,
+                            };
+                            store_auth_state(&auth_state);
+                            leptos::logging::log!(
+                                "Auth state stored in browser storage after successful login"
+                            );
                         }
                     }
                     Ok(response)
@@ -218,16 +220,12 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
         #[cfg(feature = "hydrate")]
         {
             use crate::api::logout_user;
-            if let Some(auth_state) = get_auth_state() {
-                if let Some(token) = auth_state.session_token {
-                    match logout_user(token).await {
-                        Ok(_) => {
-                            leptos::logging::log!("Server logout successful");
-                        }
-                        Err(e) => {
-                            leptos::logging::log!("Server logout failed: {}", e);
-                        }
-                    }
+            match logout_user().await {
+                Ok(_) => {
+                    leptos::logging::log!("Server logout successful");
+                }
+                Err(e) => {
+                    leptos::logging::log!("Server logout failed: {}", e);
                 }
             }
             // Clear localStorage
@@ -236,17 +234,145 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
 
         set_is_authenticated.set(false);
         set_user_info.set(None);
+        set_session_expires_in.set(None);
         leptos::logging::log!("User logged out successfully");
 
         Ok(())
     });
 
+    // Sign out everywhere: invalidate all sessions server-side, then clear local state
+    let logout_all = Action::new(move |(): &()| async move {
+        #[allow(unused_mut)]
+        let mut invalidated = 0;
+
+        #[cfg(feature = "hydrate")]
+        {
+            use crate::api::logout_all_sessions;
+
+            invalidated = logout_all_sessions()
+                .await
+                .map_err(|e| format!("Failed to sign out everywhere: {e}"))?;
+            remove_auth_state();
+        }
+
+        set_is_authenticated.set(false);
+        set_user_info.set(None);
+        set_session_expires_in.set(None);
+        leptos::logging::log!("Signed out of {} sessions", invalidated);
+
+        Ok(invalidated)
+    });
+
+    // Refresh action, used both by the automatic refresh and the expiry warning
+    let refresh = Action::new(move |(): &()| async move {
+        #[cfg(feature = "hydrate")]
+        {
+            use crate::api::refresh_session;
+
+            let expires_in = refresh_session()
+                .await
+                .map_err(|e| format!("Session refresh failed: {e}"))?;
+
+            // Pick up the extended expiry so the countdown and schedule restart
+            set_session_expires_in.set(Some(expires_in));
+            leptos::logging::log!("Session refreshed");
+        }
+        Ok(())
+    });
+
+    // A failed refresh means the session is gone, so log out
+    Effect::new(move |_| {
+        if let Some(Err(e)) = refresh.value().get() {
+            leptos::logging::log!("{}", e);
+            logout.dispatch(());
+        }
+    });
+
+    // Client-side countdown of the seconds left in the session
+    let (session_remaining, set_session_remaining) = signal(None::<i64>);
+    Effect::new(move |_| {
+        set_session_remaining.set(session_expires_in.get());
+    });
+
+    #[cfg(feature = "hydrate")]
+    {
+        use leptos::leptos_dom::helpers::{IntervalHandle, set_interval_with_handle};
+
+        let countdown_handle = set_interval_with_handle(
+            move || {
+                set_session_remaining.update(|remaining| {
+                    if let Some(seconds) = remaining {
+                        *seconds = (*seconds - 1).max(0);
+                    }
+                });
+            },
+            std::time::Duration::from_secs(1),
+        );
+        match countdown_handle {
+            Ok(handle) => on_cleanup(move || handle.clear()),
+            Err(e) => leptos::logging::warn!("Failed to start session countdown: {:?}", e),
+        }
+
+        // Refresh the session once ~80% of its remaining lifetime has passed. The
+        // interval keeps firing at the same period, which stays well inside the
+        // full lifetime granted by each refresh.
+        let refresh_handle = StoredValue::new(None::<IntervalHandle>);
+
+        Effect::new(move |_| {
+            if let Some(handle) = refresh_handle.get_value() {
+                handle.clear();
+                refresh_handle.set_value(None);
+            }
+
+            let Some(expires_in) = session_expires_in.get() else {
+                return;
+            };
+            if !is_authenticated.get() {
+                return;
+            }
+
+            let period_secs = u64::try_from(expires_in.saturating_mul(4) / 5)
+                .unwrap_or(0)
+                .max(MIN_REFRESH_INTERVAL_SECS);
+            let period = std::time::Duration::from_secs(period_secs);
+
+            match set_interval_with_handle(
+                move || {
+                    refresh.dispatch(());
+                },
+                period,
+            ) {
+                Ok(handle) => refresh_handle.set_value(Some(handle)),
+                Err(e) => {
+                    leptos::logging::warn!("Failed to schedule session refresh: {:?}", e);
+                }
+            }
+        });
+
+        on_cleanup(move || {
+            if let Some(handle) = refresh_handle.get_value() {
+                handle.clear();
+            }
+        });
+    }
+
+    let permissions = Signal::derive(move || {
+        user_info
+            .get()
+            .map(|user| user.permissions)
+            .unwrap_or_default()
+    });
+
     let auth_context = AuthContext {
         is_authenticated,
         user_info,
+        permissions,
         logout,
         login,
+        logout_all,
+        refresh,
         is_loading,
+        session_remaining,
     };
 
     provide_context(auth_context);
@@ -258,34 +384,79 @@ pub fn AuthProvider(children: Children) -> impl IntoView {
 //            {children()}
 //        </Provider>
 
+/// Lower bound for the session refresh interval, so very short sessions don't
+/// flood the server with refresh calls.
+#[cfg(feature = "hydrate")]
+const MIN_REFRESH_INTERVAL_SECS: u64 = 30;
+
 #[must_use]
 pub fn use_auth() -> AuthContext {
     expect_context::<AuthContext>()
 }
 
-// localStorage helpers for auth state
+// Browser storage helpers for auth state. A remembered login lives in
+// `localStorage`; otherwise `sessionStorage` is used so it clears when the browser closes.
+#[cfg(feature = "hydrate")]
+const AUTH_STATE_NAME: &str = "auth_state";
+
+/// Key the auth state was stored under before it was namespaced by app name.
+#[cfg(feature = "hydrate")]
+const LEGACY_AUTH_STATE_KEY: &str = "auth_state";
+
+#[cfg(feature = "hydrate")]
+fn auth_state_key() -> String {
+    crate::config::storage_key(AUTH_STATE_NAME)
+}
+
+/// Moves an auth state stored under the legacy key to the namespaced one, in
+/// whichever storage holds it; an existing namespaced state is kept.
+#[cfg(feature = "hydrate")]
+fn migrate_legacy_auth_state(storage: &web_sys::Storage, key: &str) {
+    let Ok(Some(legacy)) = storage.get_item(LEGACY_AUTH_STATE_KEY) else {
+        return;
+    };
+    if matches!(storage.get_item(key), Ok(None)) {
+        let _ = storage.set_item(key, &legacy);
+    }
+    let _ = storage.remove_item(LEGACY_AUTH_STATE_KEY);
+}
+
+#[cfg(feature = "hydrate")]
+fn auth_storages() -> Vec<web_sys::Storage> {
+    let Some(window) = web_sys::window() else {
+        return Vec::new();
+    };
+    [window.local_storage(), window.session_storage()]
+        .into_iter()
+        .filter_map(|storage| storage.ok().flatten())
+        .collect()
+}
+
 #[cfg(feature = "hydrate")]
 pub fn get_auth_state() -> Option<AuthState> {
     use leptos::leptos_dom::logging;
-    use web_sys::window;
-
-    let window = window()?;
-    let storage = window.local_storage().ok()??;
 
-    match storage.get_item("auth_state") {
-        Ok(Some(auth_state_str)) => match serde_json::from_str::<AuthState>(&auth_state_str) {
-            Ok(auth_state) => Some(auth_state),
+    let key = auth_state_key();
+    let storages = auth_storages();
+    for storage in &storages {
+        migrate_legacy_auth_state(storage, &key);
+    }
+    storages
+        .into_iter()
+        .find_map(|storage| match storage.get_item(&key) {
+            Ok(Some(auth_state_str)) => match serde_json::from_str::<AuthState>(&auth_state_str) {
+                Ok(auth_state) => Some(auth_state),
+                Err(e) => {
+                    logging::console_warn(&format!("Failed to parse auth state: {}", e));
+                    None
+                }
+            },
+            Ok(None) => None,
             Err(e) => {
-                logging::console_warn(&format!("Failed to parse auth state: {}", e));
+                logging::console_warn(&format!("Failed to get auth state: {:?}", e));
                 None
             }
-        },
-        Ok(None) => None,
-        Err(e) => {
-            logging::console_warn(&format!("Failed to get auth state: {:?}", e));
-            None
-        }
-    }
+        })
 }
 
 #[cfg(not(feature = "hydrate"))]
@@ -297,18 +468,28 @@ pub fn get_auth_state() -> Option<AuthState> {
 #[cfg(feature = "hydrate")]
 pub fn store_auth_state(auth_state: &AuthState) {
     use leptos::leptos_dom::logging;
-    if let Some(window) = web_sys::window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            match serde_json::to_string(auth_state) {
-                Ok(auth_state_str) => {
-                    if let Err(e) = storage.set_item("auth_state", &auth_state_str) {
-                        logging::console_warn(&format!("Failed to store auth state: {:?}", e));
-                    }
-                }
-                Err(e) => {
-                    logging::console_warn(&format!("Failed to serialize auth state: {}", e));
+
+    // Drop any copy in the other storage so a stale state cannot be picked up later
+    remove_auth_state();
+
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let storage = if auth_state.remember_me {
+        window.local_storage()
+    } else {
+        window.session_storage()
+    };
+    if let Ok(Some(storage)) = storage {
+        match serde_json::to_string(auth_state) {
+            Ok(auth_state_str) => {
+                if let Err(e) = storage.set_item(&auth_state_key(), &auth_state_str) {
+                    logging::console_warn(&format!("Failed to store auth state: {:?}", e));
                 }
             }
+            Err(e) => {
+                logging::console_warn(&format!("Failed to serialize auth state: {}", e));
+            }
         }
     }
 }
@@ -321,11 +502,11 @@ pub fn store_auth_state(_auth_state: &AuthState) {
 #[cfg(feature = "hydrate")]
 pub fn remove_auth_state() {
     use leptos::leptos_dom::logging;
-    if let Some(window) = web_sys::window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            if let Err(e) = storage.remove_item("auth_state") {
-                logging::console_warn(&format!("Failed to remove auth state: {:?}", e));
-            }
+
+    let key = auth_state_key();
+    for storage in auth_storages() {
+        if let Err(e) = storage.remove_item(&key) {
+            logging::console_warn(&format!("Failed to remove auth state: {:?}", e));
         }
     }
 }
@@ -334,14 +515,3 @@ pub fn remove_auth_state() {
 pub fn remove_auth_state() {
     // No-op on server
 }
-
-#[cfg(feature = "hydrate")]
-pub fn get_session_token() -> Option<String> {
-    get_auth_state().and_then(|state| state.session_token)
-}
-
-#[cfg(not(feature = "hydrate"))]
-#[must_use]
-pub fn get_session_token() -> Option<String> {
-    None
-}
@@ -1,5 +1,14 @@
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+/// Permission to view todos.
+pub const READ_TODOS: &str = "read_todos";
+/// Permission to create, edit and delete todos.
+pub const WRITE_TODOS: &str = "write_todos";
+/// Every permission a user can be granted.
+pub const KNOWN_PERMISSIONS: [&str; 2] = [READ_TODOS, WRITE_TODOS];
+/// Group of todos created before groups existed, and of users without `COSMIC_GROUP_ID`.
+pub const DEFAULT_GROUP_ID: &str = "family";
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct LoginRequest {
@@ -8,14 +17,56 @@ pub struct LoginRequest {
 
     #[validate(length(min = 1, message = "Password is required"))]
     pub password: String,
+
+    /// Keep the session for the long "remember me" lifetime and persist it across browser restarts
+    #[serde(default)]
+    pub remember_me: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ChangePasswordRequest {
+    #[validate(length(min = 1, message = "Current password is required"))]
+    pub current_password: String,
+
+    #[validate(length(min = 8, max = 128, message = "Password must be 8-128 characters"))]
+    #[validate(custom(function = "validate_password_strength"))]
+    pub new_password: String,
+}
+
+/// Validates that a password mixes upper and lower case letters, digits and
+/// special characters.
+///
+/// # Errors
+///
+/// Returns a `ValidationError` with code "`weak_password`" if any of them is missing.
+pub fn validate_password_strength(password: &str) -> Result<(), ValidationError> {
+    let has_upper = password.chars().any(char::is_uppercase);
+    let has_lower = password.chars().any(char::is_lowercase);
+    let has_digit = password.chars().any(char::is_numeric);
+    let has_special = password
+        .chars()
+        .any(|c| "!@#$%^&*()_+-=[]{}|;:,.<>?".contains(c));
+
+    if !(has_upper && has_lower && has_digit && has_special) {
+        return Err(ValidationError::new("weak_password").with_message(
+            "Password must contain uppercase, lowercase, digit, and special character".into(),
+        ));
+    }
+
+    Ok(())
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoginResponse {
     pub success: bool,
     pub message: String,
+    /// Always `None`: the web app receives the session token as an HttpOnly
+    /// cookie, and JSON API clients get one from `POST /api/v1/auth/token`
     pub token: Option<String>,
     pub user_info: Option<UserInfo>,
+    /// Seconds until the new session expires
+    #[serde(default)]
+    pub expires_in: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,13 +74,27 @@ pub struct UserInfo {
     pub username: String,
     pub email: String,
     pub display_name: String,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    /// Household whose todos the user sees
+    #[serde(default)]
+    pub group_id: String,
+}
+
+impl UserInfo {
+    #[must_use]
+    pub fn has_permission(&self, perm: &str) -> bool {
+        self.permissions.iter().any(|p| p == perm)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthState {
     pub is_authenticated: bool,
     pub user_info: Option<UserInfo>,
-    pub session_token: Option<String>,
+    /// Kept in `localStorage` when set, otherwise in `sessionStorage`
+    #[serde(default)]
+    pub remember_me: bool,
 }
 
 impl AuthState {
@@ -38,23 +103,22 @@ impl AuthState {
         Self {
             is_authenticated: false,
             user_info: None,
-            session_token: None,
+            remember_me: false,
         }
     }
 
     #[must_use]
-    pub fn authenticated(user_info: UserInfo, token: String) -> Self {
+    pub fn authenticated(user_info: UserInfo, remember_me: bool) -> Self {
         Self {
             is_authenticated: true,
             user_info: Some(user_info),
-            session_token: Some(token),
+            remember_me,
         }
     }
 
     pub fn logout(&mut self) {
         self.is_authenticated = false;
         self.user_info = None;
-        self.session_token = None;
     }
 }
 
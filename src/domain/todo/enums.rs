@@ -5,6 +5,7 @@ use std::str::FromStr;
 #[derive(Clone, Debug, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum TodoStatus {
     Pending,
+    InProgress,
     Completed,
 }
 
@@ -13,6 +14,7 @@ impl TodoStatus {
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Pending => "Pending",
+            Self::InProgress => "In Progress",
             Self::Completed => "Completed",
         }
     }
@@ -21,6 +23,7 @@ impl TodoStatus {
     pub fn bg_color(self) -> &'static str {
         match self {
             TodoStatus::Pending => "bg-gray-100 text-gray-800",
+            TodoStatus::InProgress => "bg-blue-100 text-blue-800",
             TodoStatus::Completed => "bg-green-100 text-green-800",
         }
     }
@@ -38,36 +41,138 @@ impl FromStr for TodoStatus {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "Pending" => Ok(Self::Pending),
+            "In Progress" | "InProgress" => Ok(Self::InProgress),
             "Completed" => Ok(Self::Completed),
             _ => Err(format!("Invalid todo status: {s}")),
         }
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
-pub enum TodoAssignee {
-    Mikko,
-    Niina,
+#[derive(
+    Clone, Debug, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum TodoPriority {
+    Low,
+    #[default]
+    Medium,
+    High,
 }
 
-impl TodoAssignee {
+impl TodoPriority {
     #[must_use]
     pub fn as_str(&self) -> &'static str {
         match self {
-            Self::Mikko => "Mikko",
-            Self::Niina => "Niina",
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
         }
     }
 
     #[must_use]
-    pub fn email(&self) -> &'static str {
+    pub fn bg_color(self) -> &'static str {
         match self {
-            Self::Mikko => "mikko@familyleppanen.com",
-            Self::Niina => "niina@familyleppanen.com",
+            TodoPriority::Low => "bg-slate-100 text-slate-700",
+            TodoPriority::Medium => "bg-orange-100 text-orange-800",
+            TodoPriority::High => "bg-red-100 text-red-800",
         }
     }
 }
 
+impl Display for TodoPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for TodoPriority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Low" => Ok(Self::Low),
+            "Medium" => Ok(Self::Medium),
+            "High" => Ok(Self::High),
+            _ => Err(format!("Invalid todo priority: {s}")),
+        }
+    }
+}
+
+/// Tailwind classes of the avatar backgrounds, see [`TodoAssignee::avatar_color`].
+const AVATAR_COLORS: &[&str] = &[
+    "bg-purple-500 text-white",
+    "bg-pink-500 text-white",
+    "bg-indigo-500 text-white",
+    "bg-teal-500 text-white",
+    "bg-amber-500 text-white",
+    "bg-sky-500 text-white",
+    "bg-rose-500 text-white",
+    "bg-emerald-500 text-white",
+];
+
+/// A family member a todo can be assigned to.
+///
+/// Assignees are configured at runtime (see `EmailConfig`), so the type only
+/// guarantees that the name is well-formed, not that it is currently configured.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct TodoAssignee(String);
+
+impl TodoAssignee {
+    pub const MAX_NAME_LENGTH: usize = 50;
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Up to two initials for an avatar, e.g. `MA` for "Mary Ann".
+    #[must_use]
+    pub fn initials(&self) -> String {
+        self.0
+            .split([' ', '-'])
+            .filter_map(|word| word.chars().next())
+            .take(2)
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+
+    /// Avatar background for the name, picked by hashing it so that each person
+    /// keeps the same color however the configured assignees change.
+    #[must_use]
+    pub fn avatar_color(&self) -> &'static str {
+        // FNV-1a, which unlike the std hasher is stable across builds and targets
+        let hash = self
+            .0
+            .to_lowercase()
+            .bytes()
+            .fold(0x811c_9dc5_u32, |hash, byte| {
+                (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+            });
+        AVATAR_COLORS[hash as usize % AVATAR_COLORS.len()]
+    }
+
+    /// Whether this assignee stands for the signed-in user, compared without
+    /// case against the username, the display name and its first name, so that
+    /// "Niina" matches a user shown as "Niina Leppänen".
+    #[must_use]
+    pub fn matches_user(&self, username: &str, display_name: &str) -> bool {
+        let name = self.0.trim().to_lowercase();
+        let first_name = display_name.split_whitespace().next().unwrap_or_default();
+        [username, display_name, first_name]
+            .into_iter()
+            .any(|candidate| candidate.trim().to_lowercase() == name)
+    }
+
+    /// Wraps a name without validating it.
+    ///
+    /// Only meant for values that were already persisted, so that a todo whose
+    /// assignee is no longer configured still shows the raw stored name.
+    #[must_use]
+    pub fn unchecked(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+}
+
 impl Display for TodoAssignee {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
@@ -78,10 +183,30 @@ impl FromStr for TodoAssignee {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Mikko" => Ok(Self::Mikko),
-            "Niina" => Ok(Self::Niina),
-            _ => Err(format!("Invalid assignee: {s}")),
+        let name = s.trim();
+        let is_valid = !name.is_empty()
+            && name.chars().count() <= Self::MAX_NAME_LENGTH
+            && name
+                .chars()
+                .all(|c| c.is_alphabetic() || c == ' ' || c == '-' || c == '\'');
+        if is_valid {
+            Ok(Self(name.to_string()))
+        } else {
+            Err(format!("Invalid assignee: {s}"))
         }
     }
 }
+
+impl TryFrom<String> for TodoAssignee {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<TodoAssignee> for String {
+    fn from(assignee: TodoAssignee) -> Self {
+        assignee.0
+    }
+}
@@ -0,0 +1,121 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Todo, TodoStatus};
+
+/// Order of the todo list; each criterion sorts ascending and callers reverse
+/// it for descending.
+#[derive(Clone, Debug, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SortBy {
+    Title,
+    DueDate,
+    Status,
+    Assignee,
+    Priority,
+    CreatedDate,
+    /// Order set by dragging cards, see [`Todo::effective_position`]
+    Manual,
+}
+
+impl SortBy {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortBy::Title => "title",
+            SortBy::DueDate => "due_date",
+            SortBy::Status => "status",
+            SortBy::Assignee => "assignee",
+            SortBy::Priority => "priority",
+            SortBy::CreatedDate => "created_date",
+            SortBy::Manual => "manual",
+        }
+    }
+
+    /// Compares two todos in ascending order; todos without a due date sort
+    /// after those with one.
+    #[must_use]
+    pub fn compare(self, a: &Todo, b: &Todo) -> Ordering {
+        match self {
+            SortBy::Title => a.title.cmp(&b.title),
+            SortBy::DueDate => match (a.due_date, b.due_date) {
+                (Some(a_date), Some(b_date)) => a_date.cmp(&b_date),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+            SortBy::Status => a.status.as_str().cmp(b.status.as_str()),
+            SortBy::Assignee => a.assignee.as_str().cmp(b.assignee.as_str()),
+            SortBy::Priority => a.priority.cmp(&b.priority),
+            SortBy::CreatedDate => a.created_at.cmp(&b.created_at),
+            SortBy::Manual => a.effective_position().total_cmp(&b.effective_position()),
+        }
+    }
+}
+
+impl FromStr for SortBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "title" => Ok(SortBy::Title),
+            "due_date" => Ok(SortBy::DueDate),
+            "status" => Ok(SortBy::Status),
+            "assignee" => Ok(SortBy::Assignee),
+            "priority" => Ok(SortBy::Priority),
+            "created_date" => Ok(SortBy::CreatedDate),
+            "manual" => Ok(SortBy::Manual),
+            _ => Err(format!("Unknown sort type: {s}")),
+        }
+    }
+}
+
+/// Filters applied by the server when listing todos; unset fields match every todo.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TodoFilter {
+    pub status: Option<TodoStatus>,
+    pub assignee: Option<String>,
+    /// Free text matched case-insensitively against title, description, assignee and tags
+    pub search: Option<String>,
+    /// Earliest due date, inclusive, as a Unix timestamp
+    pub due_from: Option<u64>,
+    /// Due dates before this Unix timestamp match
+    pub due_to: Option<u64>,
+}
+
+impl TodoFilter {
+    /// Returns true if the todo satisfies every set field.
+    ///
+    /// Todos without a due date never match a date range.
+    #[must_use]
+    pub fn matches(&self, todo: &Todo) -> bool {
+        let matches_search = self.search.as_deref().is_none_or(|search| {
+            let search = search.to_lowercase();
+            todo.title.to_lowercase().contains(&search)
+                || todo
+                    .description
+                    .as_ref()
+                    .is_some_and(|desc| desc.to_lowercase().contains(&search))
+                || todo.assignee.as_str().to_lowercase().contains(&search)
+                || todo
+                    .tags
+                    .iter()
+                    .any(|tag| tag.to_lowercase().contains(&search))
+        });
+        let has_range = self.due_from.is_some() || self.due_to.is_some();
+        let matches_due = !has_range
+            || todo.due_date.is_some_and(|due| {
+                self.due_from.is_none_or(|from| due >= from)
+                    && self.due_to.is_none_or(|to| due < to)
+            });
+
+        self.status.is_none_or(|status| todo.status == status)
+            && self
+                .assignee
+                .as_deref()
+                .is_none_or(|assignee| todo.assignee.as_str() == assignee)
+            && matches_search
+            && matches_due
+    }
+}
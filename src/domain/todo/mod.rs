@@ -1,7 +1,12 @@
 pub mod enums;
+pub mod filter;
 pub mod model;
 pub mod validation;
 
-pub use enums::{TodoAssignee, TodoStatus};
-pub use model::Todo;
+pub use enums::{TodoAssignee, TodoPriority, TodoStatus};
+pub use filter::{SortBy, TodoFilter};
+pub use model::{
+    ATTACHMENT_CONTENT_TYPES, ATTACHMENT_ROUTE, Attachment, BulkItemResult, Comment, ImportSummary,
+    MAX_PAGE_SIZE, Todo, TodoPage, TodoStats, UNKNOWN_CREATOR, is_blob_path,
+};
 pub use validation::*;
@@ -1,32 +1,169 @@
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-use super::enums::{TodoAssignee, TodoStatus};
-use super::validation::validate_no_html;
+use super::enums::{TodoAssignee, TodoPriority, TodoStatus};
+use super::validation::{
+    MAX_DESCRIPTION_LENGTH, MAX_TITLE_LENGTH, validate_no_html, validate_tags, validate_uuid,
+};
 
-#[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq, Validate)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Validate)]
 pub struct Todo {
+    #[validate(custom(function = "validate_uuid", message = "Id must be a valid UUID"))]
     pub id: String,
 
     #[validate(length(
         min = 1,
-        max = 200,
+        max = MAX_TITLE_LENGTH,
         message = "Title must be between 1 and 200 characters"
     ))]
     #[validate(custom(function = "validate_no_html", message = "Title cannot contain HTML"))]
     pub title: String,
 
-    #[validate(length(max = 1000, message = "Description cannot exceed 1000 characters"))]
-    #[validate(custom(
-        function = "validate_no_html",
-        message = "Description cannot contain HTML"
+    /// Markdown text; rendered through `utils::render_markdown`, which escapes raw HTML
+    #[validate(length(
+        max = MAX_DESCRIPTION_LENGTH,
+        message = "Description cannot exceed 1000 characters"
     ))]
     pub description: Option<String>,
 
+    /// Unix timestamp (seconds, UTC); converted to the user's time zone only for display
     pub due_date: Option<u64>,
     pub assignee: TodoAssignee,
     pub status: TodoStatus,
+
+    #[serde(default)]
+    pub priority: TodoPriority,
+
+    #[serde(default)]
+    #[validate(custom(
+        function = "validate_tags",
+        message = "Tags must be 1-30 characters and cannot contain HTML"
+    ))]
+    pub tags: Vec<String>,
+
+    /// Unix timestamp (seconds) of when the todo was created
+    #[serde(default)]
+    pub created_at: u64,
+
+    /// Username of whoever created the todo; set by the server from the session,
+    /// [`UNKNOWN_CREATOR`] for todos created before it was tracked
+    #[serde(default)]
+    pub created_by: String,
+
+    /// Unix timestamp (seconds) of the last change
+    #[serde(default)]
+    pub updated_at: u64,
+
+    /// Username of whoever last edited the todo, empty if it was never edited;
+    /// set by the server from the session
+    #[serde(default)]
+    pub updated_by: String,
+
+    /// Unix timestamp (seconds) of when the todo was last marked as completed
+    #[serde(default)]
+    pub completed_at: Option<u64>,
+
+    /// Manual sort key set by drag and drop; see [`Todo::effective_position`]
+    #[serde(default)]
+    pub position: Option<f64>,
+
+    /// Notes added over time, oldest first
+    #[serde(default)]
+    #[validate(nested)]
+    pub comments: Vec<Comment>,
+
+    /// Files uploaded with `upload_attachment_server`
+    #[serde(default)]
+    #[validate(nested)]
+    pub attachments: Vec<Attachment>,
+}
+
+/// `created_by` of todos stored before the creator was recorded.
+pub const UNKNOWN_CREATOR: &str = "unknown";
+
+/// Content types accepted as attachments.
+pub const ATTACHMENT_CONTENT_TYPES: [&str; 6] = [
+    "image/jpeg",
+    "image/png",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+    "text/plain",
+];
+
+/// A file stored in Azure Blob Storage and linked to a todo.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate)]
+pub struct Attachment {
+    #[validate(length(min = 1, max = 255, message = "File name must be 1-255 characters"))]
+    pub name: String,
+
+    #[validate(url(message = "Attachment URL is invalid"))]
+    pub url: String,
+
+    pub content_type: String,
+
+    /// Size in bytes
+    pub size: u64,
+}
+
+/// Route serving attachments to signed-in users, followed by the blob path;
+/// see [`Attachment::href`].
+pub const ATTACHMENT_ROUTE: &str = "/api/attachments";
+
+/// Whether `path` is a blob path as the blob service names uploads,
+/// `<uuid>/<file name>` with a file name of letters, digits, `.`, `-` and `_`.
+#[must_use]
+pub fn is_blob_path(path: &str) -> bool {
+    let Some((blob_id, file_name)) = path.split_once('/') else {
+        return false;
+    };
+    uuid::Uuid::parse_str(blob_id).is_ok()
+        && !file_name.trim_matches('.').is_empty()
+        && file_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+}
+
+impl Attachment {
+    /// The `<uuid>/<file name>` ending of the blob URL, if the URL has one.
+    #[must_use]
+    pub fn blob_path(&self) -> Option<&str> {
+        let mut segments = self.url.rsplitn(3, '/');
+        let file_name = segments.next()?;
+        let blob_id = segments.next()?;
+        segments.next()?;
+        let path = &self.url[self.url.len() - blob_id.len() - 1 - file_name.len()..];
+        is_blob_path(path).then_some(path)
+    }
+
+    /// Link downloading the attachment through the server, which checks the
+    /// session; `None` if the URL is not one the blob service uploaded.
+    ///
+    /// The stored URL is never linked to directly, so a forged one cannot run
+    /// script in the page.
+    #[must_use]
+    pub fn href(&self) -> Option<String> {
+        self.blob_path()
+            .map(|path| format!("{ATTACHMENT_ROUTE}/{path}"))
+    }
+}
+
+/// A note left on a todo.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Validate)]
+pub struct Comment {
+    pub author: String,
+
+    #[validate(length(
+        min = 1,
+        max = 1000,
+        message = "Comment must be between 1 and 1000 characters"
+    ))]
+    #[validate(custom(function = "validate_no_html", message = "Comment cannot contain HTML"))]
+    pub text: String,
+
+    /// Unix timestamp (seconds) of when the comment was added
+    pub created_at: u64,
 }
 
 impl Todo {
@@ -39,6 +176,20 @@ impl Todo {
             due_date: None,
             assignee,
             status: TodoStatus::Pending,
+            priority: TodoPriority::default(),
+            tags: Vec::new(),
+            created_at: chrono::Utc::now()
+                .timestamp()
+                .max(0)
+                .try_into()
+                .unwrap_or(0),
+            created_by: String::new(),
+            updated_at: 0,
+            updated_by: String::new(),
+            completed_at: None,
+            position: None,
+            comments: Vec::new(),
+            attachments: Vec::new(),
         }
     }
 
@@ -48,42 +199,137 @@ impl Todo {
         self
     }
 
+    #[must_use]
+    pub fn with_priority(mut self, priority: TodoPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    #[must_use]
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
     #[must_use]
     pub fn with_due_date(mut self, due_date: Option<u64>) -> Self {
         self.due_date = due_date;
         self
     }
 
+    /// Manual sort key; todos never reordered fall back to newest first.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn effective_position(&self) -> f64 {
+        self.position.unwrap_or(-(self.created_at as f64))
+    }
+
     #[must_use]
     pub fn is_overdue(&self) -> bool {
         if let Some(due_timestamp) = self.due_date {
             if let Ok(timestamp_i64) = i64::try_from(due_timestamp) {
                 if let Some(due_datetime) = DateTime::from_timestamp(timestamp_i64, 0) {
                     let now = Utc::now();
-                    return now > due_datetime && self.status == TodoStatus::Pending;
+                    return now > due_datetime && self.status != TodoStatus::Completed;
                 }
             }
         }
         false
     }
 
+    /// Formats the due date in the given time zone, see [`crate::utils::user_tz`].
     #[must_use]
-    pub fn formatted_due_date(&self) -> Option<String> {
-        self.due_date.and_then(|timestamp| {
-            i64::try_from(timestamp).ok().and_then(|ts| {
-                DateTime::from_timestamp(ts, 0).map(|dt| {
-                    let local_dt = dt.with_timezone(&Local);
-                    local_dt.format("%A, %B %d, %Y at %I:%M %p").to_string()
-                })
-            })
-        })
+    pub fn formatted_due_date(&self, tz: chrono_tz::Tz) -> Option<String> {
+        self.due_date
+            .map(|timestamp| crate::utils::format_due_date(timestamp, tz))
     }
+}
+
+/// Most todos a page holds; larger requested page sizes are clamped to it.
+pub const MAX_PAGE_SIZE: u32 = 100;
 
+/// One page of todos as returned by paginated queries.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct TodoPage {
+    pub todos: Vec<Todo>,
+    /// Opaque token to pass back to fetch the next page, `None` on the last page
+    pub continuation: Option<String>,
+    /// Total number of todos, only computed for the first page
+    pub total: Option<u64>,
+}
+
+/// Outcome of one item in a bulk operation.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BulkItemResult {
+    pub id: String,
+    /// Error message if the operation failed for this item
+    pub error: Option<String>,
+}
+
+impl BulkItemResult {
     #[must_use]
-    pub fn email(&self) -> &'static str {
-        self.assignee.email()
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Outcome of importing a batch of todos.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub imported: usize,
+    /// Todos rejected by validation
+    pub skipped: usize,
+    /// Valid todos that could not be saved
+    pub failed: usize,
+    /// Error for every skipped or failed todo
+    pub errors: Vec<BulkItemResult>,
+}
+
+/// Overview counts of the todo list.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TodoStats {
+    pub total: usize,
+    pub pending: usize,
+    pub in_progress: usize,
+    pub completed: usize,
+    /// Open todos past their due date, as reported by [`Todo::is_overdue`]
+    pub overdue: usize,
+    /// Todo count per assignee, sorted by name
+    pub per_assignee: Vec<(String, usize)>,
+    /// Due dates of the open todos, for the overdue badge and calendar
+    pub open_due_dates: Vec<u64>,
+}
+
+impl TodoStats {
+    #[must_use]
+    pub fn from_todos(todos: &[Todo]) -> Self {
+        let mut stats = Self {
+            total: todos.len(),
+            ..Self::default()
+        };
+        let mut per_assignee = std::collections::BTreeMap::<&str, usize>::new();
+        for todo in todos {
+            match todo.status {
+                TodoStatus::Pending => stats.pending += 1,
+                TodoStatus::InProgress => stats.in_progress += 1,
+                TodoStatus::Completed => stats.completed += 1,
+            }
+            if todo.is_overdue() {
+                stats.overdue += 1;
+            }
+            if todo.status != TodoStatus::Completed {
+                stats.open_due_dates.extend(todo.due_date);
+            }
+            *per_assignee.entry(todo.assignee.as_str()).or_default() += 1;
+        }
+        stats.per_assignee = per_assignee
+            .into_iter()
+            .map(|(assignee, count)| (assignee.to_string(), count))
+            .collect();
+        stats
     }
 }
+
 impl std::fmt::Display for Todo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -93,3 +339,75 @@ impl std::fmt::Display for Todo {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use validator::Validate;
+
+    use super::*;
+
+    fn todo_titled(title: String) -> Todo {
+        Todo::new(title, TodoAssignee::from_str("Niina").unwrap())
+    }
+
+    #[test]
+    fn title_of_200_characters_is_accepted() {
+        assert!(todo_titled("a".repeat(200)).validate().is_ok());
+        assert!(todo_titled("ä".repeat(200)).validate().is_ok());
+    }
+
+    #[test]
+    fn title_over_200_characters_is_rejected() {
+        assert!(todo_titled("a".repeat(201)).validate().is_err());
+        assert!(todo_titled("a".repeat(500)).validate().is_err());
+    }
+
+    #[test]
+    fn empty_title_is_rejected() {
+        assert!(todo_titled(String::new()).validate().is_err());
+    }
+
+    #[test]
+    fn assignee_parsing_keeps_the_case() {
+        let lower = TodoAssignee::from_str("niina").unwrap();
+        let capitalized = TodoAssignee::from_str("Niina").unwrap();
+
+        assert_eq!(capitalized.as_str(), "Niina");
+        assert_eq!(lower.as_str(), "niina");
+        assert_ne!(lower, capitalized);
+    }
+
+    #[test]
+    fn status_parsing_is_case_sensitive() {
+        assert_eq!(TodoStatus::from_str("Completed"), Ok(TodoStatus::Completed));
+        assert_eq!(
+            TodoStatus::from_str("In Progress"),
+            Ok(TodoStatus::InProgress)
+        );
+        assert!(TodoStatus::from_str("completed").is_err());
+        assert!(TodoStatus::from_str("pending").is_err());
+    }
+
+    #[test]
+    fn stats_list_the_due_dates_of_open_todos_only() {
+        let todos = [
+            Todo {
+                due_date: Some(1_700_000_000),
+                ..todo_titled("Buy milk".to_string())
+            },
+            Todo {
+                due_date: Some(1_800_000_000),
+                status: TodoStatus::Completed,
+                ..todo_titled("Walk the dog".to_string())
+            },
+            todo_titled("Call grandma".to_string()),
+        ];
+
+        let stats = TodoStats::from_todos(&todos);
+
+        assert_eq!(stats.open_due_dates, vec![1_700_000_000]);
+        assert_eq!(stats.overdue, 1);
+    }
+}
@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use validator::ValidationError;
 
 /// Validates that the input string does not contain HTML tags.
@@ -15,17 +17,151 @@ pub fn validate_no_html(input: &str) -> Result<(), ValidationError> {
     Ok(())
 }
 
-/// Validates that the timestamp represents a future date.
+/// Validates that a todo id is a well-formed UUID.
+///
+/// # Errors
+///
+/// Returns a `ValidationError` with code "`invalid_id`" if the id cannot be parsed
+/// as a UUID.
+pub fn validate_uuid(id: &str) -> Result<(), ValidationError> {
+    uuid::Uuid::parse_str(id)
+        .map(|_| ())
+        .map_err(|_| ValidationError::new("invalid_id"))
+}
+
+/// Maximum length of a todo title in characters.
+pub const MAX_TITLE_LENGTH: u64 = 200;
+
+/// Maximum length of a todo description in characters.
+pub const MAX_DESCRIPTION_LENGTH: u64 = 1000;
+
+/// Maximum length of a single tag in characters.
+pub const MAX_TAG_LENGTH: usize = 30;
+
+/// Validates that every tag is non-empty, at most `MAX_TAG_LENGTH` characters
+/// and free of HTML.
+///
+/// # Errors
+///
+/// Returns a `ValidationError` with code "`invalid_tag`" for empty or overlong
+/// tags, or "`contains_html`" if a tag contains HTML tags.
+pub fn validate_tags(tags: &[String]) -> Result<(), ValidationError> {
+    for tag in tags {
+        if tag.trim().is_empty() || tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(ValidationError::new("invalid_tag"));
+        }
+        validate_no_html(tag)?;
+    }
+    Ok(())
+}
+
+/// Validates that the timestamp does not fall on a day that has already passed
+/// in the household's time zone, see [`crate::utils::server_tz`].
 ///
 /// # Errors
 ///
 /// Returns a `ValidationError` with code "`must_be_future_date`" if the timestamp
-/// is not in the future relative to the current UTC time.
+/// falls on a day before today.
 pub fn validate_future_date(timestamp: u64) -> Result<(), ValidationError> {
-    let now = chrono::Utc::now().timestamp();
-    let timestamp_i64 = timestamp.try_into().unwrap_or(i64::MAX);
-    if now < 0 || timestamp_i64 <= now {
+    if is_before_today(timestamp, crate::utils::server_tz(), Utc::now()) {
         return Err(ValidationError::new("must_be_future_date"));
     }
     Ok(())
 }
+
+/// Whether `timestamp` falls on an earlier calendar day than `now` in `tz`.
+///
+/// Days are compared rather than instants, so a todo due earlier today is not
+/// in the past, and a day is whatever length the zone's clock changes make it.
+/// Timestamps too large to represent are far in the future.
+#[must_use]
+pub fn is_before_today(timestamp: u64, tz: Tz, now: DateTime<Utc>) -> bool {
+    let today = now.with_timezone(&tz).date_naive();
+    crate::utils::user_date(timestamp, tz).is_some_and(|day| day < today)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    const HELSINKI: Tz = Tz::Europe__Helsinki;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, second)
+            .unwrap()
+    }
+
+    fn timestamp(datetime: DateTime<Utc>) -> u64 {
+        u64::try_from(datetime.timestamp()).unwrap()
+    }
+
+    #[test]
+    fn helsinki_midnight_before_spring_forward_is_at_utc_plus_2() {
+        // 02:30 on 30 March 2025 in Helsinki, half an hour before clocks go forward
+        let now = utc(2025, 3, 30, 0, 30, 0);
+
+        assert!(is_before_today(
+            timestamp(utc(2025, 3, 29, 21, 59, 59)),
+            HELSINKI,
+            now
+        ));
+        assert!(!is_before_today(
+            timestamp(utc(2025, 3, 29, 22, 0, 0)),
+            HELSINKI,
+            now
+        ));
+    }
+
+    #[test]
+    fn helsinki_midnight_after_spring_forward_is_at_utc_plus_3() {
+        // 00:30 on 31 March 2025 in Helsinki; 30 March had only 23 hours
+        let now = utc(2025, 3, 30, 21, 30, 0);
+
+        assert!(is_before_today(
+            timestamp(utc(2025, 3, 30, 20, 59, 59)),
+            HELSINKI,
+            now
+        ));
+        assert!(!is_before_today(
+            timestamp(utc(2025, 3, 30, 21, 0, 0)),
+            HELSINKI,
+            now
+        ));
+    }
+
+    #[test]
+    fn helsinki_day_of_fall_back_lasts_25_hours() {
+        // 26 October 2025 runs from 21:00 UTC the day before to 22:00 UTC
+        let late_on_the_day = utc(2025, 10, 26, 21, 59, 59);
+        let next_day = utc(2025, 10, 26, 22, 0, 0);
+        let start_of_day = timestamp(utc(2025, 10, 25, 21, 0, 0));
+
+        assert!(!is_before_today(start_of_day, HELSINKI, late_on_the_day));
+        assert!(is_before_today(start_of_day, HELSINKI, next_day));
+        assert!(is_before_today(
+            timestamp(utc(2025, 10, 25, 20, 59, 59)),
+            HELSINKI,
+            late_on_the_day
+        ));
+    }
+
+    #[test]
+    fn helsinki_and_utc_disagree_around_midnight() {
+        // 00:30 in Helsinki is still the previous day in UTC
+        let now = utc(2025, 3, 30, 0, 30, 0);
+        let due = timestamp(utc(2025, 3, 29, 22, 30, 0));
+
+        assert!(!is_before_today(due, HELSINKI, now));
+        assert!(is_before_today(due, Tz::UTC, now));
+    }
+
+    #[test]
+    fn later_today_and_unrepresentable_dates_are_not_past() {
+        let now = utc(2025, 10, 26, 12, 0, 0);
+
+        assert!(!is_before_today(timestamp(now) - 3600, HELSINKI, now));
+        assert!(!is_before_today(u64::MAX, HELSINKI, now));
+    }
+}
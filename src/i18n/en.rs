@@ -0,0 +1,468 @@
+//! English UI strings; every key used by the views must be here.
+
+pub const STRINGS: &[(&str, &str)] = &[
+    ("app.title", "Family Leppänen Todos"),
+    ("archive.completed_on", "Completed {date}"),
+    ("archive.date_unknown", "Completion date unknown"),
+    ("archive.empty", "No completed todos yet"),
+    ("archive.empty_hint", "Completed todos will show up here."),
+    ("bulk.assign_to", "Assign to..."),
+    (
+        "bulk.cleared_completed",
+        "{succeeded} of {total} completed todos moved to the trash",
+    ),
+    (
+        "bulk.confirm_clear_completed",
+        "Move all {count} completed todos to the trash?",
+    ),
+    (
+        "bulk.confirm_complete_month",
+        "Mark {count} todos in {month} as completed?",
+    ),
+    (
+        "bulk.confirm_delete",
+        "Are you sure you want to delete {count} todos?",
+    ),
+    ("bulk.deleted", "{succeeded} of {total} todos deleted"),
+    ("bulk.done", "Done"),
+    ("bulk.mark_completed", "Mark Completed"),
+    ("bulk.reassign", "Reassign"),
+    ("bulk.select", "Select"),
+    ("bulk.selected", "{count} selected"),
+    ("bulk.updated", "{succeeded} of {total} todos updated"),
+    ("calendar.clear_date", "Clear date"),
+    ("calendar.due_count", "{count} todo(s) due"),
+    ("calendar.fri", "Fri"),
+    ("calendar.go_to_today", "Today"),
+    ("calendar.mon", "Mon"),
+    ("calendar.monday", "Monday"),
+    ("calendar.month", "Month"),
+    ("calendar.sat", "Sat"),
+    ("calendar.showing", "Showing:"),
+    ("calendar.sun", "Sun"),
+    ("calendar.sunday", "Sunday"),
+    ("calendar.thu", "Thu"),
+    ("calendar.today", "Today:"),
+    ("calendar.tue", "Tue"),
+    ("calendar.wed", "Wed"),
+    ("calendar.week_starts_on", "Week starts on"),
+    ("calendar.year", "Year"),
+    ("card.added_by", "added by {name}"),
+    (
+        "card.confirm_delete",
+        "Are you sure you want to delete this todo?",
+    ),
+    ("card.copy_link", "Copy link"),
+    ("card.copy_link_failed", "Couldn't copy the link: {url}"),
+    ("card.delete", "Delete todo"),
+    ("card.due_on", "Due: {date}"),
+    ("card.edit", "Edit todo"),
+    ("card.edited_by", "edited by {name} • {relative}"),
+    ("card.link_copied", "Link copied to the clipboard"),
+    ("card.mark_completed", "Mark as completed"),
+    ("card.mark_pending", "Mark as pending"),
+    ("card.overdue_on", "OVERDUE: {date}"),
+    ("card.overdue_relative", "overdue, {relative}"),
+    ("comments.empty", "No comments yet"),
+    ("comments.no_html", "Comment cannot contain HTML"),
+    ("comments.placeholder", "Add a comment"),
+    ("comments.title", "Comments"),
+    ("common.add", "Add"),
+    ("common.cancel", "Cancel"),
+    ("common.close", "Close"),
+    ("common.delete", "Delete"),
+    ("common.dismiss", "Dismiss"),
+    ("common.remove", "Remove"),
+    ("common.undo", "Undo"),
+    ("conflict.changed", "Changed on the server: {fields}"),
+    ("conflict.keep_mine", "Keep mine"),
+    (
+        "conflict.keep_mine_hint",
+        "Save your edit over the version on the server",
+    ),
+    (
+        "conflict.message",
+        "\"{title}\" was changed by someone else, so your edit was not saved.",
+    ),
+    ("conflict.reload", "Reload"),
+    (
+        "conflict.reload_hint",
+        "Discard your edit and show the latest version",
+    ),
+    ("conflict.use_theirs", "Use theirs"),
+    (
+        "conflict.use_theirs_hint",
+        "Discard your edit and keep the version on the server",
+    ),
+    ("count.todos", "{count} todos"),
+    ("date.invalid", "Invalid date"),
+    ("detail.back", "Back to todos"),
+    ("detail.completed", "Completed {date}"),
+    ("detail.created", "Created {date}"),
+    ("detail.created_by", "Created {date} by {name}"),
+    ("detail.edited_by", "Last edited {date} by {name}"),
+    ("detail.history", "History"),
+    ("detail.load_failed", "Failed to load todo: {error}"),
+    ("detail.loading", "Loading todo..."),
+    ("detail.no_description", "No description"),
+    (
+        "detail.not_found",
+        "This todo does not exist or has been deleted.",
+    ),
+    (
+        "dev.confirm_wipe",
+        "Permanently delete every todo, including the trash? This cannot be undone.",
+    ),
+    ("dev.title", "Development tools"),
+    ("dev.wipe", "Wipe all todos"),
+    ("dev.wipe_failed", "Failed to wipe todos: {error}"),
+    ("dev.wiped", "Wiped {count} todo(s)"),
+    ("dev.wiping", "Wiping..."),
+    ("error.add_comment", "Failed to add comment: {error}"),
+    (
+        "error.create_share_link",
+        "Failed to create share link: {error}",
+    ),
+    ("error.create_todo", "Failed to create todo: {error}"),
+    ("error.delete_todo", "Failed to delete todo: {error}"),
+    ("error.delete_todos", "Failed to delete todos: {error}"),
+    ("error.details", "Error details"),
+    (
+        "error.export_calendar",
+        "Failed to export calendar: {error}",
+    ),
+    ("error.export_todos", "Failed to export todos: {error}"),
+    ("error.import_todos", "Failed to import todos: {error}"),
+    ("error.load_assignees", "Failed to load assignees: {error}"),
+    ("error.load_todos", "Failed to load todos: {error}"),
+    ("error.load_trash", "Failed to load trash: {error}"),
+    (
+        "error.purge_todo",
+        "Failed to permanently delete todo: {error}",
+    ),
+    (
+        "error.rate_limited",
+        "You are adding todos too quickly. Wait a minute and try again.",
+    ),
+    ("error.restore_todo", "Failed to restore todo: {error}"),
+    (
+        "error.revoke_share_link",
+        "Failed to revoke share link: {error}",
+    ),
+    ("error.something_went_wrong", "Something went wrong"),
+    ("error.too_large", "The todo is too large: {error}"),
+    ("error.undo_delete", "Failed to undo delete: {error}"),
+    ("error.update_todo", "Failed to update todo: {error}"),
+    ("error.update_todos", "Failed to update todos: {error}"),
+    (
+        "error.upload_attachment",
+        "Failed to upload attachment: {error}",
+    ),
+    ("field.assignee", "Assignee"),
+    ("field.attachments", "Attachments"),
+    ("field.description", "Description"),
+    ("field.due_date", "Due Date"),
+    ("field.due_time", "Due Time"),
+    ("field.priority", "Priority"),
+    ("field.status", "Status"),
+    ("field.tag", "Tag"),
+    ("field.tags", "Tags"),
+    ("field.title", "Title"),
+    ("filter.all_assignees", "All Assignees"),
+    ("filter.all_statuses", "All Status"),
+    ("filter.all_tags", "All Tags"),
+    ("filter.assigned_to_me", "Assigned to me"),
+    ("filter.assigned_to_me_default", "Show my todos on load"),
+    (
+        "filter.assigned_to_me_default_hint",
+        "Open the list filtered to the todos assigned to you",
+    ),
+    (
+        "filter.assigned_to_me_unmatched",
+        "Your username does not match any assignee",
+    ),
+    ("filter.clear", "Clear Filters"),
+    ("filter.due_this_week", "Due This Week"),
+    ("filter.due_today", "Due Today"),
+    ("filter.hide_completed", "Hide completed"),
+    ("filter.no_due_date", "No Due Date"),
+    ("filter.overdue", "Overdue"),
+    ("filter.search", "Search todos"),
+    (
+        "filter.search_placeholder",
+        "Search, or filter with status:, assignee:, priority:, tag:",
+    ),
+    ("filter.showing", "Showing {shown} of {total} todos"),
+    ("filter.skip_delete_confirm", "Skip delete confirmation"),
+    (
+        "filter.skip_delete_confirm_hint",
+        "Delete right away; a deleted todo can still be undone from its toast",
+    ),
+    (
+        "form.confirm_past",
+        "Warning: You're creating a todo with a due date in the past. Are you sure you want to continue?",
+    ),
+    (
+        "form.confirm_past_days",
+        "Warning: You're creating a todo with a due date {count} day(s) in the past. Are you sure you want to continue?",
+    ),
+    (
+        "form.confirm_past_hours",
+        "Warning: You're creating a todo with a due date {count} hour(s) in the past. Are you sure you want to continue?",
+    ),
+    ("form.contains_html", "Cannot contain HTML"),
+    ("form.create", "Create Todo"),
+    ("form.create_title", "Create New Todo"),
+    ("form.creating", "Creating..."),
+    (
+        "form.description_length",
+        "Description cannot exceed 1000 characters",
+    ),
+    (
+        "form.description_placeholder",
+        "Enter description (optional, markdown supported)",
+    ),
+    ("form.due_before_1970", "Due date cannot be before 1970"),
+    ("form.edit_title", "Edit Todo"),
+    (
+        "form.invalid",
+        "Invalid todo data: Error validating todo: {error}",
+    ),
+    ("form.invalid_datetime", "Invalid date/time format"),
+    ("form.invalid_local_datetime", "Invalid local datetime"),
+    ("form.no_due_date", "No due date"),
+    (
+        "form.no_due_date_hint",
+        "This todo stays undated and is listed under \"No Due Date\"",
+    ),
+    (
+        "form.past_warning",
+        "This date/time is in the past. You'll be asked to confirm when creating the todo.",
+    ),
+    ("form.tag_html", "Tag \"{tag}\" cannot contain HTML"),
+    (
+        "form.tags_placeholder",
+        "e.g. shopping, school (comma-separated)",
+    ),
+    ("form.title_label", "Title *"),
+    (
+        "form.title_length",
+        "Title must be between 1 and 200 characters",
+    ),
+    ("form.title_placeholder", "Enter todo title"),
+    ("form.title_required", "Title is required"),
+    ("form.update", "Update Todo"),
+    ("form.updating", "Updating..."),
+    ("form.uploading", "Uploading..."),
+    ("group.complete_all", "Complete all ({count})"),
+    (
+        "group.complete_all_hint",
+        "Mark the {count} open todos of this month as completed",
+    ),
+    ("group.completing", "Completing..."),
+    ("home.add_todo", "Add Todo"),
+    (
+        "home.confirm_sign_out_everywhere",
+        "Sign out of all sessions on every device?",
+    ),
+    ("home.create_first", "Create First Todo"),
+    ("home.download_ics", "Download .ics"),
+    ("home.empty", "No todos yet"),
+    ("home.empty_hint", "Create your first todo to get started!"),
+    ("home.export_csv", "Export CSV"),
+    ("home.loading", "Loading todos..."),
+    ("home.logo_alt", "Family Todos Logo"),
+    ("home.no_matches", "No todos match your filters"),
+    (
+        "home.no_matches_hint",
+        "Try adjusting your search or filter criteria.",
+    ),
+    ("home.overdue_badge", "{count} overdue"),
+    ("home.overdue_badge_hint", "Show overdue todos"),
+    ("home.print_share", "Print / Share"),
+    (
+        "home.print_share_hint",
+        "Print the open todos or share a read-only link",
+    ),
+    ("home.sign_out_everywhere", "Sign out everywhere"),
+    ("home.tab_archive", "Archive"),
+    ("home.tab_todos", "Todos"),
+    ("home.title", "Family Todos"),
+    ("import.button", "Import JSON"),
+    ("import.importing", "Importing..."),
+    ("import.item_failed", "Todo {id}: {error}"),
+    (
+        "import.summary",
+        "Imported {imported} todos, skipped {skipped} invalid, {failed} failed",
+    ),
+    ("lang.choose", "Language"),
+    ("login.failed", "Authentication failed. Please try again."),
+    (
+        "login.footer",
+        "© 2025 Family Leppänen · v{version} · All rights reserved",
+    ),
+    (
+        "login.invalid_credentials",
+        "Invalid username or password: {error}",
+    ),
+    ("login.password", "Password"),
+    ("login.remember_me", "Remember me"),
+    ("login.sign_in", "Sign In"),
+    (
+        "login.signed_out_everywhere",
+        "Signed out of {count} session(s) everywhere",
+    ),
+    ("login.signing_in", "Signing In..."),
+    ("login.subtitle", "Sign in to manage your tasks"),
+    ("login.username", "Username"),
+    ("password.change", "Change password"),
+    (
+        "password.changed",
+        "Password changed. Signed out of {count} other sessions.",
+    ),
+    ("password.changing", "Changing..."),
+    ("password.confirm", "Confirm new password"),
+    ("password.current", "Current password"),
+    ("password.failed", "Failed to change password: {error}"),
+    ("password.mismatch", "The new passwords do not match"),
+    ("password.new", "New password"),
+    (
+        "password.requirements",
+        "8-128 characters with upper and lower case letters, a digit and a special character",
+    ),
+    (
+        "password.too_weak",
+        "The new password does not meet the requirements",
+    ),
+    ("preset.next_monday", "Next Monday"),
+    ("preset.this_weekend", "This weekend"),
+    ("preset.today", "Today"),
+    ("preset.tomorrow", "Tomorrow"),
+    ("print.back", "Back to todos"),
+    ("print.create_link", "Create share link"),
+    ("print.new_link", "New link"),
+    (
+        "print.new_link_hint",
+        "Replace the link; the old one stops working",
+    ),
+    ("print.print", "Print"),
+    ("print.revoke", "Revoke"),
+    (
+        "print.share_hint",
+        "Anyone with the link can see the todos without signing in, but cannot change them.",
+    ),
+    ("print.share_link", "Share link"),
+    (
+        "print.sign_in",
+        "Sign in, or open a share link, to see the todos.",
+    ),
+    ("priority.high", "High"),
+    ("priority.low", "Low"),
+    ("priority.medium", "Medium"),
+    (
+        "quick_add.hint",
+        "Press Enter to add. Recognises today, tomorrow, weekdays, times such as 5pm or 17:00, #tags, @assignee and !high, !medium or !low priority.",
+    ),
+    ("quick_add.label", "Quick add"),
+    (
+        "quick_add.no_assignee",
+        "No assignees are configured, so the todo cannot be added",
+    ),
+    (
+        "quick_add.placeholder",
+        "Quick add, e.g. Buy milk tomorrow 5pm",
+    ),
+    ("quick_add.title_required", "Type a title for the todo"),
+    (
+        "quick_add.unknown_assignee",
+        "{name} is not a configured assignee",
+    ),
+    ("reassign.choose", "Choose both people"),
+    ("reassign.confirm", "Reassign every todo of {from} to {to}?"),
+    (
+        "reassign.description",
+        "Move every todo of one person, including those in the trash, to someone else, e.g. when they leave the household.",
+    ),
+    ("reassign.done", "{count} todos moved from {from} to {to}"),
+    ("reassign.failed", "Failed to reassign todos: {error}"),
+    ("reassign.from", "From"),
+    ("reassign.running", "Reassigning..."),
+    ("reassign.same", "Choose two different people"),
+    ("reassign.select", "Select a person"),
+    ("reassign.submit", "Reassign"),
+    ("reassign.title", "Reassign todos"),
+    ("reassign.to", "To"),
+    ("server.check_now", "Check server status"),
+    ("server.checking", "Checking..."),
+    (
+        "server.connection_lost",
+        "Server connection lost. Some features may not work.",
+    ),
+    (
+        "server.database_down",
+        "Todos cannot be loaded or saved right now.",
+    ),
+    ("server.degraded", "Database Unreachable"),
+    ("server.disconnected", "Disconnected"),
+    ("server.last_check", "Last check:"),
+    ("server.latency", "Latency: {ms} ms"),
+    ("server.offline", "Server Offline"),
+    ("server.online", "Server Online"),
+    ("server.pause", "Pause checks"),
+    ("server.paused", "Automatic checks paused"),
+    ("server.resume", "Resume checks"),
+    ("server.retry_every", "Retrying every {interval}"),
+    ("server.unknown", "Unknown"),
+    ("session.expires_in", "Your session expires in {time}"),
+    ("session.stay_signed_in", "Stay signed in"),
+    ("snooze.hint", "Snooze todo"),
+    ("snooze.label", "Snooze"),
+    ("snooze.next_week", "Next week"),
+    ("snooze.one_hour", "1 hour"),
+    ("snooze.tomorrow", "Tomorrow"),
+    ("sort.ascending", "Ascending"),
+    ("sort.created_date", "Created Date"),
+    ("sort.descending", "Descending"),
+    ("sort.label", "Sort by"),
+    ("sort.manual", "Manual (drag to reorder)"),
+    ("sort.order", "Order"),
+    ("stats.clear_completed", "Clear completed"),
+    ("stats.clearing_completed", "Clearing..."),
+    ("stats.total", "Total"),
+    ("status.completed", "Completed"),
+    ("status.in_progress", "In Progress"),
+    ("status.pending", "Pending"),
+    (
+        "sync.changed_elsewhere",
+        "\"{title}\" was changed by someone else",
+    ),
+    (
+        "sync.deleted_elsewhere",
+        "\"{title}\" was deleted by someone else",
+    ),
+    (
+        "sync.discarded",
+        "Some offline changes were discarded: {problems}",
+    ),
+    ("sync.not_saved", "\"{title}\" could not be saved: {error}"),
+    ("sync.pending", "{count} pending sync"),
+    (
+        "sync.pending_hint",
+        "Changes made offline, synced when the server is back",
+    ),
+    ("sync.syncing", "Syncing..."),
+    ("time.now", "now"),
+    ("toast.created", "Todo created"),
+    ("toast.deleted", "Todo deleted"),
+    ("toast.updated", "Todo updated"),
+    (
+        "trash.confirm_purge",
+        "Permanently delete this todo? This cannot be undone.",
+    ),
+    ("trash.empty", "Trash is empty"),
+    ("trash.loading", "Loading trash..."),
+    ("trash.purge", "Delete forever"),
+    ("trash.restore", "Restore"),
+    ("trash.title", "Trash"),
+    ("undo.deleted", "Deleted \"{title}\""),
+];
@@ -0,0 +1,537 @@
+//! Finnish UI strings.
+
+pub const STRINGS: &[(&str, &str)] = &[
+    ("app.title", "Perhe Leppäsen tehtävät"),
+    ("archive.completed_on", "Valmistui {date}"),
+    ("archive.date_unknown", "Valmistumispäivä tuntematon"),
+    ("archive.empty", "Ei vielä valmiita tehtäviä"),
+    ("archive.empty_hint", "Valmiit tehtävät näkyvät täällä."),
+    ("bulk.assign_to", "Siirrä henkilölle..."),
+    (
+        "bulk.cleared_completed",
+        "{succeeded}/{total} valmista tehtävää siirretty roskakoriin",
+    ),
+    (
+        "bulk.confirm_clear_completed",
+        "Siirretäänkö kaikki {count} valmista tehtävää roskakoriin?",
+    ),
+    (
+        "bulk.confirm_complete_month",
+        "Merkitäänkö {count} tehtävää ({month}) valmiiksi?",
+    ),
+    (
+        "bulk.confirm_delete",
+        "Haluatko varmasti poistaa {count} tehtävää?",
+    ),
+    ("bulk.deleted", "{succeeded}/{total} tehtävää poistettu"),
+    ("bulk.done", "Valmis"),
+    ("bulk.mark_completed", "Merkitse valmiiksi"),
+    ("bulk.reassign", "Siirrä"),
+    ("bulk.select", "Valitse"),
+    ("bulk.selected", "{count} valittu"),
+    ("bulk.updated", "{succeeded}/{total} tehtävää päivitetty"),
+    ("calendar.clear_date", "Tyhjennä päivä"),
+    ("calendar.due_count", "{count} tehtävää erääntyy"),
+    ("calendar.fri", "pe"),
+    ("calendar.go_to_today", "Tänään"),
+    ("calendar.mon", "ma"),
+    ("calendar.monday", "maanantaista"),
+    ("calendar.month", "Kuukausi"),
+    ("calendar.sat", "la"),
+    ("calendar.showing", "Näytetään:"),
+    ("calendar.sun", "su"),
+    ("calendar.sunday", "sunnuntaista"),
+    ("calendar.thu", "to"),
+    ("calendar.today", "Tänään:"),
+    ("calendar.tue", "ti"),
+    ("calendar.wed", "ke"),
+    ("calendar.week_starts_on", "Viikko alkaa"),
+    ("calendar.year", "Vuosi"),
+    ("card.added_by", "lisännyt {name}"),
+    (
+        "card.confirm_delete",
+        "Haluatko varmasti poistaa tämän tehtävän?",
+    ),
+    ("card.copy_link", "Kopioi linkki"),
+    (
+        "card.copy_link_failed",
+        "Linkin kopiointi epäonnistui: {url}",
+    ),
+    ("card.delete", "Poista tehtävä"),
+    ("card.due_on", "Erääntyy: {date}"),
+    ("card.edit", "Muokkaa tehtävää"),
+    ("card.edited_by", "muokannut {name} • {relative}"),
+    ("card.link_copied", "Linkki kopioitu leikepöydälle"),
+    ("card.mark_completed", "Merkitse valmiiksi"),
+    ("card.mark_pending", "Merkitse keskeneräiseksi"),
+    ("card.overdue_on", "MYÖHÄSSÄ: {date}"),
+    ("card.overdue_relative", "myöhässä, {relative}"),
+    ("comments.empty", "Ei vielä kommentteja"),
+    ("comments.no_html", "Kommentti ei voi sisältää HTML:ää"),
+    ("comments.placeholder", "Lisää kommentti"),
+    ("comments.title", "Kommentit"),
+    ("common.add", "Lisää"),
+    ("common.cancel", "Peruuta"),
+    ("common.close", "Sulje"),
+    ("common.delete", "Poista"),
+    ("common.dismiss", "Sulje"),
+    ("common.remove", "Poista"),
+    ("common.undo", "Kumoa"),
+    ("conflict.changed", "Muutettu palvelimella: {fields}"),
+    ("conflict.keep_mine", "Pidä omani"),
+    (
+        "conflict.keep_mine_hint",
+        "Tallenna muokkauksesi palvelimen version päälle",
+    ),
+    (
+        "conflict.message",
+        "Joku muu muutti tehtävää \"{title}\", joten muokkaustasi ei tallennettu.",
+    ),
+    ("conflict.reload", "Lataa uudelleen"),
+    (
+        "conflict.reload_hint",
+        "Hylkää muokkauksesi ja näytä uusin versio",
+    ),
+    ("conflict.use_theirs", "Käytä heidän versiotaan"),
+    (
+        "conflict.use_theirs_hint",
+        "Hylkää muokkauksesi ja pidä palvelimen versio",
+    ),
+    ("count.todos", "{count} tehtävää"),
+    ("date.invalid", "Virheellinen päivämäärä"),
+    ("detail.back", "Takaisin tehtäviin"),
+    ("detail.completed", "Valmistunut {date}"),
+    ("detail.created", "Luotu {date}"),
+    ("detail.created_by", "Luonut {name} {date}"),
+    ("detail.edited_by", "Viimeksi muokannut {name} {date}"),
+    ("detail.history", "Historia"),
+    ("detail.load_failed", "Tehtävän lataus epäonnistui: {error}"),
+    ("detail.loading", "Ladataan tehtävää..."),
+    ("detail.no_description", "Ei kuvausta"),
+    (
+        "detail.not_found",
+        "Tehtävää ei ole olemassa tai se on poistettu.",
+    ),
+    (
+        "dev.confirm_wipe",
+        "Poistetaanko kaikki tehtävät pysyvästi, myös roskakorista? Tätä ei voi perua.",
+    ),
+    ("dev.title", "Kehitystyökalut"),
+    ("dev.wipe", "Poista kaikki tehtävät"),
+    (
+        "dev.wipe_failed",
+        "Tehtävien poistaminen epäonnistui: {error}",
+    ),
+    ("dev.wiped", "{count} tehtävää poistettu"),
+    ("dev.wiping", "Poistetaan..."),
+    (
+        "error.add_comment",
+        "Kommentin lisääminen epäonnistui: {error}",
+    ),
+    (
+        "error.create_share_link",
+        "Jakolinkin luominen epäonnistui: {error}",
+    ),
+    (
+        "error.create_todo",
+        "Tehtävän luominen epäonnistui: {error}",
+    ),
+    (
+        "error.delete_todo",
+        "Tehtävän poistaminen epäonnistui: {error}",
+    ),
+    (
+        "error.delete_todos",
+        "Tehtävien poistaminen epäonnistui: {error}",
+    ),
+    ("error.details", "Virheen tiedot"),
+    (
+        "error.export_calendar",
+        "Kalenterin vieminen epäonnistui: {error}",
+    ),
+    (
+        "error.export_todos",
+        "Tehtävien vieminen epäonnistui: {error}",
+    ),
+    (
+        "error.import_todos",
+        "Tehtävien tuominen epäonnistui: {error}",
+    ),
+    (
+        "error.load_assignees",
+        "Henkilöiden lataaminen epäonnistui: {error}",
+    ),
+    (
+        "error.load_todos",
+        "Tehtävien lataaminen epäonnistui: {error}",
+    ),
+    (
+        "error.load_trash",
+        "Roskakorin lataaminen epäonnistui: {error}",
+    ),
+    (
+        "error.purge_todo",
+        "Tehtävän pysyvä poistaminen epäonnistui: {error}",
+    ),
+    (
+        "error.rate_limited",
+        "Lisäät tehtäviä liian nopeasti. Odota hetki ja yritä uudelleen.",
+    ),
+    (
+        "error.restore_todo",
+        "Tehtävän palauttaminen epäonnistui: {error}",
+    ),
+    (
+        "error.revoke_share_link",
+        "Jakolinkin peruminen epäonnistui: {error}",
+    ),
+    ("error.something_went_wrong", "Jokin meni pieleen"),
+    ("error.too_large", "Tehtävä on liian suuri: {error}"),
+    (
+        "error.undo_delete",
+        "Poiston peruminen epäonnistui: {error}",
+    ),
+    (
+        "error.update_todo",
+        "Tehtävän päivittäminen epäonnistui: {error}",
+    ),
+    (
+        "error.update_todos",
+        "Tehtävien päivittäminen epäonnistui: {error}",
+    ),
+    (
+        "error.upload_attachment",
+        "Liitteen lähettäminen epäonnistui: {error}",
+    ),
+    ("field.assignee", "Vastuuhenkilö"),
+    ("field.attachments", "Liitteet"),
+    ("field.description", "Kuvaus"),
+    ("field.due_date", "Eräpäivä"),
+    ("field.due_time", "Kellonaika"),
+    ("field.priority", "Tärkeys"),
+    ("field.status", "Tila"),
+    ("field.tag", "Tunniste"),
+    ("field.tags", "Tunnisteet"),
+    ("field.title", "Otsikko"),
+    ("filter.all_assignees", "Kaikki henkilöt"),
+    ("filter.all_statuses", "Kaikki tilat"),
+    ("filter.all_tags", "Kaikki tunnisteet"),
+    ("filter.assigned_to_me", "Minulle osoitetut"),
+    (
+        "filter.assigned_to_me_default",
+        "Näytä omat tehtävät avattaessa",
+    ),
+    (
+        "filter.assigned_to_me_default_hint",
+        "Avaa lista suodatettuna sinulle osoitettuihin tehtäviin",
+    ),
+    (
+        "filter.assigned_to_me_unmatched",
+        "Käyttäjätunnuksesi ei vastaa ketään vastuuhenkilöä",
+    ),
+    ("filter.clear", "Tyhjennä suodattimet"),
+    ("filter.due_this_week", "Erääntyy tällä viikolla"),
+    ("filter.due_today", "Erääntyy tänään"),
+    ("filter.hide_completed", "Piilota valmiit"),
+    ("filter.no_due_date", "Ei eräpäivää"),
+    ("filter.overdue", "Myöhässä"),
+    ("filter.search", "Hae tehtäviä"),
+    (
+        "filter.search_placeholder",
+        "Hae, tai suodata: status:, assignee:, priority:, tag:",
+    ),
+    ("filter.showing", "Näytetään {shown}/{total} tehtävää"),
+    (
+        "filter.skip_delete_confirm",
+        "Älä kysy vahvistusta poistolle",
+    ),
+    (
+        "filter.skip_delete_confirm_hint",
+        "Poista heti; poiston voi silti perua ilmoituksesta",
+    ),
+    (
+        "form.confirm_past",
+        "Varoitus: tehtävän eräpäivä on menneisyydessä. Haluatko varmasti jatkaa?",
+    ),
+    (
+        "form.confirm_past_days",
+        "Varoitus: tehtävän eräpäivä on {count} päivää menneisyydessä. Haluatko varmasti jatkaa?",
+    ),
+    (
+        "form.confirm_past_hours",
+        "Varoitus: tehtävän eräpäivä on {count} tuntia menneisyydessä. Haluatko varmasti jatkaa?",
+    ),
+    ("form.contains_html", "Ei saa sisältää HTML:ää"),
+    ("form.create", "Luo tehtävä"),
+    ("form.create_title", "Luo uusi tehtävä"),
+    ("form.creating", "Luodaan..."),
+    (
+        "form.description_length",
+        "Kuvaus voi olla enintään 1000 merkkiä",
+    ),
+    (
+        "form.description_placeholder",
+        "Kirjoita kuvaus (valinnainen, markdown sallittu)",
+    ),
+    (
+        "form.due_before_1970",
+        "Eräpäivä ei voi olla ennen vuotta 1970",
+    ),
+    ("form.edit_title", "Muokkaa tehtävää"),
+    ("form.invalid", "Virheelliset tehtävän tiedot: {error}"),
+    (
+        "form.invalid_datetime",
+        "Virheellinen päivämäärän tai ajan muoto",
+    ),
+    (
+        "form.invalid_local_datetime",
+        "Virheellinen paikallinen aika",
+    ),
+    ("form.no_due_date", "Ei eräpäivää"),
+    (
+        "form.no_due_date_hint",
+        "Tehtävällä ei ole eräpäivää, ja se näytetään kohdassa \"Ei eräpäivää\"",
+    ),
+    (
+        "form.past_warning",
+        "Tämä ajankohta on menneisyydessä. Sinulta pyydetään vahvistus tehtävää luodessa.",
+    ),
+    (
+        "form.tag_html",
+        "Tunniste \"{tag}\" ei voi sisältää HTML:ää",
+    ),
+    (
+        "form.tags_placeholder",
+        "esim. kauppa, koulu (pilkuilla erotettuna)",
+    ),
+    ("form.title_label", "Otsikko *"),
+    (
+        "form.title_length",
+        "Otsikon pituuden on oltava 1–200 merkkiä",
+    ),
+    ("form.title_placeholder", "Kirjoita tehtävän otsikko"),
+    ("form.title_required", "Otsikko on pakollinen"),
+    ("form.update", "Päivitä tehtävä"),
+    ("form.updating", "Päivitetään..."),
+    ("form.uploading", "Lähetetään..."),
+    ("group.complete_all", "Merkitse kaikki valmiiksi ({count})"),
+    (
+        "group.complete_all_hint",
+        "Merkitse tämän kuukauden {count} avointa tehtävää valmiiksi",
+    ),
+    ("group.completing", "Merkitään..."),
+    ("home.add_todo", "Lisää tehtävä"),
+    (
+        "home.confirm_sign_out_everywhere",
+        "Kirjaudutaanko ulos kaikista istunnoista kaikilla laitteilla?",
+    ),
+    ("home.create_first", "Luo ensimmäinen tehtävä"),
+    ("home.download_ics", "Lataa .ics"),
+    ("home.empty", "Ei vielä tehtäviä"),
+    ("home.empty_hint", "Aloita luomalla ensimmäinen tehtäväsi!"),
+    ("home.export_csv", "Vie CSV"),
+    ("home.loading", "Ladataan tehtäviä..."),
+    ("home.logo_alt", "Perheen tehtävien logo"),
+    ("home.no_matches", "Yksikään tehtävä ei vastaa suodattimia"),
+    (
+        "home.no_matches_hint",
+        "Kokeile muuttaa hakua tai suodattimia.",
+    ),
+    ("home.overdue_badge", "{count} myöhässä"),
+    ("home.overdue_badge_hint", "Näytä myöhässä olevat tehtävät"),
+    ("home.print_share", "Tulosta / jaa"),
+    (
+        "home.print_share_hint",
+        "Tulosta avoimet tehtävät tai jaa lukulinkki",
+    ),
+    ("home.sign_out_everywhere", "Kirjaudu ulos kaikkialta"),
+    ("home.tab_archive", "Arkisto"),
+    ("home.tab_todos", "Tehtävät"),
+    ("home.title", "Perheen tehtävät"),
+    ("import.button", "Tuo JSON"),
+    ("import.importing", "Tuodaan..."),
+    ("import.item_failed", "Tehtävä {id}: {error}"),
+    (
+        "import.summary",
+        "Tuotiin {imported} tehtävää, ohitettiin {skipped} virheellistä, {failed} epäonnistui",
+    ),
+    ("lang.choose", "Kieli"),
+    (
+        "login.failed",
+        "Kirjautuminen epäonnistui. Yritä uudelleen.",
+    ),
+    (
+        "login.footer",
+        "© 2025 Perhe Leppänen · v{version} · Kaikki oikeudet pidätetään",
+    ),
+    (
+        "login.invalid_credentials",
+        "Virheellinen käyttäjätunnus tai salasana: {error}",
+    ),
+    ("login.password", "Salasana"),
+    ("login.remember_me", "Muista minut"),
+    ("login.sign_in", "Kirjaudu sisään"),
+    (
+        "login.signed_out_everywhere",
+        "Kirjauduttu ulos kaikkialta ({count} istuntoa)",
+    ),
+    ("login.signing_in", "Kirjaudutaan..."),
+    ("login.subtitle", "Kirjaudu sisään hallitaksesi tehtäviäsi"),
+    ("login.username", "Käyttäjätunnus"),
+    ("password.change", "Vaihda salasana"),
+    (
+        "password.changed",
+        "Salasana vaihdettu. Muut istunnot ({count}) kirjattiin ulos.",
+    ),
+    ("password.changing", "Vaihdetaan..."),
+    ("password.confirm", "Vahvista uusi salasana"),
+    ("password.current", "Nykyinen salasana"),
+    ("password.failed", "Salasanan vaihto epäonnistui: {error}"),
+    ("password.mismatch", "Uudet salasanat eivät täsmää"),
+    ("password.new", "Uusi salasana"),
+    (
+        "password.requirements",
+        "8–128 merkkiä, joissa on isoja ja pieniä kirjaimia, numero ja erikoismerkki",
+    ),
+    ("password.too_weak", "Uusi salasana ei täytä vaatimuksia"),
+    ("preset.next_monday", "Ensi maanantaina"),
+    ("preset.this_weekend", "Tänä viikonloppuna"),
+    ("preset.today", "Tänään"),
+    ("preset.tomorrow", "Huomenna"),
+    ("print.back", "Takaisin tehtäviin"),
+    ("print.create_link", "Luo jakolinkki"),
+    ("print.new_link", "Uusi linkki"),
+    (
+        "print.new_link_hint",
+        "Korvaa linkki; vanha lakkaa toimimasta",
+    ),
+    ("print.print", "Tulosta"),
+    ("print.revoke", "Peru linkki"),
+    (
+        "print.share_hint",
+        "Kuka tahansa linkin saanut näkee tehtävät kirjautumatta, mutta ei voi muuttaa niitä.",
+    ),
+    ("print.share_link", "Jakolinkki"),
+    (
+        "print.sign_in",
+        "Kirjaudu sisään tai avaa jakolinkki nähdäksesi tehtävät.",
+    ),
+    ("priority.high", "Korkea"),
+    ("priority.low", "Matala"),
+    ("priority.medium", "Keskitaso"),
+    (
+        "quick_add.hint",
+        "Lisää painamalla Enter. Tunnistaa sanat today, tomorrow, viikonpäivät (englanniksi), kellonajat kuten 5pm tai 17:00, #tunnisteet, @vastuuhenkilön sekä prioriteetin !high, !medium tai !low.",
+    ),
+    ("quick_add.label", "Pikalisäys"),
+    (
+        "quick_add.no_assignee",
+        "Vastuuhenkilöitä ei ole määritetty, joten tehtävää ei voi lisätä",
+    ),
+    (
+        "quick_add.placeholder",
+        "Pikalisäys, esim. Osta maitoa tomorrow 5pm",
+    ),
+    ("quick_add.title_required", "Kirjoita tehtävälle otsikko"),
+    (
+        "quick_add.unknown_assignee",
+        "{name} ei ole määritetty vastuuhenkilö",
+    ),
+    ("reassign.choose", "Valitse molemmat henkilöt"),
+    (
+        "reassign.confirm",
+        "Siirretäänkö kaikki henkilön {from} tehtävät henkilölle {to}?",
+    ),
+    (
+        "reassign.description",
+        "Siirrä kaikki yhden henkilön tehtävät, myös roskakorissa olevat, toiselle, esimerkiksi kun hän muuttaa pois.",
+    ),
+    ("reassign.done", "{count} tehtävää siirretty: {from} → {to}"),
+    ("reassign.failed", "Tehtävien siirto epäonnistui: {error}"),
+    ("reassign.from", "Keneltä"),
+    ("reassign.running", "Siirretään..."),
+    ("reassign.same", "Valitse kaksi eri henkilöä"),
+    ("reassign.select", "Valitse henkilö"),
+    ("reassign.submit", "Siirrä"),
+    ("reassign.title", "Siirrä tehtävät"),
+    ("reassign.to", "Kenelle"),
+    ("server.check_now", "Tarkista palvelimen tila"),
+    ("server.checking", "Tarkistetaan..."),
+    (
+        "server.connection_lost",
+        "Yhteys palvelimeen katkesi. Kaikki toiminnot eivät ehkä toimi.",
+    ),
+    (
+        "server.database_down",
+        "Tehtäviä ei voi juuri nyt ladata eikä tallentaa.",
+    ),
+    ("server.degraded", "Tietokanta ei vastaa"),
+    ("server.disconnected", "Ei yhteyttä"),
+    ("server.last_check", "Viimeisin tarkistus:"),
+    ("server.latency", "Viive: {ms} ms"),
+    ("server.offline", "Palvelin ei vastaa"),
+    ("server.online", "Palvelin toiminnassa"),
+    ("server.pause", "Keskeytä tarkistukset"),
+    ("server.paused", "Automaattiset tarkistukset keskeytetty"),
+    ("server.resume", "Jatka tarkistuksia"),
+    (
+        "server.retry_every",
+        "Yritetään uudelleen {interval} välein",
+    ),
+    ("server.unknown", "Tuntematon"),
+    ("session.expires_in", "Istuntosi vanhenee {time} kuluttua"),
+    ("session.stay_signed_in", "Pysy kirjautuneena"),
+    ("snooze.hint", "Torkuta tehtävää"),
+    ("snooze.label", "Torkku"),
+    ("snooze.next_week", "Ensi viikolla"),
+    ("snooze.one_hour", "1 tunti"),
+    ("snooze.tomorrow", "Huomenna"),
+    ("sort.ascending", "Nouseva"),
+    ("sort.created_date", "Luontipäivä"),
+    ("sort.descending", "Laskeva"),
+    ("sort.label", "Järjestä"),
+    ("sort.manual", "Oma järjestys (vedä)"),
+    ("sort.order", "Suunta"),
+    ("stats.clear_completed", "Tyhjennä valmiit"),
+    ("stats.clearing_completed", "Tyhjennetään..."),
+    ("stats.total", "Yhteensä"),
+    ("status.completed", "Valmis"),
+    ("status.in_progress", "Työn alla"),
+    ("status.pending", "Odottaa"),
+    (
+        "sync.changed_elsewhere",
+        "Joku muu muutti tehtävää \"{title}\"",
+    ),
+    (
+        "sync.deleted_elsewhere",
+        "Joku muu poisti tehtävän \"{title}\"",
+    ),
+    (
+        "sync.discarded",
+        "Osa offline-muutoksista hylättiin: {problems}",
+    ),
+    (
+        "sync.not_saved",
+        "Tehtävää \"{title}\" ei voitu tallentaa: {error}",
+    ),
+    ("sync.pending", "{count} odottaa synkronointia"),
+    (
+        "sync.pending_hint",
+        "Offline-tilassa tehdyt muutokset, synkronoidaan kun palvelin palaa",
+    ),
+    ("sync.syncing", "Synkronoidaan..."),
+    ("time.now", "nyt"),
+    ("toast.created", "Tehtävä luotu"),
+    ("toast.deleted", "Tehtävä poistettu"),
+    ("toast.updated", "Tehtävä päivitetty"),
+    (
+        "trash.confirm_purge",
+        "Poistetaanko tehtävä pysyvästi? Tätä ei voi perua.",
+    ),
+    ("trash.empty", "Roskakori on tyhjä"),
+    ("trash.loading", "Ladataan roskakoria..."),
+    ("trash.purge", "Poista pysyvästi"),
+    ("trash.restore", "Palauta"),
+    ("trash.title", "Roskakori"),
+    ("undo.deleted", "Poistettu \"{title}\""),
+];
@@ -0,0 +1,298 @@
+//! UI strings in English and Finnish.
+//!
+//! Views look strings up by key through the [`Lang`] context, e.g.
+//! `lang.t("home.add_todo")`. A key missing from a locale falls back to
+//! English, and one missing from English to the key itself, so a typo shows up
+//! on screen rather than as an empty label.
+
+mod en;
+mod fi;
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::LazyLock;
+
+use chrono::{DateTime, NaiveDate};
+use chrono_tz::Tz;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::todo::{TodoPriority, TodoStatus};
+use crate::utils::{get_preference, set_preference, to_user_time};
+
+/// localStorage key of the chosen UI language.
+pub const LOCALE_KEY: &str = "pref_locale";
+
+/// A language the UI is available in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    En,
+    Fi,
+}
+
+/// Kinds of dates shown in the UI, each formatted the local way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateFormat {
+    /// Weekday, date and time of a due date
+    DueDate,
+    /// A calendar day
+    Day,
+    /// A calendar day with its weekday, for headings
+    Weekday,
+    /// A calendar month, for headings
+    Month,
+    /// Name of a month without the year, for pickers
+    MonthName,
+    /// A short date with the time, for comments
+    Timestamp,
+    /// A time of day
+    Time,
+}
+
+impl Locale {
+    pub const ALL: [Self; 2] = [Self::En, Self::Fi];
+
+    /// Language tag, as used in the `lang` attribute.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Fi => "fi",
+        }
+    }
+
+    /// Name of the language in the language itself.
+    #[must_use]
+    pub const fn native_name(self) -> &'static str {
+        match self {
+            Self::En => "English",
+            Self::Fi => "Suomi",
+        }
+    }
+
+    /// The locale matching a browser language tag such as "fi-FI", English
+    /// for any language without a translation.
+    #[must_use]
+    pub fn from_language_tag(tag: &str) -> Self {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.code().eq_ignore_ascii_case(language))
+            .unwrap_or_default()
+    }
+
+    const fn chrono_locale(self) -> chrono::Locale {
+        match self {
+            Self::En => chrono::Locale::en_US,
+            Self::Fi => chrono::Locale::fi_FI,
+        }
+    }
+
+    const fn pattern(self, format: DateFormat) -> &'static str {
+        match (self, format) {
+            (Self::En, DateFormat::DueDate) => "%A, %B %d, %Y at %I:%M %p",
+            (Self::En, DateFormat::Day) => "%B %d, %Y",
+            (Self::En, DateFormat::Weekday) => "%A, %B %d, %Y",
+            (Self::En, DateFormat::Month) => "%B %Y",
+            (Self::En, DateFormat::MonthName) => "%B",
+            (Self::En, DateFormat::Timestamp) => "%b %d, %H:%M",
+            (Self::En, DateFormat::Time) => "%H:%M",
+            (Self::Fi, DateFormat::DueDate) => "%A %-d.%-m.%Y klo %H.%M",
+            (Self::Fi, DateFormat::Day) => "%-d.%-m.%Y",
+            (Self::Fi, DateFormat::Weekday) => "%A %-d.%-m.%Y",
+            (Self::Fi, DateFormat::Month) => "%B %Y",
+            (Self::Fi, DateFormat::MonthName) => "%B",
+            (Self::Fi, DateFormat::Timestamp) => "%-d.%-m. klo %H.%M",
+            (Self::Fi, DateFormat::Time) => "%H.%M",
+        }
+    }
+
+    fn strings(self) -> &'static HashMap<&'static str, &'static str> {
+        static STRINGS: LazyLock<HashMap<Locale, HashMap<&'static str, &'static str>>> =
+            LazyLock::new(|| {
+                HashMap::from([
+                    (Locale::En, en::STRINGS.iter().copied().collect()),
+                    (Locale::Fi, fi::STRINGS.iter().copied().collect()),
+                ])
+            });
+        &STRINGS[&self]
+    }
+
+    /// The string of `key` in this locale.
+    #[must_use]
+    pub fn t(self, key: &'static str) -> &'static str {
+        self.strings()
+            .get(key)
+            .or_else(|| Self::En.strings().get(key))
+            .copied()
+            .unwrap_or(key)
+    }
+
+    /// The string of `key` with each `{name}` replaced by its value in `args`.
+    #[must_use]
+    pub fn t_with(self, key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+        args.iter()
+            .fold(self.t(key).to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{name}}}"), &value.to_string())
+            })
+    }
+
+    /// Display name of a todo status.
+    #[must_use]
+    pub fn status(self, status: TodoStatus) -> &'static str {
+        self.t(match status {
+            TodoStatus::Pending => "status.pending",
+            TodoStatus::InProgress => "status.in_progress",
+            TodoStatus::Completed => "status.completed",
+        })
+    }
+
+    /// Display name of a todo priority.
+    #[must_use]
+    pub fn priority(self, priority: TodoPriority) -> &'static str {
+        self.t(match priority {
+            TodoPriority::Low => "priority.low",
+            TodoPriority::Medium => "priority.medium",
+            TodoPriority::High => "priority.high",
+        })
+    }
+
+    /// Formats a calendar day.
+    #[must_use]
+    pub fn format_date(self, date: NaiveDate, format: DateFormat) -> String {
+        date.format_localized(self.pattern(format), self.chrono_locale())
+            .to_string()
+    }
+
+    /// Formats a date and time.
+    #[must_use]
+    pub fn format_datetime(self, datetime: &DateTime<Tz>, format: DateFormat) -> String {
+        datetime
+            .format_localized(self.pattern(format), self.chrono_locale())
+            .to_string()
+    }
+
+    /// Formats a stored timestamp (Unix seconds, UTC) in the user's time zone.
+    #[must_use]
+    pub fn format_timestamp(self, timestamp: u64, tz: Tz, format: DateFormat) -> String {
+        to_user_time(timestamp, tz).map_or_else(
+            || self.t("date.invalid").to_string(),
+            |datetime| self.format_datetime(&datetime, format),
+        )
+    }
+}
+
+/// UI language shared by every page.
+#[derive(Clone, Copy)]
+pub struct Lang {
+    pub locale: ReadSignal<Locale>,
+    set_locale: WriteSignal<Locale>,
+}
+
+impl Lang {
+    /// Switches the UI language and remembers the choice.
+    pub fn set(&self, locale: Locale) {
+        self.set_locale.set(locale);
+        set_preference(LOCALE_KEY, &locale);
+    }
+
+    /// The string of `key` in the current language; reactive.
+    #[must_use]
+    pub fn t(&self, key: &'static str) -> &'static str {
+        self.locale.get().t(key)
+    }
+
+    /// The string of `key` with its `{name}` placeholders filled in; reactive.
+    #[must_use]
+    pub fn t_with(&self, key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+        self.locale.get().t_with(key, args)
+    }
+
+    /// Display name of a todo status in the current language; reactive.
+    #[must_use]
+    pub fn status(&self, status: TodoStatus) -> &'static str {
+        self.locale.get().status(status)
+    }
+
+    /// Display name of a todo priority in the current language; reactive.
+    #[must_use]
+    pub fn priority(&self, priority: TodoPriority) -> &'static str {
+        self.locale.get().priority(priority)
+    }
+}
+
+/// Provides the `Lang` context, restoring a stored choice or, on a first
+/// visit, following the browser's `navigator.language`.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn LangProvider(children: Children) -> impl IntoView {
+    let (locale, set_locale) = signal(Locale::default());
+
+    // Restore after mount, so the server-rendered markup hydrates unchanged
+    Effect::new(move |_| {
+        set_locale.set(get_preference(LOCALE_KEY).unwrap_or_else(browser_locale));
+    });
+
+    Effect::new(move |_| apply_lang_attribute(locale.get()));
+
+    provide_context(Lang { locale, set_locale });
+
+    view! { {children()} }
+}
+
+#[must_use]
+pub fn use_lang() -> Lang {
+    expect_context::<Lang>()
+}
+
+/// Dropdown switching the UI language.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn LanguageSelect() -> impl IntoView {
+    let lang = use_lang();
+
+    view! {
+        <select
+            prop:value=move || lang.locale.get().code()
+            on:change=move |ev| lang.set(Locale::from_language_tag(&event_target_value(&ev)))
+            class="px-2 py-2 rounded-lg border border-gray-300 text-gray-700 bg-transparent hover:bg-gray-50 transition-colors dark:border-gray-600 dark:text-gray-200 dark:hover:bg-gray-700"
+            title=move || lang.t("lang.choose")
+        >
+            {Locale::ALL
+                .into_iter()
+                .map(|locale| view! { <option value=locale.code()>{locale.native_name()}</option> })
+                .collect_view()}
+        </select>
+    }
+}
+
+#[cfg(feature = "hydrate")]
+fn browser_locale() -> Locale {
+    web_sys::window()
+        .and_then(|window| window.navigator().language())
+        .map(|tag| Locale::from_language_tag(&tag))
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "hydrate")]
+fn apply_lang_attribute(locale: Locale) {
+    let Some(root) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element())
+    else {
+        return;
+    };
+    if let Err(e) = root.set_attribute("lang", locale.code()) {
+        leptos::logging::warn!("Failed to set the page language: {:?}", e);
+    }
+}
+
+// Server-side stubs
+#[cfg(not(feature = "hydrate"))]
+fn browser_locale() -> Locale {
+    Locale::default()
+}
+
+#[cfg(not(feature = "hydrate"))]
+fn apply_lang_attribute(_locale: Locale) {}
@@ -5,9 +5,9 @@ pub mod components;
 pub mod config;
 pub mod config_tmp;
 pub mod domain;
+pub mod i18n;
 pub mod pages;
 pub mod services;
-pub mod todo;
 pub mod utils;
 
 #[cfg(feature = "hydrate")]
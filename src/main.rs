@@ -4,6 +4,7 @@
 #[tokio::main]
 async fn main() -> miette::Result<()> {
     use axum::Router;
+    use cosmic_rust::api::{attachment_routes, request_id_layer, rest_routes};
     use cosmic_rust::app_tmp::App;
     use cosmic_rust::app_tmp::shell;
     use cosmic_rust::config::get_config;
@@ -17,8 +18,11 @@ async fn main() -> miette::Result<()> {
     let app_config = get_config()
         .map_err(|e| miette::miette!("Failed to get configuration: {}", e))?
         .clone();
+    init_tracing(&app_config.logging);
 
-    initialize_cosmos_db().map_err(|e| miette::miette!("Failed to initialize Cosmos DB: {}", e))?;
+    initialize_cosmos_db()
+        .await
+        .map_err(|e| miette::miette!("Failed to initialize Cosmos DB: {}", e))?;
 
     let conf = get_configuration(None)
         .map_err(|e| miette::miette!("Failed to get Leptos configuration: {}", e))?;
@@ -28,29 +32,62 @@ async fn main() -> miette::Result<()> {
     // Generate the list of routes in your Leptos App
     let routes = generate_route_list(App);
 
-    leptos::logging::debug_warn!("Application configuration:\n {}", &app_config);
+    tracing::debug!("Application configuration:\n {}", &app_config);
+    // Attachments arrive base64 encoded, a third larger than the file itself
+    let body_limit = usize::try_from(app_config.blob.max_attachment_bytes.saturating_mul(2))
+        .unwrap_or(usize::MAX)
+        .max(2 * 1024 * 1024);
     let app = Router::new()
         .leptos_routes(&leptos_options, routes, {
             let leptos_options = leptos_options.clone();
             move || shell(leptos_options.clone())
         })
+        .merge(rest_routes())
+        .merge(attachment_routes())
         .fallback(leptos_axum::file_and_error_handler(shell))
+        .layer(axum::extract::DefaultBodyLimit::max(body_limit))
+        .layer(axum::middleware::from_fn(request_id_layer))
         .with_state(leptos_options)
         .with_state(app_config); // Inject app_config into state
 
     // Run our app with hyper
-    leptos::logging::log!("🌌 Cosmic Todos listening on http://{}", &addr);
+    tracing::info!("🌌 Cosmic Todos listening on http://{}", &addr);
     let listener = tokio::net::TcpListener::bind(&addr)
         .await
         .map_err(|e| miette::miette!("Failed to bind to address {}: {}", addr, e))?;
 
-    axum::serve(listener, app.into_make_service())
-        .await
-        .map_err(|e| miette::miette!("Server error: {}", e))?;
+    // The login lockout is tracked per client address
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await
+    .map_err(|e| miette::miette!("Server error: {}", e))?;
 
     Ok(())
 }
 
+/// Installs the global `tracing` subscriber, writing JSON lines or
+/// human-readable output at the configured level.
+///
+/// The level accepts `RUST_LOG` directives such as `info,cosmic_rust=debug`;
+/// an invalid one falls back to `info`.
+#[cfg(feature = "ssr")]
+fn init_tracing(logging: &cosmic_rust::config::LoggingConfig) {
+    use cosmic_rust::config::LogFormat;
+    use tracing_subscriber::EnvFilter;
+
+    let filter = EnvFilter::try_new(&logging.level).unwrap_or_else(|e| {
+        eprintln!("Invalid log level {:?} ({e}), using info", logging.level);
+        EnvFilter::new("info")
+    });
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter);
+    match logging.format {
+        LogFormat::Json => subscriber.json().init(),
+        LogFormat::Pretty => subscriber.pretty().init(),
+    }
+}
+
 #[cfg(not(feature = "ssr"))]
 pub fn main() {
     // no client-side main function
@@ -1,53 +1,242 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::str::FromStr;
 
-use crate::app_tmp::{
-    create_todo_server, delete_todo_server, get_todos_server, update_todo_server,
+use crate::api::{
+    add_comment_server, bulk_delete_todos_server, bulk_update_todos_server, conflict_current_todo,
+    create_todo_server, delete_todo_server, export_todos_csv_server, export_todos_ical_server,
+    get_assignees_server, get_deleted_todos_server, get_todo_stats_server, get_todos_server,
+    import_todos_server, is_conflict, is_rate_limited, is_too_large, is_unauthorized,
+    purge_todo_server, restore_todo_server, update_todo_server, upload_attachment_server,
 };
-use crate::components::status_bar::StatusBar;
-use crate::domain::todo::{Todo, TodoAssignee, TodoStatus};
-use chrono::{Datelike, Local, NaiveDate, TimeZone};
+use crate::components::avatar::Avatar;
+use crate::components::change_password::ChangePasswordDialog;
+use crate::components::dev_tools::DevTools;
+use crate::components::lazy_group::LazyGroup;
+use crate::components::reassign::ReassignDialog;
+use crate::components::status_bar::{ServerStatus, StatusBar};
+use crate::components::theme::ThemeToggle;
+use crate::components::toast::use_toasts;
+use crate::components::todo_dialog::{TodoDialog, TodoFormFields};
+use crate::domain::auth::{WRITE_TODOS, use_auth};
+use crate::domain::todo::validation::validate_no_html;
+use crate::domain::todo::{
+    BulkItemResult, SortBy, Todo, TodoAssignee, TodoFilter, TodoPriority, TodoStats, TodoStatus,
+    UNKNOWN_CREATOR,
+};
+use crate::i18n::{DateFormat, LanguageSelect, Locale, use_lang};
+use crate::utils::{
+    ASSIGNED_TO_ME_DEFAULT_KEY, FILTER_ASSIGNEE_KEY, FILTER_STATUS_KEY, PendingMutation,
+    SKIP_DELETE_CONFIRM_KEY, SORT_ASCENDING_KEY, SORT_BY_KEY, SearchQuery, SelectedFile, Snooze,
+    SyncOutcome, WEEK_START_KEY, apply_pending_mutations, copy_to_clipboard, download_file,
+    enqueue_mutation, format_relative_time, from_user_time, get_preference, is_offline_error,
+    load_pending_mutations, parse_quick_add, read_file_text, render_markdown, replay_mutation,
+    sanitize_string, save_pending_mutations, set_preference, snoozed_due_date, to_user_time,
+    unsanitize_string, unsanitized_todo, user_date, user_today, user_tz,
+};
+use chrono::{Datelike, NaiveDate, Weekday};
+use chrono_tz::Tz;
 use leptos::leptos_dom::logging;
 use leptos::web_sys;
 use leptos::{ev, prelude::*};
+use leptos_router::{NavigateOptions, hooks::use_navigate};
 use uuid::Uuid;
-use validator::Validate;
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum SortBy {
-    Title,
-    DueDate,
-    Status,
-    Assignee,
-    CreatedDate,
+use validator::{Validate, ValidationErrors};
+
+/// One-click filters on the due date, applied on top of the other filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueFilter {
+    Overdue,
+    Today,
+    ThisWeek,
+    NoDueDate,
 }
 
-impl SortBy {
-    fn as_str(&self) -> &'static str {
+impl DueFilter {
+    const ALL: [DueFilter; 4] = [
+        DueFilter::Overdue,
+        DueFilter::Today,
+        DueFilter::ThisWeek,
+        DueFilter::NoDueDate,
+    ];
+
+    /// Key of the filter's label in [`crate::i18n`].
+    fn label_key(self) -> &'static str {
+        match self {
+            DueFilter::Overdue => "filter.overdue",
+            DueFilter::Today => "filter.due_today",
+            DueFilter::ThisWeek => "filter.due_this_week",
+            DueFilter::NoDueDate => "filter.no_due_date",
+        }
+    }
+
+    /// Days are compared in the user's time zone, like the calendar.
+    fn matches(self, todo: &Todo, today: NaiveDate, tz: Tz) -> bool {
+        let due_day = todo.due_date.and_then(|timestamp| user_date(timestamp, tz));
         match self {
-            SortBy::Title => "title",
-            SortBy::DueDate => "due_date",
-            SortBy::Status => "status",
-            SortBy::Assignee => "assignee",
-            SortBy::CreatedDate => "created_date",
+            DueFilter::Overdue => todo.is_overdue(),
+            DueFilter::Today => due_day == Some(today),
+            DueFilter::ThisWeek => due_day.is_some_and(|day| day.iso_week() == today.iso_week()),
+            DueFilter::NoDueDate => todo.due_date.is_none(),
         }
     }
 }
 
-impl std::str::FromStr for SortBy {
-    type Err = String;
+/// Position for `dragged` when dropped just before `target`, halfway between
+/// `target` and the todo shown above it.
+///
+/// `order` holds the ids and effective positions of the todos in display order.
+/// Returns `None` when dropped onto itself or an unknown target.
+fn drop_position(
+    order: &[(String, f64)],
+    dragged: &str,
+    target: &str,
+    ascending: bool,
+) -> Option<f64> {
+    let others: Vec<&(String, f64)> = order.iter().filter(|(id, _)| id != dragged).collect();
+    let target_index = others.iter().position(|(id, _)| id == target)?;
+    let next = others[target_index].1;
+    Some(match target_index.checked_sub(1) {
+        Some(previous_index) => f64::midpoint(others[previous_index].1, next),
+        None if ascending => next - 1.0,
+        None => next + 1.0,
+    })
+}
+
+/// An edit rejected because the todo was changed elsewhere meanwhile.
+#[derive(Clone, Debug, PartialEq)]
+struct EditConflict {
+    /// The rejected edit
+    mine: Todo,
+    /// The stored version; `None` if it could not be read, e.g. because the todo was deleted
+    theirs: Option<Todo>,
+    /// Label keys of the fields changed on the server since the edit started
+    changed: Vec<&'static str>,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "title" => Ok(SortBy::Title),
-            "due_date" => Ok(SortBy::DueDate),
-            "status" => Ok(SortBy::Status),
-            "assignee" => Ok(SortBy::Assignee),
-            "created_date" => Ok(SortBy::CreatedDate),
-            _ => Err(format!("Unknown sort type: {s}")),
-        }
+/// Label keys of the editable fields that differ between `base` and `current`.
+fn changed_fields(base: &Todo, current: &Todo) -> Vec<&'static str> {
+    [
+        ("field.title", base.title != current.title),
+        ("field.description", base.description != current.description),
+        ("field.due_date", base.due_date != current.due_date),
+        ("field.assignee", base.assignee != current.assignee),
+        ("field.status", base.status != current.status),
+        ("field.priority", base.priority != current.priority),
+        ("field.tags", base.tags != current.tags),
+        ("field.attachments", base.attachments != current.attachments),
+    ]
+    .into_iter()
+    .filter_map(|(key, changed)| changed.then_some(key))
+    .collect()
+}
+
+/// Marks a drag as a move of the todo card; Firefox only starts a drag with data set.
+#[cfg(feature = "hydrate")]
+fn start_drag(ev: &ev::DragEvent, todo_id: &str) {
+    if let Some(data_transfer) = ev.data_transfer() {
+        data_transfer.set_effect_allowed("move");
+        let _ = data_transfer.set_data("text/plain", todo_id);
+    }
+}
+
+#[cfg(not(feature = "hydrate"))]
+fn start_drag(_ev: &ev::DragEvent, _todo_id: &str) {}
+
+/// Attribute marking the todo cards that arrow keys move between.
+#[cfg(feature = "hydrate")]
+const CARD_SELECTOR: &str = "[data-todo-card]";
+
+/// Moves focus from the card handling `ev` to the next or previous card in
+/// the list; nothing happens at either end.
+#[cfg(feature = "hydrate")]
+fn focus_adjacent_card(ev: &ev::KeyboardEvent, forward: bool) {
+    use wasm_bindgen::JsCast;
+    use web_sys::{Element, HtmlElement};
+
+    let Some(current) = ev
+        .current_target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+    else {
+        return;
+    };
+    let Some(nodes) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.query_selector_all(CARD_SELECTOR).ok())
+    else {
+        return;
+    };
+    let cards: Vec<HtmlElement> = (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect();
+    let Some(index) = cards
+        .iter()
+        .position(|card| AsRef::<Element>::as_ref(card) == &current)
+    else {
+        return;
+    };
+    let next = if forward {
+        cards.get(index + 1)
+    } else {
+        index.checked_sub(1).and_then(|index| cards.get(index))
+    };
+    if let Some(card) = next {
+        let _ = card.focus();
     }
 }
 
+#[cfg(not(feature = "hydrate"))]
+fn focus_adjacent_card(_ev: &ev::KeyboardEvent, _forward: bool) {}
+
+/// How long a deleted todo can be brought back from its undo toast.
+#[cfg(feature = "hydrate")]
+const UNDO_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(8);
+
+/// How long the search box must be idle before its text is sent to the server.
+#[cfg(feature = "hydrate")]
+const SEARCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// How often the relative due labels ("in 3 hours") are recomputed.
+#[cfg(feature = "hydrate")]
+const RELATIVE_TIME_REFRESH: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Month groups rendered up front; later ones mount as they near the viewport.
+const EAGER_GROUPS: usize = 3;
+
+/// Rough height of a todo card plus its gap, reserved for groups not yet rendered.
+const ESTIMATED_CARD_HEIGHT: f64 = 220.0;
+
+/// Years before and after the current one offered by the calendar's year picker.
+const CALENDAR_YEAR_RANGE: i32 = 5;
+
+const CALENDAR_SELECT_CLASS: &str = "px-2 py-1 text-sm font-semibold text-gray-800 dark:text-gray-100 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent";
+
+/// Todo fields whose validation errors show beneath their input in the form.
+const FORM_FIELDS: [&str; 2] = ["title", "description"];
+
+/// Message for each of the `FORM_FIELDS` that `errors` rejects, keyed by field
+/// name. Errors of other fields are not included.
+fn form_field_errors(errors: &ValidationErrors, locale: Locale) -> HashMap<&'static str, String> {
+    errors
+        .field_errors()
+        .into_iter()
+        .filter_map(|(field, field_errors)| {
+            let field = FORM_FIELDS.into_iter().find(|name| *name == field)?;
+            let error = field_errors.first()?;
+            let message = match (field, error.code.as_ref()) {
+                ("title", "length") => locale.t("form.title_length").to_string(),
+                ("description", "length") => locale.t("form.description_length").to_string(),
+                (_, "contains_html") => locale.t("form.contains_html").to_string(),
+                _ => error
+                    .message
+                    .as_ref()
+                    .map_or_else(|| error.code.to_string(), ToString::to_string),
+            };
+            Some((field, message))
+        })
+        .collect()
+}
+
 #[component]
 #[allow(clippy::must_use_candidate)]
 #[allow(clippy::too_many_lines)]
@@ -58,31 +247,65 @@ pub fn SearchAndFilters(
     set_filter_status: WriteSignal<String>,
     filter_assignee: ReadSignal<String>,
     set_filter_assignee: WriteSignal<String>,
+    assignees: ReadSignal<Vec<String>>,
+    /// Assignee name of the signed-in user, if any configured assignee matches
+    #[prop(into)]
+    my_assignee: Signal<Option<String>>,
+    assigned_to_me_default: ReadSignal<bool>,
+    set_assigned_to_me_default: WriteSignal<bool>,
+    filter_tag: ReadSignal<String>,
+    set_filter_tag: WriteSignal<String>,
+    #[prop(into)] tags: Signal<Vec<String>>,
     sort_by: ReadSignal<SortBy>,
     set_sort_by: WriteSignal<SortBy>,
     sort_ascending: ReadSignal<bool>,
     set_sort_ascending: WriteSignal<bool>,
+    hide_completed: ReadSignal<bool>,
+    set_hide_completed: WriteSignal<bool>,
+    skip_delete_confirm: ReadSignal<bool>,
+    set_skip_delete_confirm: WriteSignal<bool>,
+    due_filter: ReadSignal<Option<DueFilter>>,
+    set_due_filter: WriteSignal<Option<DueFilter>>,
     total_todos: impl Fn() -> usize + Send + 'static,
     filtered_todos: impl Fn() -> usize + Send + 'static,
 ) -> impl IntoView {
+    let lang = use_lang();
     let clear_filters = move |_| {
         set_search_term.set(String::new());
         set_filter_status.set("All".to_string());
         set_filter_assignee.set("All".to_string());
+        set_filter_tag.set("All".to_string());
+        set_due_filter.set(None);
+    };
+    let assigned_to_me = Signal::derive(move || {
+        my_assignee.with(|name| {
+            name.as_ref()
+                .is_some_and(|name| filter_assignee.with(|filter| filter == name))
+        })
+    });
+    let toggle_assigned_to_me = move |_| {
+        let Some(name) = my_assignee.get_untracked() else {
+            return;
+        };
+        set_filter_assignee.set(if assigned_to_me.get_untracked() {
+            "All".to_string()
+        } else {
+            name
+        });
     };
 
     view! {
-        <div class="bg-white rounded-xl shadow-sm border border-gray-100 p-4 mb-4">
+        <div class="bg-white dark:bg-gray-800 rounded-xl shadow-sm border border-gray-100 dark:border-gray-700 p-4 mb-4">
             // Search bar
             <div class="mb-4">
-                <label class="block text-sm font-medium text-gray-700 mb-2">"Search todos"</label>
+                <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2">{move || lang.t("filter.search")}</label>
                 <div class="relative">
                     <input
                         type="text"
                         prop:value=move || search_term.get()
                         on:input=move |ev| set_search_term.set(event_target_value(&ev))
-                        class="w-full pl-10 pr-4 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
-                        placeholder="Search by title or description..."
+                        class="w-full pl-10 pr-4 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
+                        placeholder=move || lang.t("filter.search_placeholder")
                     />
                     <svg
                         class="absolute left-3 top-2.5 h-5 w-5 text-gray-400"
@@ -100,39 +323,157 @@ pub fn SearchAndFilters(
                 </div>
             </div>
 
+            // Quick filters: the signed-in user's todos, and at most one due date range
+            <div class="flex flex-wrap gap-2 mb-4">
+                <button
+                    type="button"
+                    on:click=toggle_assigned_to_me
+                    disabled=move || my_assignee.with(Option::is_none)
+                    aria-pressed=move || assigned_to_me.get().to_string()
+                    title=move || {
+                        my_assignee
+                            .with(Option::is_none)
+                            .then(|| lang.t("filter.assigned_to_me_unmatched"))
+                    }
+                    class=move || {
+                        if assigned_to_me.get() {
+                            "px-3 py-1 text-sm rounded-full border border-purple-300 bg-purple-100 text-purple-700 transition-colors"
+                        } else {
+                            "px-3 py-1 text-sm rounded-full border border-gray-200 dark:border-gray-700 text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors disabled:opacity-50 disabled:cursor-not-allowed"
+                        }
+                    }
+                >
+                    {move || lang.t("filter.assigned_to_me")}
+                </button>
+                {DueFilter::ALL
+                    .into_iter()
+                    .map(|filter| {
+                        view! {
+                            <button
+                                on:click=move |_| {
+                                    set_due_filter
+                                        .update(|current| {
+                                            *current = if *current == Some(filter) {
+                                                None
+                                            } else {
+                                                Some(filter)
+                                            };
+                                        });
+                                }
+                                class=move || {
+                                    if due_filter.get() == Some(filter) {
+                                        "px-3 py-1 text-sm rounded-full border border-purple-300 bg-purple-100 text-purple-700 transition-colors"
+                                    } else {
+                                        "px-3 py-1 text-sm rounded-full border border-gray-200 dark:border-gray-700 text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                                    }
+                                }
+                            >
+                                {move || lang.t(filter.label_key())}
+                            </button>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+
             // Filters and sorting row
-            <div class="grid grid-cols-1 md:grid-cols-4 gap-4">
+            <div class="grid grid-cols-1 md:grid-cols-5 gap-4">
                 // Status filter
                 <div>
-                    <label class="block text-sm font-medium text-gray-700 mb-1">"Status"</label>
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-1">{move || lang.t("field.status")}</label>
                     <select
                         prop:value=move || filter_status.get()
                         on:change=move |ev| set_filter_status.set(event_target_value(&ev))
-                        class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent text-sm"
+                        class="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent text-sm"
                     >
-                        <option value="All">"All Status"</option>
-                        <option value="Pending">"Pending"</option>
-                        <option value="Completed">"Completed"</option>
+                        <option value="All">{move || lang.t("filter.all_statuses")}</option>
+                        <option value="Pending">{move || lang.status(TodoStatus::Pending)}</option>
+                        <option value="In Progress">
+                            {move || lang.status(TodoStatus::InProgress)}
+                        </option>
+                        <option value="Completed">{move || lang.status(TodoStatus::Completed)}</option>
                     </select>
                 </div>
 
                 // Assignee filter
                 <div>
-                    <label class="block text-sm font-medium text-gray-700 mb-1">"Assignee"</label>
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-1">{move || lang.t("field.assignee")}</label>
                     <select
                         prop:value=move || filter_assignee.get()
                         on:change=move |ev| set_filter_assignee.set(event_target_value(&ev))
-                        class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent text-sm"
+                        class="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent text-sm"
+                    >
+                        <option value="All">{move || lang.t("filter.all_assignees")}</option>
+                        {move || {
+                            assignees
+                                .get()
+                                .into_iter()
+                                .map(|name| view! { <option value=name.clone()>{name.clone()}</option> })
+                                .collect_view()
+                        }}
+                    </select>
+                    <div class="mt-2 flex flex-wrap gap-1">
+                        {move || {
+                            assignees
+                                .get()
+                                .into_iter()
+                                .map(|name| {
+                                    let toggle_name = name.clone();
+                                    let selected_name = name.clone();
+                                    let is_selected = Signal::derive(move || {
+                                        filter_assignee.with(|filter| *filter == selected_name)
+                                    });
+                                    view! {
+                                        <button
+                                            type="button"
+                                            on:click=move |_| {
+                                                set_filter_assignee
+                                                    .update(|filter| {
+                                                        *filter = if *filter == toggle_name {
+                                                            "All".to_string()
+                                                        } else {
+                                                            toggle_name.clone()
+                                                        };
+                                                    });
+                                            }
+                                            aria-pressed=move || is_selected.get().to_string()
+                                            class=move || {
+                                                if is_selected.get() {
+                                                    "rounded-full ring-2 ring-purple-500 ring-offset-1"
+                                                } else {
+                                                    "rounded-full opacity-70 hover:opacity-100 focus:outline-none focus:ring-2 focus:ring-purple-500"
+                                                }
+                                            }
+                                        >
+                                            <Avatar assignee=TodoAssignee::unchecked(name) />
+                                        </button>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </div>
+                </div>
+
+                // Tag filter
+                <div>
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-1">{move || lang.t("field.tag")}</label>
+                    <select
+                        prop:value=move || filter_tag.get()
+                        on:change=move |ev| set_filter_tag.set(event_target_value(&ev))
+                        class="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent text-sm"
                     >
-                        <option value="All">"All Assignees"</option>
-                        <option value="Mikko">"Mikko"</option>
-                        <option value="Niina">"Niina"</option>
+                        <option value="All">{move || lang.t("filter.all_tags")}</option>
+                        {move || {
+                            tags.get()
+                                .into_iter()
+                                .map(|tag| view! { <option value=tag.clone()>{tag.clone()}</option> })
+                                .collect_view()
+                        }}
                     </select>
                 </div>
 
                 // Sort by
                 <div>
-                    <label class="block text-sm font-medium text-gray-700 mb-1">"Sort by"</label>
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-1">{move || lang.t("sort.label")}</label>
                     <select
                         prop:value=move || sort_by.get().as_str()
                         on:change=move |ev| {
@@ -145,24 +486,26 @@ pub fn SearchAndFilters(
                                         .unwrap_or(SortBy::CreatedDate),
                                 );
                         }
-                        class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent text-sm"
+                        class="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent text-sm"
                     >
-                        <option value="created_date">"Created Date"</option>
-                        <option value="title">"Title"</option>
-                        <option value="due_date">"Due Date"</option>
-                        <option value="status">"Status"</option>
-                        <option value="assignee">"Assignee"</option>
+                        <option value="created_date">{move || lang.t("sort.created_date")}</option>
+                        <option value="title">{move || lang.t("field.title")}</option>
+                        <option value="due_date">{move || lang.t("field.due_date")}</option>
+                        <option value="status">{move || lang.t("field.status")}</option>
+                        <option value="assignee">{move || lang.t("field.assignee")}</option>
+                        <option value="priority">{move || lang.t("field.priority")}</option>
+                        <option value="manual">{move || lang.t("sort.manual")}</option>
                     </select>
                 </div>
 
                 // Sort order toggle
                 <div>
-                    <label class="block text-sm font-medium text-gray-700 mb-1">"Order"</label>
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-1">{move || lang.t("sort.order")}</label>
                     <button
                         on:click=move |_| set_sort_ascending.update(|asc| *asc = !*asc)
-                        class="w-full px-3 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors text-sm flex items-center justify-center gap-2"
+                        class="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors text-sm flex items-center justify-center gap-2"
                     >
-                        {move || if sort_ascending.get() { "Ascending" } else { "Descending" }}
+                        {move || lang.t(if sort_ascending.get() { "sort.ascending" } else { "sort.descending" })}
                         <svg
                             class=move || {
                                 format!(
@@ -186,20 +529,69 @@ pub fn SearchAndFilters(
             </div>
 
             // Results count and clear filters
-            <div class="mt-3 pt-3 border-t border-gray-100 flex justify-between items-center">
-                <p class="text-sm text-gray-600">
-                    {move || format!("Showing {} of {} todos", filtered_todos(), total_todos())}
-                </p>
+            <div class="mt-3 pt-3 border-t border-gray-100 dark:border-gray-700 flex justify-between items-center">
+                <div class="flex items-center gap-4">
+                    <p class="text-sm text-gray-600 dark:text-gray-300">
+                        {move || {
+                            lang.t_with(
+                                "filter.showing",
+                                &[("shown", &filtered_todos()), ("total", &total_todos())],
+                            )
+                        }}
+                    </p>
+                    <label class="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-200 cursor-pointer">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || hide_completed.get()
+                            on:change=move |ev| set_hide_completed.set(event_target_checked(&ev))
+                            class="rounded border-gray-300 dark:border-gray-600 text-purple-600 focus:ring-purple-500"
+                        />
+                        {move || lang.t("filter.hide_completed")}
+                    </label>
+                    <label
+                        class="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-200 cursor-pointer"
+                        title=move || lang.t("filter.skip_delete_confirm_hint")
+                    >
+                        <input
+                            type="checkbox"
+                            prop:checked=move || skip_delete_confirm.get()
+                            on:change=move |ev| {
+                                let skip = event_target_checked(&ev);
+                                set_skip_delete_confirm.set(skip);
+                                set_preference(SKIP_DELETE_CONFIRM_KEY, &skip);
+                            }
+                            class="rounded border-gray-300 dark:border-gray-600 text-purple-600 focus:ring-purple-500"
+                        />
+                        {move || lang.t("filter.skip_delete_confirm")}
+                    </label>
+                    <label
+                        class="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-200 cursor-pointer"
+                        title=move || lang.t("filter.assigned_to_me_default_hint")
+                    >
+                        <input
+                            type="checkbox"
+                            prop:checked=move || assigned_to_me_default.get()
+                            on:change=move |ev| {
+                                let enabled = event_target_checked(&ev);
+                                set_assigned_to_me_default.set(enabled);
+                                set_preference(ASSIGNED_TO_ME_DEFAULT_KEY, &enabled);
+                            }
+                            class="rounded border-gray-300 dark:border-gray-600 text-purple-600 focus:ring-purple-500"
+                        />
+                        {move || lang.t("filter.assigned_to_me_default")}
+                    </label>
+                </div>
 
                 <Show when=move || {
                     !search_term.get().is_empty() || filter_status.get() != "All"
-                        || filter_assignee.get() != "All"
+                        || filter_assignee.get() != "All" || filter_tag.get() != "All"
+                        || due_filter.get().is_some()
                 }>
                     <button
                         on:click=clear_filters
                         class="px-3 py-1 text-sm text-purple-600 border border-purple-200 rounded-lg hover:bg-purple-50 transition-colors"
                     >
-                        "Clear Filters"
+                        {move || lang.t("filter.clear")}
                     </button>
                 </Show>
             </div>
@@ -207,40 +599,566 @@ pub fn SearchAndFilters(
     }
 }
 
+/// Ids of every completed todo, fetched page by page with the server side status filter.
+async fn completed_todo_ids() -> Result<Vec<String>, ServerFnError> {
+    let filter = TodoFilter {
+        status: Some(TodoStatus::Completed),
+        ..TodoFilter::default()
+    };
+    let mut ids = Vec::new();
+    let mut continuation = None;
+    loop {
+        let page =
+            get_todos_server(None, None, continuation, Some(filter.clone()), None, None).await?;
+        ids.extend(page.todos.into_iter().map(|todo| todo.id));
+        continuation = page.continuation;
+        if continuation.is_none() {
+            return Ok(ids);
+        }
+    }
+}
+
+/// Compact overview of todo counts shown above the calendar.
+///
+/// Writers get a button to clear the completed todos, disabled while there are none.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn StatsCard(
+    stats: ReadSignal<Option<TodoStats>>,
+    #[prop(into)] can_write: Signal<bool>,
+    #[prop(into)] clearing: Signal<bool>,
+    #[prop(into)] on_clear_completed: Callback<usize>,
+) -> impl IntoView {
+    let lang = use_lang();
+    let stat = |label: &'static str, value: usize, class: &'static str| {
+        view! {
+            <div class="text-center">
+                <p class=format!("text-2xl font-bold {class}")>{value}</p>
+                <p class="text-xs text-gray-500 dark:text-gray-400">{label}</p>
+            </div>
+        }
+    };
+
+    move || {
+        stats
+            .get()
+            .map(|stats| {
+                view! {
+                    <div class="mb-6 p-4 bg-white dark:bg-gray-800 rounded-xl shadow-sm border border-gray-100 dark:border-gray-700 flex flex-wrap items-center justify-between gap-4">
+                        <div class="flex gap-6">
+                            {stat(lang.t("stats.total"), stats.total, "text-gray-900 dark:text-gray-100")}
+                            {stat(lang.status(TodoStatus::Pending), stats.pending, "text-yellow-600")}
+                            {stat(lang.status(TodoStatus::InProgress), stats.in_progress, "text-blue-600")}
+                            {stat(lang.status(TodoStatus::Completed), stats.completed, "text-green-600")}
+                            {stat(lang.t("filter.overdue"), stats.overdue, "text-red-600")}
+                        </div>
+                        <Show when=move || can_write.get()>
+                            <button
+                                type="button"
+                                on:click=move |_| on_clear_completed.run(stats.completed)
+                                disabled=move || stats.completed == 0 || clearing.get()
+                                class="px-3 py-1 text-sm text-green-700 border border-green-200 rounded-lg hover:bg-green-50 transition-colors disabled:opacity-50 disabled:cursor-not-allowed dark:text-green-300 dark:border-green-700 dark:hover:bg-green-900"
+                            >
+                                {move || {
+                                    lang.t(
+                                        if clearing.get() {
+                                            "stats.clearing_completed"
+                                        } else {
+                                            "stats.clear_completed"
+                                        },
+                                    )
+                                }}
+                            </button>
+                        </Show>
+                        <div class="flex flex-wrap gap-2">
+                            {stats
+                                .per_assignee
+                                .into_iter()
+                                .map(|(assignee, count)| {
+                                    view! {
+                                        <span class="px-2 py-1 text-xs rounded-full bg-purple-50 text-purple-700">
+                                            {format!("{assignee}: {count}")}
+                                        </span>
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                    </div>
+                }
+            })
+    }
+}
+
+/// Shows completed todos grouped by the month they were completed, newest first.
+///
+/// Todos completed before completion times were recorded are listed under
+/// "Completion date unknown".
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn CompletedArchive(todos: ReadSignal<Vec<Todo>>) -> impl IntoView {
+    let lang = use_lang();
+    let tz = user_tz();
+    let archived_groups = move || {
+        let mut groups: BTreeMap<Option<String>, Vec<Todo>> = BTreeMap::new();
+        for todo in todos.get() {
+            if todo.status != TodoStatus::Completed {
+                continue;
+            }
+            let group_key = todo
+                .completed_at
+                .and_then(|timestamp| to_user_time(timestamp, tz))
+                .map(|datetime| datetime.format("%Y-%m").to_string());
+            groups.entry(group_key).or_default().push(todo);
+        }
+
+        for todos in groups.values_mut() {
+            todos.sort_by(|a, b| b.completed_at.cmp(&a.completed_at));
+        }
+
+        // Most recent month first, unknown completion dates last
+        let mut groups: Vec<(Option<String>, Vec<Todo>)> = groups.into_iter().collect();
+        groups.sort_by(|(a, _), (b, _)| match (a, b) {
+            (Some(a_month), Some(b_month)) => b_month.cmp(a_month),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+        groups
+    };
+
+    let format_month_header = move |month_key: Option<&str>| -> String {
+        let locale = lang.locale.get();
+        month_key.map_or_else(
+            || locale.t("archive.date_unknown").to_string(),
+            |month_key| {
+                chrono::NaiveDate::parse_from_str(&format!("{month_key}-01"), "%Y-%m-%d")
+                    .map_or_else(
+                        |_| month_key.to_string(),
+                        |date| locale.format_date(date, DateFormat::Month),
+                    )
+            },
+        )
+    };
+
+    let format_completed_at = move |timestamp: u64| -> String {
+        lang.locale
+            .get()
+            .format_timestamp(timestamp, tz, DateFormat::Day)
+    };
+
+    view! {
+        <div class="space-y-6">
+            {move || {
+                let groups = archived_groups();
+                if groups.is_empty() {
+                    view! {
+                        <div class="text-center py-12 bg-white dark:bg-gray-800 rounded-2xl shadow-sm border border-gray-100 dark:border-gray-700">
+                            <h3 class="text-lg font-medium text-gray-900 dark:text-gray-100 mb-2">
+                                {lang.t("archive.empty")}
+                            </h3>
+                            <p class="text-gray-500 dark:text-gray-400">{lang.t("archive.empty_hint")}</p>
+                        </div>
+                    }
+                        .into_any()
+                } else {
+                    groups
+                        .into_iter()
+                        .map(|(month_key, todos_in_month)| {
+                            let month_header = format_month_header(month_key.as_deref());
+                            view! {
+                                <div class="space-y-4">
+                                    <div class="flex items-center gap-4">
+                                        <h3 class="text-xl font-semibold text-gray-800 dark:text-gray-100">
+                                            {month_header}
+                                        </h3>
+                                        <div class="flex-1 h-px bg-gradient-to-r from-green-200 to-transparent"></div>
+                                        <span class="text-sm text-gray-500 dark:text-gray-400 bg-gray-100 dark:bg-gray-700 px-2 py-1 rounded-full">
+                                            {lang.t_with("count.todos", &[("count", &todos_in_month.len())])}
+                                        </span>
+                                    </div>
+                                    <div class="grid gap-3">
+                                        {todos_in_month
+                                            .into_iter()
+                                            .map(|todo| {
+                                                view! {
+                                                    <div class="bg-white dark:bg-gray-800 rounded-xl shadow-sm border border-gray-100 dark:border-gray-700 px-6 py-4 flex justify-between items-center gap-4">
+                                                        <div class="min-w-0">
+                                                            <p class="font-medium text-gray-700 dark:text-gray-200 truncate">
+                                                                {unsanitize_string(&todo.title)}
+                                                            </p>
+                                                            <p class="text-sm text-gray-500 dark:text-gray-400">
+                                                                {todo.assignee.to_string()}
+                                                            </p>
+                                                        </div>
+                                                        <span class="text-sm text-green-700 flex-shrink-0">
+                                                            {todo
+                                                                .completed_at
+                                                                .map(|timestamp| {
+                                                                    lang.t_with(
+                                                                        "archive.completed_on",
+                                                                        &[("date", &format_completed_at(timestamp))],
+                                                                    )
+                                                                })}
+                                                        </span>
+                                                    </div>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </div>
+                                </div>
+                            }
+                        })
+                        .collect_view()
+                        .into_any()
+                }
+            }}
+        </div>
+    }
+}
+
+/// Lists soft-deleted todos and lets the user restore or permanently delete them.
+///
+/// The list is reloaded whenever `refresh` changes, and `on_restore` is called
+/// after a todo has been restored so the main list can be reloaded.
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+pub fn TrashPanel(
+    refresh: ReadSignal<usize>,
+    #[prop(into)] on_restore: Callback<()>,
+) -> impl IntoView {
+    let lang = use_lang();
+    let (deleted_todos, set_deleted_todos) = signal(Vec::<Todo>::new());
+    let (error_message, set_error_message) = signal(String::new());
+
+    let load_trash_action =
+        Action::new(move |(): &()| async move { get_deleted_todos_server().await });
+    let restore_todo_action = Action::new(move |id: &String| {
+        let id = id.clone();
+        async move { (id.clone(), restore_todo_server(id).await) }
+    });
+    let purge_todo_action = Action::new(move |id: &String| {
+        let id = id.clone();
+        async move { (id.clone(), purge_todo_server(id).await) }
+    });
+
+    Effect::new(move |_| {
+        refresh.track();
+        load_trash_action.dispatch(());
+    });
+
+    Effect::new(move |_| {
+        if let Some(result) = load_trash_action.value().get() {
+            match result {
+                Ok(todos_list) => {
+                    set_deleted_todos.set(todos_list);
+                    set_error_message.set(String::new());
+                }
+                Err(e) => set_error_message.set(
+                    lang.locale
+                        .get_untracked()
+                        .t_with("error.load_trash", &[("error", &e)]),
+                ),
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some((id, result)) = restore_todo_action.value().get() {
+            match result {
+                Ok(()) => {
+                    set_deleted_todos.update(|todos| todos.retain(|t| t.id != id));
+                    set_error_message.set(String::new());
+                    on_restore.run(());
+                }
+                Err(e) => set_error_message.set(
+                    lang.locale
+                        .get_untracked()
+                        .t_with("error.restore_todo", &[("error", &e)]),
+                ),
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some((id, result)) = purge_todo_action.value().get() {
+            match result {
+                Ok(()) => {
+                    set_deleted_todos.update(|todos| todos.retain(|t| t.id != id));
+                    set_error_message.set(String::new());
+                }
+                Err(e) => {
+                    set_error_message.set(
+                        lang.locale
+                            .get_untracked()
+                            .t_with("error.purge_todo", &[("error", &e)]),
+                    );
+                }
+            }
+        }
+    });
+
+    let is_busy = move || restore_todo_action.pending().get() || purge_todo_action.pending().get();
+
+    view! {
+        <div class="bg-white dark:bg-gray-800 rounded-xl shadow-sm border border-gray-100 dark:border-gray-700 p-4 mb-4">
+            <div class="flex justify-between items-center mb-3">
+                <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100">
+                    {move || lang.t("trash.title")}
+                </h2>
+                <span class="text-sm text-gray-500 dark:text-gray-400 bg-gray-100 dark:bg-gray-700 px-2 py-1 rounded-full">
+                    {move || lang.t_with("count.todos", &[("count", &deleted_todos.get().len())])}
+                </span>
+            </div>
+
+            <Show when=move || !error_message.get().is_empty()>
+                <p class="mb-3 text-sm font-medium text-red-600">{move || error_message.get()}</p>
+            </Show>
+
+            <Show
+                when=move || !deleted_todos.get().is_empty()
+                fallback=move || {
+                    view! {
+                        <p class="text-sm text-gray-500 dark:text-gray-400 text-center py-4">
+                            {move || {
+                                if load_trash_action.pending().get() {
+                                    lang.t("trash.loading")
+                                } else {
+                                    lang.t("trash.empty")
+                                }
+                            }}
+                        </p>
+                    }
+                }
+            >
+                <ul class="divide-y divide-gray-100">
+                    <For
+                        each=move || deleted_todos.get()
+                        key=|todo| todo.id.clone()
+                        children=move |todo| {
+                            let restore_id = todo.id.clone();
+                            let purge_id = todo.id.clone();
+                            view! {
+                                <li class="flex justify-between items-center py-2 gap-4">
+                                    <div class="min-w-0">
+                                        <p class="text-sm font-medium text-gray-700 dark:text-gray-200 truncate line-through">
+                                            {unsanitize_string(&todo.title)}
+                                        </p>
+                                        <p class="text-xs text-gray-500 dark:text-gray-400">{todo.assignee.to_string()}</p>
+                                    </div>
+                                    <div class="flex gap-2 flex-shrink-0">
+                                        <button
+                                            on:click=move |_| {
+                                                restore_todo_action.dispatch(restore_id.clone());
+                                            }
+                                            class="px-3 py-1 text-sm text-purple-600 border border-purple-200 rounded-lg hover:bg-purple-50 transition-colors disabled:opacity-50"
+                                            disabled=is_busy
+                                        >
+                                            {move || lang.t("trash.restore")}
+                                        </button>
+                                        <button
+                                            on:click=move |_| {
+                                                if let Some(window) = web_sys::window() {
+                                                    if window
+                                                        .confirm_with_message(
+                                                            lang.locale.get_untracked().t("trash.confirm_purge"),
+                                                        )
+                                                        .unwrap_or(false)
+                                                    {
+                                                        purge_todo_action.dispatch(purge_id.clone());
+                                                    }
+                                                }
+                                            }
+                                            class="px-3 py-1 text-sm text-red-600 border border-red-200 rounded-lg hover:bg-red-50 transition-colors disabled:opacity-50"
+                                            disabled=is_busy
+                                        >
+                                            {move || lang.t("trash.purge")}
+                                        </button>
+                                    </div>
+                                </li>
+                            }
+                        }
+                    />
+                </ul>
+            </Show>
+        </div>
+    }
+}
+
 #[component]
 #[allow(clippy::too_many_lines)]
 #[allow(clippy::must_use_candidate)]
 pub fn HomePage() -> impl IntoView {
+    let lang = use_lang();
+    // Messages set from effects and handlers must not re-run them on a language change
+    let locale = move || lang.locale.get_untracked();
+    let toasts = use_toasts();
+
     // State for the todo list
     let (todos, set_todos) = signal(Vec::<Todo>::new());
 
+    // Assignee names configured on the server
+    let (assignees, set_assignees) = signal(Vec::<String>::new());
+
+    // Pagination state: token for the next page and the total number of todos
+    let (continuation, set_continuation) = signal(None::<String>);
+    let (total_count, set_total_count) = signal(None::<u64>);
+    let (stats, set_stats) = signal(None::<TodoStats>);
+
     // Loading and error states
     let (loading, set_loading) = signal(true);
     let (error_message, set_error_message) = signal(String::new());
+    // Edit rejected because someone else changed the todo meanwhile, kept until the user picks a version
+    let (update_conflict, set_update_conflict) = signal(None::<EditConflict>);
+
+    // Trash state; the version is bumped after each delete so an open trash reloads
+    let (show_trash, set_show_trash) = signal(false);
+    let (show_change_password, set_show_change_password) = signal(false);
+    let (show_reassign, set_show_reassign) = signal(false);
+    let (trash_version, set_trash_version) = signal(0_usize);
+
+    // Id of the todo whose status is being toggled from its card
+    let (status_toggle, set_status_toggle) = signal(None::<String>);
+
+    // Drag and drop reordering: the card being dragged and the todo whose new position is saving
+    let (dragged_id, set_dragged_id) = signal(None::<String>);
+    let (reordering, set_reordering) = signal(None::<String>);
+
+    // Recently deleted todos that can still be undone, keyed so each delete gets its own toast
+    let (undo_entries, set_undo_entries) = signal(Vec::<(usize, Todo)>::new());
+    let next_undo_key = StoredValue::new(0_usize);
+    #[cfg(feature = "hydrate")]
+    let undo_timers =
+        StoredValue::new(Vec::<(usize, leptos::leptos_dom::helpers::TimeoutHandle)>::new());
+
+    let auth = use_auth();
+
+    // Read-only users can browse todos but get no controls that modify them
+    let permissions = auth.permissions;
+    let can_write =
+        Signal::derive(move || permissions.with(|perms| perms.iter().any(|p| p == WRITE_TODOS)));
+
+    // Set when a server function rejects the session; sends the user back to the login page
+    let (session_expired, set_session_expired) = signal(false);
+    let navigate = use_navigate();
+    Effect::new(move |_| {
+        if session_expired.get() {
+            auth.logout.dispatch(());
+            navigate("/login", NavigateOptions::default());
+        }
+    });
+    let check_unauthorized = move |e: &ServerFnError| {
+        if is_unauthorized(e) {
+            set_session_expired.set(true);
+        }
+    };
+
+    // Changes made while the server is unreachable, replayed once it is back
+    let (server_status, set_server_status) = signal(ServerStatus::Checking);
+    let (pending_sync, set_pending_sync) = signal(Vec::<PendingMutation>::new());
+    let queue_offline = move |mutation: PendingMutation| {
+        set_pending_sync.update(|queue| enqueue_mutation(queue, mutation));
+        save_pending_mutations(&pending_sync.get_untracked());
+        set_error_message.set(String::new());
+    };
+
+    // Bulk selection state
+    let (selection_mode, set_selection_mode) = signal(false);
+    let (selected_ids, set_selected_ids) = signal(HashSet::<String>::new());
+    let (bulk_assignee, set_bulk_assignee) = signal(String::new());
+    let (bulk_message, set_bulk_message) = signal(String::new());
+    // Month group whose open todos are being completed with "Complete all"
+    let (completing_month, set_completing_month) = signal(None::<String>);
 
     // Modal state for creating/editing todos
     let (show_modal, set_show_modal) = signal(false);
     let (editing_todo, set_editing_todo) = signal(None::<Todo>);
 
-    // Calendar state
-    let (current_month, set_current_month) = signal(Local::now().month());
-    let (current_year, set_current_year) = signal(Local::now().year());
-    let today = Local::now().date_naive();
-
-    // Form fields for new/edit todo
-    let (new_title, set_new_title) = signal(String::new());
-    let (new_description, set_new_description) = signal(String::new());
-    let (new_due_date, set_new_due_date) = signal(String::new());
-    let (new_due_time, set_new_due_time) = signal(String::new());
-    let (new_assignee, set_new_assignee) = signal("Mikko".to_string());
-    let (new_status, set_new_status) = signal("Pending".to_string());
+    // Calendar state; dates are shown in the user's time zone while timestamps stay UTC
+    let tz = user_tz();
+    let today = user_today(tz);
+    let (current_month, set_current_month) = signal(today.month());
+    let (current_year, set_current_year) = signal(today.year());
+    let (selected_date, set_selected_date) = signal(None::<NaiveDate>);
+
+    // Form fields for new/edit todo, edited in the TodoDialog
+    let form = TodoFormFields::default();
+    let (new_title, set_new_title) = form.title.split();
+    let (new_description, set_new_description) = form.description.split();
+    let (new_due_date, set_new_due_date) = form.due_date.split();
+    let (new_due_time, set_new_due_time) = form.due_time.split();
+    let (no_due_date, set_no_due_date) = form.no_due_date.split();
+    let (new_assignee, set_new_assignee) = form.assignee.split();
+    let (new_status, set_new_status) = form.status.split();
+    let (new_priority, set_new_priority) = form.priority.split();
+    let (new_tags, set_new_tags) = form.tags.split();
+    let set_new_comment = form.comment.write_only();
+    let (new_attachments, set_new_attachments) = form.attachments.split();
+    let set_field_errors = form.errors.write_only();
 
     // Sorting and filtering state
     let (sort_by, set_sort_by) = signal(SortBy::CreatedDate);
     let (sort_ascending, set_sort_ascending) = signal(false);
     let (filter_status, set_filter_status) = signal("All".to_string());
     let (filter_assignee, set_filter_assignee) = signal("All".to_string());
+    let (filter_tag, set_filter_tag) = signal("All".to_string());
     let (search_term, set_search_term) = signal(String::new());
+    // Off by default, so deleting asks first until the user opts out
+    let (skip_delete_confirm, set_skip_delete_confirm) = signal(false);
+    // Whether the list opens filtered to the signed-in user's todos
+    let (assigned_to_me_default, set_assigned_to_me_default) = signal(false);
+    // Monday first by default, as in Finland; Sunday is the other choice
+    let (week_start, set_week_start) = signal(Weekday::Mon);
+
+    // Restore saved sort and filter preferences once mounted; unknown values keep the defaults
+    Effect::new(move |_| {
+        if let Some(saved) =
+            get_preference::<String>(SORT_BY_KEY).and_then(|value| SortBy::from_str(&value).ok())
+        {
+            set_sort_by.set(saved);
+        }
+        if let Some(saved) = get_preference::<bool>(SORT_ASCENDING_KEY) {
+            set_sort_ascending.set(saved);
+        }
+        if let Some(saved) = get_preference::<String>(FILTER_STATUS_KEY)
+            .filter(|value| value == "All" || TodoStatus::from_str(value).is_ok())
+        {
+            set_filter_status.set(saved);
+        }
+        if let Some(saved) = get_preference::<String>(FILTER_ASSIGNEE_KEY) {
+            set_filter_assignee.set(saved);
+        }
+        if let Some(saved) = get_preference::<bool>(SKIP_DELETE_CONFIRM_KEY) {
+            set_skip_delete_confirm.set(saved);
+        }
+        if let Some(saved) = get_preference::<bool>(ASSIGNED_TO_ME_DEFAULT_KEY) {
+            set_assigned_to_me_default.set(saved);
+        }
+        if let Some(saved) = get_preference::<Weekday>(WEEK_START_KEY)
+            .filter(|day| matches!(day, Weekday::Mon | Weekday::Sun))
+        {
+            set_week_start.set(saved);
+        }
+    });
+
+    // Save them whenever they change, but not the defaults seen before restoring
+    Effect::new(move |initialized: Option<()>| {
+        let sort_by = sort_by.get();
+        let sort_ascending = sort_ascending.get();
+        let filter_status = filter_status.get();
+        let filter_assignee = filter_assignee.get();
+        if initialized.is_some() {
+            set_preference(SORT_BY_KEY, &sort_by.as_str());
+            set_preference(SORT_ASCENDING_KEY, &sort_ascending);
+            set_preference(FILTER_STATUS_KEY, &filter_status);
+            set_preference(FILTER_ASSIGNEE_KEY, &filter_assignee);
+        }
+    });
+    let (hide_completed, set_hide_completed) = signal(false);
+    let (due_filter, set_due_filter) = signal(None::<DueFilter>);
+
+    // Whether the completed-todos archive is shown instead of the main list
+    let (show_archive, set_show_archive) = signal(false);
 
     // Helper to reset form
     let reset_form = move || {
@@ -248,48 +1166,69 @@ pub fn HomePage() -> impl IntoView {
         set_new_description.set(String::new());
         set_new_due_date.set(String::new());
         set_new_due_time.set(String::new());
-        set_new_assignee.set("Mikko".to_string());
+        set_no_due_date.set(false);
+        set_new_assignee.set(
+            assignees
+                .get_untracked()
+                .first()
+                .cloned()
+                .unwrap_or_default(),
+        );
         set_new_status.set("Pending".to_string());
+        set_new_priority.set(TodoPriority::default().as_str().to_string());
+        set_new_tags.set(String::new());
+        set_new_comment.set(String::new());
+        set_new_attachments.set(Vec::new());
+        set_field_errors.set(HashMap::new());
         set_editing_todo.set(None);
     };
 
     // Helper to populate form with existing todo data
     let populate_form = move |todo: &Todo| {
-        set_new_title.set(todo.title.clone());
+        set_new_title.set(unsanitize_string(&todo.title));
         set_new_description.set(todo.description.clone().unwrap_or_default());
         set_new_assignee.set(todo.assignee.as_str().to_string());
         set_new_status.set(todo.status.as_str().to_string());
-
-        if let Some(timestamp) = todo.due_date {
-            if let Ok(timestamp_i64) = i64::try_from(timestamp) {
-                if let Some(datetime) = chrono::DateTime::from_timestamp(timestamp_i64, 0) {
-                    let local_datetime = datetime.with_timezone(&chrono::Local);
-                    set_new_due_date.set(local_datetime.format("%Y-%m-%d").to_string());
-                    set_new_due_time.set(local_datetime.format("%H:%M").to_string());
-                }
-            }
+        set_new_priority.set(todo.priority.as_str().to_string());
+        set_new_tags.set(todo.tags.join(", "));
+        set_new_attachments.set(todo.attachments.clone());
+        set_field_errors.set(HashMap::new());
+
+        // An undated todo opens with "No due date" on, so saving it keeps it undated
+        if let Some(user_datetime) = todo
+            .due_date
+            .and_then(|timestamp| to_user_time(timestamp, tz))
+        {
+            set_new_due_date.set(user_datetime.format("%Y-%m-%d").to_string());
+            set_new_due_time.set(user_datetime.format("%H:%M").to_string());
         } else {
             set_new_due_date.set(String::new());
             set_new_due_time.set(String::new());
         }
+        set_no_due_date.set(todo.due_date.is_none());
     };
 
-    // Filter and sort todos
-    let filtered_and_sorted_todos = move || {
+    // Filter and sort todos once per change of the list, filters or sort order;
+    // the results count and the grouped list both read the memoized result
+    let filtered_and_sorted_todos = Memo::new(move |_| {
         let mut todos_list = todos.get();
-        let search = search_term.get().to_lowercase();
+        let search = SearchQuery::parse(&search_term.get());
         let status_filter = filter_status.get();
         let assignee_filter = filter_assignee.get();
+        let tag_filter = filter_tag.get();
+        let date_filter = selected_date.get();
+        let hide_completed = hide_completed.get();
+        let due_filter = due_filter.get();
+        let today = user_today(tz);
+        // Read the sort signals up front so they are tracked even when there is
+        // nothing to compare
+        let sort_criteria = sort_by.get();
+        let ascending = sort_ascending.get();
 
         // Apply filters
         todos_list.retain(|todo| {
-            // Search filter
-            let matches_search = search.is_empty()
-                || todo.title.to_lowercase().contains(&search)
-                || todo
-                    .description
-                    .as_ref()
-                    .is_some_and(|desc| desc.to_lowercase().contains(&search));
+            // Search box: free text plus key:value filters
+            let matches_search = search.matches(todo);
 
             // Status filter
             let matches_status = status_filter == "All" || todo.status.as_str() == status_filter;
@@ -298,28 +1237,33 @@ pub fn HomePage() -> impl IntoView {
             let matches_assignee =
                 assignee_filter == "All" || todo.assignee.as_str() == assignee_filter;
 
-            matches_search && matches_status && matches_assignee
+            // Tag filter
+            let matches_tag = tag_filter == "All" || todo.tags.contains(&tag_filter);
+
+            // Calendar day filter, compared in the user's time zone like the calendar itself
+            let matches_date = date_filter.is_none_or(|date| {
+                todo.due_date.and_then(|timestamp| user_date(timestamp, tz)) == Some(date)
+            });
+
+            // Completed todos can be hidden regardless of the status filter
+            let matches_completion = !hide_completed || todo.status != TodoStatus::Completed;
+
+            // Quick due date filter chip
+            let matches_due = due_filter.is_none_or(|filter| filter.matches(todo, today, tz));
+
+            matches_search
+                && matches_status
+                && matches_assignee
+                && matches_tag
+                && matches_date
+                && matches_completion
+                && matches_due
         });
 
         // Apply sorting
-        let sort_criteria = move || sort_by.get();
-        let ascending = move || sort_ascending.get();
-
         todos_list.sort_by(|a, b| {
-            let comparison = match sort_criteria() {
-                SortBy::Title => a.title.cmp(&b.title),
-                SortBy::DueDate => match (a.due_date, b.due_date) {
-                    (Some(a_date), Some(b_date)) => a_date.cmp(&b_date),
-                    (Some(_), None) => std::cmp::Ordering::Less,
-                    (None, Some(_)) => std::cmp::Ordering::Greater,
-                    (None, None) => std::cmp::Ordering::Equal,
-                },
-                SortBy::Status => a.status.as_str().cmp(b.status.as_str()),
-                SortBy::Assignee => a.assignee.as_str().cmp(b.assignee.as_str()),
-                SortBy::CreatedDate => a.id.cmp(&b.id),
-            };
-
-            if ascending() {
+            let comparison = sort_criteria.compare(a, b);
+            if ascending {
                 comparison
             } else {
                 comparison.reverse()
@@ -327,26 +1271,19 @@ pub fn HomePage() -> impl IntoView {
         });
 
         todos_list
-    };
+    });
 
     let grouped_todos = move || {
-        use std::collections::BTreeMap;
-
-        let todos_list = filtered_and_sorted_todos();
+        let todos_list = filtered_and_sorted_todos.get();
+        let manual_order = sort_by.get() == SortBy::Manual;
         let mut groups: BTreeMap<String, Vec<Todo>> = BTreeMap::new();
 
         for todo in todos_list {
             let group_key = if let Some(due_timestamp) = todo.due_date {
-                if let Ok(timestamp_i64) = i64::try_from(due_timestamp) {
-                    if let Some(datetime) = chrono::DateTime::from_timestamp(timestamp_i64, 0) {
-                        let local_datetime = datetime.with_timezone(&chrono::Local);
-                        local_datetime.format("%Y-%m").to_string()
-                    } else {
-                        "Invalid Date".to_string()
-                    }
-                } else {
-                    "No Due Date".to_string()
-                }
+                to_user_time(due_timestamp, tz).map_or_else(
+                    || "Invalid Date".to_string(),
+                    |user_datetime| user_datetime.format("%Y-%m").to_string(),
+                )
             } else {
                 "No Due Date".to_string()
             };
@@ -354,8 +1291,8 @@ pub fn HomePage() -> impl IntoView {
             groups.entry(group_key).or_default().push(todo);
         }
 
-        // Sort todos within each group by due date
-        for todos in groups.values_mut() {
+        // Sort todos within each group by due date, unless they were ordered by hand
+        for todos in groups.values_mut().filter(|_| !manual_order) {
             todos.sort_by(|a, b| {
                 match (a.due_date, b.due_date) {
                     (Some(a_date), Some(b_date)) => a_date.cmp(&b_date),
@@ -369,36 +1306,23 @@ pub fn HomePage() -> impl IntoView {
         groups
     };
 
-    let format_month_header = |month_key: &str| -> String {
+    // Group keys stay English, so they sort the same in every language
+    let format_month_header = move |month_key: &str| -> String {
+        let locale = lang.locale.get();
         if month_key == "No Due Date" {
-            "No Due Date".to_string()
+            locale.t("filter.no_due_date").to_string()
+        } else if month_key == "Invalid Date" {
+            locale.t("date.invalid").to_string()
         } else if let Ok(date) =
             chrono::NaiveDate::parse_from_str(&format!("{month_key}-01"), "%Y-%m-%d")
         {
-            date.format("%B %Y").to_string()
+            locale.format_date(date, DateFormat::Month)
         } else {
             month_key.to_string()
         }
     };
 
     // Calendar helper functions
-    let get_month_name = |month: u32| -> &'static str {
-        match month {
-            1 => "January",
-            2 => "February",
-            3 => "March",
-            4 => "April",
-            5 => "May",
-            6 => "June",
-            7 => "July",
-            8 => "August",
-            9 => "September",
-            10 => "October",
-            11 => "November",
-            12 => "December",
-            _ => "Invalid",
-        }
-    };
 
     let get_days_in_month = |year: i32, month: u32| -> u32 {
         match month {
@@ -415,14 +1339,36 @@ pub fn HomePage() -> impl IntoView {
         }
     };
 
-    let get_first_day_of_month = |year: i32, month: u32| -> u32 {
+    // Blank cells before the 1st, counted from the column of the week's first day
+    let get_first_day_of_month = move |year: i32, month: u32| -> u32 {
         if let Some(date) = NaiveDate::from_ymd_opt(year, month, 1) {
-            date.weekday().num_days_from_sunday()
+            date.weekday().days_since(week_start.get())
         } else {
             0
         }
     };
 
+    // Number of open todos due on each day of the given calendar month, from
+    // the stats so that todos on pages not loaded or filtered out are counted
+    let due_counts = move |year: i32, month: u32| -> BTreeMap<NaiveDate, usize> {
+        let mut counts = BTreeMap::new();
+        let due_dates = stats.with(|stats| {
+            stats
+                .as_ref()
+                .map(|stats| stats.open_due_dates.clone())
+                .unwrap_or_default()
+        });
+        for due_date in due_dates
+            .into_iter()
+            .filter_map(|timestamp| user_date(timestamp, tz))
+        {
+            if due_date.year() == year && due_date.month() == month {
+                *counts.entry(due_date).or_insert(0) += 1;
+            }
+        }
+        counts
+    };
+
     // Calendar navigation
     let prev_month = move |_| {
         if current_month.get() == 1 {
@@ -442,77 +1388,316 @@ pub fn HomePage() -> impl IntoView {
         }
     };
 
-    // Actions
-    let load_todos_action = Action::new(move |(): &()| async move { get_todos_server().await });
-    let create_todo_action = Action::new(move |todo: &Todo| {
-        let todo = todo.clone();
-        async move { create_todo_server(todo).await }
-    });
-    let update_todo_action = Action::new(move |todo: &Todo| {
-        let todo = todo.clone();
-        async move { update_todo_server(todo).await }
-    });
-    let delete_todo_action = Action::new(move |id: &String| {
-        let id = id.clone();
-        async move { delete_todo_server(id).await }
-    });
+    let go_to_today = move |_| {
+        set_current_month.set(today.month());
+        set_current_year.set(today.year());
+    };
 
-    let is_overdue = |due_timestamp: u64| -> bool {
-        if let Ok(timestamp_i64) = i64::try_from(due_timestamp) {
-            if let Some(datetime) = chrono::DateTime::from_timestamp(timestamp_i64, 0) {
-                let due_date = datetime.with_timezone(&chrono::Local);
-                let now = chrono::Local::now();
-                due_date < now
-            } else {
-                false
-            }
-        } else {
-            false
+    // Years offered by the calendar's year picker; a year reached with the
+    // arrows beyond the range is added so the picker can still show it
+    let calendar_years = move || {
+        let mut years: Vec<i32> =
+            (today.year() - CALENDAR_YEAR_RANGE..=today.year() + CALENDAR_YEAR_RANGE).collect();
+        let year = current_year.get();
+        if !years.contains(&year) {
+            years.push(year);
+            years.sort_unstable();
         }
+        years
     };
 
-    let is_past_date = move || {
-        let date_str = new_due_date.get();
-        let time_str = new_due_time.get();
+    // Free text of the search box as sent to the server, trailing it by
+    // SEARCH_DEBOUNCE so typing does not query on every keystroke
+    let (server_search, set_server_search) = signal(String::new());
+    #[cfg(feature = "hydrate")]
+    let search_timer = StoredValue::new(None::<leptos::leptos_dom::helpers::TimeoutHandle>);
+    Effect::new(move |_| {
+        let text = SearchQuery::parse(&search_term.get()).text;
+        #[cfg(feature = "hydrate")]
+        {
+            use leptos::leptos_dom::helpers::set_timeout_with_handle;
 
-        if date_str.is_empty() {
-            return false;
+            if let Some(handle) = search_timer.get_value() {
+                handle.clear();
+            }
+            match set_timeout_with_handle(move || set_server_search.set(text), SEARCH_DEBOUNCE) {
+                Ok(handle) => search_timer.set_value(Some(handle)),
+                Err(e) => leptos::logging::warn!("Failed to start search timer: {:?}", e),
+            }
         }
+        #[cfg(not(feature = "hydrate"))]
+        set_server_search.set(text);
+    });
 
-        let time_str = if time_str.is_empty() {
-            "00:00"
-        } else {
-            &time_str
-        };
-        let datetime_str = format!("{date_str} {time_str}");
+    // Filters and order applied by the server, so only matching todos are transferred.
+    // The calendar day is left to the local filters, since the calendar counts todos
+    // due on every day of the month; so are tags, priority and the due date chips.
+    let server_filter = Memo::new(move |_| TodoFilter {
+        status: TodoStatus::from_str(&filter_status.get()).ok(),
+        assignee: Some(filter_assignee.get()).filter(|name| name != "All"),
+        search: Some(server_search.get()).filter(|text| !text.is_empty()),
+        due_from: None,
+        due_to: None,
+    });
+    let server_sort = Memo::new(move |_| (sort_by.get(), sort_ascending.get()));
 
-        if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(&datetime_str, "%Y-%m-%d %H:%M") {
-            if let Some(local_dt) = chrono::Local.from_local_datetime(&dt).single() {
-                local_dt < chrono::Local::now()
-            } else {
-                false
+    // Actions
+    // Loads the first page when given no continuation token, otherwise the next page
+    // Setting `bypass_cache` makes the next load skip the server's todo cache
+    let bypass_cache = StoredValue::new(false);
+    let load_todos_action = Action::new(move |continuation: &Option<String>| {
+        let continuation = continuation.clone();
+        let filter = server_filter.get_untracked();
+        let sort = server_sort.get_untracked();
+        let refresh = bypass_cache
+            .try_update_value(std::mem::take)
+            .unwrap_or(false);
+        async move {
+            let is_first_page = continuation.is_none();
+            (
+                is_first_page,
+                get_todos_server(
+                    None,
+                    None,
+                    continuation,
+                    Some(filter),
+                    Some(sort),
+                    Some(refresh),
+                )
+                .await,
+            )
+        }
+    });
+    let load_assignees_action =
+        Action::new(move |(): &()| async move { get_assignees_server().await });
+    // Replays the queue in order, stopping at the first mutation that still cannot reach the server
+    let sync_action = Action::new(move |queue: &Vec<PendingMutation>| {
+        let queue = queue.clone();
+        async move {
+            let mut outcomes = Vec::new();
+            for mutation in queue {
+                match replay_mutation(mutation.clone()).await {
+                    Ok(outcome) => outcomes.push((mutation, outcome)),
+                    Err(_) => break,
+                }
             }
-        } else {
-            false
+            outcomes
         }
+    });
+    // Create, update and delete are optimistic: the action closure runs synchronously on
+    // dispatch, applies the change to `todos` right away and hands back what is needed to
+    // roll that todo back, by id, if the server rejects it.
+    // The flag confirms a due date in the past, which the server otherwise may reject
+    let create_todo_action = Action::new(move |(todo, allow_past): &(Todo, bool)| {
+        let todo = todo.clone();
+        let allow_past = *allow_past;
+        set_todos.update(|todos| todos.push(todo.clone()));
+        set_total_count.update(|total| {
+            if let Some(total) = total {
+                *total += 1;
+            }
+        });
+        async move {
+            (
+                todo.clone(),
+                create_todo_server(todo, Some(allow_past)).await,
+            )
+        }
+    });
+    let update_todo_action = Action::new(move |todo: &Todo| {
+        let todo = todo.clone();
+        let mut previous = None;
+        set_todos.update(|todos| {
+            if let Some(existing) = todos.iter_mut().find(|t| t.id == todo.id) {
+                previous = Some(std::mem::replace(existing, todo.clone()));
+            }
+        });
+        // The list keeps the stored todo; the server gets the title as typed
+        async move {
+            (
+                previous,
+                todo.clone(),
+                update_todo_server(unsanitized_todo(todo)).await,
+            )
+        }
+    });
+    let delete_todo_action = Action::new(move |id: &String| {
+        let id = id.clone();
+        // Keep the todo and its position to put it back on failure or undo it later
+        let mut deleted = None;
+        set_todos.update(|todos| {
+            if let Some(index) = todos.iter().position(|todo| todo.id == id) {
+                deleted = Some((index, todos.remove(index)));
+            }
+        });
+        if deleted.is_some() {
+            set_total_count.update(|total| {
+                if let Some(total) = total {
+                    *total = total.saturating_sub(1);
+                }
+            });
+        }
+        async move { (deleted, delete_todo_server(id).await) }
+    });
+    let undo_delete_action = Action::new(move |todo: &Todo| {
+        let id = todo.id.clone();
+        async move { restore_todo_server(id).await }
+    });
+    let export_csv_action =
+        Action::new(move |(): &()| async move { export_todos_csv_server().await });
+    let export_ical_action =
+        Action::new(move |(): &()| async move { export_todos_ical_server().await });
+    let add_comment_action = Action::new(move |(todo_id, text): &(String, String)| {
+        let todo_id = todo_id.clone();
+        let text = text.clone();
+        async move { add_comment_server(todo_id, text).await }
+    });
+    let upload_attachment_action = Action::new(move |file: &SelectedFile| {
+        let file = file.clone();
+        async move { upload_attachment_server(file.name, file.content_type, file.data_base64).await }
+    });
+    let stats_action = Action::new(move |(): &()| async move { get_todo_stats_server().await });
+    let import_action = Action::new(move |json: &String| {
+        let json = json.clone();
+        async move { import_todos_server(json).await }
+    });
+    let bulk_update_action = Action::new(move |todos: &Vec<Todo>| {
+        let todos = todos.iter().cloned().map(unsanitized_todo).collect();
+        async move { bulk_update_todos_server(todos).await }
+    });
+    // Collects every completed todo from the server, not just the loaded pages,
+    // and moves them to the trash
+    let clear_completed_action = Action::new(move |(): &()| async move {
+        let ids = completed_todo_ids().await?;
+        bulk_delete_todos_server(ids).await
+    });
+    let bulk_delete_action = Action::new(move |ids: &Vec<String>| {
+        let ids = ids.clone();
+        async move { bulk_delete_todos_server(ids).await }
+    });
+
+    let is_overdue = |due_timestamp: u64| -> bool {
+        i64::try_from(due_timestamp).is_ok_and(|due| due < chrono::Utc::now().timestamp())
     };
 
-    // Load todos on component mount
+    // Load the first page of todos on component mount and whenever the server side filters
+    // or the sort order change
+    Effect::new(move |_| {
+        server_filter.track();
+        server_sort.track();
+        load_todos_action.dispatch(None);
+    });
+
+    // Fetch the next page when the user scrolls near the bottom
+    let scroll_handle = window_event_listener(ev::scroll, move |_| {
+        if continuation.get_untracked().is_none() || load_todos_action.pending().get_untracked() {
+            return;
+        }
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let viewport_bottom = window
+            .inner_height()
+            .ok()
+            .and_then(|height| height.as_f64())
+            .unwrap_or(0.0)
+            + window.scroll_y().unwrap_or(0.0);
+        let page_height = window
+            .document()
+            .and_then(|document| document.document_element())
+            .map_or(0, |element| element.scroll_height());
+        if viewport_bottom >= f64::from(page_height) - 200.0 {
+            load_todos_action.dispatch(continuation.get_untracked());
+        }
+    });
+    on_cleanup(move || scroll_handle.remove());
+
+    // Configured assignee standing for the signed-in user; None when the username matches nobody
+    let user_info = auth.user_info;
+    let my_assignee = Memo::new(move |_| {
+        user_info.with(|user| {
+            let user = user.as_ref()?;
+            assignees.with(|names| {
+                names
+                    .iter()
+                    .find(|name| {
+                        TodoAssignee::unchecked(name.as_str())
+                            .matches_user(&user.username, &user.display_name)
+                    })
+                    .cloned()
+            })
+        })
+    });
+
+    // Open on the user's own todos once their assignee is known, if they chose so;
+    // the stored preference is read here so that ticking the box later does not refilter
+    Effect::new(move |applied: Option<bool>| {
+        if applied == Some(true) {
+            return true;
+        }
+        let Some(name) = my_assignee.get() else {
+            return false;
+        };
+        if get_preference::<bool>(ASSIGNED_TO_ME_DEFAULT_KEY).unwrap_or(false) {
+            set_filter_assignee.set(name);
+        }
+        true
+    });
+
+    // Load configured assignees on component mount
     Effect::new(move |_| {
-        load_todos_action.dispatch(());
+        load_assignees_action.dispatch(());
+    });
+
+    Effect::new(move |_| {
+        if let Some(result) = load_assignees_action.value().get() {
+            match result {
+                Ok(names) => {
+                    if new_assignee.get_untracked().is_empty() {
+                        set_new_assignee.set(names.first().cloned().unwrap_or_default());
+                    }
+                    set_assignees.set(names);
+                }
+                Err(e) => {
+                    set_error_message
+                        .set(locale().t_with("error.load_assignees", &[("error", &e)]));
+                }
+            }
+        }
     });
 
     // Watch for load todos results
     Effect::new(move |_| {
-        if let Some(result) = load_todos_action.value().get() {
+        if let Some((is_first_page, result)) = load_todos_action.value().get() {
             match result {
-                Ok(todos_list) => {
-                    set_todos.set(todos_list);
+                Ok(page) => {
+                    if is_first_page {
+                        let mut todos = page.todos;
+                        apply_pending_mutations(&mut todos, &pending_sync.get_untracked());
+                        set_total_count.set(page.total);
+                        set_todos.set(todos);
+                    } else {
+                        set_todos.update(|todos| {
+                            // Offsets shift when todos are created meanwhile, so skip duplicates
+                            let new_todos: Vec<Todo> = page
+                                .todos
+                                .into_iter()
+                                .filter(|todo| !todos.iter().any(|t| t.id == todo.id))
+                                .collect();
+                            todos.extend(new_todos);
+                        });
+                    }
+                    set_continuation.set(page.continuation);
                     set_loading.set(false);
                     set_error_message.set(String::new());
                 }
+                // Offline the loaded todos stay and the local filters narrow them instead
+                Err(e) if is_offline_error(&e) && !todos.with_untracked(Vec::is_empty) => {
+                    set_loading.set(false);
+                }
                 Err(e) => {
-                    set_error_message.set(format!("Failed to load todos: {e}"));
+                    check_unauthorized(&e);
+                    set_error_message.set(locale().t_with("error.load_todos", &[("error", &e)]));
                     set_loading.set(false);
                 }
             }
@@ -521,18 +1706,38 @@ pub fn HomePage() -> impl IntoView {
 
     // Watch for create todo results
     Effect::new(move |_| {
-        if let Some(result) = create_todo_action.value().get() {
+        if let Some((attempted, result)) = create_todo_action.value().get() {
             match result {
                 Ok(created_todo) => {
+                    // Swap in the server's copy, which carries the sanitized fields
                     set_todos.update(|todos| {
-                        todos.push(created_todo);
+                        if let Some(todo) = todos.iter_mut().find(|t| t.id == created_todo.id) {
+                            *todo = created_todo;
+                        }
                     });
-                    reset_form();
-                    set_show_modal.set(false);
                     set_error_message.set(String::new());
+                    toasts.show(locale().t("toast.created"));
                 }
+                Err(e) if is_offline_error(&e) => queue_offline(PendingMutation::Create(attempted)),
                 Err(e) => {
-                    set_error_message.set(format!("Failed to create todo: {e}"));
+                    // Roll back and reopen the form with what was entered
+                    set_todos.update(|todos| todos.retain(|t| t.id != attempted.id));
+                    set_total_count.update(|total| {
+                        if let Some(total) = total {
+                            *total = total.saturating_sub(1);
+                        }
+                    });
+                    populate_form(&attempted);
+                    set_editing_todo.set(None);
+                    set_show_modal.set(true);
+                    check_unauthorized(&e);
+                    set_error_message.set(if is_rate_limited(&e) {
+                        locale().t("error.rate_limited").to_string()
+                    } else if is_too_large(&e) {
+                        locale().t_with("error.too_large", &[("error", &e)])
+                    } else {
+                        locale().t_with("error.create_todo", &[("error", &e)])
+                    });
                 }
             }
         }
@@ -540,7 +1745,12 @@ pub fn HomePage() -> impl IntoView {
 
     // Watch for update todo results
     Effect::new(move |_| {
-        if let Some(result) = update_todo_action.value().get() {
+        if let Some((previous, attempted, result)) = update_todo_action.value().get() {
+            // Quick status toggles and reordering happen outside the modal, so leave the form alone for them
+            let toggled = status_toggle.get_untracked();
+            set_status_toggle.set(None);
+            let reordered = reordering.get_untracked();
+            set_reordering.set(None);
             match result {
                 Ok(updated_todo) => {
                     set_todos.update(|todos| {
@@ -548,44 +1758,512 @@ pub fn HomePage() -> impl IntoView {
                             *todo = updated_todo;
                         }
                     });
-                    reset_form();
-                    set_show_modal.set(false);
                     set_error_message.set(String::new());
+                    // Dragging a card is its own feedback
+                    if reordered.is_none() {
+                        toasts.show(locale().t("toast.updated"));
+                    }
+                }
+                Err(e) if is_offline_error(&e) && previous.is_some() => {
+                    if let Some(base) = previous {
+                        queue_offline(PendingMutation::Update {
+                            base,
+                            todo: attempted,
+                        });
+                    }
+                }
+                Err(e) if is_conflict(&e) => {
+                    let theirs = conflict_current_todo(&e);
+                    let changed = theirs.as_ref().map_or_else(Vec::new, |theirs| {
+                        changed_fields(previous.as_ref().unwrap_or(&attempted), theirs)
+                    });
+                    if let Some(previous) = previous {
+                        set_todos.update(|todos| {
+                            if let Some(todo) = todos.iter_mut().find(|t| t.id == previous.id) {
+                                *todo = previous;
+                            }
+                        });
+                    }
+                    set_update_conflict.set(Some(EditConflict {
+                        mine: attempted,
+                        theirs,
+                        changed,
+                    }));
                 }
                 Err(e) => {
-                    set_error_message.set(format!("Failed to update todo: {e}"));
+                    // Roll back the optimistic change of this todo only
+                    if let Some(previous) = previous {
+                        set_todos.update(|todos| {
+                            if let Some(todo) = todos.iter_mut().find(|t| t.id == previous.id) {
+                                *todo = previous;
+                            }
+                        });
+                    }
+                    if toggled.is_none() && reordered.is_none() {
+                        populate_form(&attempted);
+                        set_editing_todo.set(Some(attempted));
+                        set_show_modal.set(true);
+                    }
+                    check_unauthorized(&e);
+                    set_error_message.set(locale().t_with("error.update_todo", &[("error", &e)]));
                 }
             }
         }
     });
 
+    // Drops an undo toast together with its expiry timer
+    let dismiss_undo = move |key: usize| {
+        set_undo_entries.update(|entries| entries.retain(|(entry_key, _)| *entry_key != key));
+        #[cfg(feature = "hydrate")]
+        undo_timers.update_value(|timers| {
+            timers.retain(|(timer_key, handle)| {
+                if *timer_key == key {
+                    handle.clear();
+                }
+                *timer_key != key
+            });
+        });
+    };
+
+    // Shows an undo toast for a deleted todo that expires after UNDO_TIMEOUT
+    let offer_undo = move |todo: Todo| {
+        let key = next_undo_key.get_value();
+        next_undo_key.set_value(key + 1);
+        set_undo_entries.update(|entries| entries.push((key, todo)));
+
+        #[cfg(feature = "hydrate")]
+        {
+            use leptos::leptos_dom::helpers::set_timeout_with_handle;
+
+            match set_timeout_with_handle(move || dismiss_undo(key), UNDO_TIMEOUT) {
+                Ok(handle) => undo_timers.update_value(|timers| timers.push((key, handle))),
+                Err(e) => leptos::logging::warn!("Failed to start undo timer: {:?}", e),
+            }
+        }
+    };
+
+    #[cfg(feature = "hydrate")]
+    on_cleanup(move || {
+        for (_, handle) in undo_timers.get_value() {
+            handle.clear();
+        }
+    });
+
+    let undo_delete = move |key: usize, todo: Todo| {
+        dismiss_undo(key);
+        undo_delete_action.dispatch(todo);
+    };
+
     // Watch for delete todo results
     Effect::new(move |_| {
-        if let Some(result) = delete_todo_action.value().get() {
+        if let Some((deleted, result)) = delete_todo_action.value().get() {
             match result {
                 Ok(()) => {
-                    // Reload todos after successful delete
-                    load_todos_action.dispatch(());
+                    if let Some((_, todo)) = deleted {
+                        offer_undo(todo);
+                    }
+                    set_trash_version.update(|version| *version += 1);
                     set_error_message.set(String::new());
+                    toasts.show(locale().t("toast.deleted"));
+                }
+                Err(e) if is_offline_error(&e) && deleted.is_some() => {
+                    if let Some((_, todo)) = deleted {
+                        queue_offline(PendingMutation::Delete(todo));
+                    }
+                }
+                Err(e) => {
+                    // Put the todo back where it was
+                    if let Some((index, todo)) = deleted {
+                        set_todos.update(|todos| {
+                            if !todos.iter().any(|t| t.id == todo.id) {
+                                todos.insert(index.min(todos.len()), todo);
+                            }
+                        });
+                        set_total_count.update(|total| {
+                            if let Some(total) = total {
+                                *total += 1;
+                            }
+                        });
+                    }
+                    check_unauthorized(&e);
+                    set_error_message.set(locale().t_with("error.delete_todo", &[("error", &e)]));
+                }
+            }
+        }
+    });
+
+    // Restore the offline queue from a previous visit
+    Effect::new(move |_| {
+        set_pending_sync.set(load_pending_mutations());
+    });
+
+    // Replay the queue whenever the heartbeat reports the server online again
+    Effect::new(move |_| {
+        if server_status.get() == ServerStatus::Online
+            && !pending_sync.get_untracked().is_empty()
+            && !sync_action.pending().get_untracked()
+        {
+            sync_action.dispatch(pending_sync.get_untracked());
+        }
+    });
+
+    // Drop replayed mutations and reload, so conflicts resolve to the server's copy
+    Effect::new(move |_| {
+        let Some(outcomes) = sync_action.value().get() else {
+            return;
+        };
+        if outcomes.is_empty() {
+            return;
+        }
+
+        let mut problems = Vec::new();
+        set_pending_sync.update(|queue| {
+            for (mutation, outcome) in &outcomes {
+                queue.retain(|queued| queued != mutation);
+                match outcome {
+                    SyncOutcome::Synced(_) => {}
+                    SyncOutcome::Conflict { todo, server } => problems.push(match server {
+                        Some(_) => {
+                            locale().t_with("sync.changed_elsewhere", &[("title", &todo.title)])
+                        }
+                        None => {
+                            locale().t_with("sync.deleted_elsewhere", &[("title", &todo.title)])
+                        }
+                    }),
+                    SyncOutcome::Failed { todo, error } => {
+                        problems.push(locale().t_with(
+                            "sync.not_saved",
+                            &[("title", &todo.title), ("error", error)],
+                        ));
+                    }
+                }
+            }
+        });
+        save_pending_mutations(&pending_sync.get_untracked());
+        load_todos_action.dispatch(None);
+        set_trash_version.update(|version| *version += 1);
+        if problems.is_empty() {
+            set_error_message.set(String::new());
+        } else {
+            set_error_message
+                .set(locale().t_with("sync.discarded", &[("problems", &problems.join("; "))]));
+        }
+    });
+
+    // Watch for undo results; the todo comes back out of the trash
+    Effect::new(move |_| {
+        if let Some(result) = undo_delete_action.value().get() {
+            match result {
+                Ok(()) => {
+                    load_todos_action.dispatch(None);
+                    set_trash_version.update(|version| *version += 1);
+                    set_error_message.set(String::new());
+                }
+                Err(e) => {
+                    check_unauthorized(&e);
+                    set_error_message.set(locale().t_with("error.undo_delete", &[("error", &e)]));
+                }
+            }
+        }
+    });
+
+    // Save the exported CSV as a file
+    Effect::new(move |_| {
+        if let Some(result) = export_csv_action.value().get() {
+            let saved = result
+                .map_err(|e| {
+                    check_unauthorized(&e);
+                    locale().t_with("error.export_todos", &[("error", &e)])
+                })
+                .and_then(|csv| download_file("todos.csv", "text/csv;charset=utf-8", &csv));
+            if let Err(e) = saved {
+                set_error_message.set(e);
+            }
+        }
+    });
+
+    // Save the exported calendar as a file
+    Effect::new(move |_| {
+        if let Some(result) = export_ical_action.value().get() {
+            let saved = result
+                .map_err(|e| {
+                    check_unauthorized(&e);
+                    locale().t_with("error.export_calendar", &[("error", &e)])
+                })
+                .and_then(|ical| download_file("todos.ics", "text/calendar;charset=utf-8", &ical));
+            if let Err(e) = saved {
+                set_error_message.set(e);
+            }
+        }
+    });
+
+    // Show a new comment in the open modal and in the list
+    Effect::new(move |_| {
+        if let Some(result) = add_comment_action.value().get() {
+            match result {
+                Ok(updated_todo) => {
+                    set_new_comment.set(String::new());
+                    set_todos.update(|todos| {
+                        if let Some(todo) = todos.iter_mut().find(|t| t.id == updated_todo.id) {
+                            todo.comments.clone_from(&updated_todo.comments);
+                            // Commenting counts as an edit, so the next save must build on it
+                            todo.updated_at = updated_todo.updated_at;
+                            todo.updated_by.clone_from(&updated_todo.updated_by);
+                        }
+                    });
+                    set_editing_todo.update(|editing| {
+                        if let Some(editing) = editing
+                            .as_mut()
+                            .filter(|editing| editing.id == updated_todo.id)
+                        {
+                            editing.updated_at = updated_todo.updated_at;
+                            editing.updated_by = updated_todo.updated_by;
+                            editing.comments = updated_todo.comments;
+                        }
+                    });
+                    set_error_message.set(String::new());
+                }
+                Err(e) => {
+                    check_unauthorized(&e);
+                    set_error_message.set(locale().t_with("error.add_comment", &[("error", &e)]));
+                }
+            }
+        }
+    });
+
+    // Add an uploaded file to the form; it is saved with the todo
+    Effect::new(move |_| {
+        if let Some(result) = upload_attachment_action.value().get() {
+            match result {
+                Ok(attachment) => {
+                    set_new_attachments.update(|attachments| attachments.push(attachment));
+                    set_error_message.set(String::new());
+                }
+                Err(e) => {
+                    check_unauthorized(&e);
+                    set_error_message
+                        .set(locale().t_with("error.upload_attachment", &[("error", &e)]));
+                }
+            }
+        }
+    });
+
+    // Refresh the stats whenever the list changes
+    Effect::new(move |_| {
+        todos.track();
+        stats_action.dispatch(());
+    });
+
+    Effect::new(move |_| {
+        if let Some(result) = stats_action.value().get() {
+            match result {
+                Ok(new_stats) => set_stats.set(Some(new_stats)),
+                Err(e) => {
+                    check_unauthorized(&e);
+                    logging::console_error(&format!("Failed to load stats: {e}"));
+                }
+            }
+        }
+    });
+
+    // Report the outcome of an import and show the new todos
+    Effect::new(move |_| {
+        if let Some(result) = import_action.value().get() {
+            match result {
+                Ok(summary) => {
+                    set_bulk_message.set(locale().t_with(
+                        "import.summary",
+                        &[
+                            ("imported", &summary.imported),
+                            ("skipped", &summary.skipped),
+                            ("failed", &summary.failed),
+                        ],
+                    ));
+                    let first_error = summary.errors.into_iter().find_map(|item| {
+                        item.error.map(|error| {
+                            locale().t_with(
+                                "import.item_failed",
+                                &[("id", &item.id), ("error", &error)],
+                            )
+                        })
+                    });
+                    set_error_message.set(first_error.unwrap_or_default());
+                    load_todos_action.dispatch(None);
+                }
+                Err(e) => {
+                    check_unauthorized(&e);
+                    set_error_message.set(locale().t_with("error.import_todos", &[("error", &e)]));
+                }
+            }
+        }
+    });
+
+    // Watch for sign-out-everywhere failures; on success the login page takes over
+    Effect::new(move |_| {
+        if let Some(Err(e)) = auth.logout_all.value().get() {
+            set_error_message.set(e);
+        }
+    });
+
+    // Summarizes a finished bulk operation and refreshes the list
+    // `summary_key` names a message with `{succeeded}` and `{total}` placeholders
+    let report_bulk_results = move |summary_key: &'static str, results: &[BulkItemResult]| {
+        let succeeded = results.iter().filter(|r| r.is_success()).count();
+        set_bulk_message.set(locale().t_with(
+            summary_key,
+            &[("succeeded", &succeeded), ("total", &results.len())],
+        ));
+        if let Some(error) = results.iter().find_map(|r| r.error.clone()) {
+            set_error_message.set(error);
+        } else {
+            set_error_message.set(String::new());
+        }
+        set_selected_ids.set(HashSet::new());
+        load_todos_action.dispatch(None);
+    };
+
+    // Watch for bulk update results
+    Effect::new(move |_| {
+        if let Some(result) = bulk_update_action.value().get() {
+            set_completing_month.set(None);
+            match result {
+                Ok(results) => report_bulk_results("bulk.updated", &results),
+                Err(e) => {
+                    set_error_message.set(locale().t_with("error.update_todos", &[("error", &e)]))
+                }
+            }
+        }
+    });
+
+    // Watch for bulk delete results
+    Effect::new(move |_| {
+        if let Some(result) = bulk_delete_action.value().get() {
+            match result {
+                Ok(results) => {
+                    report_bulk_results("bulk.deleted", &results);
+                    set_trash_version.update(|version| *version += 1);
                 }
                 Err(e) => {
-                    set_error_message.set(format!("Failed to delete todo: {e}"));
+                    set_error_message.set(locale().t_with("error.delete_todos", &[("error", &e)]))
                 }
             }
         }
     });
 
+    // Watch for clear completed results
+    Effect::new(move |_| {
+        if let Some(result) = clear_completed_action.value().get() {
+            match result {
+                Ok(results) => {
+                    report_bulk_results("bulk.cleared_completed", &results);
+                    set_trash_version.update(|version| *version += 1);
+                }
+                Err(e) => {
+                    check_unauthorized(&e);
+                    set_error_message.set(locale().t_with("error.delete_todos", &[("error", &e)]));
+                }
+            }
+        }
+    });
+
+    let clear_completed = move |count: usize| {
+        let message = locale().t_with("bulk.confirm_clear_completed", &[("count", &count)]);
+        let confirmed = web_sys::window()
+            .is_some_and(|window| window.confirm_with_message(&message).unwrap_or(false));
+        if confirmed {
+            clear_completed_action.dispatch(());
+        }
+    };
+
+    let selected_todos = move || {
+        let selected = selected_ids.get_untracked();
+        todos
+            .get_untracked()
+            .into_iter()
+            .filter(|todo| selected.contains(&todo.id))
+            .collect::<Vec<_>>()
+    };
+
+    let bulk_complete = move |_| {
+        let updated = selected_todos()
+            .into_iter()
+            .map(|todo| Todo {
+                status: TodoStatus::Completed,
+                ..todo
+            })
+            .collect();
+        bulk_update_action.dispatch(updated);
+    };
+
+    // Completes the open todos of a month group after confirmation
+    let complete_month = move |month_key: String, month_label: &str, open_todos: Vec<Todo>| {
+        let message = locale().t_with(
+            "bulk.confirm_complete_month",
+            &[("count", &open_todos.len()), ("month", &month_label)],
+        );
+        let confirmed = web_sys::window()
+            .is_some_and(|window| window.confirm_with_message(&message).unwrap_or(false));
+        if !confirmed {
+            return;
+        }
+        set_completing_month.set(Some(month_key));
+        let updated = open_todos
+            .into_iter()
+            .map(|todo| Todo {
+                status: TodoStatus::Completed,
+                ..todo
+            })
+            .collect();
+        bulk_update_action.dispatch(updated);
+    };
+
+    let bulk_delete = move |_| {
+        let ids: Vec<String> = selected_ids.get_untracked().into_iter().collect();
+        let message = locale().t_with("bulk.confirm_delete", &[("count", &ids.len())]);
+        if let Some(window) = web_sys::window() {
+            if window.confirm_with_message(&message).unwrap_or(false) {
+                bulk_delete_action.dispatch(ids);
+            }
+        }
+    };
+
+    let bulk_reassign = move |_| {
+        let assignee = match TodoAssignee::from_str(&bulk_assignee.get_untracked()) {
+            Ok(assignee) => assignee,
+            Err(e) => {
+                set_error_message.set(e);
+                return;
+            }
+        };
+        let updated = selected_todos()
+            .into_iter()
+            .map(|todo| Todo {
+                assignee: assignee.clone(),
+                ..todo
+            })
+            .collect();
+        bulk_update_action.dispatch(updated);
+    };
+
+    let is_bulk_pending =
+        move || bulk_update_action.pending().get() || bulk_delete_action.pending().get();
+
     // Handle form submission
     let handle_submit = move |ev: ev::SubmitEvent| {
         ev.prevent_default();
 
         let title = new_title.get_untracked();
         if title.trim().is_empty() {
-            set_error_message.set("Title is required".to_string());
+            set_field_errors.update(|errors| {
+                errors.insert("title", locale().t("form.title_required").to_string());
+            });
             return;
         }
 
-        let due_timestamp = if new_due_date.get_untracked().is_empty() {
+        let mut allow_past = false;
+        let due_timestamp = if no_due_date.get_untracked()
+            || new_due_date.get_untracked().is_empty()
+        {
             None
         } else {
             let date_str = new_due_date.get_untracked();
@@ -597,31 +2275,32 @@ pub fn HomePage() -> impl IntoView {
             let datetime_str = format!("{date_str} {time_str}");
 
             if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(&datetime_str, "%Y-%m-%d %H:%M") {
-                if let Some(local_dt) = chrono::Local.from_local_datetime(&dt).single() {
-                    let timestamp_i64 = local_dt.timestamp();
-                    let Ok(timestamp) = u64::try_from(timestamp_i64) else {
-                        set_error_message.set("Due date cannot be before 1970".to_string());
+                if let Some(local_dt) =
+                    from_user_time(dt, tz).and_then(|timestamp| to_user_time(timestamp, tz))
+                {
+                    let Ok(timestamp) = u64::try_from(local_dt.timestamp()) else {
+                        set_error_message.set(locale().t("form.due_before_1970").to_string());
                         return;
                     };
 
                     // Check if the due date is in the past and show warning
-                    let now = chrono::Local::now();
+                    let now = chrono::Utc::now();
                     if local_dt < now {
                         // Only show warning for new todos, not when editing existing ones
                         if editing_todo.get_untracked().is_none() {
                             let time_diff = now.signed_duration_since(local_dt);
                             let warning_msg = if time_diff.num_days() > 0 {
-                                format!(
-                                    "Warning: You're creating a todo with a due date {} day(s) in the past. Are you sure you want to continue?",
-                                    time_diff.num_days()
+                                locale().t_with(
+                                    "form.confirm_past_days",
+                                    &[("count", &time_diff.num_days())],
                                 )
                             } else if time_diff.num_hours() > 0 {
-                                format!(
-                                    "Warning: You're creating a todo with a due date {} hour(s) in the past. Are you sure you want to continue?",
-                                    time_diff.num_hours()
+                                locale().t_with(
+                                    "form.confirm_past_hours",
+                                    &[("count", &time_diff.num_hours())],
                                 )
                             } else {
-                                "Warning: You're creating a todo with a due date in the past. Are you sure you want to continue?".to_string()
+                                locale().t("form.confirm_past").to_string()
                             };
 
                             // Show confirmation dialog
@@ -630,31 +2309,47 @@ pub fn HomePage() -> impl IntoView {
                                     return; // User cancelled, don't create the todo
                                 }
                             }
+                            allow_past = true;
                         }
                     }
 
                     Some(timestamp)
                 } else {
-                    set_error_message.set("Invalid local datetime".to_string());
+                    set_error_message.set(locale().t("form.invalid_local_datetime").to_string());
                     return;
                 }
             } else {
-                set_error_message.set("Invalid date/time format".to_string());
+                set_error_message.set(locale().t("form.invalid_datetime").to_string());
+                return;
+            }
+        };
+
+        let assignee = match TodoAssignee::from_str(&new_assignee.get_untracked()) {
+            Ok(assignee) => assignee,
+            Err(e) => {
+                set_error_message.set(e);
                 return;
             }
         };
 
+        // Tags are entered comma-separated; blanks and duplicates are dropped
+        let mut tags: Vec<String> = Vec::new();
+        for tag in new_tags.get_untracked().split(',') {
+            let tag = tag.trim();
+            if tag.is_empty() || tags.iter().any(|t| t == tag) {
+                continue;
+            }
+            if validate_no_html(tag).is_err() {
+                set_error_message.set(locale().t_with("form.tag_html", &[("tag", &tag)]));
+                return;
+            }
+            tags.push(tag.to_string());
+        }
+
         let todo = Todo {
-            id: editing_todo.get_untracked().map_or_else(
-                || match Uuid::new_v4().to_string() {
-                    id if !id.is_empty() => id,
-                    _ => {
-                        logging::console_error("Failed to generate UUID");
-                        format!("fallback-{}", chrono::Utc::now().timestamp())
-                    }
-                },
-                |t| t.id,
-            ),
+            id: editing_todo
+                .get_untracked()
+                .map_or_else(|| Uuid::new_v4().to_string(), |t| t.id),
             title: title.trim().to_string(),
             description: if new_description.get_untracked().trim().is_empty() {
                 None
@@ -662,56 +2357,260 @@ pub fn HomePage() -> impl IntoView {
                 Some(new_description.get_untracked().trim().to_string())
             },
             due_date: due_timestamp,
-            assignee: TodoAssignee::from_str(&new_assignee.get_untracked())
-                .map_err(|e| leptos::logging::warn!("valid assignee: {:#?}", e))
-                .unwrap_or(TodoAssignee::Mikko),
+            assignee,
             status: TodoStatus::from_str(&new_status.get_untracked())
                 .map_err(|e| leptos::logging::warn!("Invalid status: {:#?}", e))
                 .unwrap_or(TodoStatus::Pending),
+            priority: TodoPriority::from_str(&new_priority.get_untracked())
+                .map_err(|e| leptos::logging::warn!("Invalid priority: {:#?}", e))
+                .unwrap_or_default(),
+            tags,
+            created_at: editing_todo.get_untracked().map_or_else(
+                || {
+                    chrono::Utc::now()
+                        .timestamp()
+                        .max(0)
+                        .try_into()
+                        .unwrap_or(0)
+                },
+                |t| t.created_at,
+            ),
+            // Set by the server from the session
+            created_by: editing_todo
+                .get_untracked()
+                .map(|t| t.created_by)
+                .unwrap_or_default(),
+            updated_at: editing_todo.get_untracked().map_or(0, |t| t.updated_at),
+            updated_by: editing_todo
+                .get_untracked()
+                .map(|t| t.updated_by)
+                .unwrap_or_default(),
+            // The server stamps the completion time when the status changes to Completed
+            completed_at: editing_todo.get_untracked().and_then(|t| t.completed_at),
+            position: editing_todo.get_untracked().and_then(|t| t.position),
+            // Comments are added on their own and kept by the server on update
+            comments: editing_todo
+                .get_untracked()
+                .map(|t| t.comments)
+                .unwrap_or_default(),
+            attachments: new_attachments.get_untracked(),
         };
 
         match todo.validate() {
             Ok(()) => {}
             Err(e) => {
-                set_error_message.set(format!("Invalid todo data: Error validating todo: {e}"));
+                let errors = form_field_errors(&e, locale());
+                // Fields without an input of their own, such as the tags, still go to the banner
+                if e.field_errors().len() > errors.len() {
+                    set_error_message.set(locale().t_with("form.invalid", &[("error", &e)]));
+                }
+                set_field_errors.set(errors);
                 return;
             }
         }
 
         set_error_message.set(String::new());
+        set_field_errors.set(HashMap::new());
 
+        // The list updates optimistically, so the modal closes right away; it reopens
+        // with the entered values if the server rejects the change
         if editing_todo.get_untracked().is_some() {
-            update_todo_action.dispatch(todo);
+            // Updates take the stored form, like the todos of the list
+            update_todo_action.dispatch(Todo {
+                title: sanitize_string(&todo.title),
+                ..todo
+            });
         } else {
-            create_todo_action.dispatch(todo);
+            create_todo_action.dispatch((todo, allow_past));
         }
+        reset_form();
+        set_show_modal.set(false);
+    };
+
+    // One-line quick add such as "Buy milk tomorrow 5pm", assigned to the person
+    // typed after @ or else the first configured assignee; the full form is
+    // still there for everything else
+    let (quick_add_text, set_quick_add_text) = signal(String::new());
+    let handle_quick_add = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let now = chrono::Utc::now().with_timezone(&tz).naive_local();
+        let parsed = parse_quick_add(&quick_add_text.get_untracked(), now);
+        if parsed.title.is_empty() {
+            set_error_message.set(locale().t("quick_add.title_required").to_string());
+            return;
+        }
+        let configured = assignees.get_untracked();
+        let assignee_name = match &parsed.assignee {
+            Some(typed) => configured
+                .iter()
+                .find(|name| name.eq_ignore_ascii_case(typed)),
+            None => configured.first(),
+        };
+        let Some(assignee) = assignee_name.and_then(|name| TodoAssignee::from_str(name).ok())
+        else {
+            let message = match &parsed.assignee {
+                Some(typed) => locale().t_with("quick_add.unknown_assignee", &[("name", typed)]),
+                None => locale().t("quick_add.no_assignee").to_string(),
+            };
+            set_error_message.set(message);
+            return;
+        };
+        let due_date = match parsed.due {
+            Some(due) => match from_user_time(due, tz) {
+                Some(timestamp) => Some(timestamp),
+                None => {
+                    set_error_message.set(locale().t("form.invalid_local_datetime").to_string());
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        let todo = Todo::new(parsed.title, assignee)
+            .with_due_date(due_date)
+            .with_priority(parsed.priority.unwrap_or_default())
+            .with_tags(parsed.tags);
+        if let Err(e) = todo.validate() {
+            set_error_message.set(locale().t_with("form.invalid", &[("error", &e)]));
+            return;
+        }
+        set_error_message.set(String::new());
+        // Only a typed "today" can be in the past, which the user asked for
+        create_todo_action.dispatch((todo, true));
+        set_quick_add_text.set(String::new());
     };
 
     let is_creating = move || create_todo_action.pending().get();
     let is_updating = move || update_todo_action.pending().get();
     let is_deleting = move || delete_todo_action.pending().get();
 
-    let format_due_date = |timestamp: u64| -> String {
-        if let Ok(timestamp_i64) = i64::try_from(timestamp) {
-            if let Some(datetime) = chrono::DateTime::from_timestamp(timestamp_i64, 0) {
-                datetime.format("%A, %B %d, %Y at %I:%M %p").to_string()
+    // Deletes a todo, asking first unless the user opted out of confirmations
+    let request_delete = move |todo_id: String| {
+        let confirmed = skip_delete_confirm.get_untracked()
+            || web_sys::window().is_some_and(|window| {
+                window
+                    .confirm_with_message(locale().t("card.confirm_delete"))
+                    .unwrap_or(false)
+            });
+        if confirmed {
+            delete_todo_action.dispatch(todo_id);
+        }
+    };
+
+    // Puts the todo's deep link on the clipboard; the link is shown if that fails
+    let copy_todo_link = move |todo_id: &str| {
+        let origin = web_sys::window()
+            .and_then(|window| window.location().origin().ok())
+            .unwrap_or_default();
+        let url = format!("{origin}/todo/{todo_id}");
+        let shown_url = url.clone();
+        copy_to_clipboard(&url, move |copied| {
+            let locale = locale();
+            toasts.show(if copied {
+                locale.t("card.link_copied").to_string()
             } else {
-                "Invalid date".to_string()
-            }
+                locale.t_with("card.copy_link_failed", &[("url", &shown_url)])
+            });
+        });
+    };
+
+    let open_editor = move |todo: &Todo| {
+        populate_form(todo);
+        set_editing_todo.set(Some(todo.clone()));
+        set_show_modal.set(true);
+    };
+
+    // Flips a todo between Completed and Pending straight from its card
+    let toggle_status = move |todo: Todo| {
+        if update_todo_action.pending().get_untracked() {
+            return;
+        }
+        let new_status = if todo.status == TodoStatus::Completed {
+            TodoStatus::Pending
         } else {
-            "Invalid date".to_string()
+            TodoStatus::Completed
+        };
+        set_status_toggle.set(Some(todo.id.clone()));
+        update_todo_action.dispatch(Todo {
+            status: new_status,
+            ..todo
+        });
+    };
+
+    // Pushes a todo's due date back, giving it one if it had none
+    let snooze_todo = move |todo: Todo, snooze: Snooze| {
+        if update_todo_action.pending().get_untracked() {
+            return;
         }
+        let Some(due_date) = snoozed_due_date(snooze, todo.due_date, chrono::Utc::now(), tz) else {
+            logging::console_warn("Failed to compute the snoozed due date");
+            return;
+        };
+        update_todo_action.dispatch(Todo {
+            due_date: Some(due_date),
+            ..todo
+        });
     };
 
+    // Saves the position a todo was dragged to
+    let reorder_todo = move |todo_id: String, position: f64| {
+        if update_todo_action.pending().get_untracked() {
+            return;
+        }
+        let Some(todo) =
+            todos.with_untracked(|todos| todos.iter().find(|t| t.id == todo_id).cloned())
+        else {
+            return;
+        };
+        set_reordering.set(Some(todo_id));
+        update_todo_action.dispatch(Todo {
+            position: Some(position),
+            ..todo
+        });
+    };
+
+    // Current time for the relative due labels, ticking every RELATIVE_TIME_REFRESH
+    let (now, set_now) = signal(chrono::Utc::now().timestamp());
+    #[cfg(feature = "hydrate")]
+    {
+        use leptos::leptos_dom::helpers::set_interval_with_handle;
+
+        match set_interval_with_handle(
+            move || set_now.set(chrono::Utc::now().timestamp()),
+            RELATIVE_TIME_REFRESH,
+        ) {
+            Ok(handle) => on_cleanup(move || handle.clear()),
+            Err(e) => leptos::logging::warn!("Failed to start due label refresh: {:?}", e),
+        }
+    }
+
+    // Open todos past their due date, counted from the stats rather than the
+    // loaded pages and recounted as the clock ticks so todos crossing their
+    // deadline are picked up
+    let overdue_count = Memo::new(move |_| {
+        let now = u64::try_from(now.get()).unwrap_or(0);
+        stats.with(|stats| {
+            stats.as_ref().map_or(0, |stats| {
+                stats
+                    .open_due_dates
+                    .iter()
+                    .filter(|&&due_date| due_date < now)
+                    .count()
+            })
+        })
+    });
+
     view! {
-        <ErrorBoundary fallback=|errors| {
+        <ErrorBoundary fallback=move |errors| {
             view! {
                 <div class="min-h-screen flex items-center justify-center">
                     <div class="text-center p-8 bg-red-50 rounded-lg border border-red-200">
-                        <h2 class="text-xl font-bold text-red-800 mb-4">"Something went wrong"</h2>
+                        <h2 class="text-xl font-bold text-red-800 mb-4">
+                            {move || lang.t("error.something_went_wrong")}
+                        </h2>
                         <details class="text-left">
                             <summary class="cursor-pointer text-red-600 mb-2">
-                                "Error details"
+                                {move || lang.t("error.details")}
                             </summary>
                             <pre class="text-sm text-red-700 whitespace-pre-wrap">
                                 {format!("{:#?}", errors.get())}
@@ -719,59 +2618,376 @@ pub fn HomePage() -> impl IntoView {
                         </details>
                     </div>
                 </div>
-            }
-        }>
-            <main class="my-0 mx-auto max-w-6xl p-6 min-h-screen">
-                // Error message display
-                <Show when=move || !error_message.get().is_empty()>
-                    <div class="mb-4 p-3 rounded-xl bg-red-50 border border-red-100 shadow-sm">
-                        <p class="text-sm font-medium text-red-600">
-                            {move || error_message.get()}
-                        </p>
-                    </div>
-                </Show>
+            }
+        }>
+            <main class="my-0 mx-auto max-w-6xl p-6 min-h-screen">
+                // Error message display
+                <Show when=move || !error_message.get().is_empty()>
+                    <div class="mb-4 p-3 rounded-xl bg-red-50 border border-red-100 shadow-sm">
+                        <p class="text-sm font-medium text-red-600">
+                            {move || error_message.get()}
+                        </p>
+                    </div>
+                </Show>
+
+                // Concurrent edit
+                {move || {
+                    update_conflict
+                        .get()
+                        .map(|EditConflict { mine, theirs, changed }| {
+                            let title = unsanitize_string(&mine.title);
+                            view! {
+                                <div class="mb-4 p-3 rounded-xl bg-amber-50 border border-amber-200 shadow-sm flex flex-wrap gap-2 justify-between items-center dark:bg-amber-900/30 dark:border-amber-700">
+                                    <div class="text-sm text-amber-800 dark:text-amber-200">
+                                        <p class="font-medium">
+                                            {move || lang.t_with("conflict.message", &[("title", &title)])}
+                                        </p>
+                                        {(!changed.is_empty())
+                                            .then(|| {
+                                                view! {
+                                                    <p>
+                                                        {move || {
+                                                            let fields = changed
+                                                                .iter()
+                                                                .map(|key| lang.t(key))
+                                                                .collect::<Vec<_>>()
+                                                                .join(", ");
+                                                            lang.t_with("conflict.changed", &[("fields", &fields)])
+                                                        }}
+                                                    </p>
+                                                }
+                                            })}
+                                    </div>
+                                    <div class="flex gap-2">
+                                        {match theirs {
+                                            Some(theirs) => {
+                                                let theirs_updated_at = theirs.updated_at;
+                                                view! {
+                                                    <button
+                                                        on:click=move |_| {
+                                                            set_update_conflict.set(None);
+                                                            let theirs = theirs.clone();
+                                                            set_todos
+                                                                .update(|todos| {
+                                                                    if let Some(todo) = todos.iter_mut().find(|t| t.id == theirs.id) {
+                                                                        *todo = theirs;
+                                                                    }
+                                                                });
+                                                        }
+                                                        class="px-3 py-1 text-sm rounded-lg border border-amber-300 text-amber-800 hover:bg-amber-100 dark:border-amber-600 dark:text-amber-200 dark:hover:bg-amber-800/40"
+                                                        title=move || lang.t("conflict.use_theirs_hint")
+                                                    >
+                                                        {move || lang.t("conflict.use_theirs")}
+                                                    </button>
+                                                    <button
+                                                        on:click=move |_| {
+                                                            set_update_conflict.set(None);
+                                                            // Based on the stored version, so it is saved over it
+                                                            update_todo_action
+                                                                .dispatch(Todo {
+                                                                    updated_at: theirs_updated_at,
+                                                                    ..mine.clone()
+                                                                });
+                                                        }
+                                                        class="px-3 py-1 text-sm rounded-lg bg-amber-600 text-white hover:bg-amber-700"
+                                                        title=move || lang.t("conflict.keep_mine_hint")
+                                                    >
+                                                        {move || lang.t("conflict.keep_mine")}
+                                                    </button>
+                                                }
+                                                    .into_any()
+                                            }
+                                            None => {
+                                                view! {
+                                                    <button
+                                                        on:click=move |_| {
+                                                            set_update_conflict.set(None);
+                                                            bypass_cache.set_value(true);
+                                                            load_todos_action.dispatch(None);
+                                                        }
+                                                        class="px-3 py-1 text-sm rounded-lg border border-amber-300 text-amber-800 hover:bg-amber-100 dark:border-amber-600 dark:text-amber-200 dark:hover:bg-amber-800/40"
+                                                        title=move || lang.t("conflict.reload_hint")
+                                                    >
+                                                        {move || lang.t("conflict.reload")}
+                                                    </button>
+                                                }
+                                                    .into_any()
+                                            }
+                                        }}
+                                    </div>
+                                </div>
+                            }
+                        })
+                }}
+
+                // Bulk operation summary
+                <Show when=move || !bulk_message.get().is_empty()>
+                    <div class="mb-4 p-3 rounded-xl bg-green-50 border border-green-100 shadow-sm flex justify-between items-center">
+                        <p class="text-sm font-medium text-green-700">
+                            {move || bulk_message.get()}
+                        </p>
+                        <button
+                            on:click=move |_| set_bulk_message.set(String::new())
+                            class="text-sm text-green-700 hover:text-green-900"
+                        >
+                            {move || lang.t("common.dismiss")}
+                        </button>
+                    </div>
+                </Show>
+
+                // Header with create button
+                <div class="flex justify-between items-center mb-6">
+                    <img
+                        src="/images/familyleppanen-logo.png"
+                        alt=move || lang.t("home.logo_alt")
+                        class="h-10 w-auto"
+                        style="width: 50px; height: 50px;"
+                    />
+                    <div class="flex items-center gap-3">
+                        <h1 class="text-3xl font-bold bg-gradient-to-r from-purple-600 to-fuchsia-600 bg-clip-text text-transparent">
+                            {move || lang.t("home.title")}
+                        </h1>
+                        <Show when=move || { overdue_count.get() > 0 }>
+                            <button
+                                type="button"
+                                on:click=move |_| set_due_filter.set(Some(DueFilter::Overdue))
+                                class="px-3 py-1 text-sm font-semibold rounded-full bg-red-600 text-white hover:bg-red-700 transition-colors"
+                                title=move || lang.t("home.overdue_badge_hint")
+                            >
+                                {move || {
+                                    lang.t_with("home.overdue_badge", &[("count", &overdue_count.get())])
+                                }}
+                            </button>
+                        </Show>
+                    </div>
+                    <div class="flex gap-2">
+                        <LanguageSelect />
+                        <ThemeToggle />
+                        <Show when=move || !pending_sync.get().is_empty()>
+                            <span
+                                class="self-center px-3 py-1 text-sm font-medium rounded-full bg-amber-100 text-amber-800"
+                                title=move || lang.t("sync.pending_hint")
+                            >
+                                {move || {
+                                    if sync_action.pending().get() {
+                                        lang.t("sync.syncing").to_string()
+                                    } else {
+                                        lang.t_with("sync.pending", &[("count", &pending_sync.get().len())])
+                                    }
+                                }}
+                            </span>
+                        </Show>
+                        <button
+                            on:click=move |_| {
+                                if let Some(window) = web_sys::window() {
+                                    if window
+                                        .confirm_with_message(
+                                            locale().t("home.confirm_sign_out_everywhere"),
+                                        )
+                                        .unwrap_or(false)
+                                    {
+                                        auth.logout_all.dispatch(());
+                                    }
+                                }
+                            }
+                            class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors disabled:opacity-50"
+                            disabled=move || auth.logout_all.pending().get()
+                        >
+                            {move || lang.t("home.sign_out_everywhere")}
+                        </button>
+                        <button
+                            type="button"
+                            on:click=move |_| set_show_change_password.set(true)
+                            class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                        >
+                            {move || lang.t("password.change")}
+                        </button>
+                        <button
+                            type="button"
+                            on:click=move |_| set_show_reassign.set(true)
+                            class:hidden=move || !can_write.get()
+                            class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                        >
+                            {move || lang.t("reassign.title")}
+                        </button>
+                        <button
+                            on:click=move |_| {
+                                set_selection_mode.update(|mode| *mode = !*mode);
+                                set_selected_ids.set(HashSet::new());
+                            }
+                            class:hidden=move || !can_write.get()
+                            class=move || {
+                                if selection_mode.get() {
+                                    "px-4 py-2 border border-purple-300 bg-purple-50 text-purple-700 rounded-lg transition-colors"
+                                } else {
+                                    "px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                                }
+                            }
+                        >
+                            {move || lang.t(if selection_mode.get() { "bulk.done" } else { "bulk.select" })}
+                        </button>
+                        <a
+                            href="/print"
+                            class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                            title=move || lang.t("home.print_share_hint")
+                        >
+                            {move || lang.t("home.print_share")}
+                        </a>
+                        <button
+                            on:click=move |_| {
+                                export_csv_action.dispatch(());
+                            }
+                            class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors disabled:opacity-50"
+                            disabled=move || export_csv_action.pending().get()
+                        >
+                            {move || lang.t("home.export_csv")}
+                        </button>
+                        <button
+                            on:click=move |_| {
+                                export_ical_action.dispatch(());
+                            }
+                            class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors disabled:opacity-50"
+                            disabled=move || export_ical_action.pending().get()
+                        >
+                            {move || lang.t("home.download_ics")}
+                        </button>
+                        <label
+                            class:hidden=move || !can_write.get()
+                            class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors cursor-pointer"
+                        >
+                            {move || {
+                                lang.t(
+                                    if import_action.pending().get() { "import.importing" } else { "import.button" },
+                                )
+                            }}
+                            <input
+                                type="file"
+                                accept=".json,application/json"
+                                class="hidden"
+                                disabled=move || import_action.pending().get()
+                                on:change=move |ev| {
+                                    let input = event_target::<web_sys::HtmlInputElement>(&ev);
+                                    let read = read_file_text(
+                                        &input,
+                                        move |contents| match contents {
+                                            Ok(json) => {
+                                                import_action.dispatch(json);
+                                            }
+                                            Err(e) => set_error_message.set(e),
+                                        },
+                                    );
+                                    if let Err(e) = read {
+                                        set_error_message.set(e);
+                                    }
+                                    // Allow choosing the same file again
+                                    input.set_value("");
+                                }
+                            />
+                        </label>
+                        <button
+                            on:click=move |_| set_show_trash.update(|show| *show = !*show)
+                            class:hidden=move || !can_write.get()
+                            class=move || {
+                                if show_trash.get() {
+                                    "px-4 py-2 border border-purple-300 bg-purple-50 text-purple-700 rounded-lg transition-colors"
+                                } else {
+                                    "px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                                }
+                            }
+                        >
+                            {move || lang.t("trash.title")}
+                        </button>
+                        <button
+                            on:click=move |_| {
+                                reset_form();
+                                set_show_modal.set(true);
+                            }
+                            class:hidden=move || !can_write.get()
+                            class="px-4 py-2 bg-gradient-to-r from-purple-500 to-fuchsia-500 text-white rounded-lg hover:from-purple-600 hover:to-fuchsia-600 transition-all duration-200 shadow-lg"
+                        >
+                            {move || lang.t("home.add_todo")}
+                        </button>
+                    </div>
+                </div>
 
-                // Header with create button
-                <div class="flex justify-between items-center mb-6">
-                    <img
-                        src="/images/familyleppanen-logo.png"
-                        alt="Family Todos Logo"
-                        class="h-10 w-auto"
-                        style="width: 50px; height: 50px;"
-                    />
-                    <h1 class="text-3xl font-bold bg-gradient-to-r from-purple-600 to-fuchsia-600 bg-clip-text text-transparent">
-                        "Family Todos"
-                    </h1>
-                    <button
-                        on:click=move |_| {
-                            reset_form();
-                            set_show_modal.set(true);
-                        }
-                        class="px-4 py-2 bg-gradient-to-r from-purple-500 to-fuchsia-500 text-white rounded-lg hover:from-purple-600 hover:to-fuchsia-600 transition-all duration-200 shadow-lg"
-                    >
-                        "Add Todo"
-                    </button>
-                </div>
+                <StatsCard
+                    stats=stats
+                    can_write=can_write
+                    clearing=Signal::derive(move || clear_completed_action.pending().get())
+                    on_clear_completed=clear_completed
+                />
 
                 // Main content grid
                 <div class="grid grid-cols-1 lg:grid-cols-3 gap-6">
                     // Calendar section
                     <div class="lg:col-span-1">
-                        <div class="bg-white rounded-2xl shadow-sm border border-gray-100 p-6">
-                            <div class="flex justify-between items-center mb-4">
-                                <h2 class="text-xl font-semibold text-gray-800">
+                        <div class="bg-white dark:bg-gray-800 rounded-2xl shadow-sm border border-gray-100 dark:border-gray-700 p-6">
+                            <div class="flex justify-between items-center gap-2 mb-4">
+                                <h2 class="sr-only">
                                     {move || {
-                                        format!(
-                                            "{} {}",
-                                            get_month_name(current_month.get()),
-                                            current_year.get(),
-                                        )
+                                        NaiveDate::from_ymd_opt(current_year.get(), current_month.get(), 1)
+                                            .map(|month| {
+                                                lang.locale.get().format_date(month, DateFormat::Month)
+                                            })
                                     }}
                                 </h2>
-                                <div class="flex gap-2">
+                                <div class="flex gap-1">
+                                    <select
+                                        prop:value=move || current_month.get().to_string()
+                                        on:change=move |ev| {
+                                            if let Ok(month) = event_target_value(&ev).parse() {
+                                                set_current_month.set(month);
+                                            }
+                                        }
+                                        class=CALENDAR_SELECT_CLASS
+                                        aria-label=move || lang.t("calendar.month")
+                                    >
+                                        {(1..=12u32)
+                                            .map(|month| {
+                                                view! {
+                                                    <option value=month.to_string()>
+                                                        {move || {
+                                                            NaiveDate::from_ymd_opt(2000, month, 1)
+                                                                .map(|date| {
+                                                                    lang.locale.get().format_date(date, DateFormat::MonthName)
+                                                                })
+                                                        }}
+                                                    </option>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </select>
+                                    <select
+                                        prop:value=move || current_year.get().to_string()
+                                        on:change=move |ev| {
+                                            if let Ok(year) = event_target_value(&ev).parse() {
+                                                set_current_year.set(year);
+                                            }
+                                        }
+                                        class=CALENDAR_SELECT_CLASS
+                                        aria-label=move || lang.t("calendar.year")
+                                    >
+                                        {move || {
+                                            calendar_years()
+                                                .into_iter()
+                                                .map(|year| {
+                                                    view! {
+                                                        <option
+                                                            value=year.to_string()
+                                                            selected=move || current_year.get() == year
+                                                        >
+                                                            {year}
+                                                        </option>
+                                                    }
+                                                })
+                                                .collect_view()
+                                        }}
+                                    </select>
+                                </div>
+                                <div class="flex items-center gap-1">
                                     <button
                                         on:click=prev_month
-                                        class="p-2 hover:bg-gray-100 rounded-lg transition-colors"
+                                        class="p-2 hover:bg-gray-100 dark:hover:bg-gray-700 rounded-lg transition-colors"
                                     >
                                         <svg
                                             class="w-4 h-4"
@@ -787,9 +3003,15 @@ pub fn HomePage() -> impl IntoView {
                                             />
                                         </svg>
                                     </button>
+                                    <button
+                                        on:click=go_to_today
+                                        class="px-2 py-1 text-sm text-purple-600 border border-purple-200 rounded-lg hover:bg-purple-50 dark:hover:bg-gray-700 transition-colors"
+                                    >
+                                        {move || lang.t("calendar.go_to_today")}
+                                    </button>
                                     <button
                                         on:click=next_month
-                                        class="p-2 hover:bg-gray-100 rounded-lg transition-colors"
+                                        class="p-2 hover:bg-gray-100 dark:hover:bg-gray-700 rounded-lg transition-colors"
                                     >
                                         <svg
                                             class="w-4 h-4"
@@ -810,26 +3032,29 @@ pub fn HomePage() -> impl IntoView {
 
                             // Calendar grid
                             <div class="grid grid-cols-7 gap-1 mb-2">
-                                {
-                                    const DAYS: &[&str] = &[
-                                        "Sun",
-                                        "Mon",
-                                        "Tue",
-                                        "Wed",
-                                        "Thu",
-                                        "Fri",
-                                        "Sat",
+                                {move || {
+                                    // Indexed by days since Sunday
+                                    const DAYS: [&str; 7] = [
+                                        "calendar.sun",
+                                        "calendar.mon",
+                                        "calendar.tue",
+                                        "calendar.wed",
+                                        "calendar.thu",
+                                        "calendar.fri",
+                                        "calendar.sat",
                                     ];
-                                    DAYS.iter()
-                                        .map(|day| {
+                                    let first = week_start.get().num_days_from_sunday() as usize;
+                                    (0..DAYS.len())
+                                        .map(|column| {
+                                            let day = DAYS[(first + column) % DAYS.len()];
                                             view! {
-                                                <div class="p-2 text-center text-xs font-medium text-gray-500">
-                                                    {*day}
+                                                <div class="p-2 text-center text-xs font-medium text-gray-500 dark:text-gray-400">
+                                                    {move || lang.t(day)}
                                                 </div>
                                             }
                                         })
                                         .collect::<Vec<_>>()
-                                }
+                                }}
                             </div>
 
                             <div class="grid grid-cols-7 gap-1">
@@ -838,62 +3063,193 @@ pub fn HomePage() -> impl IntoView {
                                     let month = current_month.get();
                                     let days_in_month = get_days_in_month(year, month);
                                     let first_day = get_first_day_of_month(year, month);
+                                    let counts = due_counts(year, month);
                                     let mut calendar_days = Vec::new();
                                     for _ in 0..first_day {
                                         calendar_days
-                                            .push(
-
-                                                view! { <div class="p-2 h-8">{String::new()}</div> },
-                                            );
+                                            .push(view! { <div class="p-2 h-10"></div> }.into_any());
                                     }
                                     for day in 1..=days_in_month {
-                                        let is_today = if let Some(current_date) = NaiveDate::from_ymd_opt(
-                                            year,
-                                            month,
-                                            day,
-                                        ) {
-                                            current_date == today
-                                        } else {
-                                            false
+                                        let Some(date) = NaiveDate::from_ymd_opt(year, month, day) else {
+                                            continue;
                                         };
-                                        if is_today {
-                                            calendar_days
-                                                .push(
-
-                                                    view! {
-                                                        <div class="p-2 h-8 text-center text-sm rounded-lg bg-gradient-to-r from-purple-500 to-fuchsia-500 text-white font-semibold">
-                                                            {format!("{day}")}
-                                                        </div>
-                                                    },
-                                                );
-                                        } else {
-                                            calendar_days
-                                                .push(
-                                                    view! {
-                                                        <div class="p-2 h-8 text-center text-sm rounded-lg hover:bg-gray-100 cursor-pointer transition-colors">
-                                                            {format!("{day}")}
-                                                        </div>
-                                                    },
-                                                );
-                                        }
+                                        let is_selected = selected_date.get() == Some(date);
+                                        let day_classes = match (date == today, is_selected) {
+                                            (true, true) => {
+                                                "p-1 h-10 flex flex-col items-center text-sm rounded-lg bg-gradient-to-r from-purple-500 to-fuchsia-500 text-white font-semibold ring-2 ring-purple-300 ring-offset-1 cursor-pointer"
+                                            }
+                                            (true, false) => {
+                                                "p-1 h-10 flex flex-col items-center text-sm rounded-lg bg-gradient-to-r from-purple-500 to-fuchsia-500 text-white font-semibold cursor-pointer"
+                                            }
+                                            (false, true) => {
+                                                "p-1 h-10 flex flex-col items-center text-sm rounded-lg bg-purple-100 text-purple-800 font-semibold ring-2 ring-purple-400 cursor-pointer"
+                                            }
+                                            (false, false) => {
+                                                "p-1 h-10 flex flex-col items-center text-sm rounded-lg hover:bg-gray-100 dark:hover:bg-gray-700 cursor-pointer transition-colors"
+                                            }
+                                        };
+                                        // Past days with open todos are overdue
+                                        let due_indicator = counts
+                                            .get(&date)
+                                            .map(|count| {
+                                                let dot_color = if date < today {
+                                                    "bg-red-500"
+                                                } else if date == today {
+                                                    "bg-white dark:bg-gray-800"
+                                                } else {
+                                                    "bg-purple-500"
+                                                };
+                                                view! {
+                                                    <span
+                                                        class=format!("mt-0.5 w-1.5 h-1.5 rounded-full {dot_color}")
+                                                        title=move || lang.t_with("calendar.due_count", &[("count", &count)])
+                                                    ></span>
+                                                }
+                                            });
+                                        calendar_days
+                                            .push(
+                                                view! {
+                                                    <div
+                                                        class=day_classes
+                                                        on:click=move |_| {
+                                                            // Clicking the selected day again clears the filter
+                                                            set_selected_date
+                                                                .update(|selected| {
+                                                                    *selected = if *selected == Some(date) {
+                                                                        None
+                                                                    } else {
+                                                                        Some(date)
+                                                                    };
+                                                                });
+                                                        }
+                                                    >
+                                                        <span>{format!("{day}")}</span>
+                                                        {due_indicator}
+                                                    </div>
+                                                }
+                                                    .into_any(),
+                                            );
                                     }
                                     calendar_days
                                 }}
                             </div>
 
-                            <div class="mt-4 pt-4 border-t border-gray-100">
-                                <p class="text-sm text-gray-600 text-center">
-                                    "Today: "
+                            <div class="mt-4 pt-4 border-t border-gray-100 dark:border-gray-700">
+                                <label class="mb-2 flex justify-center items-center gap-2 text-sm text-gray-600 dark:text-gray-300">
+                                    {move || lang.t("calendar.week_starts_on")}
+                                    <select
+                                        prop:value=move || week_start.get().to_string()
+                                        on:change=move |ev| {
+                                            if let Ok(day) = event_target_value(&ev).parse::<Weekday>() {
+                                                set_week_start.set(day);
+                                                set_preference(WEEK_START_KEY, &day);
+                                            }
+                                        }
+                                        class=CALENDAR_SELECT_CLASS
+                                    >
+                                        <option value=Weekday::Mon.to_string()>
+                                            {move || lang.t("calendar.monday")}
+                                        </option>
+                                        <option value=Weekday::Sun.to_string()>
+                                            {move || lang.t("calendar.sunday")}
+                                        </option>
+                                    </select>
+                                </label>
+                                <p class="text-sm text-gray-600 dark:text-gray-300 text-center">
+                                    {move || lang.t("calendar.today")}
+                                    " "
                                     <span class="font-medium text-purple-600">
-                                        {today.format("%B %d, %Y").to_string()}
+                                        {move || lang.locale.get().format_date(today, DateFormat::Day)}
                                     </span>
                                 </p>
+                                <Show when=move || selected_date.get().is_some()>
+                                    <div class="mt-2 flex justify-center items-center gap-2 text-sm">
+                                        <span class="text-gray-600 dark:text-gray-300">
+                                            {move || lang.t("calendar.showing")}
+                                            " "
+                                            <span class="font-medium text-purple-600">
+                                                {move || {
+                                                    selected_date
+                                                        .get()
+                                                        .map(|date| {
+                                                            lang.locale.get().format_date(date, DateFormat::Day)
+                                                        })
+                                                }}
+                                            </span>
+                                        </span>
+                                        <button
+                                            on:click=move |_| set_selected_date.set(None)
+                                            class="px-2 py-0.5 text-xs text-purple-600 border border-purple-200 rounded-lg hover:bg-purple-50 transition-colors"
+                                        >
+                                            {move || lang.t("calendar.clear_date")}
+                                        </button>
+                                    </div>
+                                </Show>
                             </div>
                         </div>
                     </div>
 
                     // Todo list section
                     <div class="lg:col-span-2">
+                        // Trash with soft-deleted todos
+                        <Show when=move || show_trash.get()>
+                            <TrashPanel
+                                refresh=trash_version
+                                on_restore=move |()| {
+                                    load_todos_action.dispatch(None);
+                                }
+                            />
+                        </Show>
+
+                        // Tabs to switch between the active list and the archive
+                        <div class="flex gap-2 mb-4">
+                            <button
+                                on:click=move |_| set_show_archive.set(false)
+                                class=move || {
+                                    if show_archive.get() {
+                                        "px-4 py-2 text-sm text-gray-600 dark:text-gray-300 rounded-lg hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+                                    } else {
+                                        "px-4 py-2 text-sm font-medium text-purple-700 bg-purple-50 rounded-lg"
+                                    }
+                                }
+                            >
+                                {move || lang.t("home.tab_todos")}
+                            </button>
+                            <button
+                                on:click=move |_| set_show_archive.set(true)
+                                class=move || {
+                                    if show_archive.get() {
+                                        "px-4 py-2 text-sm font-medium text-purple-700 bg-purple-50 rounded-lg"
+                                    } else {
+                                        "px-4 py-2 text-sm text-gray-600 dark:text-gray-300 rounded-lg hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+                                    }
+                                }
+                            >
+                                {move || lang.t("home.tab_archive")}
+                            </button>
+                        </div>
+
+                        <Show when=move || show_archive.get() && !loading.get()>
+                            <CompletedArchive todos=todos />
+                        </Show>
+
+                        <Show when=move || !show_archive.get()>
+                        <Show when=move || can_write.get()>
+                            <form on:submit=handle_quick_add class="mb-4">
+                                <input
+                                    type="text"
+                                    prop:value=move || quick_add_text.get()
+                                    on:input=move |ev| set_quick_add_text.set(event_target_value(&ev))
+                                    class="w-full px-4 py-2 border border-gray-300 dark:border-gray-600 dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
+                                    placeholder=move || lang.t("quick_add.placeholder")
+                                    aria-label=move || lang.t("quick_add.label")
+                                    disabled=is_creating
+                                />
+                                <p class="mt-1 text-xs text-gray-500 dark:text-gray-400">
+                                    {move || lang.t("quick_add.hint")}
+                                </p>
+                            </form>
+                        </Show>
                         // Search and filter controls
                         <SearchAndFilters
                             search_term=search_term
@@ -902,18 +3258,47 @@ pub fn HomePage() -> impl IntoView {
                             set_filter_status=set_filter_status
                             filter_assignee=filter_assignee
                             set_filter_assignee=set_filter_assignee
+                            assignees=assignees
+                            my_assignee=my_assignee
+                            assigned_to_me_default=assigned_to_me_default
+                            set_assigned_to_me_default=set_assigned_to_me_default
+                            filter_tag=filter_tag
+                            set_filter_tag=set_filter_tag
+                            tags=Signal::derive(move || {
+                                let mut tags: Vec<String> = todos
+                                    .get()
+                                    .into_iter()
+                                    .flat_map(|todo| todo.tags)
+                                    .collect();
+                                tags.sort();
+                                tags.dedup();
+                                tags
+                            })
                             sort_by=sort_by
                             set_sort_by=set_sort_by
                             sort_ascending=sort_ascending
                             set_sort_ascending=set_sort_ascending
-                            total_todos=move || todos.get().len()
-                            filtered_todos=move || filtered_and_sorted_todos().len()
+                            hide_completed=hide_completed
+                            set_hide_completed=set_hide_completed
+                            skip_delete_confirm=skip_delete_confirm
+                            set_skip_delete_confirm=set_skip_delete_confirm
+                            due_filter=due_filter
+                            set_due_filter=set_due_filter
+                            total_todos=move || {
+                                total_count
+                                    .get()
+                                    .and_then(|total| usize::try_from(total).ok())
+                                    .unwrap_or_else(|| todos.get().len())
+                            }
+                            filtered_todos=move || filtered_and_sorted_todos.with(Vec::len)
                         />
 
                         <Show when=move || loading.get()>
                             <div class="flex justify-center items-center py-8">
                                 <div class="animate-spin rounded-full h-8 w-8 border-b-2 border-purple-600"></div>
-                                <span class="ml-2 text-gray-600">"Loading todos..."</span>
+                                <span class="ml-2 text-gray-600 dark:text-gray-300">
+                                    {move || lang.t("home.loading")}
+                                </span>
                             </div>
                         </Show>
 
@@ -924,11 +3309,13 @@ pub fn HomePage() -> impl IntoView {
                                     if todos_groups.is_empty() {
                                         let has_filters = !search_term.get().is_empty()
                                             || filter_status.get() != "All"
-                                            || filter_assignee.get() != "All";
+                                            || filter_assignee.get() != "All"
+                                            || filter_tag.get() != "All"
+                                            || selected_date.get().is_some();
                                         if has_filters {
 
                                             view! {
-                                                <div class="text-center py-12 bg-white rounded-2xl shadow-sm border border-gray-100">
+                                                <div class="text-center py-12 bg-white dark:bg-gray-800 rounded-2xl shadow-sm border border-gray-100 dark:border-gray-700">
                                                     <div class="text-gray-400 mb-4">
                                                         <svg
                                                             class="mx-auto h-12 w-12"
@@ -944,28 +3331,30 @@ pub fn HomePage() -> impl IntoView {
                                                             />
                                                         </svg>
                                                     </div>
-                                                    <h3 class="text-lg font-medium text-gray-900 mb-2">
-                                                        "No todos match your filters"
+                                                    <h3 class="text-lg font-medium text-gray-900 dark:text-gray-100 mb-2">
+                                                        {move || lang.t("home.no_matches")}
                                                     </h3>
-                                                    <p class="text-gray-500 mb-4">
-                                                        "Try adjusting your search or filter criteria."
+                                                    <p class="text-gray-500 dark:text-gray-400 mb-4">
+                                                        {move || lang.t("home.no_matches_hint")}
                                                     </p>
                                                     <button
                                                         on:click=move |_| {
                                                             set_search_term.set(String::new());
                                                             set_filter_status.set("All".to_string());
                                                             set_filter_assignee.set("All".to_string());
+                                                            set_filter_tag.set("All".to_string());
+                                                            set_selected_date.set(None);
                                                         }
                                                         class="px-4 py-2 text-purple-600 border border-purple-200 rounded-lg hover:bg-purple-50 transition-colors"
                                                     >
-                                                        "Clear Filters"
+                                                        {move || lang.t("filter.clear")}
                                                     </button>
                                                 </div>
                                             }
                                                 .into_any()
                                         } else {
                                             view! {
-                                                <div class="text-center py-12 bg-white rounded-2xl shadow-sm border border-gray-100">
+                                                <div class="text-center py-12 bg-white dark:bg-gray-800 rounded-2xl shadow-sm border border-gray-100 dark:border-gray-700">
                                                     <div class="text-gray-400 mb-4">
                                                         <svg
                                                             class="mx-auto h-12 w-12"
@@ -981,11 +3370,11 @@ pub fn HomePage() -> impl IntoView {
                                                             />
                                                         </svg>
                                                     </div>
-                                                    <h3 class="text-lg font-medium text-gray-900 mb-2">
-                                                        "No todos yet"
+                                                    <h3 class="text-lg font-medium text-gray-900 dark:text-gray-100 mb-2">
+                                                        {move || lang.t("home.empty")}
                                                     </h3>
-                                                    <p class="text-gray-500 mb-4">
-                                                        "Create your first todo to get started!"
+                                                    <p class="text-gray-500 dark:text-gray-400 mb-4">
+                                                        {move || lang.t("home.empty_hint")}
                                                     </p>
                                                     <button
                                                         on:click=move |_| {
@@ -994,7 +3383,7 @@ pub fn HomePage() -> impl IntoView {
                                                         }
                                                         class="px-4 py-2 bg-gradient-to-r from-purple-500 to-fuchsia-500 text-white rounded-lg hover:from-purple-600 hover:to-fuchsia-600 transition-all duration-200"
                                                     >
-                                                        "Create First Todo"
+                                                        {move || lang.t("home.create_first")}
                                                     </button>
                                                 </div>
                                             }
@@ -1005,196 +3394,531 @@ pub fn HomePage() -> impl IntoView {
                                             <div class="space-y-6">
                                                 {todos_groups
                                                     .into_iter()
-                                                    .map(|(month_key, todos_in_month)| {
+                                                    .enumerate()
+                                                    .map(|(index, (month_key, todos_in_month))| {
                                                         let month_header = format_month_header(&month_key);
+                                                        let todo_count = todos_in_month.len();
+                                                        #[allow(clippy::cast_precision_loss)]
+                                                        let estimated_height = todo_count as f64 * ESTIMATED_CARD_HEIGHT;
+                                                        // Todos "Complete all" marks done; completed ones are skipped
+                                                        let open_todos = StoredValue::new(
+                                                            todos_in_month
+                                                                .iter()
+                                                                .filter(|todo| todo.status != TodoStatus::Completed)
+                                                                .cloned()
+                                                                .collect::<Vec<_>>(),
+                                                        );
+                                                        let open_count = open_todos.with_value(Vec::len);
+                                                        let month_label = month_header.clone();
+                                                        let completing_key = month_key.clone();
+                                                        let group_order = StoredValue::new(
+                                                            todos_in_month
+                                                                .iter()
+                                                                .map(|todo| (todo.id.clone(), todo.effective_position()))
+                                                                .collect::<Vec<_>>(),
+                                                        );
                                                         view! {
                                                             <div class="space-y-4">
                                                                 // Month header
                                                                 <div class="flex items-center gap-4">
-                                                                    <h3 class="text-xl font-semibold text-gray-800">
+                                                                    <h3 class="text-xl font-semibold text-gray-800 dark:text-gray-100">
                                                                         {month_header}
                                                                     </h3>
                                                                     <div class="flex-1 h-px bg-gradient-to-r from-purple-200 to-transparent"></div>
-                                                                    <span class="text-sm text-gray-500 bg-gray-100 px-2 py-1 rounded-full">
-                                                                        {format!("{} todos", todos_in_month.len())}
+                                                                    <Show when=move || can_write.get() && open_count > 0>
+                                                                        {
+                                                                            let month_key = month_key.clone();
+                                                                            let month_label = month_label.clone();
+                                                                            let completing_key = completing_key.clone();
+                                                                            view! {
+                                                                                <button
+                                                                                    on:click=move |_| {
+                                                                                        complete_month(
+                                                                                            month_key.clone(),
+                                                                                            &month_label,
+                                                                                            open_todos.get_value(),
+                                                                                        );
+                                                                                    }
+                                                                                    disabled=is_bulk_pending
+                                                                                    class="text-sm text-green-700 border border-green-200 px-2 py-1 rounded-full hover:bg-green-50 transition-colors disabled:opacity-50 dark:text-green-300 dark:border-green-800 dark:hover:bg-green-900/30"
+                                                                                    title=move || {
+                                                                                        lang.t_with(
+                                                                                            "group.complete_all_hint",
+                                                                                            &[("count", &open_count)],
+                                                                                        )
+                                                                                    }
+                                                                                >
+                                                                                    {move || {
+                                                                                        if completing_month.get().as_ref() == Some(&completing_key) {
+                                                                                            lang.t("group.completing").to_string()
+                                                                                        } else {
+                                                                                            lang.t_with("group.complete_all", &[("count", &open_count)])
+                                                                                        }
+                                                                                    }}
+                                                                                </button>
+                                                                            }
+                                                                        }
+                                                                    </Show>
+                                                                    <span class="text-sm text-gray-500 dark:text-gray-400 bg-gray-100 dark:bg-gray-700 px-2 py-1 rounded-full">
+                                                                        {move || lang.t_with("count.todos", &[("count", &todo_count)])}
                                                                     </span>
                                                                 </div>
 
                                                                 // Todos in this month
-                                                                <div class="grid gap-4">
-                                                                    {todos_in_month
-                                                                        .into_iter()
-                                                                        .map(|todo| {
-                                                                            let todo_clone = todo.clone();
-                                                                            let todo_id = todo.id;
-                                                                            let status_color = match todo.status {
-                                                                                TodoStatus::Pending => "bg-gray-100 text-gray-800",
-                                                                                TodoStatus::Completed => "bg-green-100 text-green-800",
-                                                                            };
-                                                                            let assignee_color = match todo.assignee {
-                                                                                TodoAssignee::Mikko => "bg-purple-100 text-purple-800",
-                                                                                TodoAssignee::Niina => "bg-pink-100 text-pink-800",
-                                                                            };
-                                                                            let is_todo_overdue = todo
-                                                                                .due_date
-                                                                                .is_some_and(|timestamp| {
-                                                                                    is_overdue(timestamp) && todo.status == TodoStatus::Pending
-                                                                                });
-                                                                            let card_classes = if is_todo_overdue {
-                                                                                "bg-red-50 border-red-200 rounded-xl shadow-sm border p-6 hover:shadow-md transition-shadow duration-200"
-                                                                            } else {
-                                                                                "bg-white rounded-xl shadow-sm border border-gray-100 p-6 hover:shadow-md transition-shadow duration-200"
-                                                                            };
-
-                                                                            // Check if todo is overdue and not completed
-
-                                                                            // Apply overdue styling
-
-                                                                            view! {
-                                                                                <div class=card_classes>
-                                                                                    <div class="flex justify-between items-start mb-3">
-                                                                                        <div class="flex items-start gap-2">
-                                                                                            // Add overdue indicator icon
-                                                                                            {if is_todo_overdue {
-                                                                                                view! {
-                                                                                                    <svg
-                                                                                                        class="w-5 h-5 text-red-500 mt-0.5 flex-shrink-0"
-                                                                                                        fill="currentColor"
-                                                                                                        viewBox="0 0 20 20"
-                                                                                                    >
-                                                                                                        <path
-                                                                                                            fill-rule="evenodd"
-                                                                                                            d="M8.257 3.099c.765-1.36 2.722-1.36 3.486 0l5.58 9.92c.75 1.334-.213 2.98-1.742 2.98H4.42c-1.53 0-2.493-1.646-1.743-2.98l5.58-9.92zM11 13a1 1 0 11-2 0 1 1 0 012 0zm-1-8a1 1 0 00-1 1v3a1 1 0 002 0V6a1 1 0 00-1-1z"
-                                                                                                            clip-rule="evenodd"
-                                                                                                        />
-                                                                                                    </svg>
+                                                                <LazyGroup
+                                                                    estimated_height=estimated_height
+                                                                    eager=index < EAGER_GROUPS
+                                                                >
+                                                                    <div class="grid gap-4">
+                                                                        {todos_in_month
+                                                                            .clone()
+                                                                            .into_iter()
+                                                                            .map(|todo| {
+                                                                                let todo_clone = todo.clone();
+                                                                                let toggle_todo = todo.clone();
+                                                                                let todo_to_snooze = todo.clone();
+                                                                                let is_completed = todo.status == TodoStatus::Completed;
+                                                                                let toggle_id = todo.id.clone();
+                                                                                let is_toggling = move || {
+                                                                                    status_toggle
+                                                                                        .get()
+                                                                                        .is_some_and(|id| id == toggle_id)
+                                                                                };
+                                                                                let select_id = todo.id.clone();
+                                                                                let checked_id = todo.id.clone();
+                                                                                let drag_id = todo.id.clone();
+                                                                                let drop_id = todo.id.clone();
+                                                                                let key_todo = todo.clone();
+                                                                                let link_id = todo.id.clone();
+                                                                                let todo_id = todo.id;
+                                                                                let status_color = todo.status.bg_color();
+                                                                                let priority_color = todo.priority.bg_color();
+                                                                                let is_todo_overdue = todo
+                                                                                    .due_date
+                                                                                    .is_some_and(|timestamp| {
+                                                                                        is_overdue(timestamp) && todo.status != TodoStatus::Completed
+                                                                                    });
+                                                                                let card_classes = if is_todo_overdue {
+                                                                                    "bg-red-50 border-red-200 dark:bg-red-950 dark:border-red-900 rounded-xl shadow-sm border p-6 hover:shadow-md transition-shadow duration-200 focus:outline-none focus:ring-2 focus:ring-purple-500"
+                                                                                } else {
+                                                                                    "bg-white dark:bg-gray-800 rounded-xl shadow-sm border border-gray-100 dark:border-gray-700 p-6 hover:shadow-md transition-shadow duration-200 focus:outline-none focus:ring-2 focus:ring-purple-500"
+                                                                                };
+
+                                                                                // Check if todo is overdue and not completed
+
+                                                                                // Apply overdue styling
+
+                                                                                view! {
+                                                                                    <div
+                                                                                        class=card_classes
+                                                                                        data-todo-card
+                                                                                        tabindex="0"
+                                                                                        aria-keyshortcuts=move || {
+                                                                                            can_write.get().then_some("ArrowUp ArrowDown Enter c d")
+                                                                                        }
+                                                                                        on:keydown=move |ev: ev::KeyboardEvent| {
+                                                                                            // Keys typed into the card's own inputs, selects and buttons are theirs
+                                                                                            if ev.target() != ev.current_target() || ev.ctrl_key() || ev.meta_key()
+                                                                                                || ev.alt_key()
+                                                                                            {
+                                                                                                return;
+                                                                                            }
+                                                                                            let can_write = can_write.get_untracked();
+                                                                                            match ev.key().as_str() {
+                                                                                                "ArrowDown" => {
+                                                                                                    ev.prevent_default();
+                                                                                                    focus_adjacent_card(&ev, true);
+                                                                                                }
+                                                                                                "ArrowUp" => {
+                                                                                                    ev.prevent_default();
+                                                                                                    focus_adjacent_card(&ev, false);
+                                                                                                }
+                                                                                                "Enter" if can_write => {
+                                                                                                    ev.prevent_default();
+                                                                                                    open_editor(&key_todo);
                                                                                                 }
-                                                                                                    .into_any()
+                                                                                                "c" if can_write => toggle_status(key_todo.clone()),
+                                                                                                "d" if can_write => request_delete(key_todo.id.clone()),
+                                                                                                _ => {}
+                                                                                            }
+                                                                                        }
+                                                                                        draggable=move || {
+                                                                                            if sort_by.get() == SortBy::Manual && can_write.get() {
+                                                                                                "true"
                                                                                             } else {
-                                                                                                view! { <div></div> }.into_any()
-                                                                                            }}
-                                                                                            <h4 class=format!(
-                                                                                                "text-lg font-semibold {}",
-                                                                                                if is_todo_overdue {
-                                                                                                    "text-red-900"
-                                                                                                } else {
-                                                                                                    "text-gray-900"
-                                                                                                },
-                                                                                            )>{todo.title.clone()}</h4>
-                                                                                        </div>
-                                                                                        <div class="flex items-center gap-2">
-                                                                                            <span class=format!(
-                                                                                                "px-2 py-1 text-xs font-medium rounded-full {status_color}",
-                                                                                            )>{todo.status.as_str()}</span>
-                                                                                            <div class="flex gap-1">
+                                                                                                "false"
+                                                                                            }
+                                                                                        }
+                                                                                        on:dragstart=move |ev| {
+                                                                                            start_drag(&ev, &drag_id);
+                                                                                            set_dragged_id.set(Some(drag_id.clone()));
+                                                                                        }
+                                                                                        on:dragend=move |_| set_dragged_id.set(None)
+                                                                                        on:dragover=move |ev| {
+                                                                                            // Only todos of the same group can be dropped here
+                                                                                            let same_group = dragged_id
+                                                                                                .with_untracked(|dragged| {
+                                                                                                    dragged
+                                                                                                        .as_ref()
+                                                                                                        .is_some_and(|dragged| {
+                                                                                                            group_order
+                                                                                                                .with_value(|order| {
+                                                                                                                    order.iter().any(|(id, _)| id == dragged)
+                                                                                                                })
+                                                                                                        })
+                                                                                                });
+                                                                                            if same_group {
+                                                                                                ev.prevent_default();
+                                                                                            }
+                                                                                        }
+                                                                                        on:drop=move |ev| {
+                                                                                            ev.prevent_default();
+                                                                                            let Some(dragged) = dragged_id.get_untracked() else {
+                                                                                                return;
+                                                                                            };
+                                                                                            set_dragged_id.set(None);
+                                                                                            let position = group_order
+                                                                                                .with_value(|order| {
+                                                                                                    drop_position(
+                                                                                                        order,
+                                                                                                        &dragged,
+                                                                                                        &drop_id,
+                                                                                                        sort_ascending.get_untracked(),
+                                                                                                    )
+                                                                                                });
+                                                                                            if let Some(position) = position {
+                                                                                                reorder_todo(dragged, position);
+                                                                                            }
+                                                                                        }
+                                                                                    >
+                                                                                        <div class="flex justify-between items-start mb-3">
+                                                                                            <div class="flex items-start gap-2">
+                                                                                                // Bulk selection checkbox
+                                                                                                <Show when=move || selection_mode.get()>
+                                                                                                    {
+                                                                                                        let select_id = select_id.clone();
+                                                                                                        let checked_id = checked_id.clone();
+                                                                                                        view! {
+                                                                                                            <input
+                                                                                                                type="checkbox"
+                                                                                                                prop:checked=move || {
+                                                                                                                    selected_ids.get().contains(&checked_id)
+                                                                                                                }
+                                                                                                                on:change=move |ev| {
+                                                                                                                    let checked = event_target_checked(&ev);
+                                                                                                                    set_selected_ids
+                                                                                                                        .update(|ids| {
+                                                                                                                            if checked {
+                                                                                                                                ids.insert(select_id.clone());
+                                                                                                                            } else {
+                                                                                                                                ids.remove(&select_id);
+                                                                                                                            }
+                                                                                                                        });
+                                                                                                                }
+                                                                                                                class="mt-1.5 rounded border-gray-300 dark:border-gray-600 text-purple-600 focus:ring-purple-500"
+                                                                                                            />
+                                                                                                        }
+                                                                                                    }
+                                                                                                </Show>
+                                                                                                // Quick status toggle
                                                                                                 <button
-                                                                                                    on:click=move |_| {
-                                                                                                        populate_form(&todo_clone);
-                                                                                                        set_editing_todo.set(Some(todo_clone.clone()));
-                                                                                                        set_show_modal.set(true);
+                                                                                                    on:click=move |ev| {
+                                                                                                        ev.stop_propagation();
+                                                                                                        toggle_status(toggle_todo.clone());
                                                                                                     }
-                                                                                                    class="p-1 text-gray-500 hover:text-blue-600 hover:bg-blue-50 rounded transition-colors"
-                                                                                                    title="Edit todo"
+                                                                                                    class=if is_completed {
+                                                                                                        "mt-1 w-5 h-5 flex-shrink-0 flex items-center justify-center rounded border-2 border-green-500 bg-green-500 text-white disabled:opacity-50"
+                                                                                                    } else {
+                                                                                                        "mt-1 w-5 h-5 flex-shrink-0 flex items-center justify-center rounded border-2 border-gray-300 dark:border-gray-600 hover:border-green-500 disabled:opacity-50"
+                                                                                                    }
+                                                                                                    title=move || {
+                                                                                                        lang.t(if is_completed { "card.mark_pending" } else { "card.mark_completed" })
+                                                                                                    }
+                                                                                                    disabled=move || is_updating() || !can_write.get()
                                                                                                 >
-                                                                                                    <svg
-                                                                                                        class="w-4 h-4"
-                                                                                                        fill="none"
-                                                                                                        stroke="currentColor"
-                                                                                                        viewBox="0 0 24 24"
+                                                                                                    <Show
+                                                                                                        when=is_toggling
+                                                                                                        fallback=move || {
+                                                                                                            is_completed
+                                                                                                                .then(|| {
+                                                                                                                    view! {
+                                                                                                                        <svg
+                                                                                                                            class="w-3 h-3"
+                                                                                                                            fill="none"
+                                                                                                                            stroke="currentColor"
+                                                                                                                            viewBox="0 0 24 24"
+                                                                                                                        >
+                                                                                                                            <path
+                                                                                                                                stroke-linecap="round"
+                                                                                                                                stroke-linejoin="round"
+                                                                                                                                stroke-width="3"
+                                                                                                                                d="M5 13l4 4L19 7"
+                                                                                                                            />
+                                                                                                                        </svg>
+                                                                                                                    }
+                                                                                                                })
+                                                                                                        }
                                                                                                     >
-                                                                                                        <path
-                                                                                                            stroke-linecap="round"
-                                                                                                            stroke-linejoin="round"
-                                                                                                            stroke-width="2"
-                                                                                                            d="M11 5H6a2 2 0 00-2 2v11a2 2 0 002 2h11a2 2 0 002-2v-5m-1.414-9.414a2 2 0 112.828 2.828L11.828 15H9v-2.828l8.586-8.586z"
-                                                                                                        />
-                                                                                                    </svg>
+                                                                                                        <div class="animate-spin rounded-full h-3 w-3 border-b-2 border-purple-600"></div>
+                                                                                                    </Show>
                                                                                                 </button>
-                                                                                                <button
-                                                                                                    on:click=move |_| {
-                                                                                                        if let Some(window) = web_sys::window() {
-                                                                                                            if window
-                                                                                                                .confirm_with_message(
-                                                                                                                    "Are you sure you want to delete this todo?",
-                                                                                                                )
-                                                                                                                .unwrap_or(false)
-                                                                                                            {
-                                                                                                                delete_todo_action.dispatch(todo_id.to_string());
-                                                                                                            }
-                                                                                                        }
+                                                                                                // Add overdue indicator icon
+                                                                                                {if is_todo_overdue {
+                                                                                                    view! {
+                                                                                                        <svg
+                                                                                                            class="w-5 h-5 text-red-500 mt-0.5 flex-shrink-0"
+                                                                                                            fill="currentColor"
+                                                                                                            viewBox="0 0 20 20"
+                                                                                                        >
+                                                                                                            <path
+                                                                                                                fill-rule="evenodd"
+                                                                                                                d="M8.257 3.099c.765-1.36 2.722-1.36 3.486 0l5.58 9.92c.75 1.334-.213 2.98-1.742 2.98H4.42c-1.53 0-2.493-1.646-1.743-2.98l5.58-9.92zM11 13a1 1 0 11-2 0 1 1 0 012 0zm-1-8a1 1 0 00-1 1v3a1 1 0 002 0V6a1 1 0 00-1-1z"
+                                                                                                                clip-rule="evenodd"
+                                                                                                            />
+                                                                                                        </svg>
                                                                                                     }
-                                                                                                    class="p-1 text-gray-500 hover:text-red-600 hover:bg-red-50 rounded transition-colors"
-                                                                                                    title="Delete todo"
-                                                                                                    disabled=is_deleting
+                                                                                                        .into_any()
+                                                                                                } else {
+                                                                                                    view! { <div></div> }.into_any()
+                                                                                                }}
+                                                                                                <h4 class=format!(
+                                                                                                    "text-lg font-semibold {}",
+                                                                                                    if is_todo_overdue {
+                                                                                                        "text-red-900"
+                                                                                                    } else {
+                                                                                                        "text-gray-900 dark:text-gray-100"
+                                                                                                    },
+                                                                                                )>
+                                                                                                    <a
+                                                                                                        href=format!("/todo/{}", todo.id)
+                                                                                                        class="hover:underline"
+                                                                                                    >
+                                                                                                        {unsanitize_string(&todo.title)}
+                                                                                                    </a>
+                                                                                                </h4>
+                                                                                            </div>
+                                                                                            <div class="flex items-center gap-2">
+                                                                                                <span class=format!(
+                                                                                                    "px-2 py-1 text-xs font-medium rounded-full {priority_color}",
+                                                                                                )>{move || lang.priority(todo.priority)}</span>
+                                                                                                <span class=format!(
+                                                                                                    "px-2 py-1 text-xs font-medium rounded-full {status_color}",
+                                                                                                )>{move || lang.status(todo.status)}</span>
+                                                                                                <div
+                                                                                                    class="flex gap-1"
+                                                                                                    class:hidden=move || !can_write.get()
                                                                                                 >
-                                                                                                    <svg
-                                                                                                        class="w-4 h-4"
-                                                                                                        fill="none"
-                                                                                                        stroke="currentColor"
-                                                                                                        viewBox="0 0 24 24"
+                                                                                                    {(!is_completed)
+                                                                                                        .then(|| {
+                                                                                                            view! {
+                                                                                                                <select
+                                                                                                                    on:change=move |ev| {
+                                                                                                                        if let Ok(snooze) = event_target_value(&ev).parse::<Snooze>() {
+                                                                                                                            snooze_todo(todo_to_snooze.clone(), snooze);
+                                                                                                                        }
+                                                                                                                        // Back to the placeholder, so the same choice can be made again
+                                                                                                                        event_target::<web_sys::HtmlSelectElement>(&ev).set_value("");
+                                                                                                                    }
+                                                                                                                    class="p-1 text-xs text-gray-500 dark:text-gray-400 bg-transparent border border-gray-200 dark:border-gray-600 rounded hover:text-blue-600 transition-colors"
+                                                                                                                    title=move || lang.t("snooze.hint")
+                                                                                                                    disabled=is_updating
+                                                                                                                >
+                                                                                                                    <option value="" selected=true>
+                                                                                                                        {move || lang.t("snooze.label")}
+                                                                                                                    </option>
+                                                                                                                    {Snooze::ALL
+                                                                                                                        .into_iter()
+                                                                                                                        .map(|snooze| {
+                                                                                                                            view! {
+                                                                                                                                <option value=snooze.as_str()>{move || lang.t(snooze.label_key())}</option>
+                                                                                                                            }
+                                                                                                                        })
+                                                                                                                        .collect_view()}
+                                                                                                                </select>
+                                                                                                            }
+                                                                                                        })}
+                                                                                                    <button
+                                                                                                        on:click=move |_| copy_todo_link(&link_id)
+                                                                                                        class="p-1 text-gray-500 dark:text-gray-400 hover:text-purple-600 hover:bg-purple-50 rounded transition-colors"
+                                                                                                        title=move || lang.t("card.copy_link")
+                                                                                                        aria-label=move || lang.t("card.copy_link")
                                                                                                     >
-                                                                                                        <path
-                                                                                                            stroke-linecap="round"
-                                                                                                            stroke-linejoin="round"
-                                                                                                            stroke-width="2"
-                                                                                                            d="M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16"
-                                                                                                        />
-                                                                                                    </svg>
-                                                                                                </button>
+                                                                                                        <svg
+                                                                                                            class="w-4 h-4"
+                                                                                                            fill="none"
+                                                                                                            stroke="currentColor"
+                                                                                                            viewBox="0 0 24 24"
+                                                                                                        >
+                                                                                                            <path
+                                                                                                                stroke-linecap="round"
+                                                                                                                stroke-linejoin="round"
+                                                                                                                stroke-width="2"
+                                                                                                                d="M13.828 10.172a4 4 0 00-5.656 0l-4 4a4 4 0 105.656 5.656l1.102-1.101m-.758-4.899a4 4 0 005.656 0l4-4a4 4 0 00-5.656-5.656l-1.1 1.1"
+                                                                                                            />
+                                                                                                        </svg>
+                                                                                                    </button>
+                                                                                                    <button
+                                                                                                        on:click=move |_| {
+                                                                                                            open_editor(&todo_clone);
+                                                                                                        }
+                                                                                                        class="p-1 text-gray-500 dark:text-gray-400 hover:text-blue-600 hover:bg-blue-50 rounded transition-colors"
+                                                                                                        title=move || lang.t("card.edit")
+                                                                                                    >
+                                                                                                        <svg
+                                                                                                            class="w-4 h-4"
+                                                                                                            fill="none"
+                                                                                                            stroke="currentColor"
+                                                                                                            viewBox="0 0 24 24"
+                                                                                                        >
+                                                                                                            <path
+                                                                                                                stroke-linecap="round"
+                                                                                                                stroke-linejoin="round"
+                                                                                                                stroke-width="2"
+                                                                                                                d="M11 5H6a2 2 0 00-2 2v11a2 2 0 002 2h11a2 2 0 002-2v-5m-1.414-9.414a2 2 0 112.828 2.828L11.828 15H9v-2.828l8.586-8.586z"
+                                                                                                            />
+                                                                                                        </svg>
+                                                                                                    </button>
+                                                                                                    <button
+                                                                                                        on:click=move |_| request_delete(todo_id.clone())
+                                                                                                        class="p-1 text-gray-500 dark:text-gray-400 hover:text-red-600 hover:bg-red-50 rounded transition-colors"
+                                                                                                        title=move || lang.t("card.delete")
+                                                                                                        disabled=is_deleting
+                                                                                                    >
+                                                                                                        <svg
+                                                                                                            class="w-4 h-4"
+                                                                                                            fill="none"
+                                                                                                            stroke="currentColor"
+                                                                                                            viewBox="0 0 24 24"
+                                                                                                        >
+                                                                                                            <path
+                                                                                                                stroke-linecap="round"
+                                                                                                                stroke-linejoin="round"
+                                                                                                                stroke-width="2"
+                                                                                                                d="M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16"
+                                                                                                            />
+                                                                                                        </svg>
+                                                                                                    </button>
+                                                                                                </div>
                                                                                             </div>
                                                                                         </div>
-                                                                                    </div>
-
-                                                                                    {todo
-                                                                                        .description
-                                                                                        .as_ref()
-                                                                                        .map(|desc| {
-                                                                                            view! {
-                                                                                                <p class=format!(
-                                                                                                    "mb-3 {}",
-                                                                                                    if is_todo_overdue {
-                                                                                                        "text-red-700"
-                                                                                                    } else {
-                                                                                                        "text-gray-600"
-                                                                                                    },
-                                                                                                )>{desc.clone()}</p>
-                                                                                            }
-                                                                                        })}
-
-                                                                                    <div class="flex flex-wrap gap-2 items-center">
-                                                                                        <span class=format!(
-                                                                                            "px-2 py-1 text-xs font-medium rounded-full {assignee_color}",
-                                                                                        )>{todo.assignee.as_str()}</span>
 
                                                                                         {todo
-                                                                                            .due_date
-                                                                                            .map(|timestamp| {
-                                                                                                let due_date_class = if is_overdue(timestamp)
-                                                                                                    && todo.status == TodoStatus::Pending
-                                                                                                {
-                                                                                                    "px-2 py-1 text-xs font-medium rounded-full bg-red-200 text-red-900 font-bold"
-                                                                                                } else {
-                                                                                                    "px-2 py-1 text-xs font-medium rounded-full bg-yellow-100 text-yellow-800"
-                                                                                                };
-
+                                                                                            .description
+                                                                                            .as_ref()
+                                                                                            .map(|desc| {
                                                                                                 view! {
-                                                                                                    <span class=due_date_class>
-                                                                                                        {if is_overdue(timestamp)
-                                                                                                            && todo.status == TodoStatus::Pending
-                                                                                                        {
-                                                                                                            format!("OVERDUE: {}", format_due_date(timestamp))
-                                                                                                        } else {
-                                                                                                            format!("Due: {}", format_due_date(timestamp))
-                                                                                                        }}
-                                                                                                    </span>
+                                                                                                    <div
+                                                                                                        class=format!(
+                                                                                                            "mb-3 space-y-2 [&_a]:underline [&_a]:text-purple-600 [&_ul]:list-disc [&_ul]:pl-5 [&_ol]:list-decimal [&_ol]:pl-5 [&_code]:bg-gray-100 [&_code]:px-1 [&_code]:rounded {}",
+                                                                                                            if is_todo_overdue {
+                                                                                                                "text-red-700"
+                                                                                                            } else {
+                                                                                                                "text-gray-600 dark:text-gray-300"
+                                                                                                            },
+                                                                                                        )
+                                                                                                        inner_html=render_markdown(desc)
+                                                                                                    ></div>
                                                                                                 }
                                                                                             })}
+
+                                                                                        <div class="flex flex-wrap gap-2 items-center">
+                                                                                            <Avatar assignee=todo.assignee.clone() />
+
+                                                                                            {todo
+                                                                                                .tags
+                                                                                                .iter()
+                                                                                                .map(|tag| {
+                                                                                                    view! {
+                                                                                                        <span class="px-2 py-0.5 text-xs rounded-full bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300 border border-gray-200 dark:border-gray-700">
+                                                                                                            {format!("#{tag}")}
+                                                                                                        </span>
+                                                                                                    }
+                                                                                                })
+                                                                                                .collect_view()}
+
+                                                                                            {todo
+                                                                                                .due_date
+                                                                                                .map(|timestamp| {
+                                                                                                    let due_date_class = if is_overdue(timestamp)
+                                                                                                        && todo.status != TodoStatus::Completed
+                                                                                                    {
+                                                                                                        "px-2 py-1 text-xs font-medium rounded-full bg-red-200 text-red-900 font-bold"
+                                                                                                    } else {
+                                                                                                        "px-2 py-1 text-xs font-medium rounded-full bg-yellow-100 text-yellow-800"
+                                                                                                    };
+
+                                                                                                    let is_pending = todo.status != TodoStatus::Completed;
+                                                                                                    let due_at = i64::try_from(timestamp).unwrap_or(i64::MAX);
+
+                                                                                                    view! {
+                                                                                                        <span class=due_date_class>
+                                                                                                            {if is_overdue(timestamp)
+                                                                                                                && todo.status != TodoStatus::Completed
+                                                                                                            {
+                                                                                                                lang.t_with(
+                                                                                                                    "card.overdue_on",
+                                                                                                                    &[("date", &lang.locale.get().format_timestamp(timestamp, tz, DateFormat::DueDate))],
+                                                                                                                )
+                                                                                                            } else {
+                                                                                                                lang.t_with(
+                                                                                                                    "card.due_on",
+                                                                                                                    &[("date", &lang.locale.get().format_timestamp(timestamp, tz, DateFormat::DueDate))],
+                                                                                                                )
+                                                                                                            }}
+                                                                                                        </span>
+                                                                                                        <span class=move || {
+                                                                                                            if is_pending && due_at <= now.get() {
+                                                                                                                "text-xs font-medium text-red-600"
+                                                                                                            } else {
+                                                                                                                "text-xs text-gray-500 dark:text-gray-400"
+                                                                                                            }
+                                                                                                        }>
+                                                                                                            {move || {
+                                                                                                                let relative = format_relative_time(timestamp, now.get(), lang.locale.get());
+                                                                                                                if is_pending && due_at <= now.get() {
+                                                                                                                    lang.t_with("card.overdue_relative", &[("relative", &relative)])
+                                                                                                                } else {
+                                                                                                                    relative
+                                                                                                                }
+                                                                                                            }}
+                                                                                                        </span>
+                                                                                                    }
+                                                                                                })}
+
+                                                                                            {(!todo.created_by.is_empty() && todo.created_by != UNKNOWN_CREATOR)
+                                                                                                .then(|| {
+                                                                                                    view! {
+                                                                                                        <span class="text-xs text-gray-400 dark:text-gray-500">
+                                                                                                            {lang.t_with("card.added_by", &[("name", &todo.created_by)])}
+                                                                                                        </span>
+                                                                                                    }
+                                                                                                })}
+
+                                                                                            {(!todo.updated_by.is_empty())
+                                                                                                .then(|| {
+                                                                                                    let updated_by = todo.updated_by.clone();
+                                                                                                    let updated_at = todo.updated_at;
+                                                                                                    view! {
+                                                                                                        <span class="text-xs text-gray-400 dark:text-gray-500">
+                                                                                                            {move || {
+                                                                                                                lang.t_with(
+                                                                                                                    "card.edited_by",
+                                                                                                                    &[
+                                                                                                                        ("name", &updated_by),
+                                                                                                                        ("relative", &format_relative_time(updated_at, now.get(), lang.locale.get())),
+                                                                                                                    ],
+                                                                                                                )
+                                                                                                            }}
+                                                                                                        </span>
+                                                                                                    }
+                                                                                                })}
+                                                                                        </div>
                                                                                     </div>
-                                                                                </div>
-                                                                            }
-                                                                        })
-                                                                        .collect::<Vec<_>>()}
-                                                                </div>
+                                                                                }
+                                                                            })
+                                                                            .collect::<Vec<_>>()}
+                                                                    </div>
+                                                                </LazyGroup>
                                                             </div>
                                                         }
                                                     })
@@ -1206,199 +3930,145 @@ pub fn HomePage() -> impl IntoView {
                                 }}
                             </div>
                         </Show>
+                        </Show>
                     </div>
                 </div>
 
-                // Modal for creating/editing todos
-                <Show when=move || show_modal.get()>
-                    <div class="fixed inset-0 bg-black/50 flex items-center justify-center z-50 p-4">
-                        <div class="bg-white rounded-2xl p-6 w-full max-w-md shadow-2xl">
-                            <div class="flex justify-between items-center mb-4">
-                                <h2 class="text-xl font-bold text-gray-800">
-                                    {move || {
-                                        if editing_todo.get().is_some() {
-                                            "Edit Todo"
-                                        } else {
-                                            "Create New Todo"
-                                        }
-                                    }}
-                                </h2>
-                                <button
-                                    on:click=move |_| set_show_modal.set(false)
-                                    class="text-gray-500 hover:text-gray-700 text-2xl leading-none"
-                                >
-                                    "×"
-                                </button>
-                            </div>
-
-                            <form on:submit=handle_submit>
-                                <div class="mb-4">
-                                    <label class="block text-sm font-medium text-gray-700 mb-2">
-                                        "Title *"
-                                    </label>
-                                    <input
-                                        type="text"
-                                        prop:value=move || new_title.get()
-                                        on:input=move |ev| {
-                                            set_new_title.set(event_target_value(&ev));
-                                        }
-                                        class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
-                                        placeholder="Enter todo title"
-                                        required
-                                    />
-                                </div>
-
-                                <div class="mb-4">
-                                    <label class="block text-sm font-medium text-gray-700 mb-2">
-                                        "Description"
-                                    </label>
-                                    <textarea
-                                        prop:value=move || new_description.get()
-                                        on:input=move |ev| {
-                                            set_new_description.set(event_target_value(&ev));
-                                        }
-                                        class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
-                                        placeholder="Enter description (optional)"
-                                        rows="3"
-                                    />
-                                </div>
-
-                                <div class="grid grid-cols-2 gap-4 mb-4">
-                                    <div>
-                                        <label class="block text-sm font-medium text-gray-700 mb-2">
-                                            "Due Date"
-                                        </label>
-                                        <input
-                                            type="date"
-                                            prop:value=move || new_due_date.get()
-                                            on:input=move |ev| {
-                                                set_new_due_date.set(event_target_value(&ev));
-                                            }
-                                            class=move || {
-                                                if is_past_date() {
-                                                    "w-full px-3 py-2 border border-orange-300 rounded-lg focus:ring-2 focus:ring-orange-500 focus:border-transparent bg-orange-50"
-                                                } else {
-                                                    "w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
-                                                }
-                                            }
-                                        />
-                                    </div>
-                                    <div>
-                                        <label class="block text-sm font-medium text-gray-700 mb-2">
-                                            "Due Time"
-                                        </label>
-                                        <input
-                                            type="time"
-                                            prop:value=move || new_due_time.get()
-                                            on:input=move |ev| {
-                                                set_new_due_time.set(event_target_value(&ev));
-                                            }
-                                            class=move || {
-                                                if is_past_date() {
-                                                    "w-full px-3 py-2 border border-orange-300 rounded-lg focus:ring-2 focus:ring-orange-500 focus:border-transparent bg-orange-50"
-                                                } else {
-                                                    "w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
-                                                }
-                                            }
-                                        />
-                                    </div>
-                                </div>
-                                <Show when=move || is_past_date() && editing_todo.get().is_none()>
-                                    <div class="mb-4 p-2 rounded-lg bg-orange-50 border border-orange-200">
-                                        <div class="flex items-center gap-2">
-                                            <svg
-                                                class="w-4 h-4 text-orange-500 flex-shrink-0"
-                                                fill="currentColor"
-                                                viewBox="0 0 20 20"
-                                            >
-                                                <path
-                                                    fill-rule="evenodd"
-                                                    d="M8.257 3.099c.765-1.36 2.722-1.36 3.486 0l5.58 9.92c.75 1.334-.213 2.98-1.742 2.98H4.42c-1.53 0-2.493-1.646-1.743-2.98l5.58-9.92zM11 13a1 1 0 11-2 0 1 1 0 012 0zm-1-8a1 1 0 00-1 1v3a1 1 0 002 0V6a1 1 0 00-1-1z"
-                                                    clip-rule="evenodd"
-                                                />
-                                            </svg>
-                                            <p class="text-sm text-orange-700">
-                                                "This date/time is in the past. You'll be asked to confirm when creating the todo."
-                                            </p>
-                                        </div>
-                                    </div>
-                                </Show>
-
-                                <div class="mb-4">
-                                    <label class="block text-sm font-medium text-gray-700 mb-2">
-                                        "Assignee"
-                                    </label>
-                                    <select
-                                        prop:value=move || new_assignee.get()
-                                        on:change=move |ev| {
-                                            set_new_assignee.set(event_target_value(&ev));
-                                        }
-                                        class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
-                                    >
-                                        <option value="Mikko">"Mikko"</option>
-                                        <option value="Niina">"Niina"</option>
-                                    </select>
-                                </div>
-
-                                <div class="mb-6">
-                                    <label class="block text-sm font-medium text-gray-700 mb-2">
-                                        "Status"
-                                    </label>
-                                    <select
-                                        prop:value=move || new_status.get()
-                                        on:change=move |ev| {
-                                            set_new_status.set(event_target_value(&ev));
-                                        }
-                                        class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent"
-                                    >
-                                        <option value="Pending">"Pending"</option>
-                                        <option value="Completed">"Completed"</option>
-                                    </select>
-                                </div>
-
-                                <div class="flex gap-3">
-                                    <button
-                                        type="button"
-                                        on:click=move |_| {
-                                            reset_form();
-                                            set_show_modal.set(false);
-                                        }
-                                        class="flex-1 px-4 py-2 border border-gray-300 text-gray-700 rounded-lg hover:bg-gray-50 transition-colors"
-                                        disabled=move || is_creating() || is_updating()
-                                    >
-                                        "Cancel"
-                                    </button>
+                // Undo toasts for recently deleted todos
+                <div class="fixed bottom-6 left-6 z-40 flex flex-col gap-2">
+                    <For
+                        each=move || undo_entries.get()
+                        key=|(key, _)| *key
+                        children=move |(key, todo)| {
+                            let title = unsanitize_string(&todo.title);
+                            view! {
+                                <div class="bg-gray-900 text-white rounded-xl shadow-2xl px-4 py-3 flex items-center gap-4">
+                                    <span class="text-sm truncate max-w-xs">
+                                        {move || lang.t_with("undo.deleted", &[("title", &title)])}
+                                    </span>
                                     <button
-                                        type="submit"
-                                        class="flex-1 px-4 py-2 bg-gradient-to-r from-purple-500 to-fuchsia-500 text-white rounded-lg hover:from-purple-600 hover:to-fuchsia-600 transition-all duration-200 disabled:opacity-50 disabled:cursor-not-allowed"
-                                        disabled=move || is_creating() || is_updating()
+                                        on:click=move |_| undo_delete(key, todo.clone())
+                                        class="text-sm font-semibold text-purple-300 hover:text-purple-200 transition-colors"
                                     >
-                                        <Show
-                                            when=move || is_creating() || is_updating()
-                                            fallback=move || {
-                                                if editing_todo.get().is_some() {
-                                                    "Update Todo"
-                                                } else {
-                                                    "Create Todo"
-                                                }
-                                            }
-                                        >
-                                            {move || {
-                                                if editing_todo.get().is_some() {
-                                                    "Updating..."
-                                                } else {
-                                                    "Creating..."
-                                                }
-                                            }}
-                                        </Show>
+                                        {move || lang.t("common.undo")}
                                     </button>
                                 </div>
-                            </form>
+                            }
+                        }
+                    />
+                </div>
+
+                // Floating action bar for the selected todos
+                <Show when=move || selection_mode.get() && !selected_ids.get().is_empty()>
+                    <div class="fixed bottom-6 left-1/2 -translate-x-1/2 z-40 bg-white dark:bg-gray-800 rounded-2xl shadow-2xl border border-gray-200 dark:border-gray-700 px-4 py-3 flex flex-wrap items-center gap-3">
+                        <span class="text-sm font-medium text-gray-700 dark:text-gray-200">
+                            {move || lang.t_with("bulk.selected", &[("count", &selected_ids.get().len())])}
+                        </span>
+                        <button
+                            on:click=bulk_complete
+                            class="px-3 py-1.5 text-sm text-white bg-green-600 rounded-lg hover:bg-green-700 transition-colors disabled:opacity-50"
+                            disabled=is_bulk_pending
+                        >
+                            {move || lang.t("bulk.mark_completed")}
+                        </button>
+                        <button
+                            on:click=bulk_delete
+                            class="px-3 py-1.5 text-sm text-white bg-red-600 rounded-lg hover:bg-red-700 transition-colors disabled:opacity-50"
+                            disabled=is_bulk_pending
+                        >
+                            {move || lang.t("common.delete")}
+                        </button>
+                        <div class="flex items-center gap-2">
+                            <select
+                                prop:value=move || bulk_assignee.get()
+                                on:change=move |ev| set_bulk_assignee.set(event_target_value(&ev))
+                                class="px-2 py-1.5 border border-gray-300 dark:border-gray-600 rounded-lg text-sm focus:ring-2 focus:ring-purple-500 focus:border-transparent"
+                            >
+                                <option value="">{move || lang.t("bulk.assign_to")}</option>
+                                {move || {
+                                    assignees
+                                        .get()
+                                        .into_iter()
+                                        .map(|name| {
+                                            view! { <option value=name.clone()>{name.clone()}</option> }
+                                        })
+                                        .collect_view()
+                                }}
+                            </select>
+                            <button
+                                on:click=bulk_reassign
+                                class="px-3 py-1.5 text-sm text-purple-600 border border-purple-200 rounded-lg hover:bg-purple-50 transition-colors disabled:opacity-50"
+                                disabled=move || is_bulk_pending() || bulk_assignee.get().is_empty()
+                            >
+                                {move || lang.t("bulk.reassign")}
+                            </button>
                         </div>
+                        <Show when=is_bulk_pending>
+                            <div class="animate-spin rounded-full h-4 w-4 border-b-2 border-purple-600"></div>
+                        </Show>
                     </div>
                 </Show>
+
+                <Show when=move || show_change_password.get()>
+                    <ChangePasswordDialog on_close=move |()| set_show_change_password.set(false) />
+                </Show>
+
+                <Show when=move || show_reassign.get()>
+                    <ReassignDialog
+                        assignees=assignees
+                        on_reassigned=move |()| {
+                            bypass_cache.set_value(true);
+                            load_todos_action.dispatch(None);
+                        }
+                        on_close=move |()| set_show_reassign.set(false)
+                    />
+                </Show>
+
+                // Development helpers; the server refuses them in production
+                {cfg!(debug_assertions)
+                    .then(|| {
+                        view! {
+                            <Show when=move || can_write.get()>
+                                <DevTools on_wiped=move |()| {
+                                    bypass_cache.set_value(true);
+                                    load_todos_action.dispatch(None);
+                                } />
+                            </Show>
+                        }
+                    })}
+
+                // Modal for creating/editing todos
+                <Show when=move || show_modal.get()>
+                    <TodoDialog
+                        fields=form
+                        editing=editing_todo
+                        assignees=assignees
+                        can_write=can_write
+                        saving=Signal::derive(move || is_creating() || is_updating())
+                        uploading=upload_attachment_action.pending()
+                        commenting=add_comment_action.pending()
+                        tz=tz
+                        on_submit=handle_submit
+                        on_upload=move |file: SelectedFile| {
+                            upload_attachment_action.dispatch(file);
+                        }
+                        on_comment=move |text: String| {
+                            if let Some(todo) = editing_todo.get_untracked() {
+                                add_comment_action.dispatch((todo.id, text));
+                            }
+                        }
+                        on_error=move |message: String| set_error_message.set(message)
+                        on_cancel=move |()| {
+                            reset_form();
+                            set_show_modal.set(false);
+                        }
+                        on_close=move |()| set_show_modal.set(false)
+                    />
+                </Show>
             </main>
-            <StatusBar />
+            <StatusBar on_status_change=Callback::new(move |status: ServerStatus| {
+                set_server_status.set(status);
+            }) />
         </ErrorBoundary>
     }
 }
@@ -1,5 +1,7 @@
+use crate::components::theme::ThemeToggle;
 use crate::domain::auth::context::use_auth;
 use crate::domain::auth::model::LoginRequest;
+use crate::i18n::{LanguageSelect, use_lang};
 use leptos::leptos_dom::logging;
 use leptos::{ev, prelude::*};
 use leptos_router::{NavigateOptions, hooks::use_navigate};
@@ -14,10 +16,12 @@ const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub fn LoginPage() -> impl IntoView {
     let (username, set_username) = signal(String::new());
     let (password, set_password) = signal(String::new());
+    let (remember_me, set_remember_me) = signal(false);
     let (error, set_error) = signal(String::new());
 
     // Use the auth context instead of manual state management
     let auth = use_auth();
+    let lang = use_lang();
     let navigate = use_navigate();
 
     let handle_login = move |ev: ev::SubmitEvent| {
@@ -27,6 +31,7 @@ pub fn LoginPage() -> impl IntoView {
         let credentials = LoginRequest {
             username: username.get(),
             password: password.get(),
+            remember_me: remember_me.get(),
         };
 
         match credentials.validate() {
@@ -37,7 +42,11 @@ pub fn LoginPage() -> impl IntoView {
                 ));
             }
             Err(e) => {
-                set_error.set(format!("Invalid username or password: {e}"));
+                set_error.set(
+                    lang.locale
+                        .get_untracked()
+                        .t_with("login.invalid_credentials", &[("error", &e)]),
+                );
                 logging::console_debug_warn(&format!("Validation error: {e}"));
                 return;
             }
@@ -64,14 +73,18 @@ pub fn LoginPage() -> impl IntoView {
             }
         } else if let Some(Err(e)) = auth.login.value().get() {
             leptos::logging::error!("Login error: {}", e);
-            set_error.set("Authentication failed. Please try again.".to_string());
+            set_error.set(lang.locale.get_untracked().t("login.failed").to_string());
         }
     });
 
     view! {
-        <main class="flex items-center justify-center min-h-screen bg-gradient-to-br from-fuchsia-100 via-sky-100 to-indigo-200">
+        <main class="flex items-center justify-center min-h-screen bg-gradient-to-br from-fuchsia-100 via-sky-100 to-indigo-200 dark:from-gray-900 dark:via-slate-900 dark:to-indigo-950">
+            <div class="fixed top-4 right-4 flex gap-2">
+                <LanguageSelect />
+                <ThemeToggle />
+            </div>
             <div class="w-full max-w-md transform transition-all hover:scale-[1.02]">
-                <div class="relative bg-white/90 backdrop-blur-sm rounded-2xl shadow-xl overflow-hidden border border-indigo-100">
+                <div class="relative bg-white/90 dark:bg-gray-800/90 backdrop-blur-sm rounded-2xl shadow-xl overflow-hidden border border-indigo-100 dark:border-gray-700">
                     // Decorative top border
                     <div class="absolute top-0 left-0 right-0 h-3 bg-gradient-to-r from-purple-500 via-fuchsia-500 to-indigo-500"></div>
 
@@ -100,10 +113,10 @@ pub fn LoginPage() -> impl IntoView {
                                 </div>
                             </div>
                             <h1 class="text-3xl font-extrabold bg-clip-text text-transparent bg-gradient-to-r from-purple-600 via-fuchsia-600 to-indigo-600">
-                                "Family Leppänen Todos"
+                                {move || lang.t("app.title")}
                             </h1>
-                            <p class="mt-2 text-gray-600 font-medium">
-                                "Sign in to manage your tasks"
+                            <p class="mt-2 text-gray-600 dark:text-gray-300 font-medium">
+                                {move || lang.t("login.subtitle")}
                             </p>
                         </div>
 
@@ -117,7 +130,7 @@ pub fn LoginPage() -> impl IntoView {
                                     class="block w-full px-4 py-3 bg-indigo-50/50 border-0 rounded-xl shadow-sm placeholder-indigo-400 focus:outline-none focus:ring-2 focus:ring-fuchsia-500 transition-all disabled:opacity-50 disabled:cursor-not-allowed"
                                     prop:value=move || username.get()
                                     on:input=move |ev| set_username.set(event_target_value(&ev))
-                                    placeholder="Username"
+                                    placeholder=move || lang.t("login.username")
                                 />
                             </div>
 
@@ -130,10 +143,40 @@ pub fn LoginPage() -> impl IntoView {
                                     class="block w-full px-4 py-3 bg-indigo-50/50 border-0 rounded-xl shadow-sm placeholder-indigo-400 focus:outline-none focus:ring-2 focus:ring-fuchsia-500 transition-all disabled:opacity-50 disabled:cursor-not-allowed"
                                     prop:value=move || password.get()
                                     on:input=move |ev| set_password.set(event_target_value(&ev))
-                                    placeholder="Password"
+                                    placeholder=move || lang.t("login.password")
                                 />
                             </div>
 
+                            <label class="flex items-center gap-2 text-sm text-gray-600 dark:text-gray-300 select-none">
+                                <input
+                                    id="remember-me"
+                                    type="checkbox"
+                                    disabled=move || auth.login.pending().get()
+                                    class="rounded border-indigo-300 text-fuchsia-600 focus:ring-fuchsia-500"
+                                    prop:checked=move || remember_me.get()
+                                    on:change=move |ev| set_remember_me.set(event_target_checked(&ev))
+                                />
+                                {move || lang.t("login.remember_me")}
+                            </label>
+
+                            // Confirmation after signing out everywhere
+                            <Show when=move || {
+                                matches!(auth.logout_all.value().get(), Some(Ok(_)))
+                            }>
+                                <div class="p-3 rounded-xl bg-green-50 border border-green-100 shadow-sm">
+                                    <p class="text-sm font-medium text-green-700">
+                                        {move || {
+                                            match auth.logout_all.value().get() {
+                                                Some(Ok(count)) => {
+                                                    lang.t_with("login.signed_out_everywhere", &[("count", &count)])
+                                                }
+                                                _ => String::new(),
+                                            }
+                                        }}
+                                    </p>
+                                </div>
+                            </Show>
+
                             // Error display
                             <Show when=move || !error.get().is_empty() fallback=|| "">
                                 <div class="p-3 rounded-xl bg-red-50 border border-red-100 shadow-sm">
@@ -168,7 +211,7 @@ pub fn LoginPage() -> impl IntoView {
                             >
                                 <Show
                                     when=move || auth.login.pending().get()
-                                    fallback=|| view! { "Sign In" }
+                                    fallback=move || view! { {move || lang.t("login.sign_in")} }
                                 >
                                     <div class="flex items-center space-x-2">
                                         <svg
@@ -191,7 +234,7 @@ pub fn LoginPage() -> impl IntoView {
                                                 d="m12 2a10 10 0 0 0-10 10h4a6 6 0 0 1 6-6v-4z"
                                             ></path>
                                         </svg>
-                                        <span>"Signing In..."</span>
+                                        <span>{move || lang.t("login.signing_in")}</span>
                                     </div>
                                 </Show>
                             </button>
@@ -201,9 +244,7 @@ pub fn LoginPage() -> impl IntoView {
 
                 <div class="mt-4 text-center">
                     <p class="text-xs font-medium bg-clip-text text-transparent bg-gradient-to-r from-purple-600 to-indigo-600">
-                        {format!(
-                            "© 2025 Family Leppänen · v{APP_VERSION} · All rights reserved",
-                        )}
+                        {move || lang.t_with("login.footer", &[("version", &APP_VERSION)])}
                     </p>
                 </div>
             </div>
@@ -1,2 +1,4 @@
 pub mod home;
 pub mod login;
+pub mod print;
+pub mod todo_detail;
@@ -0,0 +1,276 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use leptos::prelude::*;
+use leptos::web_sys;
+use leptos_router::hooks::use_query_map;
+
+use crate::api::{
+    create_share_token_server, get_share_token_server, get_shared_todos_server, get_todos_server,
+    revoke_share_token_server,
+};
+use crate::domain::auth::use_auth;
+use crate::domain::todo::{Todo, TodoStatus};
+use crate::i18n::{DateFormat, use_lang};
+use crate::utils::{unsanitize_string, user_date, user_tz};
+
+/// Most todos fetched for printing; the list is not paged on paper.
+const PRINT_PAGE_SIZE: u32 = 1000;
+
+/// Open todos grouped by due day, days in order and undated todos last.
+fn group_by_day(
+    todos: Vec<Todo>,
+    tz: chrono_tz::Tz,
+) -> BTreeMap<(bool, Option<NaiveDate>), Vec<Todo>> {
+    let mut groups: BTreeMap<(bool, Option<NaiveDate>), Vec<Todo>> = BTreeMap::new();
+    for todo in todos
+        .into_iter()
+        .filter(|todo| todo.status != TodoStatus::Completed)
+    {
+        let day = todo.due_date.and_then(|due| user_date(due, tz));
+        groups.entry((day.is_none(), day)).or_default().push(todo);
+    }
+    for todos in groups.values_mut() {
+        todos.sort_by_key(|todo| todo.due_date);
+    }
+    groups
+}
+
+/// Black-and-white list of the open todos, grouped by due date, for printing.
+///
+/// Opened with `?token=<share token>` it shows the todos shared by that link
+/// without a login; otherwise it shows the signed-in user's todos and lets
+/// them create or revoke their share link. Controls are hidden when printing.
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+pub fn PrintPage() -> impl IntoView {
+    let auth = use_auth();
+    let lang = use_lang();
+    let locale = move || lang.locale.get_untracked();
+    let query = use_query_map();
+    let share_token = move || query.with(|query| query.get("token"));
+    let tz = user_tz();
+
+    let (todos, set_todos) = signal(Vec::<Todo>::new());
+    let (error_message, set_error_message) = signal(String::new());
+    let (own_share_token, set_own_share_token) = signal(None::<String>);
+
+    let load_todos_action = Action::new(move |token: &Option<String>| {
+        let token = token.clone();
+        async move {
+            match token {
+                Some(token) => get_shared_todos_server(token).await,
+                None => get_todos_server(None, Some(PRINT_PAGE_SIZE), None, None, None, None)
+                    .await
+                    .map(|page| page.todos),
+            }
+        }
+    });
+    let load_share_token_action =
+        Action::new(move |(): &()| async move { get_share_token_server().await });
+    let create_share_token_action =
+        Action::new(move |(): &()| async move { create_share_token_server().await });
+    let revoke_share_token_action =
+        Action::new(move |(): &()| async move { revoke_share_token_server().await });
+
+    // Shared links load without a session; the own list waits for the login
+    Effect::new(move |_| {
+        let token = share_token();
+        if token.is_some() {
+            load_todos_action.dispatch(token);
+        } else if auth.is_authenticated.get() {
+            load_todos_action.dispatch(None);
+            load_share_token_action.dispatch(());
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some(result) = load_todos_action.value().get() {
+            match result {
+                Ok(loaded) => {
+                    set_todos.set(loaded);
+                    set_error_message.set(String::new());
+                }
+                Err(e) => {
+                    set_error_message.set(locale().t_with("error.load_todos", &[("error", &e)]));
+                }
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some(Ok(token)) = load_share_token_action.value().get() {
+            set_own_share_token.set(token);
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some(result) = create_share_token_action.value().get() {
+            match result {
+                Ok(token) => set_own_share_token.set(Some(token)),
+                Err(e) => set_error_message
+                    .set(locale().t_with("error.create_share_link", &[("error", &e)])),
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some(result) = revoke_share_token_action.value().get() {
+            match result {
+                Ok(()) => set_own_share_token.set(None),
+                Err(e) => set_error_message
+                    .set(locale().t_with("error.revoke_share_link", &[("error", &e)])),
+            }
+        }
+    });
+
+    let share_url = move || {
+        own_share_token.get().map(|token| {
+            let origin = web_sys::window()
+                .and_then(|window| window.location().origin().ok())
+                .unwrap_or_default();
+            format!("{origin}/print?token={token}")
+        })
+    };
+
+    let format_day = move |day: Option<NaiveDate>| {
+        let locale = lang.locale.get();
+        day.map_or_else(
+            || locale.t("form.no_due_date").to_string(),
+            |day| locale.format_date(day, DateFormat::Weekday),
+        )
+    };
+    let format_time = move |todo: &Todo| {
+        let locale = lang.locale.get();
+        todo.due_date
+            .map(|due| locale.format_timestamp(due, tz, DateFormat::Time))
+    };
+
+    view! {
+        <div class="max-w-3xl mx-auto p-8 bg-white text-black min-h-screen print:p-0">
+            <div class="flex justify-between items-center mb-6 print:hidden">
+                <a href="/" class="text-sm underline">
+                    {move || lang.t("print.back")}
+                </a>
+                <button
+                    on:click=move |_| {
+                        if let Some(window) = web_sys::window() {
+                            if let Err(e) = window.print() {
+                                leptos::logging::warn!("Failed to open the print dialog: {:?}", e);
+                            }
+                        }
+                    }
+                    class="px-4 py-2 border border-black rounded-lg hover:bg-gray-100"
+                >
+                    {move || lang.t("print.print")}
+                </button>
+            </div>
+
+            <h1 class="text-2xl font-bold mb-6">{move || lang.t("home.title")}</h1>
+
+            <Show when=move || !error_message.get().is_empty()>
+                <p class="mb-4 border border-black p-3 print:hidden">{move || error_message.get()}</p>
+            </Show>
+
+            <Show when=move || share_token().is_none() && !auth.is_authenticated.get()>
+                <p class="mb-4">{move || lang.t("print.sign_in")}</p>
+            </Show>
+
+            // Share link of the signed-in user
+            <Show when=move || share_token().is_none() && auth.is_authenticated.get()>
+                <div class="mb-8 border border-black p-4 space-y-2 print:hidden">
+                    <h2 class="font-semibold">{move || lang.t("print.share_link")}</h2>
+                    <p class="text-sm">
+                        {move || lang.t("print.share_hint")}
+                    </p>
+                    {move || match share_url() {
+                        Some(url) => {
+                            view! {
+                                <div class="flex flex-wrap gap-2">
+                                    <input
+                                        type="text"
+                                        readonly=true
+                                        prop:value=url
+                                        class="flex-1 min-w-0 px-2 py-1 border border-black text-sm"
+                                    />
+                                    <button
+                                        on:click=move |_| {
+                                            create_share_token_action.dispatch(());
+                                        }
+                                        class="px-3 py-1 border border-black text-sm hover:bg-gray-100"
+                                        title=move || lang.t("print.new_link_hint")
+                                    >
+                                        {move || lang.t("print.new_link")}
+                                    </button>
+                                    <button
+                                        on:click=move |_| {
+                                            revoke_share_token_action.dispatch(());
+                                        }
+                                        class="px-3 py-1 border border-black text-sm hover:bg-gray-100"
+                                    >
+                                        {move || lang.t("print.revoke")}
+                                    </button>
+                                </div>
+                            }
+                                .into_any()
+                        }
+                        None => {
+                            view! {
+                                <button
+                                    on:click=move |_| {
+                                        create_share_token_action.dispatch(());
+                                    }
+                                    class="px-3 py-1 border border-black text-sm hover:bg-gray-100 disabled:opacity-50"
+                                    disabled=move || create_share_token_action.pending().get()
+                                >
+                                    {move || lang.t("print.create_link")}
+                                </button>
+                            }
+                                .into_any()
+                        }
+                    }}
+                </div>
+            </Show>
+
+            <Show when=move || load_todos_action.pending().get()>
+                <p class="print:hidden">{move || lang.t("home.loading")}</p>
+            </Show>
+
+            <div class="space-y-6">
+                {move || {
+                    group_by_day(todos.get(), tz)
+                        .into_iter()
+                        .map(|((_, day), todos_on_day)| {
+                            view! {
+                                <section class="break-inside-avoid">
+                                    <h2 class="text-lg font-semibold border-b border-black mb-2">
+                                        {format_day(day)}
+                                    </h2>
+                                    <ul class="space-y-1">
+                                        {todos_on_day
+                                            .into_iter()
+                                            .map(|todo| {
+                                                let time = format_time(&todo);
+                                                view! {
+                                                    <li class="flex gap-3">
+                                                        <span aria-hidden="true">"☐"</span>
+                                                        <span class="flex-1">
+                                                            {unsanitize_string(&todo.title)}
+                                                        </span>
+                                                        <span class="text-sm">{todo.assignee.to_string()}</span>
+                                                        <span class="text-sm w-12 text-right">{time}</span>
+                                                    </li>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </ul>
+                                </section>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,297 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_params_map;
+
+use crate::api::get_todo_server;
+use crate::domain::todo::{Todo, UNKNOWN_CREATOR};
+use crate::i18n::{DateFormat, use_lang};
+use crate::utils::{render_markdown, unsanitize_string, user_tz};
+
+/// What the page shows for the requested todo.
+#[derive(Clone, Debug)]
+enum Loaded {
+    Found(Box<Todo>),
+    NotFound,
+    Failed(String),
+}
+
+/// Full view of a single todo at `/todo/:id`, so it can be linked to.
+///
+/// Shows the whole description, the comments and attachments, and when the
+/// todo was created, last edited and completed, and by whom. An unknown id, or
+/// one of a todo in the trash, gets a message with a link back to the list.
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+pub fn TodoDetail() -> impl IntoView {
+    let lang = use_lang();
+    let params = use_params_map();
+    let todo_id = move || params.with(|params| params.get("id")).unwrap_or_default();
+    let tz = user_tz();
+
+    let (loaded, set_loaded) = signal(None::<Loaded>);
+
+    let load_todo_action = Action::new(move |id: &String| {
+        let id = id.clone();
+        async move { get_todo_server(id).await }
+    });
+
+    Effect::new(move |_| {
+        set_loaded.set(None);
+        load_todo_action.dispatch(todo_id());
+    });
+
+    Effect::new(move |_| {
+        if let Some(result) = load_todo_action.value().get() {
+            set_loaded.set(Some(match result {
+                Ok(Some(todo)) => Loaded::Found(Box::new(todo)),
+                Ok(None) => Loaded::NotFound,
+                Err(e) => Loaded::Failed(e.to_string()),
+            }));
+        }
+    });
+
+    let format_timestamp = move |timestamp: u64| {
+        lang.locale
+            .get()
+            .format_timestamp(timestamp, tz, DateFormat::DueDate)
+    };
+
+    let todo_view = move |todo: Todo| {
+        let created_by = (todo.created_by != UNKNOWN_CREATOR).then_some(todo.created_by);
+        let edited = (!todo.updated_by.is_empty()).then_some((todo.updated_by, todo.updated_at));
+        let tags = todo.tags;
+        let comments = todo.comments;
+        let attachments = todo.attachments;
+
+        view! {
+            <article class="bg-white dark:bg-gray-800 rounded-2xl shadow-lg p-6 space-y-6">
+                <header class="space-y-3">
+                    <h1 class="text-2xl font-bold text-gray-900 dark:text-gray-100">
+                        {unsanitize_string(&todo.title)}
+                    </h1>
+                    <div class="flex flex-wrap gap-2 text-xs font-medium">
+                        <span class="px-2 py-1 rounded-full bg-gray-100 text-gray-800 dark:bg-gray-700 dark:text-gray-100">
+                            {move || lang.status(todo.status)}
+                        </span>
+                        <span class="px-2 py-1 rounded-full bg-gray-100 text-gray-800 dark:bg-gray-700 dark:text-gray-100">
+                            {move || lang.priority(todo.priority)}
+                        </span>
+                        <span class="px-2 py-1 rounded-full bg-purple-100 text-purple-800">
+                            {todo.assignee.to_string()}
+                        </span>
+                        {tags
+                            .into_iter()
+                            .map(|tag| {
+                                view! {
+                                    <span class="px-2 py-1 rounded-full bg-indigo-50 text-indigo-700">
+                                        {format!("#{tag}")}
+                                    </span>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                    <p class="text-sm text-gray-600 dark:text-gray-300">
+                        {move || match todo.due_date {
+                            Some(due) => {
+                                lang.t_with("card.due_on", &[("date", &format_timestamp(due))])
+                            }
+                            None => lang.t("filter.no_due_date").to_string(),
+                        }}
+                    </p>
+                </header>
+
+                <section>
+                    <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                        {move || lang.t("field.description")}
+                    </h2>
+                    {match todo.description.as_deref() {
+                        Some(description) => {
+                            view! {
+                                <div
+                                    class="space-y-2 text-gray-700 dark:text-gray-200 [&_a]:underline [&_a]:text-purple-600 [&_ul]:list-disc [&_ul]:pl-5 [&_ol]:list-decimal [&_ol]:pl-5 [&_code]:bg-gray-100 [&_code]:px-1 [&_code]:rounded"
+                                    inner_html=render_markdown(description)
+                                ></div>
+                            }
+                                .into_any()
+                        }
+                        None => {
+                            view! {
+                                <p class="text-sm text-gray-400">
+                                    {move || lang.t("detail.no_description")}
+                                </p>
+                            }
+                                .into_any()
+                        }
+                    }}
+                </section>
+
+                {(!attachments.is_empty())
+                    .then(|| {
+                        view! {
+                            <section>
+                                <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                                    {move || lang.t("field.attachments")}
+                                </h2>
+                                <ul class="space-y-1">
+                                    {attachments
+                                        .into_iter()
+                                        .map(|attachment| {
+                                            view! {
+                                                <li>
+                                                    <a
+                                                        href=attachment.href()
+                                                        target="_blank"
+                                                        rel="noopener noreferrer"
+                                                        class="text-sm text-purple-600 underline"
+                                                    >
+                                                        {attachment.name}
+                                                    </a>
+                                                </li>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </ul>
+                            </section>
+                        }
+                    })}
+
+                <section>
+                    <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                        {move || lang.t("comments.title")}
+                    </h2>
+                    {if comments.is_empty() {
+                        view! {
+                            <p class="text-sm text-gray-400">{move || lang.t("comments.empty")}</p>
+                        }
+                            .into_any()
+                    } else {
+                        view! {
+                            <div class="space-y-2">
+                                {comments
+                                    .into_iter()
+                                    .map(|comment| {
+                                        let created_at = comment.created_at;
+                                        view! {
+                                            <div class="p-2 bg-gray-50 dark:bg-gray-700 rounded-lg">
+                                                <p class="text-xs text-gray-500 dark:text-gray-400">
+                                                    {move || {
+                                                        format!(
+                                                            "{} · {}",
+                                                            comment.author,
+                                                            lang
+                                                                .locale
+                                                                .get()
+                                                                .format_timestamp(created_at, tz, DateFormat::Timestamp),
+                                                        )
+                                                    }}
+                                                </p>
+                                                <p class="text-sm text-gray-800 dark:text-gray-100 whitespace-pre-wrap">
+                                                    {comment.text}
+                                                </p>
+                                            </div>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </div>
+                        }
+                            .into_any()
+                    }}
+                </section>
+
+                <section>
+                    <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                        {move || lang.t("detail.history")}
+                    </h2>
+                    <ul class="space-y-1 text-sm text-gray-600 dark:text-gray-300">
+                        <li>
+                            {move || match &created_by {
+                                Some(name) => {
+                                    lang.t_with(
+                                        "detail.created_by",
+                                        &[("date", &format_timestamp(todo.created_at)), ("name", name)],
+                                    )
+                                }
+                                None => {
+                                    lang.t_with(
+                                        "detail.created",
+                                        &[("date", &format_timestamp(todo.created_at))],
+                                    )
+                                }
+                            }}
+                        </li>
+                        {edited
+                            .map(|(name, updated_at)| {
+                                view! {
+                                    <li>
+                                        {move || {
+                                            lang.t_with(
+                                                "detail.edited_by",
+                                                &[("date", &format_timestamp(updated_at)), ("name", &name)],
+                                            )
+                                        }}
+                                    </li>
+                                }
+                            })}
+                        {todo
+                            .completed_at
+                            .map(|completed_at| {
+                                view! {
+                                    <li>
+                                        {move || {
+                                            lang.t_with(
+                                                "detail.completed",
+                                                &[("date", &format_timestamp(completed_at))],
+                                            )
+                                        }}
+                                    </li>
+                                }
+                            })}
+                    </ul>
+                </section>
+            </article>
+        }
+    };
+
+    view! {
+        <div class="min-h-screen bg-gradient-to-br from-purple-50 via-white to-fuchsia-50 dark:from-gray-900 dark:via-gray-900 dark:to-gray-800">
+            <div class="max-w-3xl mx-auto px-4 py-8 space-y-6">
+                <a href="/" class="text-sm text-purple-600 hover:text-purple-800 underline">
+                    {move || lang.t("detail.back")}
+                </a>
+
+                {move || match loaded.get() {
+                    None => {
+                        view! {
+                            <p class="text-gray-500 dark:text-gray-400">
+                                {move || lang.t("detail.loading")}
+                            </p>
+                        }
+                            .into_any()
+                    }
+                    Some(Loaded::Found(todo)) => todo_view(*todo).into_any(),
+                    Some(Loaded::NotFound) => {
+                        view! {
+                            <div class="bg-white dark:bg-gray-800 rounded-2xl shadow-lg p-6 space-y-3">
+                                <p class="text-gray-800 dark:text-gray-100">
+                                    {move || lang.t("detail.not_found")}
+                                </p>
+                                <a href="/" class="text-sm text-purple-600 hover:text-purple-800 underline">
+                                    {move || lang.t("detail.back")}
+                                </a>
+                            </div>
+                        }
+                            .into_any()
+                    }
+                    Some(Loaded::Failed(error)) => {
+                        view! {
+                            <p class="bg-red-50 border border-red-200 text-red-700 rounded-lg p-3">
+                                {move || lang.t_with("detail.load_failed", &[("error", &error)])}
+                            </p>
+                        }
+                            .into_any()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
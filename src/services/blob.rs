@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::config::BlobConfig;
+use crate::domain::todo::{ATTACHMENT_CONTENT_TYPES, Attachment, is_blob_path};
+
+/// How long an upload can be added to a todo before it must be uploaded again.
+const UPLOAD_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// An upload not necessarily saved on a todo yet, and the group it was made for.
+struct Upload {
+    group_id: String,
+    attachment: Attachment,
+    uploaded_at: Instant,
+}
+
+/// Uploads todo attachments to a private Azure Blob Storage container and
+/// reads them back for the attachment route.
+pub struct BlobService {
+    http: reqwest::Client,
+    container_url: String,
+    sas_token: String,
+    max_attachment_bytes: u64,
+    /// Recent uploads by URL; only these and attachments already stored on a
+    /// todo may be saved on one
+    uploads: Mutex<HashMap<String, Upload>>,
+}
+
+impl BlobService {
+    /// Creates a blob service for the configured container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Blob Storage is not configured or the HTTP client
+    /// cannot be created.
+    pub fn new(config: &BlobConfig) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !config.is_configured() {
+            return Err("AZURE_STORAGE_ACCOUNT_URL is not set".into());
+        }
+
+        Ok(Self {
+            http: reqwest::Client::builder().build()?,
+            container_url: config.container_url(),
+            sas_token: config.sas_token.clone(),
+            max_attachment_bytes: config.max_attachment_bytes,
+            uploads: Mutex::new(HashMap::new()),
+        })
+    }
+
+    // A panic while holding the lock cannot leave an entry half written
+    fn uploads(&self) -> MutexGuard<'_, HashMap<String, Upload>> {
+        self.uploads.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// URL of the container, without a trailing slash or the SAS token.
+    #[must_use]
+    pub fn container_url(&self) -> &str {
+        &self.container_url
+    }
+
+    /// The blob path of `url`, if it points into this container at a blob
+    /// named like uploads are.
+    #[must_use]
+    pub fn blob_path<'a>(&self, url: &'a str) -> Option<&'a str> {
+        url.strip_prefix(self.container_url.as_str())?
+            .strip_prefix('/')
+            .filter(|path| is_blob_path(path))
+    }
+
+    /// The attachment uploaded at `url` for `group_id` within the last day, as
+    /// recorded by [`BlobService::upload`] rather than as sent by a client.
+    #[must_use]
+    pub fn uploaded(&self, url: &str, group_id: &str) -> Option<Attachment> {
+        let mut uploads = self.uploads();
+        uploads.retain(|_, upload| upload.uploaded_at.elapsed() < UPLOAD_TTL);
+        uploads
+            .get(url)
+            .filter(|upload| upload.group_id == group_id)
+            .map(|upload| upload.attachment.clone())
+    }
+
+    /// Stores `data` as a new block blob for `group_id` and returns the
+    /// attachment pointing to it.
+    ///
+    /// Each upload gets its own blob name, so files with the same name never
+    /// overwrite each other. The upload is remembered for a day, which is how
+    /// long it can be saved on a todo of the group.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file is empty or too large, if its content type is
+    /// not allowed, or if the upload fails.
+    pub async fn upload(
+        &self,
+        group_id: &str,
+        file_name: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<Attachment, Box<dyn std::error::Error + Send + Sync>> {
+        let size = u64::try_from(data.len())?;
+        if size == 0 {
+            return Err("File is empty".into());
+        }
+        if size > self.max_attachment_bytes {
+            return Err(format!(
+                "File is {size} bytes, the limit is {} bytes",
+                self.max_attachment_bytes
+            )
+            .into());
+        }
+        if !ATTACHMENT_CONTENT_TYPES.contains(&content_type) {
+            return Err(format!("Files of type {content_type} are not allowed").into());
+        }
+
+        let url = format!(
+            "{}/{}/{}",
+            self.container_url,
+            uuid::Uuid::new_v4(),
+            blob_file_name(file_name)
+        );
+        let response = self
+            .http
+            .put(format!("{url}?{}", self.sas_token))
+            .header("x-ms-blob-type", "BlockBlob")
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(data)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            tracing::error!(file_name, %status, "Blob upload failed");
+            return Err(format!("Blob Storage rejected the upload: {status}").into());
+        }
+
+        let attachment = Attachment {
+            name: file_name.to_string(),
+            url,
+            content_type: content_type.to_string(),
+            size,
+        };
+        self.uploads().insert(
+            attachment.url.clone(),
+            Upload {
+                group_id: group_id.to_string(),
+                attachment: attachment.clone(),
+                uploaded_at: Instant::now(),
+            },
+        );
+        Ok(attachment)
+    }
+
+    /// Reads the blob at `blob_path`, see [`BlobService::blob_path`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not an upload's, or if Blob Storage
+    /// fails or refuses the read.
+    pub async fn download(
+        &self,
+        blob_path: &str,
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        if !is_blob_path(blob_path) {
+            return Err(format!("Invalid blob path: {blob_path}").into());
+        }
+        let response = self
+            .http
+            .get(format!(
+                "{}/{blob_path}?{}",
+                self.container_url, self.sas_token
+            ))
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            tracing::error!(blob_path, %status, "Blob download failed");
+            return Err(format!("Blob Storage rejected the download: {status}").into());
+        }
+        Ok(response.bytes().await?.to_vec())
+    }
+}
+
+/// Reduces a file name to characters that need no escaping in a blob URL.
+fn blob_file_name(file_name: &str) -> String {
+    let name: String = file_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.trim_matches('.').is_empty() {
+        "file".to_string()
+    } else {
+        name
+    }
+}
+
+// Global lazy-initialized instance
+static BLOB_SERVICE: std::sync::LazyLock<
+    Result<BlobService, Box<dyn std::error::Error + Send + Sync>>,
+> = std::sync::LazyLock::new(|| {
+    let app_config =
+        crate::config::get_config().map_err(|e| format!("Failed to get app config: {e}"))?;
+    BlobService::new(&app_config.blob)
+});
+
+/// Returns a reference to the global blob service instance.
+///
+/// # Errors
+///
+/// Returns an error if Blob Storage is not configured or the service failed to initialize.
+#[allow(clippy::borrowed_box)]
+pub fn get_blob_service()
+-> Result<&'static BlobService, &'static Box<dyn std::error::Error + Send + Sync>> {
+    BLOB_SERVICE.as_ref()
+}
@@ -1,9 +1,65 @@
-use crate::config::AppConfig;
+use std::sync::Arc;
+
+use crate::config::{AppConfig, ConfigError, CosmosAuthMethod};
+use async_trait::async_trait;
 use azure_core::credentials::Secret;
+use azure_core::http::headers::HeaderName;
+use azure_core::http::policies::{Policy, PolicyResult};
+use azure_core::http::{ClientOptions, Context, Request, StatusCode};
 use azure_data_cosmos::{
-    CosmosClient,
+    CosmosClient, CosmosClientOptions, CreateContainerOptions,
     clients::{ContainerClient, DatabaseClient},
+    models::{ContainerProperties, ThroughputProperties},
 };
+use azure_identity::DefaultAzureCredential;
+
+/// Path of the partition key of the todos container, matching `CosmosTodo::partition_key`.
+pub const PARTITION_KEY_PATH: &str = "/partition_key";
+
+const CONTINUATION: HeaderName = HeaderName::from_static("x-ms-continuation");
+const MAX_ITEM_COUNT: HeaderName = HeaderName::from_static("x-ms-max-item-count");
+
+/// Where a query starts and how many items its pages hold, passed in the
+/// context of `query_items`; the SDK has no query options for either.
+#[derive(Debug, Clone, Default)]
+pub struct QueryPaging {
+    /// Token of a previous page's `FeedPage::continuation`, `None` to start over
+    pub continuation: Option<String>,
+    pub max_item_count: u32,
+}
+
+impl QueryPaging {
+    /// Context for `QueryOptions` carrying this paging.
+    #[must_use]
+    pub fn into_context(self) -> Context<'static> {
+        Context::new().with_value(self)
+    }
+}
+
+/// Sends the [`QueryPaging`] of a request's context as Cosmos DB headers.
+///
+/// The pager sets the continuation of its own later pages, which is kept.
+#[derive(Debug)]
+struct QueryPagingPolicy;
+
+#[async_trait]
+impl Policy for QueryPagingPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        if let Some(paging) = ctx.value::<QueryPaging>() {
+            request.insert_header(MAX_ITEM_COUNT, paging.max_item_count.to_string());
+            let has_continuation = request.headers().get_optional_str(&CONTINUATION).is_some();
+            if let (Some(continuation), false) = (&paging.continuation, has_continuation) {
+                request.insert_header(CONTINUATION, continuation.clone());
+            }
+        }
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
 
 pub struct CosmosDBClient {
     client: CosmosClient,
@@ -14,15 +70,43 @@ pub struct CosmosDBClient {
 impl CosmosDBClient {
     /// Creates a new `CosmosDBClient` instance.
     ///
+    /// With `CosmosAuthMethod::AzureAD` the client authenticates with
+    /// `DefaultAzureCredential`, which picks up a managed identity, Azure AD
+    /// environment credentials or an Azure CLI login; otherwise the primary key
+    /// is used.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the `CosmosClient` fails to initialize with the provided configuration.
+    /// Returns a `ConfigError` if Azure AD authentication is configured but no
+    /// credential is available, or an error if the `CosmosClient` fails to
+    /// initialize with the provided configuration.
     pub fn new(config: &AppConfig) -> Result<Self, Box<dyn std::error::Error>> {
-        let client = CosmosClient::with_key(
-            &config.cosmos.uri,
-            Secret::from(config.cosmos.connection_string.clone()),
-            None,
-        )?;
+        let options = || {
+            Some(CosmosClientOptions {
+                client_options: ClientOptions {
+                    per_call_policies: vec![Arc::new(QueryPagingPolicy)],
+                    ..Default::default()
+                },
+            })
+        };
+        let client = match config.cosmos.auth_method {
+            CosmosAuthMethod::AzureAD => {
+                let credential = DefaultAzureCredential::new().map_err(|e| {
+                    tracing::error!(error = %e, "No Azure AD credential available");
+                    let config_line = "AZURE_COSMOS_AUTH_METHOD=azure_ad".to_string();
+                    ConfigError::CosmosConfigError {
+                        src: config_line.clone(),
+                        span: (25, config_line.len() - 25).into(),
+                    }
+                })?;
+                CosmosClient::new(&config.cosmos.uri, credential, options())?
+            }
+            CosmosAuthMethod::ConnectionString => CosmosClient::with_key(
+                &config.cosmos.uri,
+                Secret::from(config.cosmos.connection_string.clone()),
+                options(),
+            )?,
+        };
 
         Ok(Self {
             client,
@@ -31,6 +115,64 @@ impl CosmosDBClient {
         })
     }
 
+    /// Creates the database and the todos container unless they already exist,
+    /// giving a new container `throughput` RU/s. Safe to call on every startup.
+    ///
+    /// Azure AD identities with only a data plane role may not create either;
+    /// that is logged and the existing resources are assumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Cosmos DB fails a create for any other reason than
+    /// the resource existing already.
+    pub async fn ensure_provisioned(&self, throughput: u32) -> azure_core::Result<()> {
+        match self.client.create_database(&self.database_name, None).await {
+            Ok(_) => tracing::info!(database = %self.database_name, "Created Cosmos DB database"),
+            Err(e) if e.http_status() == Some(StatusCode::Conflict) => {
+                tracing::debug!(database = %self.database_name, "Cosmos DB database exists");
+            }
+            Err(e) if e.http_status() == Some(StatusCode::Forbidden) => {
+                tracing::warn!(
+                    database = %self.database_name,
+                    "Not allowed to create the Cosmos DB database, assuming it exists"
+                );
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        }
+
+        let properties = ContainerProperties {
+            id: self.container_name.clone().into(),
+            partition_key: PARTITION_KEY_PATH.into(),
+            ..Default::default()
+        };
+        let options = CreateContainerOptions {
+            throughput: Some(ThroughputProperties::manual(throughput)),
+            ..Default::default()
+        };
+        match self
+            .database()
+            .create_container(properties, Some(options))
+            .await
+        {
+            Ok(_) => tracing::info!(
+                container = %self.container_name,
+                partition_key = PARTITION_KEY_PATH,
+                throughput,
+                "Created Cosmos DB container"
+            ),
+            Err(e) if e.http_status() == Some(StatusCode::Conflict) => {
+                tracing::debug!(container = %self.container_name, "Cosmos DB container exists");
+            }
+            Err(e) if e.http_status() == Some(StatusCode::Forbidden) => tracing::warn!(
+                container = %self.container_name,
+                "Not allowed to create the Cosmos DB container, assuming it exists"
+            ),
+            Err(e) => return Err(e),
+        }
+        Ok(())
+    }
+
     #[must_use]
     pub fn database(&self) -> DatabaseClient {
         self.client.database_client(&self.database_name)
@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+use std::sync::{Mutex, PoisonError};
+
+use azure_core::http::headers::{HeaderName, Headers};
+use serde::Serialize;
+
+/// Header carrying the request units Cosmos DB charged for a request.
+const REQUEST_CHARGE: HeaderName = HeaderName::from_static("x-ms-request-charge");
+
+/// Request units charged for one kind of operation since the server started.
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq)]
+pub struct OperationCharge {
+    pub requests: u64,
+    pub request_units: f64,
+}
+
+/// Running totals of the request units charged per operation, for cost tuning.
+#[derive(Debug, Default)]
+pub struct RequestCharges {
+    totals: Mutex<BTreeMap<&'static str, OperationCharge>>,
+}
+
+impl RequestCharges {
+    /// Logs the charge of a response and adds it to the total of `operation`;
+    /// a query read in several pages records each page.
+    ///
+    /// Responses without the charge header are ignored.
+    pub fn record(&self, operation: &'static str, headers: &Headers) {
+        let Some(charge) = headers
+            .get_optional_str(&REQUEST_CHARGE)
+            .and_then(|value| value.parse::<f64>().ok())
+        else {
+            return;
+        };
+        tracing::debug!(
+            operation,
+            request_units = charge,
+            "{operation} consumed {charge:.2} RU"
+        );
+
+        let mut totals = self.totals.lock().unwrap_or_else(PoisonError::into_inner);
+        let total = totals.entry(operation).or_default();
+        total.requests += 1;
+        total.request_units += charge;
+    }
+
+    /// Totals per operation, by operation name.
+    #[must_use]
+    pub fn snapshot(&self) -> BTreeMap<&'static str, OperationCharge> {
+        self.totals
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
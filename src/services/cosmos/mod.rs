@@ -1,5 +1,7 @@
 pub mod client;
+pub mod metrics;
 pub mod model;
+pub mod retry;
 pub mod todo_repository;
 
 pub use client::*;
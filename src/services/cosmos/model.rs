@@ -2,7 +2,11 @@ use std::str::FromStr;
 
 use crate::{
     config::get_config,
-    domain::todo::{Todo, TodoAssignee, TodoStatus},
+    domain::auth::DEFAULT_GROUP_ID,
+    domain::todo::{
+        Attachment, Comment, Todo, TodoAssignee, TodoPriority, TodoStatus, UNKNOWN_CREATOR,
+    },
+    utils::validate_email,
 };
 
 use serde::{Deserialize, Serialize};
@@ -15,9 +19,26 @@ pub struct CosmosDbTodo {
     pub due_date: Option<u64>,
     pub assignee: String,
     pub status: String,
+    // Records created before priorities existed default to Medium
+    #[serde(default = "default_priority")]
+    pub priority: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
     pub created_at: u64,
+    // Username from the session that created the todo, never taken from the client
+    #[serde(default = "default_created_by")]
+    pub created_by: String,
     pub updated_at: u64,
+    // Username from the session of the last edit, empty until the todo is edited
+    #[serde(default)]
+    pub updated_by: String,
+    // Set by the service when the status transitions to Completed
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub completed_at: Option<u64>,
     pub partition_key: String,
+    // Household owning the todo; records created before groups existed belong to the default group
+    #[serde(default = "default_group_id")]
+    pub group_id: String,
     pub email: String,
     // Optional notification tracking fields for Azure Functions
     #[serde(skip_serializing_if = "Option::is_none", default = "default_false")]
@@ -28,6 +49,25 @@ pub struct CosmosDbTodo {
 
     #[serde(skip_serializing_if = "Option::is_none", default = "default_none")]
     pub last_notification_time: Option<i64>,
+
+    // Manual sort key, missing on todos that were never reordered
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub position: Option<f64>,
+
+    // Notes on the todo, oldest first; only changed through add_comment
+    #[serde(default)]
+    pub comments: Vec<Comment>,
+
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+
+    // Set when the todo is moved to the trash (soft delete)
+    #[serde(skip_serializing_if = "Option::is_none", default = "default_none")]
+    pub deleted_at: Option<i64>,
+
+    // Version stamped by Cosmos DB on every write; only read, used for If-Match on replace
+    #[serde(rename = "_etag", skip_serializing, default)]
+    pub etag: Option<String>,
 }
 
 // Helper functions for default values
@@ -39,42 +79,91 @@ fn default_none() -> Option<i64> {
     None
 }
 
+fn default_priority() -> String {
+    TodoPriority::default().as_str().to_string()
+}
+
+fn default_group_id() -> String {
+    DEFAULT_GROUP_ID.to_string()
+}
+
+fn default_created_by() -> String {
+    UNKNOWN_CREATOR.to_string()
+}
+
 impl CosmosDbTodo {
-    /// Converts a `Todo` into a `CosmosDbTodo` for database storage.
+    /// Converts a `Todo` into a `CosmosDbTodo` for database storage, stamped
+    /// with the `group_id` of the session that saves it and the username of
+    /// the todo's creator; `todo.created_by` and `todo.comments` are ignored.
     ///
     /// # Errors
     ///
-    /// Returns an error if the app configuration cannot be retrieved or if the
-    /// assignee email is not found in the configuration.
-    pub fn try_from_todo(todo: Todo) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+    /// Returns an error if the app configuration cannot be retrieved, or if the
+    /// assignee email is not found in the configuration or is malformed.
+    pub fn try_from_todo(
+        todo: Todo,
+        group_id: &str,
+        created_by: &str,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let config = get_config().map_err(|e| format!("Failed to get app config: {e}"))?;
+        let email = config
+            .emails
+            .email_for(todo.assignee.as_str())
+            .ok_or_else(|| format!("No email configured for assignee {}", todo.assignee))?
+            .to_string();
+        validate_email(&email).map_err(|e| {
+            format!(
+                "Email configured for assignee {} is invalid: {e}",
+                todo.assignee
+            )
+        })?;
+
+        Ok(Self::with_email(todo, group_id, created_by, email))
+    }
+
+    /// Converts a `Todo` like [`CosmosDbTodo::try_from_todo`], with the
+    /// assignee's `email` already looked up.
+    fn with_email(todo: Todo, group_id: &str, created_by: &str, email: String) -> Self {
         let now = chrono::Utc::now()
             .timestamp()
             .max(0)
             .try_into()
             .unwrap_or(0);
-        let config = get_config().map_err(|e| format!("Failed to get app config: {e}"))?;
-        let email = config
-            .emails
-            .get(&todo.assignee)
-            .ok_or("Assignee email not found")?;
 
-        let due_date = todo.due_date; // No conversion needed, already u64
+        // 0 is never a real due date; storing it would bring back the 1970 dates of old records
+        let due_date = todo.due_date.filter(|&timestamp| timestamp != 0);
 
-        Ok(Self {
+        Self {
             id: todo.id,
             title: todo.title,
             description: todo.description,
             due_date,
             assignee: todo.assignee.as_str().to_string(),
             status: todo.status.as_str().to_string(),
-            created_at: now,
+            priority: todo.priority.as_str().to_string(),
+            tags: todo.tags,
+            created_at: if todo.created_at == 0 {
+                now
+            } else {
+                todo.created_at
+            },
+            created_by: created_by.to_string(),
             updated_at: now,
+            updated_by: String::new(),
+            completed_at: todo.completed_at,
+            position: todo.position,
+            // Only CosmosService::add_comment writes comments, stamping their author
+            comments: Vec::new(),
+            attachments: todo.attachments,
             partition_key: "family_todos".to_string(),
-            email: email.clone(),
+            group_id: group_id.to_string(),
+            email,
             reminder_24h_sent: None,
             final_reminder_sent: None,
             last_notification_time: None,
-        })
+            deleted_at: None,
+            etag: None,
+        }
     }
 }
 
@@ -84,9 +173,145 @@ impl From<CosmosDbTodo> for Todo {
             id: cosmos_todo.id.parse().unwrap_or(String::new()), // Convert string ID back to usize for UI
             title: cosmos_todo.title,
             description: cosmos_todo.description,
-            due_date: Some(cosmos_todo.due_date.unwrap_or(0)), // Convert u64 back to i64 for UI
-            assignee: TodoAssignee::from_str(&cosmos_todo.assignee).unwrap_or(TodoAssignee::Mikko),
+            // Earlier versions stored a missing due date as 0 after a round trip
+            due_date: cosmos_todo.due_date.filter(|&timestamp| timestamp != 0),
+            assignee: TodoAssignee::from_str(&cosmos_todo.assignee)
+                .unwrap_or_else(|_| TodoAssignee::unchecked(cosmos_todo.assignee.clone())),
             status: TodoStatus::from_str(&cosmos_todo.status).unwrap_or(TodoStatus::Pending),
+            priority: TodoPriority::from_str(&cosmos_todo.priority).unwrap_or_default(),
+            tags: cosmos_todo.tags,
+            created_at: cosmos_todo.created_at,
+            created_by: cosmos_todo.created_by,
+            updated_at: cosmos_todo.updated_at,
+            updated_by: cosmos_todo.updated_by,
+            completed_at: cosmos_todo.completed_at,
+            position: cosmos_todo.position,
+            comments: cosmos_todo.comments,
+            attachments: cosmos_todo.attachments,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn todo() -> Todo {
+        Todo {
+            description: Some("Oat, not dairy".to_string()),
+            due_date: Some(1_767_225_600),
+            status: TodoStatus::Completed,
+            priority: TodoPriority::High,
+            tags: vec!["shopping".to_string()],
+            created_at: 1_700_000_000,
+            completed_at: Some(1_700_000_100),
+            position: Some(1.5),
+            attachments: vec![Attachment {
+                name: "list.pdf".to_string(),
+                url: "https://example.blob.core.windows.net/attachments/0f8fad5b-d9cb-469f-a165-70867728950e/list.pdf".to_string(),
+                content_type: "application/pdf".to_string(),
+                size: 1024,
+            }],
+            ..Todo::new("Buy milk".to_string(), "Mike".parse().unwrap())
+        }
+    }
+
+    fn round_trip(todo: Todo) -> Todo {
+        let cosmos_todo =
+            CosmosDbTodo::with_email(todo, "family", "mike", "mike@example.com".to_string());
+        let json = serde_json::to_string(&cosmos_todo).unwrap();
+        Todo::from(serde_json::from_str::<CosmosDbTodo>(&json).unwrap())
+    }
+
+    #[test]
+    fn todo_survives_a_round_trip() {
+        let todo = todo();
+
+        let stored = round_trip(todo.clone());
+
+        assert_eq!(
+            stored,
+            Todo {
+                created_by: "mike".to_string(),
+                updated_at: stored.updated_at,
+                ..todo
+            }
+        );
+    }
+
+    #[test]
+    fn round_trip_stamps_the_server_owned_fields() {
+        let mut todo = todo();
+        todo.created_at = 0;
+        todo.created_by = "eve".to_string();
+        todo.comments.push(Comment {
+            author: "eve".to_string(),
+            text: "forged".to_string(),
+            created_at: 0,
+        });
+
+        let cosmos_todo =
+            CosmosDbTodo::with_email(todo, "family", "mike", "mike@example.com".to_string());
+
+        assert_eq!(cosmos_todo.created_by, "mike");
+        assert_eq!(cosmos_todo.group_id, "family");
+        assert_eq!(cosmos_todo.email, "mike@example.com");
+        assert_ne!(cosmos_todo.created_at, 0);
+        assert!(cosmos_todo.comments.is_empty());
+    }
+
+    #[test]
+    fn missing_and_zero_due_dates_come_back_missing() {
+        let without = Todo {
+            due_date: None,
+            ..todo()
+        };
+        let zero = Todo {
+            due_date: Some(0),
+            ..todo()
+        };
+
+        assert_eq!(round_trip(without).due_date, None);
+        assert_eq!(round_trip(zero).due_date, None);
+    }
+
+    #[test]
+    fn documents_from_older_versions_get_defaults() {
+        let json = r#"{
+            "id": "0f8fad5b-d9cb-469f-a165-70867728950e",
+            "title": "Buy milk",
+            "description": null,
+            "due_date": 0,
+            "assignee": "Mike",
+            "status": "Pending",
+            "created_at": 1700000000,
+            "updated_at": 1700000000,
+            "partition_key": "family_todos",
+            "email": "mike@example.com",
+            "_etag": "\"0000d986-0000-0d00-0000-65a6d4a30000\""
+        }"#;
+
+        let cosmos_todo: CosmosDbTodo = serde_json::from_str(json).unwrap();
+        assert_eq!(cosmos_todo.group_id, DEFAULT_GROUP_ID);
+        assert!(cosmos_todo.etag.is_some());
+
+        let todo = Todo::from(cosmos_todo);
+        assert_eq!(todo.priority, TodoPriority::default());
+        assert_eq!(todo.created_by, UNKNOWN_CREATOR);
+        assert_eq!(todo.due_date, None);
+        assert!(todo.tags.is_empty());
+        assert!(todo.comments.is_empty());
+    }
+
+    #[test]
+    fn etag_is_never_written_back() {
+        let mut cosmos_todo =
+            CosmosDbTodo::with_email(todo(), "family", "mike", "mike@example.com".to_string());
+        cosmos_todo.etag = Some("\"etag\"".to_string());
+
+        let json = serde_json::to_value(&cosmos_todo).unwrap();
+
+        assert!(json.get("_etag").is_none());
+        assert!(json.get("etag").is_none());
+    }
+}
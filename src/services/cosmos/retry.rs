@@ -0,0 +1,212 @@
+use std::future::Future;
+use std::time::Duration;
+
+use azure_core::error::{Error as AzureError, ErrorKind, HttpError};
+use azure_core::http::StatusCode;
+
+/// Header Cosmos DB uses to tell how long to wait after throttling a request.
+const RETRY_AFTER_MS_HEADER: &str = "x-ms-retry-after-ms";
+
+/// Retries transient Cosmos DB failures with exponential backoff and jitter.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; 0 disables retrying
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    #[must_use]
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+
+    /// Runs `operation`, retrying it while it fails with a retryable error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first non-retryable error, or the last error once the retries
+    /// are used up.
+    pub async fn run<T, F, Fut>(&self, name: &str, operation: F) -> Result<T, AzureError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, AzureError>>,
+    {
+        self.run_while(name, operation, is_retryable).await
+    }
+
+    /// Runs a write conditioned on an etag (`If-Match`), retrying it only while
+    /// it is throttled.
+    ///
+    /// A write that timed out or failed on the server may still have been
+    /// applied and changed the etag, so retrying it would fail with a spurious
+    /// 412 (precondition failed). Throttled requests are never applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error that is not throttling, or the last error once
+    /// the retries are used up.
+    pub async fn run_conditional<T, F, Fut>(
+        &self,
+        name: &str,
+        operation: F,
+    ) -> Result<T, AzureError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, AzureError>>,
+    {
+        self.run_while(name, operation, is_throttled).await
+    }
+
+    async fn run_while<T, F, Fut>(
+        &self,
+        name: &str,
+        mut operation: F,
+        retryable: fn(&AzureError) -> bool,
+    ) -> Result<T, AzureError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, AzureError>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match operation().await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt < self.max_retries && retryable(&e) => {
+                    let delay = retry_after(&e).unwrap_or_else(|| self.backoff(attempt));
+                    attempt += 1;
+                    tracing::warn!(
+                        operation = name,
+                        attempt,
+                        max_retries = self.max_retries,
+                        ?delay,
+                        error = %e,
+                        "Cosmos DB operation failed, retrying"
+                    );
+                    sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Exponential delay for the given attempt with up to 50% random jitter.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let jitter_range = u128::max(exponential.as_millis() / 2, 1);
+        let jitter = uuid::Uuid::new_v4().as_u128() % jitter_range;
+        exponential + Duration::from_millis(u64::try_from(jitter).unwrap_or(0))
+    }
+}
+
+/// Returns true for throttling, timeouts, server-side failures and network errors.
+///
+/// Client errors such as 404 (not found) or 409 (conflict) are not retried.
+#[must_use]
+pub fn is_retryable(error: &AzureError) -> bool {
+    match error.http_status() {
+        Some(status) => matches!(
+            status,
+            StatusCode::TooManyRequests
+                | StatusCode::RequestTimeout
+                | StatusCode::InternalServerError
+                | StatusCode::BadGateway
+                | StatusCode::ServiceUnavailable
+                | StatusCode::GatewayTimeout
+        ),
+        None => matches!(error.kind(), ErrorKind::Io),
+    }
+}
+
+/// Returns true if Cosmos DB throttled the request, which it then did not apply.
+fn is_throttled(error: &AzureError) -> bool {
+    error.http_status() == Some(StatusCode::TooManyRequests)
+}
+
+/// Reads the wait time from the `x-ms-retry-after-ms` header of a throttled response.
+fn retry_after(error: &AzureError) -> Option<Duration> {
+    HttpError::try_from(error)?
+        .headers()
+        .get(RETRY_AFTER_MS_HEADER)?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_millis)
+}
+
+#[cfg(feature = "ssr")]
+async fn sleep(delay: Duration) {
+    tokio::time::sleep(delay).await;
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn sleep(_delay: Duration) {
+    // Cosmos DB is only called on the server
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use azure_core::http::Response;
+    use azure_core::http::headers::Headers;
+    use futures::executor::block_on;
+
+    use super::*;
+
+    fn http_error(status: StatusCode, headers: Headers) -> AzureError {
+        let response = Response::<()>::from_bytes(status, headers, Vec::new());
+        AzureError::new(
+            ErrorKind::http_response(status, None),
+            block_on(HttpError::new(response)),
+        )
+    }
+
+    #[test]
+    fn retry_after_is_read_from_the_response_header() {
+        let mut headers = Headers::new();
+        headers.insert(RETRY_AFTER_MS_HEADER, "250");
+        let error = http_error(StatusCode::TooManyRequests, headers);
+
+        assert_eq!(retry_after(&error), Some(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn retry_after_is_none_without_the_header() {
+        let error = http_error(StatusCode::TooManyRequests, Headers::new());
+        assert_eq!(retry_after(&error), None);
+
+        let error = AzureError::message(ErrorKind::Io, "connection reset");
+        assert_eq!(retry_after(&error), None);
+    }
+
+    #[test]
+    fn conditional_writes_are_not_retried_after_a_timeout() {
+        let policy = RetryPolicy::new(3);
+        let attempts = Cell::new(0);
+
+        let result: Result<(), _> = block_on(policy.run_conditional("replace", || {
+            attempts.set(attempts.get() + 1);
+            async { Err(http_error(StatusCode::RequestTimeout, Headers::new())) }
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+        assert!(is_retryable(&http_error(
+            StatusCode::RequestTimeout,
+            Headers::new()
+        )));
+        assert!(is_throttled(&http_error(
+            StatusCode::TooManyRequests,
+            Headers::new()
+        )));
+    }
+}
@@ -1,29 +1,218 @@
-use azure_core::error::Error as AzureError;
-use azure_data_cosmos::PartitionKey;
-use futures::TryStreamExt;
-use leptos::leptos_dom::logging;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use azure_core::error::{Error as AzureError, ErrorKind};
+use azure_core::http::{ClientMethodOptions, Etag, StatusCode};
+use azure_data_cosmos::{ItemOptions, PartitionKey, Query, QueryOptions};
+use futures::{StreamExt, TryStreamExt};
 
 use crate::{
-    domain::todo::Todo,
-    services::cosmos::{CosmosDBClient, model::CosmosDbTodo},
+    domain::auth::DEFAULT_GROUP_ID,
+    domain::todo::{Comment, MAX_PAGE_SIZE, SortBy, Todo, TodoFilter, TodoPage, TodoStatus},
+    services::cosmos::{
+        CosmosDBClient, QueryPaging,
+        metrics::{OperationCharge, RequestCharges},
+        model::CosmosDbTodo,
+        retry::RetryPolicy,
+    },
 };
 
+/// Most replaces [`CosmosService::reassign_todos`] keeps in flight at once.
+const REASSIGN_CONCURRENCY: usize = 8;
+
+/// Reads of a todo a conditional replace makes before giving up on a todo
+/// that keeps changing.
+const MAX_REPLACE_ATTEMPTS: u32 = 3;
+
+/// Most deletes [`CosmosService::wipe_todos`] keeps in flight at once.
+const WIPE_CONCURRENCY: usize = 8;
+
+/// Query condition matching todos that have not been moved to the trash.
+const NOT_DELETED: &str = "(NOT IS_DEFINED(c.deleted_at) OR IS_NULL(c.deleted_at))";
+
+/// Query condition matching todos of the group bound as `@group_id`; todos
+/// stored before groups existed belong to the default group.
+fn in_group() -> String {
+    format!("(c.group_id ?? '{DEFAULT_GROUP_ID}') = @group_id")
+}
+
+/// Stored field a query can order by for `sort_by`; `None` for priority, which
+/// is stored as text, and manual order, which falls back to the creation time.
+fn order_by_field(sort_by: SortBy) -> Option<&'static str> {
+    match sort_by {
+        SortBy::Title => Some("c.title"),
+        SortBy::DueDate => Some("c.due_date"),
+        SortBy::Status => Some("c.status"),
+        SortBy::Assignee => Some("c.assignee"),
+        SortBy::CreatedDate => Some("c.created_at"),
+        SortBy::Priority | SortBy::Manual => None,
+    }
+}
+
+/// Query conditions for the set fields of `filter`, each prefixed with `AND`,
+/// and the parameters they bind.
+fn filter_conditions(filter: &TodoFilter) -> (String, Vec<(&'static str, serde_json::Value)>) {
+    let mut conditions = String::new();
+    let mut parameters = Vec::new();
+
+    if let Some(status) = filter.status {
+        conditions.push_str(" AND c.status = @status");
+        parameters.push(("@status", status.as_str().into()));
+    }
+    if let Some(assignee) = &filter.assignee {
+        conditions.push_str(" AND c.assignee = @assignee");
+        parameters.push(("@assignee", assignee.as_str().into()));
+    }
+    if let Some(search) = &filter.search {
+        conditions.push_str(
+            " AND (CONTAINS(c.title, @search, true) OR CONTAINS(c.description, @search, true) \
+             OR CONTAINS(c.assignee, @search, true) \
+             OR EXISTS(SELECT VALUE t FROM t IN c.tags WHERE CONTAINS(t, @search, true)))",
+        );
+        parameters.push(("@search", search.as_str().into()));
+    }
+    if let Some(due_from) = filter.due_from {
+        conditions.push_str(" AND c.due_date >= @due_from");
+        parameters.push(("@due_from", due_from.into()));
+    }
+    if let Some(due_to) = filter.due_to {
+        conditions.push_str(" AND c.due_date < @due_to");
+        parameters.push(("@due_to", due_to.into()));
+    }
+
+    (conditions, parameters)
+}
+
+/// Builds a query with the caller's group bound as the `@group_id` parameter.
+fn group_query(query: String, group_id: &str) -> Result<Query, AzureError> {
+    Query::from(query)
+        .with_parameter("@group_id", group_id)
+        .map_err(|e| AzureError::new(ErrorKind::DataConversion, e))
+}
+
+/// Returned by [`CosmosService::update_todo`] when the todo was changed by
+/// someone else between reading and replacing it.
+#[derive(Debug)]
+pub struct UpdateConflict {
+    pub todo_id: String,
+}
+
+impl std::fmt::Display for UpdateConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Todo {} was changed by someone else", self.todo_id)
+    }
+}
+
+impl std::error::Error for UpdateConflict {}
+
+/// Recent results of [`CosmosService::get_todos`], per group.
+///
+/// Every write to a group bumps its generation, and a query only stores its
+/// result if the generation is unchanged when it finishes, so a slow read can
+/// never put back todos that a concurrent write has already replaced.
+struct TodoCache {
+    ttl: Duration,
+    groups: Mutex<HashMap<String, CachedGroup>>,
+}
+
+#[derive(Default)]
+struct CachedGroup {
+    generation: u64,
+    todos: Option<(Instant, Vec<CosmosDbTodo>)>,
+}
+
+impl TodoCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            groups: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // A panic while holding the lock cannot leave an entry half written
+    fn groups(&self) -> MutexGuard<'_, HashMap<String, CachedGroup>> {
+        self.groups.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the todos of the group if they were cached within the TTL,
+    /// otherwise the generation to pass to [`TodoCache::store`].
+    fn get(&self, group_id: &str) -> Result<Vec<CosmosDbTodo>, u64> {
+        let groups = self.groups();
+        let Some(group) = groups.get(group_id) else {
+            return Err(0);
+        };
+        match &group.todos {
+            Some((cached_at, todos)) if cached_at.elapsed() < self.ttl => Ok(todos.clone()),
+            _ => Err(group.generation),
+        }
+    }
+
+    fn store(&self, group_id: &str, generation: u64, todos: &[CosmosDbTodo]) {
+        if self.ttl.is_zero() {
+            return;
+        }
+        let mut groups = self.groups();
+        let group = groups.entry(group_id.to_string()).or_default();
+        if group.generation == generation {
+            group.todos = Some((Instant::now(), todos.to_vec()));
+        }
+    }
+
+    fn invalidate(&self, group_id: &str) {
+        let mut groups = self.groups();
+        let group = groups.entry(group_id.to_string()).or_default();
+        group.generation += 1;
+        group.todos = None;
+    }
+}
+
 pub struct CosmosService {
     client: CosmosDBClient,
+    retry: RetryPolicy,
+    cache: TodoCache,
+    charges: RequestCharges,
 }
 
 impl CosmosService {
-    /// Creates a new instance of the Cosmos DB service.
+    /// Creates a new instance of the Cosmos DB service, serving the todo list
+    /// of a group from memory for `cache_ttl` after it was queried.
     ///
     /// # Errors
     ///
     /// Returns an error if the app configuration cannot be retrieved or if the Cosmos client
     /// cannot be initialized with the provided connection details.
-    pub fn new(client: CosmosDBClient) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        Ok(Self { client })
+    pub fn new(
+        client: CosmosDBClient,
+        retry: RetryPolicy,
+        cache_ttl: Duration,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Self {
+            client,
+            retry,
+            cache: TodoCache::new(cache_ttl),
+            charges: RequestCharges::default(),
+        })
+    }
+
+    /// Request units charged per operation since the server started, logged at
+    /// debug level as each response arrives.
+    #[must_use]
+    pub fn request_charges(&self) -> BTreeMap<&'static str, OperationCharge> {
+        self.charges.snapshot()
     }
 
-    /// Creates a new todo item in the Cosmos DB container.
+    /// Drops the cached todo list of `group_id`, so the next
+    /// [`CosmosService::get_todos`] queries Cosmos DB.
+    ///
+    /// Writes through this service do this themselves; it is needed after
+    /// changes made elsewhere, e.g. by another server instance.
+    pub fn invalidate_todos(&self, group_id: &str) {
+        self.cache.invalidate(group_id);
+    }
+
+    /// Creates a new todo item in the Cosmos DB container, owned by `group_id`
+    /// and recorded as created by `created_by`.
     ///
     /// # Errors
     ///
@@ -32,181 +221,940 @@ impl CosmosService {
     pub async fn create_todo(
         &self,
         todo: Todo,
+        group_id: &str,
+        created_by: &str,
     ) -> Result<Todo, Box<dyn std::error::Error + Send + Sync>> {
-        let todo_cloned = todo.clone();
-        let cosmos_todo = CosmosDbTodo::try_from_todo(todo)?;
+        let mut cosmos_todo = CosmosDbTodo::try_from_todo(todo.clone(), group_id, created_by)?;
+        // The completion time is stamped here, never taken from the client
+        cosmos_todo.completed_at = (cosmos_todo.status == TodoStatus::Completed.as_str())
+            .then_some(cosmos_todo.updated_at);
+        let todo_cloned = Todo {
+            created_by: created_by.to_string(),
+            completed_at: cosmos_todo.completed_at,
+            ..todo
+        };
         let partition_key = PartitionKey::from("family_todos");
+        let container = &self.client.container();
         match self
-            .client
-            .container()
-            .create_item(partition_key, cosmos_todo, None)
+            .retry
+            .run("create", || {
+                let partition_key = partition_key.clone();
+                let cosmos_todo = cosmos_todo.clone();
+                async move {
+                    container
+                        .create_item(partition_key, cosmos_todo, None)
+                        .await
+                }
+            })
             .await
         {
-            Ok(_) => {
-                logging::console_log(&format!("Created todo in Cosmos DB: {todo_cloned:#?}",));
+            Ok(response) => {
+                self.charges.record("create_todo", response.headers());
+                self.cache.invalidate(group_id);
+                tracing::info!(todo_id = %todo_cloned.id, "Created todo in Cosmos DB");
                 Ok(todo_cloned)
             }
             Err(e) => {
-                logging::console_error("ERROR");
-                eprintln!("Error creating todo in Cosmos DB: {e}");
+                tracing::error!(error = %e, "Error creating todo in Cosmos DB");
                 Err(Box::new(e))
             }
         }
     }
 
-    /// Retrieves a list of todo items from the Cosmos DB container for a specific todo ID.
+    /// Retrieves every todo of `group_id` that is not in the trash, newest first.
+    ///
+    /// Repeated calls within the cache TTL are answered from memory; use
+    /// [`CosmosService::refresh_todos`] to bypass the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the query operation fails or if there's an issue
+    /// connecting to the Cosmos DB service.
+    pub async fn get_todos(&self, group_id: &str) -> Result<Vec<CosmosDbTodo>, AzureError> {
+        match self.cache.get(group_id) {
+            Ok(todos) => {
+                tracing::debug!(count = todos.len(), "Served todos from cache");
+                Ok(todos)
+            }
+            Err(generation) => self.query_todos(group_id, generation).await,
+        }
+    }
+
+    /// Retrieves every todo of `group_id` like [`CosmosService::get_todos`], but
+    /// always from Cosmos DB, and caches the fresh result.
     ///
     /// # Errors
     ///
     /// Returns an `AzureError` if the query operation fails or if there's an issue
     /// connecting to the Cosmos DB service.
-    pub async fn get_todos(&self) -> Result<Vec<CosmosDbTodo>, AzureError> {
+    pub async fn refresh_todos(&self, group_id: &str) -> Result<Vec<CosmosDbTodo>, AzureError> {
+        self.cache.invalidate(group_id);
+        let generation = self.cache.get(group_id).err().unwrap_or_default();
+        self.query_todos(group_id, generation).await
+    }
+
+    async fn query_todos(
+        &self,
+        group_id: &str,
+        generation: u64,
+    ) -> Result<Vec<CosmosDbTodo>, AzureError> {
         // Use a more explicit query approach
-        let query =
-            "SELECT * FROM c WHERE c.partition_key = 'family_todos' ORDER BY c.created_at DESC";
-        let partition_key = PartitionKey::from("family_todos");
+        let query = group_query(
+            format!(
+                "SELECT * FROM c WHERE c.partition_key = 'family_todos' AND {} AND {NOT_DELETED} ORDER BY c.created_at DESC",
+                in_group()
+            ),
+            group_id,
+        )?;
+        tracing::debug!("Starting Cosmos DB query for todos");
 
-        logging::console_log("Starting Cosmos DB query for todos...");
-
-        let mut todos = Vec::new();
-
-        // Create the query stream
-        let query_result =
-            self.client
-                .container()
-                .query_items::<CosmosDbTodo>(query, partition_key, None);
-
-        match query_result {
-            Ok(mut query_stream) => {
-                logging::console_log("Query stream created successfully");
-
-                // Process the stream more carefully
-                loop {
-                    match query_stream.try_next().await {
-                        Ok(Some(feed_page)) => {
-                            logging::console_log(&format!(
-                                "Received feed page with {} items",
-                                feed_page.items().len()
-                            ));
-
-                            for item in feed_page.items() {
-                                logging::console_log(&format!("Processing item: {item:#?}"));
-                                todos.push(item.clone());
-                            }
-                        }
-                        Ok(None) => {
-                            break; // No more pages
-                        }
-                        Err(e) => {
-                            logging::console_error(&format!(
-                                "Error reading from query stream: {e}"
-                            ));
-                            return Err(e);
-                        }
-                    }
-                }
-            }
+        let todos = self
+            .query_all::<CosmosDbTodo>("get_todos", query)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(error = %e, "Error querying todos");
+            })?;
+
+        tracing::debug!(count = todos.len(), "Retrieved todos from Cosmos DB");
+        self.cache.store(group_id, generation, &todos);
+        Ok(todos)
+    }
+
+    /// Retrieves a single todo with a point read on the `family_todos` partition.
+    ///
+    /// Returns `Ok(None)` if no todo has the id, if it belongs to another group
+    /// or if it is in the trash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the read fails for any reason other than the
+    /// todo not existing, or if there's an issue connecting to the Cosmos DB service.
+    pub async fn get_todo(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+    ) -> Result<Option<CosmosDbTodo>, AzureError> {
+        let container = &self.client.container();
+        let result = self
+            .retry
+            .run("read", || async move {
+                let response = container
+                    .read_item::<CosmosDbTodo>(PartitionKey::from("family_todos"), todo_id, None)
+                    .await?;
+                self.charges.record("get_todo", response.headers());
+                response.into_body().await
+            })
+            .await;
+
+        match result {
+            Ok(cosmos_todo) => Ok(Some(cosmos_todo)
+                .filter(|todo| todo.group_id == group_id && todo.deleted_at.is_none())),
+            Err(e) if e.http_status() == Some(StatusCode::NotFound) => Ok(None),
             Err(e) => {
-                logging::console_error(&format!("Error creating query stream: {e}"));
-                return Err(e);
+                tracing::error!(todo_id, error = %e, "Error reading todo");
+                Err(e)
             }
         }
-
-        logging::console_log(&format!("Retrieved {} todos from Cosmos DB", todos.len()));
-        Ok(todos)
     }
 
-    /// Updates a todo item in the Cosmos DB container
+    /// Checks that the container can be queried with a cheap `COUNT` query.
+    ///
+    /// Transient failures are not retried, so an outage is reported promptly.
     ///
     /// # Errors
     ///
-    /// Returns an `AzureError` if the update operation fails or if there's an issue
+    /// Returns an `AzureError` if the query fails or if there's an issue
     /// connecting to the Cosmos DB service.
+    pub async fn ping(&self) -> Result<(), AzureError> {
+        let container = self.client.container();
+        let mut query_stream = container.query_items::<u64>(
+            "SELECT VALUE COUNT(1) FROM c",
+            PartitionKey::from("family_todos"),
+            None,
+        )?;
+        if let Some(feed_page) = query_stream.try_next().await? {
+            self.charges.record("ping", feed_page.headers());
+        }
+        Ok(())
+    }
+
+    /// Retrieves a single page of todo items, newest first.
     ///
-    pub async fn update_todo(
+    /// `continuation` is the Cosmos DB continuation token returned with the
+    /// previous page; pass `None` together with a `page` index to start from an
+    /// arbitrary page, which follows the tokens of the pages before it. The token
+    /// is opaque to callers and can be passed back unchanged across server calls.
+    /// `page_size` is clamped to [`MAX_PAGE_SIZE`] and the total count is only
+    /// queried for the first page. While the group's todo list is cached, a first
+    /// page holding every matching todo is cut from it instead of queried.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if Cosmos DB rejects the continuation token, if a
+    /// query fails, or if there's an issue connecting to the Cosmos DB service.
+    pub async fn get_todos_paged(
         &self,
-        updated_todo: Todo,
-    ) -> Result<CosmosDbTodo, Box<dyn std::error::Error + Send + Sync>> {
-        let partition_key = PartitionKey::from("family_todos");
+        group_id: &str,
+        page: u32,
+        page_size: u32,
+        continuation: Option<&str>,
+    ) -> Result<TodoPage, AzureError> {
+        self.get_todos_filtered(
+            group_id,
+            &TodoFilter::default(),
+            None,
+            page,
+            page_size,
+            continuation,
+        )
+        .await
+    }
 
-        // First, fetch the existing item using a query to preserve created_at and notification fields
-        let query = format!(
-            "SELECT * FROM c WHERE c.id = '{}' AND c.partition_key = 'family_todos'",
-            updated_todo.id
-        );
+    /// Retrieves a single page of the todos matching `filter`, in the order of
+    /// `sort` (criterion and whether ascending), newest first without one.
+    ///
+    /// Paging works as in [`CosmosService::get_todos_paged`]; a continuation token
+    /// is only valid with the filter and sort it was returned for. Filter values
+    /// are bound as query parameters. Priority and manual order cannot be sorted
+    /// on by the query, so those pages come newest first and callers sort them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the continuation token is malformed, if a query
+    /// fails, or if there's an issue connecting to the Cosmos DB service.
+    pub async fn get_todos_filtered(
+        &self,
+        group_id: &str,
+        filter: &TodoFilter,
+        sort: Option<(SortBy, bool)>,
+        page: u32,
+        page_size: u32,
+        continuation: Option<&str>,
+    ) -> Result<TodoPage, AzureError> {
+        let page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        let order_by = sort.and_then(|(sort_by, ascending)| {
+            order_by_field(sort_by).map(|field| (sort_by, field, ascending))
+        });
 
-        let query_result = self.client.container().query_items::<CosmosDbTodo>(
-            &query,
-            partition_key.clone(),
-            None,
+        // A warm cache holds the same todos in the same order, but continuation
+        // tokens only come from Cosmos DB, so only a single page is served from it
+        if let (None, 0, Ok(cached)) = (continuation, page, self.cache.get(group_id)) {
+            let mut matching: Vec<Todo> = cached
+                .into_iter()
+                .map(Todo::from)
+                .filter(|todo| filter.matches(todo))
+                .collect();
+            if matching.len() <= page_size as usize {
+                if let Some((sort_by, _, ascending)) = order_by {
+                    matching.sort_by(|a, b| {
+                        let comparison = sort_by.compare(a, b);
+                        if ascending {
+                            comparison
+                        } else {
+                            comparison.reverse()
+                        }
+                    });
+                }
+                return Ok(TodoPage {
+                    total: Some(u64::try_from(matching.len()).unwrap_or(u64::MAX)),
+                    todos: matching,
+                    continuation: None,
+                });
+            }
+        }
+
+        let (conditions, parameters) = filter_conditions(filter);
+        let filter_clause = format!(
+            "c.partition_key = 'family_todos' AND {} AND {NOT_DELETED}{conditions}",
+            in_group()
         );
+        let order_clause = match order_by {
+            Some((_, field, true)) => format!("{field} ASC"),
+            Some((_, field, false)) => format!("{field} DESC"),
+            None => "c.created_at DESC".to_string(),
+        };
+        let with_parameters = |query: String| -> Result<Query, AzureError> {
+            parameters
+                .iter()
+                .try_fold(group_query(query, group_id)?, |query, (name, value)| {
+                    query.with_parameter(*name, value)
+                })
+                .map_err(|e| AzureError::new(ErrorKind::DataConversion, e))
+        };
 
-        let mut existing_todo: Option<CosmosDbTodo> = None;
+        let query = &with_parameters(format!(
+            "SELECT * FROM c WHERE {filter_clause} ORDER BY {order_clause}"
+        ))?;
+        let paging = &QueryPaging {
+            continuation: continuation.map(str::to_string),
+            max_item_count: page_size,
+        };
+        // Without a token, the pages before `page` are read to reach its token
+        let skip = if continuation.is_some() { 0 } else { page };
+        let container = &self.client.container();
+        let (items, next) = self
+            .retry
+            .run("query", || async move {
+                let options = QueryOptions {
+                    method_options: ClientMethodOptions {
+                        context: paging.clone().into_context(),
+                    },
+                };
+                let mut query_stream = container.query_items::<CosmosDbTodo>(
+                    query.clone(),
+                    PartitionKey::from("family_todos"),
+                    Some(options),
+                )?;
 
-        match query_result {
-            Ok(mut query_stream) => {
-                if let Ok(Some(feed_page)) = query_stream.try_next().await {
-                    if let Some(item) = feed_page.items().first() {
-                        existing_todo = Some(item.clone());
+                let mut skipped = 0;
+                while let Some(feed_page) = query_stream.try_next().await? {
+                    self.charges.record("get_todos_page", feed_page.headers());
+                    if skipped == skip {
+                        let (items, next, _) = feed_page.deconstruct();
+                        return Ok((items, next));
                     }
+                    if feed_page.continuation().is_none() {
+                        break;
+                    }
+                    skipped += 1;
                 }
-            }
-            Err(e) => {
-                logging::console_error(&format!("Error querying existing todo: {e}"));
-                return Err(Box::new(e));
-            }
-        }
+                Ok((Vec::new(), None))
+            })
+            .await?;
+
+        let total = if continuation.is_none() && page == 0 {
+            self.query_all::<u64>(
+                "count_todos",
+                with_parameters(format!(
+                    "SELECT VALUE COUNT(1) FROM c WHERE {filter_clause}"
+                ))?,
+            )
+            .await?
+            .first()
+            .copied()
+        } else {
+            None
+        };
+
+        tracing::debug!(
+            count = items.len(),
+            page,
+            "Retrieved page of todos from Cosmos DB"
+        );
+
+        Ok(TodoPage {
+            todos: items.into_iter().map(Todo::from).collect(),
+            continuation: next,
+            total,
+        })
+    }
+
+    /// Runs a query against the `family_todos` partition and collects every page,
+    /// recording the charge of each page under `operation`.
+    ///
+    /// A transient failure on any page restarts the query.
+    async fn query_all<T>(
+        &self,
+        operation: &'static str,
+        query: impl Into<Query>,
+    ) -> Result<Vec<T>, AzureError>
+    where
+        T: serde::de::DeserializeOwned + Clone + Send + 'static,
+    {
+        let container = &self.client.container();
+        let query = &query.into();
+        self.retry
+            .run("query", || async move {
+                let partition_key = PartitionKey::from("family_todos");
+                let mut query_stream =
+                    container.query_items::<T>(query.clone(), partition_key, None)?;
+
+                let mut items = Vec::new();
+                while let Some(feed_page) = query_stream.try_next().await? {
+                    self.charges.record(operation, feed_page.headers());
+                    items.extend(feed_page.items().iter().cloned());
+                }
+                Ok(items)
+            })
+            .await
+    }
+
+    /// Updates a todo item of `group_id` in the Cosmos DB container, recording
+    /// `updated_by` as its last editor.
+    ///
+    /// The replace only succeeds if the item still has the ETag it was read
+    /// with, so concurrent edits are detected instead of silently overwritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateConflict`] if the todo changed after it was read, or an
+    /// error if the todo does not exist in the group, if the update operation
+    /// fails or if there's an issue connecting to the Cosmos DB service.
+    ///
+    pub async fn update_todo(
+        &self,
+        updated_todo: Todo,
+        group_id: &str,
+        updated_by: &str,
+    ) -> Result<CosmosDbTodo, Box<dyn std::error::Error + Send + Sync>> {
+        let partition_key = PartitionKey::from("family_todos");
+
+        // First, fetch the existing item using a query to preserve created_at and notification fields
+        let existing = self
+            .query_all::<CosmosDbTodo>("update_todo", todo_by_id_query(&updated_todo.id, group_id)?)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = %e, "Error querying existing todo");
+                Box::new(e)
+            })?
+            .into_iter()
+            .next()
+            // Never replace a todo of another group, even if the id is known
+            .ok_or_else(|| format!("Todo {} not found", updated_todo.id))?;
 
         // Create the updated todo
-        let mut cosmos_todo = CosmosDbTodo::try_from_todo(updated_todo)?;
-
-        // If we found the existing todo, preserve the original timestamps and notification fields
-        if let Some(existing) = existing_todo {
-            cosmos_todo.created_at = existing.created_at; // Preserve original creation time
-            cosmos_todo.reminder_24h_sent = existing.reminder_24h_sent;
-            cosmos_todo.final_reminder_sent = existing.final_reminder_sent;
-            cosmos_todo.last_notification_time = existing.last_notification_time;
-        }
+        let mut cosmos_todo =
+            CosmosDbTodo::try_from_todo(updated_todo, group_id, &existing.created_by)?;
 
-        // Always update the modification time
+        // Preserve the original timestamps, notification fields and comments
+        cosmos_todo.created_at = existing.created_at; // Preserve original creation time
+        cosmos_todo.reminder_24h_sent = existing.reminder_24h_sent;
+        cosmos_todo.final_reminder_sent = existing.final_reminder_sent;
+        cosmos_todo.last_notification_time = existing.last_notification_time;
+        cosmos_todo.deleted_at = existing.deleted_at;
+        cosmos_todo.completed_at = existing.completed_at;
+        cosmos_todo.comments = existing.comments;
+
+        // Always update the modification time and who made the change
         cosmos_todo.updated_at = chrono::Utc::now()
             .timestamp()
             .max(0)
             .try_into()
             .unwrap_or(0);
+        cosmos_todo.updated_by = updated_by.to_string();
+
+        // Stamp the completion time on the transition to Completed and clear it when reopened
+        if cosmos_todo.status == TodoStatus::Completed.as_str() {
+            if cosmos_todo.completed_at.is_none() {
+                cosmos_todo.completed_at = Some(cosmos_todo.updated_at);
+            }
+        } else {
+            cosmos_todo.completed_at = None;
+        }
 
-        // Replace the item in Cosmos DB
+        // Replace the item in Cosmos DB, unless it changed since it was read
+        let options = ItemOptions {
+            if_match_etag: existing.etag.map(Etag::from),
+            ..Default::default()
+        };
+        let container = &self.client.container();
+        let cosmos_todo_ref = &cosmos_todo;
         let response = self
-            .client
-            .container()
-            .replace_item(partition_key, &cosmos_todo.id, &cosmos_todo, None)
+            .retry
+            .run_conditional("replace", || {
+                let partition_key = partition_key.clone();
+                let options = options.clone();
+                async move {
+                    container
+                        .replace_item(
+                            partition_key,
+                            &cosmos_todo_ref.id,
+                            cosmos_todo_ref,
+                            Some(options),
+                        )
+                        .await
+                }
+            })
             .await
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
+                if e.http_status() == Some(StatusCode::PreconditionFailed) {
+                    tracing::warn!(todo_id = %cosmos_todo_ref.id, "Todo changed during update");
+                    Box::new(UpdateConflict {
+                        todo_id: cosmos_todo_ref.id.clone(),
+                    })
+                } else {
+                    Box::new(e)
+                }
+            });
+        // Also after a conflict, since the cached copy is then known to be stale
+        self.cache.invalidate(group_id);
+        let response = response?;
+        self.charges.record("update_todo", response.headers());
 
         if !response.status().is_success() {
             let error_msg = format!("Failed to update todo in Cosmos DB: {}", response.status());
-            logging::console_error(&error_msg);
+            tracing::error!(status = %response.status(), "Failed to update todo in Cosmos DB");
             return Err(Box::new(std::io::Error::other(error_msg)));
         }
         Ok(cosmos_todo)
     }
 
-    /// Deletes a todo item from the Cosmos DB container
+    /// Appends a comment to a todo and returns the updated todo.
+    ///
+    /// The todo is replaced only if it did not change since it was read; when
+    /// it did, it is read again and the comment appended to the new version, so
+    /// concurrent edits and comments are never lost.
     ///
     /// # Errors
     ///
-    /// Returns an `AzureError` if the deletion operation fails or if there's an issue
+    /// Returns an `AzureError` if the todo does not exist, if the replace operation
+    /// fails, if the todo keeps changing concurrently or if there's an issue
     /// connecting to the Cosmos DB service.
-    pub async fn delete_todo(&self, todo_id: &str) -> Result<(), AzureError> {
-        let partition_key = PartitionKey::from("family_todos");
+    pub async fn add_comment(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        comment: Comment,
+    ) -> Result<Todo, AzureError> {
+        const MAX_ATTEMPTS: u32 = 3;
+
+        let mut attempt = 1;
+        loop {
+            let Some(mut cosmos_todo) = self.get_todo(todo_id, group_id).await? else {
+                return Err(AzureError::message(
+                    ErrorKind::Other,
+                    format!("Todo {todo_id} not found"),
+                ));
+            };
+
+            cosmos_todo.updated_at = chrono::Utc::now()
+                .timestamp()
+                .max(0)
+                .try_into()
+                .unwrap_or(0);
+            cosmos_todo.updated_by.clone_from(&comment.author);
+            cosmos_todo.comments.push(comment.clone());
+
+            let options = ItemOptions {
+                if_match_etag: cosmos_todo.etag.clone().map(Etag::from),
+                ..Default::default()
+            };
+            let container = &self.client.container();
+            let cosmos_todo_ref = &cosmos_todo;
+            let result = self
+                .retry
+                .run_conditional("replace", || {
+                    let options = options.clone();
+                    async move {
+                        container
+                            .replace_item(
+                                PartitionKey::from("family_todos"),
+                                &cosmos_todo_ref.id,
+                                cosmos_todo_ref,
+                                Some(options),
+                            )
+                            .await
+                    }
+                })
+                .await;
+            self.cache.invalidate(group_id);
+
+            match result {
+                Ok(response) => {
+                    self.charges.record("add_comment", response.headers());
+                    return Ok(Todo::from(cosmos_todo));
+                }
+                Err(e)
+                    if e.http_status() == Some(StatusCode::PreconditionFailed)
+                        && attempt < MAX_ATTEMPTS =>
+                {
+                    tracing::warn!(todo_id, attempt, "Todo changed while commenting, retrying");
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Moves a todo item to the trash by stamping its `deleted_at` time.
+    ///
+    /// The item stays in the container and can be brought back with
+    /// [`CosmosService::restore_todo`] or removed for good with
+    /// [`CosmosService::purge_todo`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the todo does not exist, if the replace operation
+    /// fails or if there's an issue connecting to the Cosmos DB service.
+    pub async fn delete_todo(&self, todo_id: &str, group_id: &str) -> Result<(), AzureError> {
+        self.set_deleted_at(
+            "delete_todo",
+            todo_id,
+            group_id,
+            Some(chrono::Utc::now().timestamp()),
+        )
+        .await
+    }
 
-        self.client
-            .container()
-            .delete_item(partition_key, todo_id, None)
+    /// Restores a todo item from the trash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the todo does not exist, if the replace operation
+    /// fails or if there's an issue connecting to the Cosmos DB service.
+    pub async fn restore_todo(&self, todo_id: &str, group_id: &str) -> Result<(), AzureError> {
+        self.set_deleted_at("restore_todo", todo_id, group_id, None)
+            .await
+    }
+
+    /// Retrieves the todo items of `group_id` currently in the trash, most recently
+    /// deleted first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the query operation fails or if there's an issue
+    /// connecting to the Cosmos DB service.
+    pub async fn get_deleted_todos(&self, group_id: &str) -> Result<Vec<CosmosDbTodo>, AzureError> {
+        self.query_all::<CosmosDbTodo>(
+            "get_deleted_todos",
+            group_query(
+                format!(
+                    "SELECT * FROM c WHERE c.partition_key = 'family_todos' AND {} AND IS_DEFINED(c.deleted_at) AND NOT IS_NULL(c.deleted_at) ORDER BY c.deleted_at DESC",
+                    in_group()
+                ),
+                group_id,
+            )?,
+        )
+        .await
+    }
+
+    /// Retrieves open todos of `group_id` due between `now` and `due_before` whose
+    /// 24h reminder has not been sent yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the query operation fails or if there's an issue
+    /// connecting to the Cosmos DB service.
+    pub async fn get_todos_due_for_reminder(
+        &self,
+        group_id: &str,
+        now: u64,
+        due_before: u64,
+    ) -> Result<Vec<CosmosDbTodo>, AzureError> {
+        let query = group_query(
+            format!(
+                "SELECT * FROM c WHERE c.partition_key = 'family_todos' AND {} AND {NOT_DELETED} \
+                 AND c.status != @completed AND c.due_date >= @now AND c.due_date <= @due_before \
+                 AND (NOT IS_DEFINED(c.reminder_24h_sent) OR c.reminder_24h_sent != true)",
+                in_group()
+            ),
+            group_id,
+        )?
+        .with_parameter("@completed", TodoStatus::Completed.as_str())
+        .and_then(|query| query.with_parameter("@now", now))
+        .and_then(|query| query.with_parameter("@due_before", due_before))
+        .map_err(|e| AzureError::new(ErrorKind::DataConversion, e))?;
+
+        self.query_all::<CosmosDbTodo>("get_todos_due_for_reminder", query)
+            .await
+    }
+
+    /// Records that the 24h reminder of a todo was sent at `sent_at`.
+    ///
+    /// [`CosmosService::update_todo`] keeps the stored notification fields, so
+    /// they are written here instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the todo does not exist, if the replace operation
+    /// fails or if there's an issue connecting to the Cosmos DB service.
+    pub async fn mark_reminder_sent(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        sent_at: i64,
+    ) -> Result<(), AzureError> {
+        self.replace_if_unchanged("mark_reminder_sent", todo_id, group_id, |cosmos_todo| {
+            cosmos_todo.reminder_24h_sent = Some(true);
+            cosmos_todo.last_notification_time = Some(sent_at);
+        })
+        .await
+    }
+
+    /// Reassigns every todo of `group_id` assigned to `from`, including those in
+    /// the trash, to `to`, recording `updated_by` as their last editor.
+    ///
+    /// The todos are read with a single query and replaced up to
+    /// `REASSIGN_CONCURRENCY` at a time. Each replace is conditional on the ETag
+    /// read, so a todo edited meanwhile is skipped rather than overwritten;
+    /// running the reassignment again picks it up. Returns how many todos were
+    /// reassigned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the query fails or if there's an issue
+    /// connecting to the Cosmos DB service.
+    pub async fn reassign_todos(
+        &self,
+        group_id: &str,
+        from: &str,
+        to: &str,
+        updated_by: &str,
+    ) -> Result<usize, AzureError> {
+        let query = group_query(
+            format!(
+                "SELECT * FROM c WHERE c.partition_key = 'family_todos' AND {} AND c.assignee = @assignee",
+                in_group()
+            ),
+            group_id,
+        )?
+        .with_parameter("@assignee", from)
+        .map_err(|e| AzureError::new(ErrorKind::DataConversion, e))?;
+        let todos = self
+            .query_all::<CosmosDbTodo>("reassign_todos", query)
+            .await?;
+
+        let updated_at: u64 = chrono::Utc::now()
+            .timestamp()
+            .max(0)
+            .try_into()
+            .unwrap_or(0);
+        let container = &self.client.container();
+        let outcomes: Vec<bool> = futures::stream::iter(todos)
+            .map(|mut cosmos_todo| async move {
+                let options = ItemOptions {
+                    if_match_etag: cosmos_todo.etag.take().map(Etag::from),
+                    ..Default::default()
+                };
+                cosmos_todo.assignee = to.to_string();
+                cosmos_todo.updated_at = updated_at;
+                cosmos_todo.updated_by = updated_by.to_string();
+
+                let cosmos_todo = &cosmos_todo;
+                let result = self
+                    .retry
+                    .run_conditional("replace", || {
+                        let options = options.clone();
+                        async move {
+                            container
+                                .replace_item(
+                                    PartitionKey::from("family_todos"),
+                                    &cosmos_todo.id,
+                                    cosmos_todo,
+                                    Some(options),
+                                )
+                                .await
+                        }
+                    })
+                    .await;
+                match result {
+                    Ok(response) => {
+                        self.charges.record("reassign_todos", response.headers());
+                        true
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            todo_id = %cosmos_todo.id,
+                            error = %e,
+                            "Failed to reassign todo"
+                        );
+                        false
+                    }
+                }
+            })
+            .buffer_unordered(REASSIGN_CONCURRENCY)
+            .collect()
+            .await;
+        self.cache.invalidate(group_id);
+
+        let reassigned = outcomes
+            .into_iter()
+            .filter(|reassigned| *reassigned)
+            .count();
+        tracing::info!(from, to, reassigned, "Reassigned todos in Cosmos DB");
+        Ok(reassigned)
+    }
+
+    /// Permanently deletes a todo item of `group_id` from the Cosmos DB container
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the todo does not exist in the group, if the
+    /// deletion operation fails or if there's an issue connecting to the Cosmos DB service.
+    pub async fn purge_todo(&self, todo_id: &str, group_id: &str) -> Result<(), AzureError> {
+        if self
+            .query_all::<CosmosDbTodo>("purge_todo", todo_by_id_query(todo_id, group_id)?)
+            .await?
+            .is_empty()
+        {
+            return Err(AzureError::message(
+                ErrorKind::Other,
+                format!("Todo {todo_id} not found"),
+            ));
+        }
+
+        let container = &self.client.container();
+        let response = self
+            .retry
+            .run("delete", || async move {
+                container
+                    .delete_item(PartitionKey::from("family_todos"), todo_id, None)
+                    .await
+            })
             .await?;
+        self.charges.record("purge_todo", response.headers());
+        self.cache.invalidate(group_id);
 
         Ok(())
     }
+
+    /// Permanently deletes every todo of `group_id`, including those in the
+    /// trash, and returns how many were deleted. Meant for clearing test data
+    /// outside production; callers must check the environment.
+    ///
+    /// Deletes run up to `WIPE_CONCURRENCY` at a time; a todo that fails to
+    /// delete is logged and skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzureError` if the todos cannot be queried or if there's an
+    /// issue connecting to the Cosmos DB service.
+    pub async fn wipe_todos(&self, group_id: &str) -> Result<usize, AzureError> {
+        let query = group_query(
+            format!(
+                "SELECT VALUE c.id FROM c WHERE c.partition_key = 'family_todos' AND {}",
+                in_group()
+            ),
+            group_id,
+        )?;
+        let ids = self.query_all::<String>("wipe_todos", query).await?;
+
+        let container = &self.client.container();
+        let outcomes: Vec<bool> = futures::stream::iter(ids)
+            .map(|todo_id| async move {
+                let todo_id = &todo_id;
+                let result = self
+                    .retry
+                    .run("delete", || async move {
+                        container
+                            .delete_item(PartitionKey::from("family_todos"), todo_id, None)
+                            .await
+                    })
+                    .await;
+                match result {
+                    Ok(response) => {
+                        self.charges.record("wipe_todos", response.headers());
+                        true
+                    }
+                    Err(e) => {
+                        tracing::warn!(todo_id = %todo_id, error = %e, "Failed to wipe todo");
+                        false
+                    }
+                }
+            })
+            .buffer_unordered(WIPE_CONCURRENCY)
+            .collect()
+            .await;
+        self.cache.invalidate(group_id);
+
+        let wiped = outcomes.into_iter().filter(|wiped| *wiped).count();
+        tracing::warn!(group_id, wiped, "Wiped todos from Cosmos DB");
+        Ok(wiped)
+    }
+
+    async fn set_deleted_at(
+        &self,
+        operation: &'static str,
+        todo_id: &str,
+        group_id: &str,
+        deleted_at: Option<i64>,
+    ) -> Result<(), AzureError> {
+        let updated_at: u64 = chrono::Utc::now()
+            .timestamp()
+            .max(0)
+            .try_into()
+            .unwrap_or(0);
+        self.replace_if_unchanged(operation, todo_id, group_id, |cosmos_todo| {
+            cosmos_todo.deleted_at = deleted_at;
+            cosmos_todo.updated_at = updated_at;
+        })
+        .await
+    }
+
+    /// Reads a todo of `group_id`, including one in the trash, applies `change`
+    /// and replaces it only if it did not change since it was read. When it
+    /// did, it is read and changed again, so a concurrent edit is never lost.
+    async fn replace_if_unchanged(
+        &self,
+        operation: &'static str,
+        todo_id: &str,
+        group_id: &str,
+        change: impl Fn(&mut CosmosDbTodo),
+    ) -> Result<(), AzureError> {
+        let mut attempt = 1;
+        loop {
+            let Some(mut cosmos_todo) = self
+                .query_all::<CosmosDbTodo>(operation, todo_by_id_query(todo_id, group_id)?)
+                .await?
+                .into_iter()
+                .next()
+            else {
+                return Err(AzureError::message(
+                    ErrorKind::Other,
+                    format!("Todo {todo_id} not found"),
+                ));
+            };
+            change(&mut cosmos_todo);
+
+            let options = ItemOptions {
+                if_match_etag: cosmos_todo.etag.clone().map(Etag::from),
+                ..Default::default()
+            };
+            let container = &self.client.container();
+            let cosmos_todo = &cosmos_todo;
+            let result = self
+                .retry
+                .run_conditional("replace", || {
+                    let options = options.clone();
+                    async move {
+                        container
+                            .replace_item(
+                                PartitionKey::from("family_todos"),
+                                todo_id,
+                                cosmos_todo,
+                                Some(options),
+                            )
+                            .await
+                    }
+                })
+                .await;
+            self.cache.invalidate(group_id);
+
+            match result {
+                Ok(response) => {
+                    self.charges.record(operation, response.headers());
+                    return Ok(());
+                }
+                Err(e)
+                    if e.http_status() == Some(StatusCode::PreconditionFailed)
+                        && attempt < MAX_REPLACE_ATTEMPTS =>
+                {
+                    tracing::warn!(
+                        todo_id,
+                        operation,
+                        attempt,
+                        "Todo changed meanwhile, retrying"
+                    );
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Builds a query for a single todo of `group_id` with the id bound as the `@id`
+/// parameter, so the id is never spliced into the query text.
+fn todo_by_id_query(todo_id: &str, group_id: &str) -> Result<Query, AzureError> {
+    group_query(
+        format!(
+            "SELECT * FROM c WHERE c.id = @id AND c.partition_key = 'family_todos' AND {}",
+            in_group()
+        ),
+        group_id,
+    )?
+    .with_parameter("@id", todo_id)
+    .map_err(|e| AzureError::new(ErrorKind::DataConversion, e))
 }
 
 // Global lazy-initialized instance
@@ -222,7 +1170,10 @@ static COSMOS_SERVICE: std::sync::LazyLock<
         crate::config::get_config().map_err(|e| format!("Failed to get app config: {e}"))?;
     let client = CosmosDBClient::new(app_config)
         .map_err(|e| format!("Failed to create Cosmos DB client: {e}"))?;
-    Ok(CosmosService::new(client).map_err(|e| format!("Failed to create Cosmos service: {e}"))?)
+    let retry = RetryPolicy::new(app_config.cosmos.max_retries);
+    let cache_ttl = Duration::from_secs(app_config.cosmos.cache_ttl_secs);
+    Ok(CosmosService::new(client, retry, cache_ttl)
+        .map_err(|e| format!("Failed to create Cosmos service: {e}"))?)
 });
 
 // Helper function to get the global instance
@@ -239,13 +1190,22 @@ pub fn get_cosmos_service() -> Result<
     COSMOS_SERVICE.as_ref()
 }
 
-/// Initialize the database and container on first access
+/// Initializes the Cosmos DB service and creates the database and container
+/// if this is a fresh account.
 ///
 /// # Errors
 ///
-/// Returns an error if the Cosmos DB service cannot be initialized or accessed.
-pub fn initialize_cosmos_db() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    get_cosmos_service().map_err(|e| format!("Failed to get Cosmos service: {e}"))?;
+/// Returns an error if the Cosmos DB service cannot be initialized or the
+/// database or container cannot be provisioned.
+pub async fn initialize_cosmos_db() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let service = get_cosmos_service().map_err(|e| format!("Failed to get Cosmos service: {e}"))?;
+    let app_config =
+        crate::config::get_config().map_err(|e| format!("Failed to get app config: {e}"))?;
+    service
+        .client
+        .ensure_provisioned(app_config.cosmos.throughput)
+        .await
+        .map_err(|e| format!("Failed to provision Cosmos DB: {e}"))?;
 
     Ok(())
 }
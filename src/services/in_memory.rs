@@ -0,0 +1,524 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::domain::todo::{Comment, MAX_PAGE_SIZE, SortBy, Todo, TodoFilter, TodoPage, TodoStatus};
+use crate::services::repository::{RepositoryError, TodoRepository};
+
+/// A stored todo with its group, when it was deleted while it is in the trash,
+/// and when its due date reminder was sent.
+#[derive(Debug, Clone)]
+struct StoredTodo {
+    group_id: String,
+    todo: Todo,
+    deleted_at: Option<i64>,
+    reminder_sent_at: Option<i64>,
+}
+
+/// [`TodoRepository`] keeping todos in memory, for tests and running without
+/// Cosmos DB.
+///
+/// It follows the Cosmos DB service: ids are unique across groups, todos of
+/// other groups are invisible, deleted todos are kept but hidden, and updates
+/// keep the creation time and comments and stamp the completion time.
+#[derive(Debug, Default)]
+pub struct InMemoryTodoRepository {
+    todos: Mutex<Vec<StoredTodo>>,
+}
+
+impl InMemoryTodoRepository {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn todos(&self) -> Result<std::sync::MutexGuard<'_, Vec<StoredTodo>>, RepositoryError> {
+        self.todos
+            .lock()
+            .map_err(|_| "In-memory todo store is poisoned".into())
+    }
+
+    fn set_deleted_at(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        deleted_at: Option<i64>,
+    ) -> Result<(), RepositoryError> {
+        let mut todos = self.todos()?;
+        let stored = todos
+            .iter_mut()
+            .find(|stored| stored.todo.id == todo_id && stored.group_id == group_id)
+            .ok_or_else(|| format!("Todo {todo_id} not found"))?;
+        stored.deleted_at = deleted_at;
+        stored.todo.updated_at = now();
+        Ok(())
+    }
+}
+
+fn now() -> u64 {
+    chrono::Utc::now()
+        .timestamp()
+        .max(0)
+        .try_into()
+        .unwrap_or(0)
+}
+
+#[async_trait]
+impl TodoRepository for InMemoryTodoRepository {
+    async fn create(
+        &self,
+        todo: Todo,
+        group_id: &str,
+        created_by: &str,
+    ) -> Result<Todo, RepositoryError> {
+        let mut todos = self.todos()?;
+        if todos.iter().any(|stored| stored.todo.id == todo.id) {
+            return Err(format!("Todo {} already exists", todo.id).into());
+        }
+        let todo = Todo {
+            created_by: created_by.to_string(),
+            completed_at: (todo.status == TodoStatus::Completed).then(now),
+            comments: Vec::new(),
+            ..todo
+        };
+        todos.push(StoredTodo {
+            group_id: group_id.to_string(),
+            todo: todo.clone(),
+            deleted_at: None,
+            reminder_sent_at: None,
+        });
+        Ok(todo)
+    }
+
+    async fn get(&self, todo_id: &str, group_id: &str) -> Result<Option<Todo>, RepositoryError> {
+        Ok(self
+            .todos()?
+            .iter()
+            .find(|stored| {
+                stored.todo.id == todo_id
+                    && stored.group_id == group_id
+                    && stored.deleted_at.is_none()
+            })
+            .map(|stored| stored.todo.clone()))
+    }
+
+    async fn list(&self, group_id: &str) -> Result<Vec<Todo>, RepositoryError> {
+        let mut todos: Vec<Todo> = self
+            .todos()?
+            .iter()
+            .filter(|stored| stored.group_id == group_id && stored.deleted_at.is_none())
+            .map(|stored| stored.todo.clone())
+            .collect();
+        todos.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(todos)
+    }
+
+    async fn update(
+        &self,
+        todo: Todo,
+        group_id: &str,
+        updated_by: &str,
+    ) -> Result<Todo, RepositoryError> {
+        let mut todos = self.todos()?;
+        let stored = todos
+            .iter_mut()
+            .find(|stored| stored.todo.id == todo.id && stored.group_id == group_id)
+            .ok_or_else(|| format!("Todo {} not found", todo.id))?;
+        let updated_at = now();
+        let completed_at = match todo.status {
+            TodoStatus::Completed => stored.todo.completed_at.or(Some(updated_at)),
+            _ => None,
+        };
+        let todo = Todo {
+            created_at: stored.todo.created_at,
+            created_by: stored.todo.created_by.clone(),
+            updated_at,
+            updated_by: updated_by.to_string(),
+            completed_at,
+            comments: stored.todo.comments.clone(),
+            ..todo
+        };
+        stored.todo = todo.clone();
+        Ok(todo)
+    }
+
+    async fn delete(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError> {
+        self.set_deleted_at(todo_id, group_id, Some(chrono::Utc::now().timestamp()))
+    }
+
+    async fn page(
+        &self,
+        group_id: &str,
+        filter: &TodoFilter,
+        sort: Option<(SortBy, bool)>,
+        page: u32,
+        page_size: u32,
+        continuation: Option<&str>,
+    ) -> Result<TodoPage, RepositoryError> {
+        let page_size = page_size.clamp(1, MAX_PAGE_SIZE) as usize;
+        let start = match continuation {
+            Some(token) => token
+                .parse::<usize>()
+                .map_err(|_| "Invalid continuation token")?,
+            None => (page as usize).saturating_mul(page_size),
+        };
+
+        let mut matching: Vec<Todo> = self
+            .list(group_id)
+            .await?
+            .into_iter()
+            .filter(|todo| filter.matches(todo))
+            .collect();
+        if let Some((sort_by, ascending)) = sort {
+            matching.sort_by(|a, b| {
+                let comparison = sort_by.compare(a, b);
+                if ascending {
+                    comparison
+                } else {
+                    comparison.reverse()
+                }
+            });
+        }
+
+        let total = matching.len();
+        let start = start.min(total);
+        let end = start.saturating_add(page_size).min(total);
+        Ok(TodoPage {
+            continuation: (end < total).then(|| end.to_string()),
+            total: (start == 0).then(|| u64::try_from(total).unwrap_or(u64::MAX)),
+            todos: matching.drain(start..end).collect(),
+        })
+    }
+
+    async fn add_comment(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        comment: Comment,
+    ) -> Result<Todo, RepositoryError> {
+        let mut todos = self.todos()?;
+        let stored = todos
+            .iter_mut()
+            .find(|stored| {
+                stored.todo.id == todo_id
+                    && stored.group_id == group_id
+                    && stored.deleted_at.is_none()
+            })
+            .ok_or_else(|| format!("Todo {todo_id} not found"))?;
+        stored.todo.updated_at = now();
+        stored.todo.updated_by.clone_from(&comment.author);
+        stored.todo.comments.push(comment);
+        Ok(stored.todo.clone())
+    }
+
+    async fn list_deleted(&self, group_id: &str) -> Result<Vec<Todo>, RepositoryError> {
+        let mut deleted: Vec<(i64, Todo)> = self
+            .todos()?
+            .iter()
+            .filter(|stored| stored.group_id == group_id)
+            .filter_map(|stored| Some((stored.deleted_at?, stored.todo.clone())))
+            .collect();
+        deleted.sort_by(|a, b| b.0.cmp(&a.0));
+        Ok(deleted.into_iter().map(|(_, todo)| todo).collect())
+    }
+
+    async fn restore(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError> {
+        self.set_deleted_at(todo_id, group_id, None)
+    }
+
+    async fn purge(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError> {
+        let mut todos = self.todos()?;
+        let count = todos.len();
+        todos.retain(|stored| !(stored.todo.id == todo_id && stored.group_id == group_id));
+        if todos.len() == count {
+            return Err(format!("Todo {todo_id} not found").into());
+        }
+        Ok(())
+    }
+
+    async fn reassign(
+        &self,
+        group_id: &str,
+        from: &str,
+        to: &str,
+        updated_by: &str,
+    ) -> Result<usize, RepositoryError> {
+        let to = to.parse().map_err(RepositoryError::from)?;
+        let updated_at = now();
+        let mut reassigned = 0;
+        for stored in self.todos()?.iter_mut() {
+            if stored.group_id == group_id && stored.todo.assignee.as_str() == from {
+                stored.todo.assignee.clone_from(&to);
+                stored.todo.updated_at = updated_at;
+                stored.todo.updated_by = updated_by.to_string();
+                reassigned += 1;
+            }
+        }
+        Ok(reassigned)
+    }
+
+    async fn wipe(&self, group_id: &str) -> Result<usize, RepositoryError> {
+        let mut todos = self.todos()?;
+        let count = todos.len();
+        todos.retain(|stored| stored.group_id != group_id);
+        Ok(count - todos.len())
+    }
+
+    async fn get_todos_due_for_reminder(
+        &self,
+        group_id: &str,
+        now: u64,
+        due_before: u64,
+    ) -> Result<Vec<Todo>, RepositoryError> {
+        Ok(self
+            .todos()?
+            .iter()
+            .filter(|stored| {
+                stored.group_id == group_id
+                    && stored.deleted_at.is_none()
+                    && stored.reminder_sent_at.is_none()
+                    && stored.todo.status != TodoStatus::Completed
+                    && stored
+                        .todo
+                        .due_date
+                        .is_some_and(|due| (now..=due_before).contains(&due))
+            })
+            .map(|stored| stored.todo.clone())
+            .collect())
+    }
+
+    async fn mark_reminder_sent(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        sent_at: i64,
+    ) -> Result<(), RepositoryError> {
+        let mut todos = self.todos()?;
+        let stored = todos
+            .iter_mut()
+            .find(|stored| stored.todo.id == todo_id && stored.group_id == group_id)
+            .ok_or_else(|| format!("Todo {todo_id} not found"))?;
+        stored.reminder_sent_at = Some(sent_at);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use super::*;
+
+    const GROUP: &str = "family";
+
+    fn todo(title: &str) -> Todo {
+        Todo::new(title.to_string(), "Mike".parse().unwrap())
+    }
+
+    #[test]
+    fn created_todo_can_be_read_back() {
+        let repository = InMemoryTodoRepository::new();
+        let created = block_on(repository.create(todo("Buy milk"), GROUP, "mike")).unwrap();
+
+        assert_eq!(created.created_by, "mike");
+        let stored = block_on(repository.get(&created.id, GROUP)).unwrap();
+        assert_eq!(stored, Some(created.clone()));
+        assert_eq!(block_on(repository.list(GROUP)).unwrap(), vec![created]);
+    }
+
+    #[test]
+    fn create_rejects_a_duplicate_id() {
+        let repository = InMemoryTodoRepository::new();
+        let todo = todo("Buy milk");
+        block_on(repository.create(todo.clone(), GROUP, "mike")).unwrap();
+
+        assert!(block_on(repository.create(todo, "other", "mike")).is_err());
+    }
+
+    #[test]
+    fn create_drops_comments_sent_by_the_client() {
+        let repository = InMemoryTodoRepository::new();
+        let mut forged = todo("Buy milk");
+        forged.comments.push(Comment {
+            author: "someone else".to_string(),
+            text: "forged".to_string(),
+            created_at: 0,
+        });
+
+        let created = block_on(repository.create(forged, GROUP, "mike")).unwrap();
+
+        assert!(created.comments.is_empty());
+    }
+
+    #[test]
+    fn create_stamps_completed_at_from_the_status() {
+        let repository = InMemoryTodoRepository::new();
+        let completed = Todo {
+            status: TodoStatus::Completed,
+            completed_at: None,
+            ..todo("Buy milk")
+        };
+        let forged = Todo {
+            completed_at: Some(1_700_000_000),
+            ..todo("Walk the dog")
+        };
+
+        let completed = block_on(repository.create(completed, GROUP, "mike")).unwrap();
+        let pending = block_on(repository.create(forged, GROUP, "mike")).unwrap();
+
+        assert!(completed.completed_at.is_some_and(|at| at > 1_700_000_000));
+        assert_eq!(pending.completed_at, None);
+    }
+
+    #[test]
+    fn todos_of_other_groups_are_invisible() {
+        let repository = InMemoryTodoRepository::new();
+        let created = block_on(repository.create(todo("Buy milk"), GROUP, "mike")).unwrap();
+
+        assert_eq!(
+            block_on(repository.get(&created.id, "other")).unwrap(),
+            None
+        );
+        assert!(block_on(repository.list("other")).unwrap().is_empty());
+        assert!(block_on(repository.update(created.clone(), "other", "eve")).is_err());
+        assert!(block_on(repository.delete(&created.id, "other")).is_err());
+    }
+
+    #[test]
+    fn update_keeps_server_owned_fields_and_stamps_the_editor() {
+        let repository = InMemoryTodoRepository::new();
+        let created = block_on(repository.create(todo("Buy milk"), GROUP, "mike")).unwrap();
+        block_on(repository.add_comment(
+            &created.id,
+            GROUP,
+            Comment {
+                author: "niina".to_string(),
+                text: "Oat milk".to_string(),
+                created_at: 1,
+            },
+        ))
+        .unwrap();
+
+        let edited = Todo {
+            title: "Buy oat milk".to_string(),
+            status: TodoStatus::Completed,
+            created_at: 0,
+            created_by: "eve".to_string(),
+            comments: Vec::new(),
+            ..created.clone()
+        };
+        let updated = block_on(repository.update(edited, GROUP, "niina")).unwrap();
+
+        assert_eq!(updated.title, "Buy oat milk");
+        assert_eq!(updated.created_at, created.created_at);
+        assert_eq!(updated.created_by, "mike");
+        assert_eq!(updated.updated_by, "niina");
+        assert_eq!(updated.comments.len(), 1);
+        assert!(updated.completed_at.is_some());
+        assert_eq!(
+            block_on(repository.get(&created.id, GROUP)).unwrap(),
+            Some(updated)
+        );
+    }
+
+    #[test]
+    fn update_of_an_unknown_todo_fails() {
+        let repository = InMemoryTodoRepository::new();
+
+        assert!(block_on(repository.update(todo("Buy milk"), GROUP, "mike")).is_err());
+    }
+
+    #[test]
+    fn deleted_todos_move_to_the_trash_until_restored_or_purged() {
+        let repository = InMemoryTodoRepository::new();
+        let kept = block_on(repository.create(todo("Buy milk"), GROUP, "mike")).unwrap();
+        let purged = block_on(repository.create(todo("Walk dog"), GROUP, "mike")).unwrap();
+
+        block_on(repository.delete(&kept.id, GROUP)).unwrap();
+        block_on(repository.delete(&purged.id, GROUP)).unwrap();
+        assert_eq!(block_on(repository.get(&kept.id, GROUP)).unwrap(), None);
+        assert!(block_on(repository.list(GROUP)).unwrap().is_empty());
+        assert_eq!(block_on(repository.list_deleted(GROUP)).unwrap().len(), 2);
+
+        block_on(repository.restore(&kept.id, GROUP)).unwrap();
+        block_on(repository.purge(&purged.id, GROUP)).unwrap();
+        assert!(block_on(repository.get(&kept.id, GROUP)).unwrap().is_some());
+        assert!(block_on(repository.list_deleted(GROUP)).unwrap().is_empty());
+        assert!(block_on(repository.restore(&purged.id, GROUP)).is_err());
+    }
+
+    #[test]
+    fn pages_follow_the_continuation_token() {
+        let repository = InMemoryTodoRepository::new();
+        for title in ["One", "Two", "Three"] {
+            block_on(repository.create(todo(title), GROUP, "mike")).unwrap();
+        }
+        let filter = TodoFilter::default();
+
+        let first = block_on(repository.page(GROUP, &filter, None, 0, 2, None)).unwrap();
+        assert_eq!(first.todos.len(), 2);
+        assert_eq!(first.total, Some(3));
+        let second =
+            block_on(repository.page(GROUP, &filter, None, 0, 2, first.continuation.as_deref()))
+                .unwrap();
+        assert_eq!(second.todos.len(), 1);
+        assert_eq!(second.continuation, None);
+        assert_eq!(second.total, None);
+    }
+
+    #[test]
+    fn reassign_and_wipe_only_touch_the_group() {
+        let repository = InMemoryTodoRepository::new();
+        block_on(repository.create(todo("Buy milk"), GROUP, "mike")).unwrap();
+        let other = block_on(repository.create(todo("Walk dog"), "other", "eve")).unwrap();
+
+        assert_eq!(
+            block_on(repository.reassign(GROUP, "Mike", "Niina", "mike")).unwrap(),
+            1
+        );
+        assert_eq!(
+            block_on(repository.list(GROUP)).unwrap()[0]
+                .assignee
+                .as_str(),
+            "Niina"
+        );
+        assert_eq!(block_on(repository.wipe(GROUP)).unwrap(), 1);
+        assert!(block_on(repository.list(GROUP)).unwrap().is_empty());
+        assert_eq!(
+            block_on(repository.get(&other.id, "other")).unwrap(),
+            Some(other)
+        );
+    }
+
+    #[test]
+    fn reminders_are_due_once_for_open_todos_of_the_group() {
+        let repository = InMemoryTodoRepository::new();
+        let due_soon = |title: &str| Todo {
+            due_date: Some(1_500),
+            ..todo(title)
+        };
+        let open = block_on(repository.create(due_soon("Buy milk"), GROUP, "mike")).unwrap();
+        let completed = Todo {
+            status: TodoStatus::Completed,
+            ..due_soon("Walk dog")
+        };
+        block_on(repository.create(completed, GROUP, "mike")).unwrap();
+        let later = Todo {
+            due_date: Some(5_000),
+            ..todo("Pay rent")
+        };
+        block_on(repository.create(later, GROUP, "mike")).unwrap();
+        block_on(repository.create(due_soon("Water plants"), "other", "eve")).unwrap();
+
+        let due = block_on(repository.get_todos_due_for_reminder(GROUP, 1_000, 2_000)).unwrap();
+        assert_eq!(due, vec![open.clone()]);
+
+        block_on(repository.mark_reminder_sent(&open.id, GROUP, 1_000)).unwrap();
+        assert!(
+            block_on(repository.get_todos_due_for_reminder(GROUP, 1_000, 2_000))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(block_on(repository.mark_reminder_sent(&open.id, "other", 1_000)).is_err());
+    }
+}
@@ -1 +1,16 @@
+#[cfg(feature = "ssr")]
+pub mod blob;
 pub mod cosmos;
+#[cfg(feature = "ssr")]
+pub mod in_memory;
+#[cfg(feature = "ssr")]
+pub mod notification;
+#[cfg(feature = "ssr")]
+pub mod repository;
+#[cfg(feature = "ssr")]
+pub mod webhook;
+
+#[cfg(feature = "ssr")]
+pub use in_memory::InMemoryTodoRepository;
+#[cfg(feature = "ssr")]
+pub use repository::{TodoRepository, TodoRepositoryContext, todo_repository};
@@ -0,0 +1,162 @@
+use chrono_tz::Tz;
+use lettre::message::{Mailbox, header::ContentType};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use crate::config::{EmailConfig, SmtpConfig};
+use crate::domain::todo::{BulkItemResult, Todo};
+use crate::services::TodoRepository;
+use crate::utils::unsanitize_string;
+
+/// How far ahead of the due date the reminder is sent.
+const REMINDER_WINDOW_SECS: u64 = 24 * 60 * 60;
+
+/// Sends due date reminder emails to assignees over SMTP.
+pub struct NotificationService {
+    mailer: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    emails: EmailConfig,
+    /// Time zone the due times are written in
+    tz: Tz,
+}
+
+impl NotificationService {
+    /// Creates a notification service sending through the configured SMTP server
+    /// and writing due times in `tz`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if SMTP is not configured, if the sender address is
+    /// invalid or if the SMTP transport cannot be created.
+    pub fn new(
+        smtp: &SmtpConfig,
+        emails: EmailConfig,
+        tz: Tz,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !smtp.is_configured() {
+            return Err("SMTP_HOST is not set".into());
+        }
+
+        let mut builder =
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp.host)?.port(smtp.port);
+        if !smtp.username.is_empty() {
+            builder = builder.credentials(Credentials::new(
+                smtp.username.clone(),
+                smtp.password.clone(),
+            ));
+        }
+
+        Ok(Self {
+            mailer: builder.build(),
+            from: smtp.from_address.parse()?,
+            emails,
+            tz,
+        })
+    }
+
+    /// Emails a reminder for every open todo of `group_id` due within the next
+    /// 24 hours that has not been reminded of yet, and marks each sent reminder
+    /// in the repository.
+    ///
+    /// A failing todo does not stop the rest; the outcome of each reminder is
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the due todos cannot be queried.
+    pub async fn send_due_reminders(
+        &self,
+        repository: &dyn TodoRepository,
+        group_id: &str,
+    ) -> Result<Vec<BulkItemResult>, Box<dyn std::error::Error + Send + Sync>> {
+        let now = chrono::Utc::now().timestamp();
+        let now_secs = u64::try_from(now).unwrap_or(0);
+        let due_todos = repository
+            .get_todos_due_for_reminder(group_id, now_secs, now_secs + REMINDER_WINDOW_SECS)
+            .await?;
+
+        let mut results = Vec::with_capacity(due_todos.len());
+        for todo in due_todos {
+            let error = match self.send_reminder(&todo).await {
+                Ok(()) => repository
+                    .mark_reminder_sent(&todo.id, group_id, now)
+                    .await
+                    .err()
+                    .map(|e| format!("Reminder sent but not recorded: {e}")),
+                Err(e) => Some(format!("Failed to send reminder: {e}")),
+            };
+            if let Some(error) = &error {
+                tracing::error!(todo_id = %todo.id, error = %error, "Due date reminder failed");
+            }
+            results.push(BulkItemResult { id: todo.id, error });
+        }
+
+        tracing::info!(
+            sent = results.iter().filter(|r| r.is_success()).count(),
+            due = results.len(),
+            "Sent due date reminders"
+        );
+        Ok(results)
+    }
+
+    async fn send_reminder(
+        &self,
+        todo: &Todo,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let recipient = self
+            .emails
+            .email_for(todo.assignee.as_str())
+            .ok_or_else(|| format!("No email configured for assignee {}", todo.assignee))?;
+
+        let due = todo
+            .due_date
+            .and_then(|due| i64::try_from(due).ok())
+            .and_then(|due| chrono::DateTime::from_timestamp(due, 0))
+            .map(|due| {
+                due.with_timezone(&self.tz)
+                    .format("%Y-%m-%d %H:%M %Z")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        // Titles and descriptions are stored HTML-escaped; the email is plain text
+        let title = unsanitize_string(&todo.title);
+        let mut body = format!("Hi {},\n\n\"{title}\" is due {due}.\n", todo.assignee);
+        if let Some(description) = todo.description.as_deref().filter(|d| !d.is_empty()) {
+            body.push_str(&format!("\n{}\n", unsanitize_string(description)));
+        }
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(recipient.parse()?)
+            .subject(format!("Reminder: {title} is due soon"))
+            .header(ContentType::TEXT_PLAIN)
+            .body(body)?;
+        self.mailer.send(message).await?;
+
+        Ok(())
+    }
+}
+
+// Global lazy-initialized instance
+static NOTIFICATION_SERVICE: std::sync::LazyLock<
+    Result<NotificationService, Box<dyn std::error::Error + Send + Sync>>,
+> = std::sync::LazyLock::new(|| {
+    let app_config =
+        crate::config::get_config().map_err(|e| format!("Failed to get app config: {e}"))?;
+    NotificationService::new(
+        &app_config.smtp,
+        app_config.emails.clone(),
+        app_config.server.timezone(),
+    )
+});
+
+/// Returns a reference to the global notification service instance.
+///
+/// # Errors
+///
+/// Returns an error if SMTP is not configured or the service failed to initialize.
+#[allow(clippy::borrowed_box)]
+pub fn get_notification_service()
+-> Result<&'static NotificationService, &'static Box<dyn std::error::Error + Send + Sync>> {
+    NOTIFICATION_SERVICE.as_ref()
+}
@@ -0,0 +1,392 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use leptos::prelude::*;
+
+use crate::domain::todo::{Comment, SortBy, Todo, TodoFilter, TodoPage};
+use crate::services::cosmos::CosmosService;
+
+/// Error returned by a [`TodoRepository`].
+pub type RepositoryError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Storage of todos, scoped to the household group of the caller.
+///
+/// Server functions reach the store through this trait, so tests can run them
+/// against an [`InMemoryTodoRepository`](crate::services::InMemoryTodoRepository)
+/// instead of Cosmos DB.
+#[async_trait]
+pub trait TodoRepository: Send + Sync {
+    /// Stores a new todo owned by `group_id`, recording `created_by` as its creator.
+    async fn create(
+        &self,
+        todo: Todo,
+        group_id: &str,
+        created_by: &str,
+    ) -> Result<Todo, RepositoryError>;
+
+    /// Returns the todo, or `None` if it does not exist in the group or is in the trash.
+    async fn get(&self, todo_id: &str, group_id: &str) -> Result<Option<Todo>, RepositoryError>;
+
+    /// Returns every todo of the group that is not in the trash, newest first.
+    async fn list(&self, group_id: &str) -> Result<Vec<Todo>, RepositoryError>;
+
+    /// Replaces a todo of the group, failing if it does not exist there, and
+    /// records `updated_by` as its last editor.
+    async fn update(
+        &self,
+        todo: Todo,
+        group_id: &str,
+        updated_by: &str,
+    ) -> Result<Todo, RepositoryError>;
+
+    /// Moves a todo of the group to the trash.
+    async fn delete(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError>;
+
+    /// Returns a page of the group's todos matching `filter`, in the order of
+    /// `sort` (criterion and whether ascending), newest first without one.
+    ///
+    /// Pass the `continuation` of the previous page to fetch the next one;
+    /// without it `page` selects the page index.
+    async fn page(
+        &self,
+        group_id: &str,
+        filter: &TodoFilter,
+        sort: Option<(SortBy, bool)>,
+        page: u32,
+        page_size: u32,
+        continuation: Option<&str>,
+    ) -> Result<TodoPage, RepositoryError>;
+
+    /// Drops anything cached for the group, so the next read sees the store.
+    fn invalidate(&self, _group_id: &str) {}
+
+    /// Appends a comment to a todo of the group and returns the updated todo.
+    async fn add_comment(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        comment: Comment,
+    ) -> Result<Todo, RepositoryError>;
+
+    /// Returns the todos of the group in the trash, most recently deleted first.
+    async fn list_deleted(&self, group_id: &str) -> Result<Vec<Todo>, RepositoryError>;
+
+    /// Brings a todo of the group back from the trash.
+    async fn restore(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError>;
+
+    /// Removes a todo of the group for good, whether or not it is in the trash.
+    async fn purge(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError>;
+
+    /// Reassigns every todo of the group assigned to `from`, including those in
+    /// the trash, to `to`, and returns how many were reassigned.
+    async fn reassign(
+        &self,
+        group_id: &str,
+        from: &str,
+        to: &str,
+        updated_by: &str,
+    ) -> Result<usize, RepositoryError>;
+
+    /// Removes every todo of the group for good and returns how many there were.
+    async fn wipe(&self, group_id: &str) -> Result<usize, RepositoryError>;
+
+    /// Returns the open todos of the group due between `now` and `due_before`
+    /// whose reminder has not been sent yet.
+    async fn get_todos_due_for_reminder(
+        &self,
+        group_id: &str,
+        now: u64,
+        due_before: u64,
+    ) -> Result<Vec<Todo>, RepositoryError>;
+
+    /// Records that the reminder of a todo of the group was sent at `sent_at`.
+    async fn mark_reminder_sent(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        sent_at: i64,
+    ) -> Result<(), RepositoryError>;
+}
+
+#[async_trait]
+impl TodoRepository for CosmosService {
+    async fn create(
+        &self,
+        todo: Todo,
+        group_id: &str,
+        created_by: &str,
+    ) -> Result<Todo, RepositoryError> {
+        self.create_todo(todo, group_id, created_by).await
+    }
+
+    async fn get(&self, todo_id: &str, group_id: &str) -> Result<Option<Todo>, RepositoryError> {
+        Ok(self.get_todo(todo_id, group_id).await?.map(Todo::from))
+    }
+
+    async fn list(&self, group_id: &str) -> Result<Vec<Todo>, RepositoryError> {
+        Ok(self
+            .get_todos(group_id)
+            .await?
+            .into_iter()
+            .map(Todo::from)
+            .collect())
+    }
+
+    async fn update(
+        &self,
+        todo: Todo,
+        group_id: &str,
+        updated_by: &str,
+    ) -> Result<Todo, RepositoryError> {
+        self.update_todo(todo, group_id, updated_by)
+            .await
+            .map(Todo::from)
+    }
+
+    async fn delete(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError> {
+        Ok(self.delete_todo(todo_id, group_id).await?)
+    }
+
+    async fn page(
+        &self,
+        group_id: &str,
+        filter: &TodoFilter,
+        sort: Option<(SortBy, bool)>,
+        page: u32,
+        page_size: u32,
+        continuation: Option<&str>,
+    ) -> Result<TodoPage, RepositoryError> {
+        Ok(self
+            .get_todos_filtered(group_id, filter, sort, page, page_size, continuation)
+            .await?)
+    }
+
+    fn invalidate(&self, group_id: &str) {
+        self.invalidate_todos(group_id);
+    }
+
+    async fn add_comment(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        comment: Comment,
+    ) -> Result<Todo, RepositoryError> {
+        Ok(CosmosService::add_comment(self, todo_id, group_id, comment).await?)
+    }
+
+    async fn list_deleted(&self, group_id: &str) -> Result<Vec<Todo>, RepositoryError> {
+        Ok(self
+            .get_deleted_todos(group_id)
+            .await?
+            .into_iter()
+            .map(Todo::from)
+            .collect())
+    }
+
+    async fn restore(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError> {
+        Ok(self.restore_todo(todo_id, group_id).await?)
+    }
+
+    async fn purge(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError> {
+        Ok(self.purge_todo(todo_id, group_id).await?)
+    }
+
+    async fn reassign(
+        &self,
+        group_id: &str,
+        from: &str,
+        to: &str,
+        updated_by: &str,
+    ) -> Result<usize, RepositoryError> {
+        Ok(self.reassign_todos(group_id, from, to, updated_by).await?)
+    }
+
+    async fn wipe(&self, group_id: &str) -> Result<usize, RepositoryError> {
+        Ok(self.wipe_todos(group_id).await?)
+    }
+
+    async fn get_todos_due_for_reminder(
+        &self,
+        group_id: &str,
+        now: u64,
+        due_before: u64,
+    ) -> Result<Vec<Todo>, RepositoryError> {
+        Ok(
+            CosmosService::get_todos_due_for_reminder(self, group_id, now, due_before)
+                .await?
+                .into_iter()
+                .map(Todo::from)
+                .collect(),
+        )
+    }
+
+    async fn mark_reminder_sent(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        sent_at: i64,
+    ) -> Result<(), RepositoryError> {
+        Ok(CosmosService::mark_reminder_sent(self, todo_id, group_id, sent_at).await?)
+    }
+}
+
+// Lets the global `&'static CosmosService` be shared as a repository
+#[async_trait]
+impl<R: TodoRepository + ?Sized> TodoRepository for &'static R {
+    async fn create(
+        &self,
+        todo: Todo,
+        group_id: &str,
+        created_by: &str,
+    ) -> Result<Todo, RepositoryError> {
+        (**self).create(todo, group_id, created_by).await
+    }
+
+    async fn get(&self, todo_id: &str, group_id: &str) -> Result<Option<Todo>, RepositoryError> {
+        (**self).get(todo_id, group_id).await
+    }
+
+    async fn list(&self, group_id: &str) -> Result<Vec<Todo>, RepositoryError> {
+        (**self).list(group_id).await
+    }
+
+    async fn update(
+        &self,
+        todo: Todo,
+        group_id: &str,
+        updated_by: &str,
+    ) -> Result<Todo, RepositoryError> {
+        (**self).update(todo, group_id, updated_by).await
+    }
+
+    async fn delete(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError> {
+        (**self).delete(todo_id, group_id).await
+    }
+
+    async fn page(
+        &self,
+        group_id: &str,
+        filter: &TodoFilter,
+        sort: Option<(SortBy, bool)>,
+        page: u32,
+        page_size: u32,
+        continuation: Option<&str>,
+    ) -> Result<TodoPage, RepositoryError> {
+        (**self)
+            .page(group_id, filter, sort, page, page_size, continuation)
+            .await
+    }
+
+    fn invalidate(&self, group_id: &str) {
+        (**self).invalidate(group_id);
+    }
+
+    async fn add_comment(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        comment: Comment,
+    ) -> Result<Todo, RepositoryError> {
+        (**self).add_comment(todo_id, group_id, comment).await
+    }
+
+    async fn list_deleted(&self, group_id: &str) -> Result<Vec<Todo>, RepositoryError> {
+        (**self).list_deleted(group_id).await
+    }
+
+    async fn restore(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError> {
+        (**self).restore(todo_id, group_id).await
+    }
+
+    async fn purge(&self, todo_id: &str, group_id: &str) -> Result<(), RepositoryError> {
+        (**self).purge(todo_id, group_id).await
+    }
+
+    async fn reassign(
+        &self,
+        group_id: &str,
+        from: &str,
+        to: &str,
+        updated_by: &str,
+    ) -> Result<usize, RepositoryError> {
+        (**self).reassign(group_id, from, to, updated_by).await
+    }
+
+    async fn wipe(&self, group_id: &str) -> Result<usize, RepositoryError> {
+        (**self).wipe(group_id).await
+    }
+
+    async fn get_todos_due_for_reminder(
+        &self,
+        group_id: &str,
+        now: u64,
+        due_before: u64,
+    ) -> Result<Vec<Todo>, RepositoryError> {
+        (**self)
+            .get_todos_due_for_reminder(group_id, now, due_before)
+            .await
+    }
+
+    async fn mark_reminder_sent(
+        &self,
+        todo_id: &str,
+        group_id: &str,
+        sent_at: i64,
+    ) -> Result<(), RepositoryError> {
+        (**self)
+            .mark_reminder_sent(todo_id, group_id, sent_at)
+            .await
+    }
+}
+
+/// Repository provided as context to replace Cosmos DB, e.g. in tests.
+#[derive(Clone)]
+pub struct TodoRepositoryContext(pub Arc<dyn TodoRepository>);
+
+/// Returns the repository provided as [`TodoRepositoryContext`], falling back
+/// to the global Cosmos DB service.
+///
+/// # Errors
+///
+/// Returns a `ServerFnError` if no repository is provided and the Cosmos DB
+/// service failed to initialize.
+pub fn todo_repository() -> Result<Arc<dyn TodoRepository>, ServerFnError> {
+    use crate::api::request_id::server_error;
+    use crate::services::cosmos::get_cosmos_service;
+
+    if let Some(TodoRepositoryContext(repository)) = use_context::<TodoRepositoryContext>() {
+        return Ok(repository);
+    }
+
+    let cosmos_service = get_cosmos_service()
+        .map_err(|e| server_error(format!("Failed to get Cosmos service: {}", e)))?;
+    Ok(Arc::new(cosmos_service))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use super::*;
+    use crate::services::InMemoryTodoRepository;
+
+    #[test]
+    fn provided_repository_replaces_cosmos_db() {
+        let owner = Owner::new();
+        owner.with(|| {
+            provide_context(TodoRepositoryContext(Arc::new(
+                InMemoryTodoRepository::new(),
+            )));
+            let todo = Todo::new("Buy milk".to_string(), "Mike".parse().unwrap());
+
+            let repository = todo_repository().unwrap();
+            let created = block_on(repository.create(todo, "family", "mike")).unwrap();
+
+            let provided = todo_repository().unwrap();
+            assert_eq!(
+                block_on(provided.get(&created.id, "family")).unwrap(),
+                Some(created)
+            );
+        });
+    }
+}
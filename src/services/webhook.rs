@@ -0,0 +1,86 @@
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use serde::Serialize;
+use tracing::Instrument;
+
+use crate::domain::todo::{Todo, TodoStatus};
+use crate::utils::unsanitize_string;
+
+/// How long a single delivery may take, including connecting.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Pause before the one retry of a failed delivery.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+static HTTP_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| {
+    reqwest::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .unwrap_or_default()
+});
+
+/// JSON body posted to the webhook when a todo is completed.
+#[derive(Debug, Clone, Serialize)]
+pub struct CompletedTodoPayload {
+    pub id: String,
+    pub title: String,
+    pub assignee: String,
+    /// Unix timestamp (seconds)
+    pub completed_at: u64,
+}
+
+impl From<&Todo> for CompletedTodoPayload {
+    fn from(todo: &Todo) -> Self {
+        Self {
+            id: todo.id.clone(),
+            // Titles are stored HTML-escaped; receivers get the text as typed
+            title: unsanitize_string(&todo.title),
+            assignee: todo.assignee.to_string(),
+            completed_at: todo.completed_at.unwrap_or(todo.updated_at),
+        }
+    }
+}
+
+/// Posts `todo` to `webhook_url` in the background if the update moved it to
+/// Completed from `previous`.
+///
+/// Does nothing when the URL is empty. The update never waits for the webhook:
+/// a failed delivery is retried once and then only logged.
+pub fn notify_if_completed(webhook_url: &str, previous: TodoStatus, todo: &Todo) {
+    if webhook_url.is_empty()
+        || previous == TodoStatus::Completed
+        || todo.status != TodoStatus::Completed
+    {
+        return;
+    }
+
+    let url = webhook_url.to_string();
+    let payload = CompletedTodoPayload::from(todo);
+    // Keep the request span, so the delivery logs carry the request id
+    tokio::spawn(deliver(url, payload).instrument(tracing::Span::current()));
+}
+
+async fn deliver(url: String, payload: CompletedTodoPayload) {
+    let mut result = post(&url, &payload).await;
+    if let Err(e) = &result {
+        tracing::warn!(todo_id = %payload.id, error = %e, "Completion webhook failed, retrying");
+        tokio::time::sleep(RETRY_DELAY).await;
+        result = post(&url, &payload).await;
+    }
+
+    match result {
+        Ok(()) => tracing::info!(todo_id = %payload.id, "Sent completion webhook"),
+        Err(e) => tracing::error!(todo_id = %payload.id, error = %e, "Completion webhook failed"),
+    }
+}
+
+async fn post(url: &str, payload: &CompletedTodoPayload) -> Result<(), reqwest::Error> {
+    HTTP_CLIENT
+        .post(url)
+        .json(payload)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
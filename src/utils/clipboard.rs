@@ -0,0 +1,81 @@
+/// Copies `text` to the clipboard, then calls `on_done` with whether it worked.
+///
+/// Uses `navigator.clipboard`. When that API is missing, as outside secure
+/// contexts, or the browser denies the write, the text is selected in a hidden
+/// input and copied with the legacy copy command instead.
+#[cfg(feature = "hydrate")]
+pub fn copy_to_clipboard(text: &str, on_done: impl Fn(bool) + Clone + 'static) {
+    use web_sys::js_sys::Reflect;
+    use web_sys::wasm_bindgen::JsValue;
+    use web_sys::wasm_bindgen::closure::Closure;
+
+    let Some(window) = web_sys::window() else {
+        on_done(false);
+        return;
+    };
+    let navigator = window.navigator();
+    let has_clipboard = Reflect::get(&navigator, &JsValue::from_str("clipboard"))
+        .is_ok_and(|clipboard| !clipboard.is_undefined());
+    if !has_clipboard {
+        on_done(copy_with_hidden_input(text));
+        return;
+    }
+
+    let fallback_text = text.to_string();
+    let on_denied = on_done.clone();
+    let resolved = Closure::<dyn FnMut(JsValue)>::once(move |_: JsValue| on_done(true));
+    let rejected = Closure::<dyn FnMut(JsValue)>::once(move |_: JsValue| {
+        on_denied(copy_with_hidden_input(&fallback_text));
+    });
+    let _ = navigator
+        .clipboard()
+        .write_text(text)
+        .then2(&resolved, &rejected);
+    // The promise calls one of them later; the other is never released, which
+    // is a few bytes per copy
+    resolved.forget();
+    rejected.forget();
+}
+
+/// Copies `text` by selecting it in a temporary input and running the legacy
+/// copy command; returns whether the browser copied it.
+#[cfg(feature = "hydrate")]
+fn copy_with_hidden_input(text: &str) -> bool {
+    use web_sys::wasm_bindgen::JsCast;
+    use web_sys::{HtmlDocument, HtmlInputElement};
+
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return false;
+    };
+    let (Some(body), Some(input)) = (
+        document.body(),
+        document
+            .create_element("input")
+            .ok()
+            .and_then(|element| element.dyn_into::<HtmlInputElement>().ok()),
+    ) else {
+        return false;
+    };
+    input.set_value(text);
+    input.set_read_only(true);
+    // Moved off screen rather than hidden with `display: none`, which cannot be selected
+    let _ = input.set_attribute(
+        "style",
+        "position: fixed; top: 0; left: -9999px; opacity: 0",
+    );
+    if body.append_child(&input).is_err() {
+        return false;
+    }
+    input.select();
+    let copied = document
+        .dyn_into::<HtmlDocument>()
+        .ok()
+        .and_then(|document| document.exec_command("copy").ok())
+        .unwrap_or(false);
+    input.remove();
+    copied
+}
+
+/// No-op outside the browser.
+#[cfg(not(feature = "hydrate"))]
+pub fn copy_to_clipboard(_text: &str, _on_done: impl Fn(bool) + Clone + 'static) {}
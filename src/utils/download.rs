@@ -0,0 +1,40 @@
+/// Saves `contents` as a file in the browser by clicking a temporary Blob link.
+///
+/// # Errors
+///
+/// Returns an error message if the Blob or the link cannot be created.
+#[cfg(feature = "hydrate")]
+pub fn download_file(file_name: &str, mime_type: &str, contents: &str) -> Result<(), String> {
+    use web_sys::js_sys::Array;
+    use web_sys::wasm_bindgen::{JsCast, JsValue};
+    use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+    let parts = Array::of1(&JsValue::from_str(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)
+        .map_err(|e| format!("Failed to create file: {e:?}"))?;
+    let url = Url::create_object_url_with_blob(&blob)
+        .map_err(|e| format!("Failed to create download link: {e:?}"))?;
+
+    let anchor = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.create_element("a").ok())
+        .and_then(|element| element.dyn_into::<HtmlAnchorElement>().ok())
+        .ok_or_else(|| "Failed to create download link".to_string())?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    Url::revoke_object_url(&url).map_err(|e| format!("Failed to release download link: {e:?}"))
+}
+
+/// No-op outside the browser.
+///
+/// # Errors
+///
+/// Never fails.
+#[cfg(not(feature = "hydrate"))]
+pub fn download_file(_file_name: &str, _mime_type: &str, _contents: &str) -> Result<(), String> {
+    Ok(())
+}
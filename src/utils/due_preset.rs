@@ -0,0 +1,168 @@
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+/// Local time a preset fills in when the form has no time of day yet; "Today"
+/// is due by the end of the day instead.
+const PRESET_HOUR: u32 = 9;
+
+/// Common due dates offered above the date field of the todo form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuePreset {
+    Today,
+    Tomorrow,
+    ThisWeekend,
+    NextMonday,
+}
+
+impl DuePreset {
+    pub const ALL: [Self; 4] = [
+        Self::Today,
+        Self::Tomorrow,
+        Self::ThisWeekend,
+        Self::NextMonday,
+    ];
+
+    /// Key of the button's label in [`crate::i18n`].
+    #[must_use]
+    pub const fn label_key(self) -> &'static str {
+        match self {
+            Self::Today => "preset.today",
+            Self::Tomorrow => "preset.tomorrow",
+            Self::ThisWeekend => "preset.this_weekend",
+            Self::NextMonday => "preset.next_monday",
+        }
+    }
+
+    /// The day the preset stands for, given the user's `today`:
+    /// - "This weekend" is the coming Saturday, or today during a weekend;
+    /// - "Next Monday" is the first Monday after today, a week ahead on Mondays.
+    #[must_use]
+    pub fn date(self, today: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Self::Today => Some(today),
+            Self::Tomorrow => today.succ_opt(),
+            Self::ThisWeekend => match today.weekday() {
+                Weekday::Sat | Weekday::Sun => Some(today),
+                weekday => {
+                    today.checked_add_days(Days::new(u64::from(Weekday::Sat.days_since(weekday))))
+                }
+            },
+            Self::NextMonday => {
+                let days_ahead = match Weekday::Mon.days_since(today.weekday()) {
+                    0 => 7,
+                    days => days,
+                };
+                today.checked_add_days(Days::new(u64::from(days_ahead)))
+            }
+        }
+    }
+
+    /// The wall-clock due time the preset fills in at `now`, in the user's time
+    /// zone: its day at the `entered` time of day, or at the preset's own time
+    /// when none is entered or the entered one has already passed. When both
+    /// have passed, as on a Sunday afternoon for "This weekend", it is due by
+    /// the end of the day.
+    #[must_use]
+    pub fn due(self, now: NaiveDateTime, entered: Option<NaiveTime>) -> Option<NaiveDateTime> {
+        let date = self.date(now.date())?;
+        let due = [entered, Some(self.default_time())]
+            .into_iter()
+            .flatten()
+            .map(|time| date.and_time(time))
+            .find(|due| *due > now);
+        Some(due.unwrap_or_else(|| date.and_time(end_of_day())))
+    }
+
+    /// Time of day to fill in when none has been entered.
+    #[must_use]
+    pub fn default_time(self) -> NaiveTime {
+        match self {
+            Self::Today => end_of_day(),
+            _ => NaiveTime::from_hms_opt(PRESET_HOUR, 0, 0).unwrap_or(NaiveTime::MIN),
+        }
+    }
+}
+
+fn end_of_day() -> NaiveTime {
+    NaiveTime::from_hms_opt(23, 59, 0).unwrap_or(NaiveTime::MIN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 6, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn time(hour: u32, minute: u32) -> Option<NaiveTime> {
+        NaiveTime::from_hms_opt(hour, minute, 0)
+    }
+
+    // June 11, 2025 is a Wednesday
+
+    #[test]
+    fn today_is_due_tonight() {
+        assert_eq!(
+            DuePreset::Today.due(at(11, 14, 30), None),
+            Some(at(11, 23, 59))
+        );
+        assert_eq!(
+            DuePreset::Today.due(at(11, 14, 30), time(18, 0)),
+            Some(at(11, 18, 0))
+        );
+    }
+
+    #[test]
+    fn an_entered_time_that_has_passed_is_replaced() {
+        assert_eq!(
+            DuePreset::Today.due(at(11, 14, 30), time(9, 0)),
+            Some(at(11, 23, 59))
+        );
+    }
+
+    #[test]
+    fn tomorrow_is_due_in_the_morning() {
+        assert_eq!(
+            DuePreset::Tomorrow.due(at(11, 14, 30), None),
+            Some(at(12, 9, 0))
+        );
+        // Past today's 9:00 already, which does not matter for tomorrow
+        assert_eq!(
+            DuePreset::Tomorrow.due(at(11, 14, 30), time(7, 0)),
+            Some(at(12, 7, 0))
+        );
+    }
+
+    #[test]
+    fn this_weekend_is_the_coming_saturday_or_today() {
+        assert_eq!(
+            DuePreset::ThisWeekend.due(at(11, 14, 30), None),
+            Some(at(14, 9, 0))
+        );
+        assert_eq!(
+            DuePreset::ThisWeekend.due(at(15, 8, 0), None),
+            Some(at(15, 9, 0))
+        );
+        // Past 9:00 on a Sunday, so by the end of the day
+        assert_eq!(
+            DuePreset::ThisWeekend.due(at(15, 14, 30), None),
+            Some(at(15, 23, 59))
+        );
+    }
+
+    #[test]
+    fn next_monday_is_next_week() {
+        assert_eq!(
+            DuePreset::NextMonday.due(at(11, 14, 30), None),
+            Some(at(16, 9, 0))
+        );
+        // On a Monday it is a week ahead
+        assert_eq!(
+            DuePreset::NextMonday.due(at(16, 14, 30), None),
+            Some(at(23, 9, 0))
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use chrono::{DateTime, SecondsFormat};
+
+use crate::domain::todo::{Todo, TodoStatus};
+use crate::utils::unsanitize_string;
+
+/// Column headers of the CSV export, in order.
+const CSV_HEADER: &str = "id,title,description,due_date,assignee,status,created_at";
+
+/// Renders todos as CSV with a header row.
+///
+/// Titles and descriptions are written as typed, without the HTML escaping they
+/// are stored with. Fields containing commas, quotes or line breaks are quoted,
+/// with embedded quotes doubled. Timestamps are written as ISO 8601 in UTC.
+#[must_use]
+pub fn todos_to_csv(todos: &[Todo]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push_str("\r\n");
+
+    for todo in todos {
+        let fields = [
+            csv_field(&todo.id),
+            csv_field(&unsanitize_string(&todo.title)),
+            csv_field(&unsanitize_string(
+                todo.description.as_deref().unwrap_or_default(),
+            )),
+            csv_field(&todo.due_date.map(iso_8601).unwrap_or_default()),
+            csv_field(todo.assignee.as_str()),
+            csv_field(todo.status.as_str()),
+            csv_field(&iso_8601(todo.created_at)),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+
+    csv
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Formats a Unix timestamp as ISO 8601, or an empty string if it is out of range.
+fn iso_8601(timestamp: u64) -> String {
+    i64::try_from(timestamp)
+        .ok()
+        .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+        .map(|datetime| datetime.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .unwrap_or_default()
+}
+
+/// Renders the todos that have a due date as an iCalendar (RFC 5545) feed.
+///
+/// Each todo becomes a `VEVENT` at its due time whose UID is derived from the todo
+/// id, so re-imports update the existing events instead of duplicating them.
+/// Titles and descriptions are written as typed, like in [`todos_to_csv`].
+#[must_use]
+pub fn todos_to_ical(todos: &[Todo]) -> String {
+    let stamp = ical_datetime(chrono::Utc::now().timestamp());
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Cosmic Todos//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+
+    for todo in todos {
+        let Some(due) = todo.due_date.and_then(|due| i64::try_from(due).ok()) else {
+            continue;
+        };
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@cosmic-todos", todo.id));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        lines.push(format!("DTSTART:{}", ical_datetime(due)));
+        lines.push(format!(
+            "SUMMARY:{}",
+            ical_text(&unsanitize_string(&todo.title))
+        ));
+        if let Some(description) = &todo.description {
+            lines.push(format!(
+                "DESCRIPTION:{}",
+                ical_text(&unsanitize_string(description))
+            ));
+        }
+        if todo.status == TodoStatus::Completed {
+            lines.push("STATUS:COMPLETED".to_string());
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut ical = String::new();
+    for line in &lines {
+        ical.push_str(&fold_ical_line(line));
+        ical.push_str("\r\n");
+    }
+    ical
+}
+
+/// Formats a Unix timestamp as an iCalendar UTC date-time, e.g. `20250131T170000Z`.
+fn ical_datetime(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .unwrap_or_default()
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string()
+}
+
+/// Escapes backslashes, semicolons, commas and line breaks in a TEXT value.
+fn ical_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\n', '\r'], "\\n")
+}
+
+/// Folds a content line into chunks of at most 75 octets, continuing each
+/// chunk on a new line that starts with a space.
+fn fold_ical_line(line: &str) -> String {
+    const MAX_OCTETS: usize = 75;
+
+    let mut folded = String::with_capacity(line.len());
+    let mut line_octets = 0;
+    for c in line.chars() {
+        if line_octets + c.len_utf8() > MAX_OCTETS {
+            folded.push_str("\r\n ");
+            line_octets = 1;
+        }
+        folded.push(c);
+        line_octets += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn todo(title: &str, description: Option<&str>) -> Todo {
+        Todo {
+            id: "1".to_string(),
+            description: description.map(str::to_string),
+            created_at: 1_735_689_600,
+            ..Todo::new(title.to_string(), "Mike".parse().unwrap())
+        }
+    }
+
+    fn csv_row(todo: &Todo) -> String {
+        todos_to_csv(std::slice::from_ref(todo))
+            .strip_prefix(&format!("{CSV_HEADER}\r\n"))
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn plain_fields_are_not_quoted() {
+        assert_eq!(
+            csv_row(&todo("Buy milk", None)),
+            "1,Buy milk,,,Mike,Pending,2025-01-01T00:00:00Z\r\n"
+        );
+    }
+
+    #[test]
+    fn commas_quotes_and_line_breaks_are_quoted() {
+        assert_eq!(csv_field("milk, eggs"), "\"milk, eggs\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(csv_field("carriage\rreturn"), "\"carriage\rreturn\"");
+    }
+
+    #[test]
+    fn titles_and_descriptions_are_written_as_typed() {
+        let todo = todo(
+            &crate::utils::sanitize_string("Tom & Jerry's <show>"),
+            Some("Rock &amp; roll"),
+        );
+
+        assert_eq!(
+            csv_row(&todo),
+            "1,Tom & Jerry's <show>,Rock & roll,,Mike,Pending,2025-01-01T00:00:00Z\r\n"
+        );
+    }
+
+    #[test]
+    fn ical_text_escapes_backslashes_separators_and_line_breaks() {
+        assert_eq!(ical_text(r"C:\todo"), r"C:\\todo");
+        assert_eq!(ical_text("milk; eggs, bread"), r"milk\; eggs\, bread");
+        assert_eq!(
+            ical_text("one\r\ntwo\nthree\rfour"),
+            r"one\ntwo\nthree\nfour"
+        );
+    }
+
+    #[test]
+    fn ical_lines_are_folded_at_75_octets() {
+        let line = "x".repeat(160);
+
+        let folded = fold_ical_line(&line);
+
+        let parts: Vec<&str> = folded.split("\r\n").collect();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].len(), 75);
+        assert_eq!(parts[1].len(), 75);
+        assert!(parts[1].starts_with(' '));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn ical_folding_keeps_multi_byte_characters_whole() {
+        // 'ä' takes two octets, so the 75th octet would fall inside the 38th one
+        let line = "ä".repeat(50);
+
+        let folded = fold_ical_line(&line);
+
+        for part in folded.split("\r\n") {
+            assert!(part.len() <= 75, "{part}");
+        }
+        assert_eq!(folded.split("\r\n").next().unwrap().len(), 74);
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn ical_summary_is_written_as_typed() {
+        let todo = Todo {
+            due_date: Some(1_735_689_600),
+            ..todo(&crate::utils::sanitize_string("Tom & Jerry's <show>"), None)
+        };
+
+        let ical = todos_to_ical(&[todo]);
+
+        assert!(
+            ical.contains("\r\nSUMMARY:Tom & Jerry's <show>\r\n"),
+            "{ical}"
+        );
+        assert!(ical.contains("\r\nDTSTART:20250101T000000Z\r\n"), "{ical}");
+    }
+}
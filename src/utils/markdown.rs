@@ -0,0 +1,113 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd, html};
+use pulldown_cmark_escape::escape_href;
+
+/// URL schemes a rendered link may point to.
+const ALLOWED_LINK_SCHEMES: [&str; 3] = ["http://", "https://", "mailto:"];
+
+/// Renders a todo description written in markdown to a safe subset of HTML.
+///
+/// Raw HTML in the input is shown as text, images are reduced to their alt
+/// text and links are only kept for `http`, `https` and `mailto` targets.
+/// Kept links open in a new tab with `rel="noopener noreferrer"`.
+#[must_use]
+pub fn render_markdown(input: &str) -> String {
+    let mut link_kept = Vec::new();
+    let events =
+        Parser::new_ext(input, Options::ENABLE_STRIKETHROUGH).filter_map(|event| match event {
+            Event::Html(raw) | Event::InlineHtml(raw) => Some(Event::Text(raw)),
+            Event::Start(Tag::Image { .. }) | Event::End(TagEnd::Image) => None,
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                let anchor = safe_anchor(&dest_url);
+                link_kept.push(anchor.is_some());
+                anchor.map(|anchor| Event::InlineHtml(anchor.into()))
+            }
+            Event::End(TagEnd::Link) => link_kept
+                .pop()
+                .unwrap_or(false)
+                .then(|| Event::InlineHtml("</a>".into())),
+            event => Some(event),
+        });
+
+    let mut output = String::with_capacity(input.len() * 3 / 2);
+    html::push_html(&mut output, events);
+    output
+}
+
+/// Builds the opening `<a>` tag for an allowed link target.
+fn safe_anchor(url: &str) -> Option<String> {
+    let lowercase = url.trim().to_lowercase();
+    if !ALLOWED_LINK_SCHEMES
+        .iter()
+        .any(|scheme| lowercase.starts_with(scheme))
+    {
+        return None;
+    }
+
+    let mut anchor = String::from("<a href=\"");
+    escape_href(&mut anchor, url.trim()).ok()?;
+    anchor.push_str("\" target=\"_blank\" rel=\"noopener noreferrer\">");
+    Some(anchor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn javascript_and_data_links_keep_only_their_text() {
+        assert_eq!(
+            render_markdown("[click](javascript:alert(1))"),
+            "<p>click</p>\n"
+        );
+        assert_eq!(
+            render_markdown("[click](JavaScript:alert(1))"),
+            "<p>click</p>\n"
+        );
+        assert_eq!(
+            render_markdown("[click](data:text/html;base64,PHNjcmlwdD4=)"),
+            "<p>click</p>\n"
+        );
+    }
+
+    #[test]
+    fn raw_html_is_shown_as_text() {
+        assert_eq!(
+            render_markdown("<script>alert(1)</script>"),
+            "&lt;script&gt;alert(1)&lt;/script&gt;"
+        );
+        assert_eq!(
+            render_markdown("Hi <b onclick=\"x()\">there</b>"),
+            "<p>Hi &lt;b onclick=\"x()\"&gt;there&lt;/b&gt;</p>\n"
+        );
+    }
+
+    #[test]
+    fn images_are_reduced_to_their_alt_text() {
+        assert_eq!(
+            render_markdown("![cat](https://example.com/cat.png)"),
+            "<p>cat</p>\n"
+        );
+    }
+
+    #[test]
+    fn kept_links_open_in_a_new_tab_without_an_opener() {
+        assert_eq!(
+            render_markdown("[docs](https://example.com/a?b=1&c=2)"),
+            "<p><a href=\"https://example.com/a?b=1&amp;c=2\" target=\"_blank\" \
+             rel=\"noopener noreferrer\">docs</a></p>\n"
+        );
+        assert_eq!(
+            render_markdown("[mail](mailto:mike@example.com)"),
+            "<p><a href=\"mailto:mike@example.com\" target=\"_blank\" \
+             rel=\"noopener noreferrer\">mail</a></p>\n"
+        );
+    }
+
+    #[test]
+    fn emphasis_and_strikethrough_are_rendered() {
+        assert_eq!(
+            render_markdown("**bold** ~~gone~~"),
+            "<p><strong>bold</strong> <del>gone</del></p>\n"
+        );
+    }
+}
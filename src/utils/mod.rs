@@ -1,5 +1,31 @@
+pub mod clipboard;
+pub mod download;
+pub mod due_preset;
+pub mod export;
+pub mod markdown;
+pub mod preferences;
+pub mod quick_add;
+pub mod relative_time;
 pub mod sanitization;
+pub mod search;
+pub mod snooze;
+pub mod sync;
+pub mod timezone;
+pub mod upload;
 pub mod validation;
 
+pub use clipboard::*;
+pub use download::*;
+pub use due_preset::*;
+pub use export::*;
+pub use markdown::*;
+pub use preferences::*;
+pub use quick_add::*;
+pub use relative_time::*;
 pub use sanitization::*;
+pub use search::*;
+pub use snooze::*;
+pub use sync::*;
+pub use timezone::*;
+pub use upload::*;
 pub use validation::*;
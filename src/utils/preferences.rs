@@ -0,0 +1,67 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// localStorage key of the todo list sort field.
+pub const SORT_BY_KEY: &str = "pref_sort_by";
+/// localStorage key of the todo list sort direction.
+pub const SORT_ASCENDING_KEY: &str = "pref_sort_ascending";
+/// localStorage key of the status filter.
+pub const FILTER_STATUS_KEY: &str = "pref_filter_status";
+/// localStorage key of the assignee filter.
+pub const FILTER_ASSIGNEE_KEY: &str = "pref_filter_assignee";
+/// localStorage key of the "Show my todos on load" setting.
+pub const ASSIGNED_TO_ME_DEFAULT_KEY: &str = "pref_assigned_to_me_default";
+/// localStorage key of the "Skip delete confirmation" setting.
+pub const SKIP_DELETE_CONFIRM_KEY: &str = "pref_skip_delete_confirm";
+/// localStorage key of the calendar's first day of the week.
+pub const WEEK_START_KEY: &str = "pref_week_start";
+
+/// Reads a preference stored as JSON in localStorage.
+///
+/// Returns `None` if the preference is missing or unreadable; a corrupt value
+/// is removed so that the default is used from then on.
+#[cfg(feature = "hydrate")]
+#[must_use]
+pub fn get_preference<T: DeserializeOwned>(key: &str) -> Option<T> {
+    use leptos::leptos_dom::logging;
+
+    let storage = web_sys::window()?.local_storage().ok()??;
+    let value = storage.get_item(key).ok()??;
+    match serde_json::from_str(&value) {
+        Ok(preference) => Some(preference),
+        Err(e) => {
+            logging::console_warn(&format!("Ignoring corrupt preference {key}: {e}"));
+            let _ = storage.remove_item(key);
+            None
+        }
+    }
+}
+
+/// Stores a preference as JSON in localStorage.
+#[cfg(feature = "hydrate")]
+pub fn set_preference<T: Serialize>(key: &str, value: &T) {
+    use leptos::leptos_dom::logging;
+
+    let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+    else {
+        return;
+    };
+    match serde_json::to_string(value) {
+        Ok(value) => {
+            if let Err(e) = storage.set_item(key, &value) {
+                logging::console_warn(&format!("Failed to store preference {key}: {e:?}"));
+            }
+        }
+        Err(e) => logging::console_warn(&format!("Failed to serialize preference {key}: {e}")),
+    }
+}
+
+// Server-side stubs
+#[cfg(not(feature = "hydrate"))]
+#[must_use]
+pub fn get_preference<T: DeserializeOwned>(_key: &str) -> Option<T> {
+    None
+}
+
+#[cfg(not(feature = "hydrate"))]
+pub fn set_preference<T: Serialize>(_key: &str, _value: &T) {}
@@ -0,0 +1,268 @@
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+use crate::domain::todo::TodoPriority;
+
+/// Local hour a quick-added todo becomes due when only a day is given, like a
+/// snoozed todo without a time of day.
+const DEFAULT_HOUR: u32 = 9;
+
+/// A todo typed on one line, such as "Buy milk tomorrow 5pm #shop @Niina !high".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuickAdd {
+    pub title: String,
+    /// Wall-clock due time in the user's time zone
+    pub due: Option<NaiveDateTime>,
+    pub tags: Vec<String>,
+    /// Name typed after `@`, not checked against the configured assignees
+    pub assignee: Option<String>,
+    pub priority: Option<TodoPriority>,
+}
+
+/// Splits a quick-add line into a title and a due time, relative to `now` in
+/// the user's time zone.
+///
+/// Recognised, in any position and case:
+/// - a day: `today`, `tomorrow` or a weekday name (`friday`, `fri`), which
+///   means the next such day after today;
+/// - a time: `5pm`, `5:30pm`, `12am` or `17:00`;
+/// - tags: `#shop`, any number of them;
+/// - an assignee: `@Niina`;
+/// - a priority: `!low`, `!medium` or `!high`.
+///
+/// The words `on` and `at` right before a day or time are dropped with it.
+/// Only the first day, time, assignee and priority count; later ones stay in
+/// the title. A day without a time is due at 9:00, and a time without a day is
+/// today, or tomorrow once that time has passed. When nothing is recognised,
+/// the whole line is the title.
+#[must_use]
+pub fn parse_quick_add(input: &str, now: NaiveDateTime) -> QuickAdd {
+    let today = now.date();
+    let mut day = None;
+    let mut time = None;
+    let mut tags: Vec<String> = Vec::new();
+    let mut assignee = None;
+    let mut priority = None;
+    let mut words: Vec<&str> = Vec::new();
+
+    for word in input.split_whitespace() {
+        if let Some(tag) = word.strip_prefix('#').filter(|tag| !tag.is_empty()) {
+            if !tags
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(tag))
+            {
+                tags.push(tag.to_string());
+            }
+            continue;
+        }
+        if let Some(name) = word
+            .strip_prefix('@')
+            .filter(|name| assignee.is_none() && !name.is_empty())
+        {
+            assignee = Some(name.to_string());
+            continue;
+        }
+        if let Some(parsed) = priority
+            .is_none()
+            .then(|| word.strip_prefix('!').and_then(parse_priority))
+            .flatten()
+        {
+            priority = Some(parsed);
+            continue;
+        }
+
+        let token = word.to_lowercase();
+        let recognised =
+            if let Some(parsed) = day.is_none().then(|| parse_day(&token, today)).flatten() {
+                day = Some(parsed);
+                true
+            } else if let Some(parsed) = time.is_none().then(|| parse_time(&token)).flatten() {
+                time = Some(parsed);
+                true
+            } else {
+                false
+            };
+
+        if recognised {
+            if words
+                .last()
+                .is_some_and(|last| matches!(last.to_lowercase().as_str(), "on" | "at"))
+            {
+                words.pop();
+            }
+        } else {
+            words.push(word);
+        }
+    }
+
+    let due = match (day, time) {
+        (None, None) => None,
+        (Some(day), time) => Some(day.and_time(time.unwrap_or_else(default_time))),
+        (None, Some(time)) => {
+            let due = today.and_time(time);
+            Some(if due > now { due } else { due + Days::new(1) })
+        }
+    };
+
+    let nothing_recognised =
+        due.is_none() && tags.is_empty() && assignee.is_none() && priority.is_none();
+    QuickAdd {
+        title: if nothing_recognised {
+            input.trim().to_string()
+        } else {
+            words.join(" ")
+        },
+        due,
+        tags,
+        assignee,
+        priority,
+    }
+}
+
+fn parse_priority(token: &str) -> Option<TodoPriority> {
+    match token.to_lowercase().as_str() {
+        "low" => Some(TodoPriority::Low),
+        "medium" | "med" => Some(TodoPriority::Medium),
+        "high" => Some(TodoPriority::High),
+        _ => None,
+    }
+}
+
+fn default_time() -> NaiveTime {
+    NaiveTime::from_hms_opt(DEFAULT_HOUR, 0, 0).unwrap_or(NaiveTime::MIN)
+}
+
+fn parse_day(token: &str, today: NaiveDate) -> Option<NaiveDate> {
+    match token {
+        "today" => Some(today),
+        "tomorrow" => today.succ_opt(),
+        _ => {
+            let weekday = token.parse::<Weekday>().ok()?;
+            let days_ahead =
+                (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+            // The same weekday as today means next week's
+            let days_ahead = if days_ahead == 0 { 7 } else { days_ahead };
+            today.checked_add_days(Days::new(u64::from(days_ahead)))
+        }
+    }
+}
+
+/// Parses `5pm`, `5:30pm`, `12am` and `17:00`.
+fn parse_time(token: &str) -> Option<NaiveTime> {
+    let (clock, meridiem) = if let Some(clock) = token.strip_suffix("am") {
+        (clock, Some(false))
+    } else if let Some(clock) = token.strip_suffix("pm") {
+        (clock, Some(true))
+    } else {
+        (token, None)
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => {
+            (hour.parse::<u32>().ok()?, minute.parse().ok()?)
+        }
+        Some(_) => return None,
+        // A bare number is only a time with am/pm, so "buy 2 apples" stays a title
+        None if meridiem.is_some() => (clock.parse::<u32>().ok()?, 0),
+        None => return None,
+    };
+
+    let hour = match meridiem {
+        Some(is_pm) if (1..=12).contains(&hour) => hour % 12 + if is_pm { 12 } else { 0 },
+        Some(_) => return None,
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wednesday 2025-06-11 at 14:30
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 6, 11)
+            .unwrap()
+            .and_hms_opt(14, 30, 0)
+            .unwrap()
+    }
+
+    fn at(month: u32, day: u32, hour: u32, minute: u32) -> Option<NaiveDateTime> {
+        NaiveDate::from_ymd_opt(2025, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+    }
+
+    #[test]
+    fn tomorrow_with_a_time() {
+        let parsed = parse_quick_add("Buy milk tomorrow 5pm", now());
+
+        assert_eq!(parsed.title, "Buy milk");
+        assert_eq!(parsed.due, at(6, 12, 17, 0));
+    }
+
+    #[test]
+    fn on_and_at_are_dropped_with_the_day_and_time() {
+        let parsed = parse_quick_add("Call grandma on Friday at 17:15", now());
+
+        assert_eq!(parsed.title, "Call grandma");
+        assert_eq!(parsed.due, at(6, 13, 17, 15));
+    }
+
+    #[test]
+    fn weekdays_are_the_next_such_day() {
+        assert_eq!(parse_quick_add("Gym fri", now()).due, at(6, 13, 9, 0));
+        // Monday has passed this week
+        assert_eq!(parse_quick_add("Gym monday", now()).due, at(6, 16, 9, 0));
+        // Today's weekday means next week's
+        assert_eq!(parse_quick_add("Gym Wednesday", now()).due, at(6, 18, 9, 0));
+    }
+
+    #[test]
+    fn a_time_that_has_passed_is_tomorrow() {
+        assert_eq!(
+            parse_quick_add("Water plants 9am", now()).due,
+            at(6, 12, 9, 0)
+        );
+        assert_eq!(
+            parse_quick_add("Water plants 5:30pm", now()).due,
+            at(6, 11, 17, 30)
+        );
+    }
+
+    #[test]
+    fn tags_assignee_and_priority_are_extracted() {
+        let parsed = parse_quick_add("Buy milk #shop @Niina !HIGH #Food tomorrow", now());
+
+        assert_eq!(parsed.title, "Buy milk");
+        assert_eq!(parsed.tags, vec!["shop", "Food"]);
+        assert_eq!(parsed.assignee.as_deref(), Some("Niina"));
+        assert_eq!(parsed.priority, Some(TodoPriority::High));
+        assert_eq!(parsed.due, at(6, 12, 9, 0));
+    }
+
+    #[test]
+    fn markers_without_a_value_stay_in_the_title() {
+        let parsed = parse_quick_add("Fix # and @ now !urgent", now());
+
+        assert_eq!(parsed.title, "Fix # and @ now !urgent");
+        assert!(parsed.tags.is_empty());
+        assert_eq!(parsed.assignee, None);
+        assert_eq!(parsed.priority, None);
+    }
+
+    #[test]
+    fn without_a_date_the_whole_line_is_the_title() {
+        let parsed = parse_quick_add("  Buy 2 apples  ", now());
+
+        assert_eq!(
+            parsed,
+            QuickAdd {
+                title: "Buy 2 apples".to_string(),
+                due: None,
+                tags: Vec::new(),
+                assignee: None,
+                priority: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::i18n::Locale;
+
+/// Unit names: English singular, and the Finnish genitive ("in"), nominative
+/// (one, "ago") and partitive (several, "ago").
+type UnitNames = (&'static str, [&'static str; 3]);
+
+/// Formats a due date relative to `now`, e.g. "in 3 hours" or "2 days ago".
+///
+/// Both values are Unix timestamps in seconds. Anything within a minute of
+/// `now` reads as "now"; the largest fitting unit is used, rounded down.
+#[must_use]
+pub fn format_relative_time(timestamp: u64, now: i64, locale: Locale) -> String {
+    const UNITS: [(i64, UnitNames); 6] = [
+        (365 * 24 * 60 * 60, ("year", ["vuoden", "vuosi", "vuotta"])),
+        (
+            30 * 24 * 60 * 60,
+            ("month", ["kuukauden", "kuukausi", "kuukautta"]),
+        ),
+        (7 * 24 * 60 * 60, ("week", ["viikon", "viikko", "viikkoa"])),
+        (24 * 60 * 60, ("day", ["päivän", "päivä", "päivää"])),
+        (60 * 60, ("hour", ["tunnin", "tunti", "tuntia"])),
+        (60, ("minute", ["minuutin", "minuutti", "minuuttia"])),
+    ];
+
+    let timestamp = i64::try_from(timestamp).unwrap_or(i64::MAX);
+    let delta = timestamp.saturating_sub(now);
+    let seconds = delta.saturating_abs();
+
+    let Some((count, (unit, [genitive, nominative, partitive]))) = UNITS
+        .iter()
+        .find(|(unit_seconds, _)| seconds >= *unit_seconds)
+        .map(|(unit_seconds, names)| (seconds / unit_seconds, *names))
+    else {
+        return locale.t("time.now").to_string();
+    };
+
+    match locale {
+        Locale::En => {
+            let plural = if count == 1 { "" } else { "s" };
+            if delta > 0 {
+                format!("in {count} {unit}{plural}")
+            } else {
+                format!("{count} {unit}{plural} ago")
+            }
+        }
+        Locale::Fi => {
+            if delta > 0 {
+                format!("{count} {genitive} päästä")
+            } else if count == 1 {
+                format!("{count} {nominative} sitten")
+            } else {
+                format!("{count} {partitive} sitten")
+            }
+        }
+    }
+}
@@ -1,11 +1,139 @@
+use crate::domain::todo::Todo;
+
+/// Entities produced by [`sanitize_string`], without the leading `&`.
+const ESCAPED_ENTITIES: [(&str, char); 5] = [
+    ("amp;", '&'),
+    ("lt;", '<'),
+    ("gt;", '>'),
+    ("quot;", '"'),
+    ("#x27;", '\''),
+];
+
+/// Escapes HTML special characters and trims surrounding whitespace.
+///
+/// Every `&` is escaped, including one that looks like an entity, so text such
+/// as "&lt;" comes back unchanged from [`unsanitize_string`]. A stored value
+/// must therefore be unsanitized before it is sent to be sanitized again.
 #[must_use]
 pub fn sanitize_string(input: &str) -> String {
-    input
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('&', "&amp;")
-        .replace('"', "&quot;")
-        .replace('\'', "&#x27;")
-        .trim()
-        .to_string()
+    let input = input.trim();
+    let mut output = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#x27;"),
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+/// Reverses [`sanitize_string`] for text that is escaped again when rendered,
+/// such as view text nodes and form fields.
+#[must_use]
+pub fn unsanitize_string(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(index) = rest.find('&') {
+        output.push_str(&rest[..index]);
+        rest = &rest[index + 1..];
+        match ESCAPED_ENTITIES
+            .iter()
+            .find(|(entity, _)| rest.starts_with(entity))
+        {
+            Some((entity, c)) => {
+                output.push(*c);
+                rest = &rest[entity.len()..];
+            }
+            None => output.push('&'),
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// `todo` with its stored title unsanitized, as the server expects it back
+/// when the todo is saved again.
+#[must_use]
+pub fn unsanitized_todo(todo: Todo) -> Todo {
+    Todo {
+        title: unsanitize_string(&todo.title),
+        ..todo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_tags_are_escaped() {
+        assert_eq!(
+            sanitize_string("<script>alert('x')</script>"),
+            "&lt;script&gt;alert(&#x27;x&#x27;)&lt;/script&gt;"
+        );
+        assert_eq!(
+            sanitize_string(r#"<img src="x" onerror="alert(1)">"#),
+            "&lt;img src=&quot;x&quot; onerror=&quot;alert(1)&quot;&gt;"
+        );
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_trimmed() {
+        assert_eq!(sanitize_string("  Buy milk \n"), "Buy milk");
+    }
+
+    #[test]
+    fn ampersands_are_always_escaped() {
+        assert_eq!(sanitize_string("Tom & Jerry"), "Tom &amp; Jerry");
+        assert_eq!(sanitize_string("&copy; &nbsp;"), "&amp;copy; &amp;nbsp;");
+        assert_eq!(
+            sanitize_string("a &lt; b &amp; c"),
+            "a &amp;lt; b &amp;amp; c"
+        );
+        assert_eq!(
+            sanitize_string("&#x27;quoted&#x27;"),
+            "&amp;#x27;quoted&amp;#x27;"
+        );
+    }
+
+    #[test]
+    fn unsanitize_reverses_sanitize() {
+        for input in [
+            "<script>alert('x')</script>",
+            "Tom & Jerry's \"best\"",
+            "&copy; 2025",
+            "Äiti & isä",
+        ] {
+            assert_eq!(unsanitize_string(&sanitize_string(input)), input);
+        }
+    }
+
+    #[test]
+    fn literal_entities_survive_a_round_trip() {
+        for input in [
+            "Write about &lt;div&gt; tags",
+            "&amp;&lt;&gt;&quot;&#x27;",
+            "&amp;amp;",
+            "&&&;",
+        ] {
+            assert_eq!(unsanitize_string(&sanitize_string(input)), input, "{input}");
+        }
+    }
+
+    #[test]
+    fn unsanitized_todo_is_sanitized_back_to_the_stored_title() {
+        let stored = sanitize_string("Tom & Jerry's &lt;b&gt;");
+        let todo = Todo::new(stored.clone(), "Mike".parse().unwrap());
+
+        assert_eq!(sanitize_string(&unsanitized_todo(todo).title), stored);
+    }
+
+    #[test]
+    fn unsanitize_keeps_unknown_entities_and_lone_ampersands() {
+        assert_eq!(unsanitize_string("&copy; & &amp"), "&copy; & &amp");
+    }
 }
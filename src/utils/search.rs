@@ -0,0 +1,92 @@
+use crate::domain::todo::Todo;
+
+/// A todo search query split into free text and `key:value` filters.
+///
+/// Recognised keys are `status`, `assignee`, `priority` and `tag`, e.g.
+/// `status:completed assignee:mikko groceries`. Tokens with any other prefix
+/// are kept as free text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    /// Lowercased free text, matched against title, description, assignee and tags
+    pub text: String,
+    pub status: Option<String>,
+    pub assignee: Option<String>,
+    pub priority: Option<String>,
+    pub tag: Option<String>,
+}
+
+impl SearchQuery {
+    #[must_use]
+    pub fn parse(query: &str) -> Self {
+        let mut parsed = Self::default();
+        let mut words = Vec::new();
+
+        for token in query.split_whitespace() {
+            let filter = token
+                .split_once(':')
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(key, value)| (key.to_lowercase(), normalize(value)));
+
+            let slot = match filter.as_ref().map(|(key, _)| key.as_str()) {
+                Some("status") => &mut parsed.status,
+                Some("assignee") => &mut parsed.assignee,
+                Some("priority") => &mut parsed.priority,
+                Some("tag") => &mut parsed.tag,
+                _ => {
+                    words.push(token.to_lowercase());
+                    continue;
+                }
+            };
+            *slot = filter.map(|(_, value)| value);
+        }
+
+        parsed.text = words.join(" ");
+        parsed
+    }
+
+    /// Returns true if the todo satisfies the free text and every filter.
+    #[must_use]
+    pub fn matches(&self, todo: &Todo) -> bool {
+        let matches_text = self.text.is_empty()
+            || todo.title.to_lowercase().contains(&self.text)
+            || todo
+                .description
+                .as_ref()
+                .is_some_and(|desc| desc.to_lowercase().contains(&self.text))
+            || todo.assignee.as_str().to_lowercase().contains(&self.text)
+            || todo
+                .tags
+                .iter()
+                .any(|tag| tag.to_lowercase().contains(&self.text));
+
+        let matches_status = self
+            .status
+            .as_ref()
+            .is_none_or(|status| normalize(todo.status.as_str()) == *status);
+        let matches_assignee = self
+            .assignee
+            .as_ref()
+            .is_none_or(|assignee| normalize(todo.assignee.as_str()).contains(assignee.as_str()));
+        let matches_priority = self
+            .priority
+            .as_ref()
+            .is_none_or(|priority| normalize(todo.priority.as_str()) == *priority);
+        let matches_tag = self.tag.as_ref().is_none_or(|tag| {
+            todo.tags
+                .iter()
+                .any(|todo_tag| normalize(todo_tag).contains(tag.as_str()))
+        });
+
+        matches_text && matches_status && matches_assignee && matches_priority && matches_tag
+    }
+}
+
+/// Lowercases a value and drops separators, so `in-progress`, `in_progress`
+/// and `In Progress` compare equal.
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
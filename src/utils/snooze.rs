@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Days, NaiveTime, Timelike, Utc};
+use chrono_tz::Tz;
+
+use crate::utils::{from_user_time, to_user_time};
+
+/// Local hour a todo snoozed to a later day becomes due, when it has no time
+/// of day of its own.
+const SNOOZE_HOUR: u32 = 9;
+
+/// How far a todo can be pushed back from its card.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Snooze {
+    OneHour,
+    Tomorrow,
+    NextWeek,
+}
+
+impl Snooze {
+    pub const ALL: [Self; 3] = [Self::OneHour, Self::Tomorrow, Self::NextWeek];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::OneHour => "one_hour",
+            Self::Tomorrow => "tomorrow",
+            Self::NextWeek => "next_week",
+        }
+    }
+
+    /// Key of the option's label in [`crate::i18n`].
+    #[must_use]
+    pub const fn label_key(self) -> &'static str {
+        match self {
+            Self::OneHour => "snooze.one_hour",
+            Self::Tomorrow => "snooze.tomorrow",
+            Self::NextWeek => "snooze.next_week",
+        }
+    }
+}
+
+impl FromStr for Snooze {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|snooze| snooze.as_str() == s)
+            .ok_or_else(|| format!("Invalid snooze: {s}"))
+    }
+}
+
+/// The due date a todo gets when snoozed at `now`, as a stored timestamp
+/// (Unix seconds, UTC).
+///
+/// Every option counts from `now`, so an overdue todo moves into the future and
+/// a todo without a due date gets one:
+/// - "1 hour" is an hour from now;
+/// - "Tomorrow" is 9:00 on the user's next day;
+/// - "Next week" is the same weekday a week from today, at the time of day of
+///   the current due date, or 9:00 without one.
+#[must_use]
+pub fn snoozed_due_date(
+    snooze: Snooze,
+    due_date: Option<u64>,
+    now: DateTime<Utc>,
+    tz: Tz,
+) -> Option<u64> {
+    let today = now.with_timezone(&tz).date_naive();
+    let morning = NaiveTime::from_hms_opt(SNOOZE_HOUR, 0, 0)?;
+
+    match snooze {
+        Snooze::OneHour => u64::try_from((now + chrono::Duration::hours(1)).timestamp()).ok(),
+        Snooze::Tomorrow => {
+            from_user_time(today.checked_add_days(Days::new(1))?.and_time(morning), tz)
+        }
+        Snooze::NextWeek => {
+            let time = due_date
+                .and_then(|due| to_user_time(due, tz))
+                .map_or(morning, |due| due.time().with_second(0).unwrap_or(morning));
+            from_user_time(today.checked_add_days(Days::new(7))?.and_time(time), tz)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono_tz::Europe::Helsinki;
+
+    use super::*;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn helsinki(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> Option<u64> {
+        let local = Helsinki
+            .with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap();
+        u64::try_from(local.timestamp()).ok()
+    }
+
+    #[test]
+    fn an_overdue_todo_moves_into_the_future() {
+        // 12:00 in Helsinki, due two days ago at 18:30
+        let now = utc(2025, 6, 11, 9, 0);
+        let overdue = helsinki(2025, 6, 9, 18, 30);
+
+        assert_eq!(
+            snoozed_due_date(Snooze::OneHour, overdue, now, Helsinki),
+            helsinki(2025, 6, 11, 13, 0)
+        );
+        assert_eq!(
+            snoozed_due_date(Snooze::Tomorrow, overdue, now, Helsinki),
+            helsinki(2025, 6, 12, 9, 0)
+        );
+        assert_eq!(
+            snoozed_due_date(Snooze::NextWeek, overdue, now, Helsinki),
+            helsinki(2025, 6, 18, 18, 30)
+        );
+    }
+
+    #[test]
+    fn a_todo_without_a_due_date_gets_one() {
+        let now = utc(2025, 6, 11, 9, 0);
+
+        assert_eq!(
+            snoozed_due_date(Snooze::OneHour, None, now, Helsinki),
+            helsinki(2025, 6, 11, 13, 0)
+        );
+        assert_eq!(
+            snoozed_due_date(Snooze::Tomorrow, None, now, Helsinki),
+            helsinki(2025, 6, 12, 9, 0)
+        );
+        assert_eq!(
+            snoozed_due_date(Snooze::NextWeek, None, now, Helsinki),
+            helsinki(2025, 6, 18, 9, 0)
+        );
+    }
+
+    #[test]
+    fn days_count_in_the_configured_zone_across_a_month_end() {
+        // Still January 31 in UTC but already February 1 in Helsinki
+        let now = utc(2025, 1, 31, 23, 30);
+
+        assert_eq!(
+            snoozed_due_date(Snooze::Tomorrow, None, now, Helsinki),
+            helsinki(2025, 2, 2, 9, 0)
+        );
+        assert_eq!(
+            snoozed_due_date(Snooze::NextWeek, None, now, Helsinki),
+            helsinki(2025, 2, 8, 9, 0)
+        );
+    }
+
+    #[test]
+    fn next_week_keeps_the_local_time_of_day_across_a_dst_change() {
+        // Clocks go forward on March 30; 18:00 is 16:00 UTC before and 15:00 after
+        let now = utc(2025, 3, 26, 8, 0);
+        let due = helsinki(2025, 3, 26, 18, 0);
+
+        let snoozed = snoozed_due_date(Snooze::NextWeek, due, now, Helsinki);
+        assert_eq!(snoozed, helsinki(2025, 4, 2, 18, 0));
+        assert_eq!(
+            snoozed,
+            u64::try_from(utc(2025, 4, 2, 15, 0).timestamp()).ok()
+        );
+    }
+
+    #[test]
+    fn snooze_options_round_trip_through_their_names() {
+        for snooze in Snooze::ALL {
+            assert_eq!(snooze.as_str().parse::<Snooze>(), Ok(snooze));
+        }
+        assert!("later".parse::<Snooze>().is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use leptos::prelude::ServerFnError;
+use serde::{Deserialize, Serialize};
+
+use super::preferences::{get_preference, set_preference};
+use super::sanitization::unsanitized_todo;
+use crate::api::{create_todo_server, delete_todo_server, get_todo_server, update_todo_server};
+use crate::domain::todo::Todo;
+
+/// localStorage key of the mutations waiting to be replayed.
+pub const PENDING_SYNC_KEY: &str = "pending_sync";
+
+/// A todo change made while the server was unreachable.
+///
+/// Updates and deletes keep the todo as it was before the change, so a replay
+/// can tell whether someone else changed it on the server in the meantime.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PendingMutation {
+    Create(Todo),
+    Update { base: Todo, todo: Todo },
+    Delete(Todo),
+}
+
+impl PendingMutation {
+    #[must_use]
+    pub fn todo_id(&self) -> &str {
+        match self {
+            Self::Create(todo) | Self::Update { todo, .. } | Self::Delete(todo) => &todo.id,
+        }
+    }
+}
+
+/// What happened to a pending mutation when it was replayed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncOutcome {
+    /// Applied on the server; carries the server's copy unless the todo was deleted
+    Synced(Option<Todo>),
+    /// The todo changed on the server while offline; the server's copy wins and
+    /// is `None` if the todo was deleted there
+    Conflict { todo: Todo, server: Option<Todo> },
+    /// Rejected by the server, e.g. by validation
+    Failed { todo: Todo, error: String },
+}
+
+/// Returns true if a server function failed because the server could not be
+/// reached, as opposed to the server rejecting the request.
+#[must_use]
+pub fn is_offline_error(error: &ServerFnError) -> bool {
+    matches!(error, ServerFnError::Request(_))
+}
+
+/// Reads the pending mutations from localStorage.
+#[must_use]
+pub fn load_pending_mutations() -> Vec<PendingMutation> {
+    get_preference(PENDING_SYNC_KEY).unwrap_or_default()
+}
+
+/// Stores the pending mutations in localStorage.
+pub fn save_pending_mutations(queue: &[PendingMutation]) {
+    set_preference(PENDING_SYNC_KEY, &queue);
+}
+
+/// Adds a mutation to the queue, folding it into an earlier one for the same todo.
+///
+/// An update of a queued create stays a create, updates keep the oldest base,
+/// and deleting a todo that was created offline drops it altogether.
+pub fn enqueue_mutation(queue: &mut Vec<PendingMutation>, mutation: PendingMutation) {
+    let Some(index) = queue.iter().position(|m| m.todo_id() == mutation.todo_id()) else {
+        queue.push(mutation);
+        return;
+    };
+
+    let folded = match (queue.remove(index), mutation) {
+        (PendingMutation::Create(_), PendingMutation::Update { todo, .. }) => {
+            Some(PendingMutation::Create(todo))
+        }
+        (PendingMutation::Create(_), PendingMutation::Delete(_)) => None,
+        (PendingMutation::Update { base, .. }, PendingMutation::Update { todo, .. }) => {
+            Some(PendingMutation::Update { base, todo })
+        }
+        (PendingMutation::Update { base, .. }, PendingMutation::Delete(_)) => {
+            Some(PendingMutation::Delete(base))
+        }
+        (_, mutation) => Some(mutation),
+    };
+    if let Some(folded) = folded {
+        queue.insert(index, folded);
+    }
+}
+
+/// Applies the pending mutations to a freshly loaded list, so that offline
+/// changes stay visible until they are synced.
+pub fn apply_pending_mutations(todos: &mut Vec<Todo>, queue: &[PendingMutation]) {
+    for mutation in queue {
+        match mutation {
+            PendingMutation::Create(todo) | PendingMutation::Update { todo, .. } => {
+                match todos.iter_mut().find(|t| t.id == todo.id) {
+                    Some(existing) => *existing = todo.clone(),
+                    None => todos.push(todo.clone()),
+                }
+            }
+            PendingMutation::Delete(todo) => todos.retain(|t| t.id != todo.id),
+        }
+    }
+}
+
+/// Replays a pending mutation against the server.
+///
+/// Updates and deletes are only applied if the server's copy still matches the
+/// base they were made against; otherwise the outcome is a conflict.
+///
+/// # Errors
+///
+/// Returns the error if the server is still unreachable or the current copy of
+/// the todo cannot be fetched, in which case the mutation should stay queued.
+pub async fn replay_mutation(mutation: PendingMutation) -> Result<SyncOutcome, ServerFnError> {
+    let outcome = match mutation {
+        // Its due date may have passed while offline
+        PendingMutation::Create(todo) => match create_todo_server(todo.clone(), Some(true)).await {
+            Ok(created) => SyncOutcome::Synced(Some(created)),
+            Err(e) if is_offline_error(&e) => return Err(e),
+            Err(e) => SyncOutcome::Failed {
+                todo,
+                error: e.to_string(),
+            },
+        },
+        PendingMutation::Update { base, todo } => {
+            let server = get_todo_server(todo.id.clone()).await?;
+            if server.as_ref() != Some(&base) {
+                return Ok(SyncOutcome::Conflict { todo, server });
+            }
+            match update_todo_server(unsanitized_todo(todo.clone())).await {
+                Ok(updated) => SyncOutcome::Synced(Some(updated)),
+                Err(e) if is_offline_error(&e) => return Err(e),
+                Err(e) => SyncOutcome::Failed {
+                    todo,
+                    error: e.to_string(),
+                },
+            }
+        }
+        PendingMutation::Delete(base) => match get_todo_server(base.id.clone()).await? {
+            // Already gone, nothing left to do
+            None => SyncOutcome::Synced(None),
+            Some(server) if server != base => SyncOutcome::Conflict {
+                todo: base,
+                server: Some(server),
+            },
+            Some(_) => match delete_todo_server(base.id.clone()).await {
+                Ok(()) => SyncOutcome::Synced(None),
+                Err(e) if is_offline_error(&e) => return Err(e),
+                Err(e) => SyncOutcome::Failed {
+                    todo: base,
+                    error: e.to_string(),
+                },
+            },
+        },
+    };
+    Ok(outcome)
+}
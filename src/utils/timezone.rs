@@ -0,0 +1,183 @@
+use chrono::{DateTime, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// The user's time zone, detected in the browser with
+/// `Intl.DateTimeFormat().resolvedOptions().timeZone`.
+///
+/// Falls back to UTC when the browser reports an unknown zone, and is always
+/// UTC on the server, which does not know where the user is.
+#[cfg(feature = "hydrate")]
+#[must_use]
+pub fn user_tz() -> Tz {
+    use web_sys::js_sys::{Array, Intl, Object, Reflect};
+
+    static USER_TZ: std::sync::OnceLock<Tz> = std::sync::OnceLock::new();
+
+    *USER_TZ.get_or_init(|| {
+        let options = Intl::DateTimeFormat::new(&Array::new(), &Object::new()).resolved_options();
+        Reflect::get(&options, &"timeZone".into())
+            .ok()
+            .and_then(|name| name.as_string())
+            .and_then(|name| name.parse().ok())
+            .unwrap_or(Tz::UTC)
+    })
+}
+
+#[cfg(not(feature = "hydrate"))]
+#[must_use]
+pub fn user_tz() -> Tz {
+    Tz::UTC
+}
+
+/// Time zone of the household, from the `TIMEZONE` setting, in which the
+/// server decides whether a date is in the past. UTC when unset.
+#[must_use]
+pub fn server_tz() -> Tz {
+    crate::config::get_config().map_or(Tz::UTC, |config| config.server.timezone())
+}
+
+/// Converts a stored timestamp (Unix seconds, UTC) to the user's time zone.
+#[must_use]
+pub fn to_user_time(timestamp: u64, tz: Tz) -> Option<DateTime<Tz>> {
+    let timestamp = i64::try_from(timestamp).ok()?;
+    DateTime::from_timestamp(timestamp, 0).map(|datetime| datetime.with_timezone(&tz))
+}
+
+/// The calendar day a stored timestamp falls on for the user.
+#[must_use]
+pub fn user_date(timestamp: u64, tz: Tz) -> Option<NaiveDate> {
+    to_user_time(timestamp, tz).map(|datetime| datetime.date_naive())
+}
+
+/// Today's date for the user.
+#[must_use]
+pub fn user_today(tz: Tz) -> NaiveDate {
+    Utc::now().with_timezone(&tz).date_naive()
+}
+
+/// Converts a wall-clock time entered by the user to a stored timestamp
+/// (Unix seconds, UTC).
+///
+/// A time that occurs twice when clocks go back resolves to the earlier
+/// instant; a time skipped when clocks go forward is moved past the gap.
+#[must_use]
+pub fn from_user_time(datetime: NaiveDateTime, tz: Tz) -> Option<u64> {
+    let resolved = match tz.from_local_datetime(&datetime) {
+        LocalResult::Single(resolved) | LocalResult::Ambiguous(resolved, _) => resolved,
+        LocalResult::None => tz
+            .from_local_datetime(&(datetime + chrono::Duration::hours(1)))
+            .earliest()?,
+    };
+    u64::try_from(resolved.timestamp()).ok()
+}
+
+/// Formats a due date in the user's time zone, e.g. "Monday, March 03, 2025 at 09:00 AM".
+#[must_use]
+pub fn format_due_date(timestamp: u64, tz: Tz) -> String {
+    to_user_time(timestamp, tz).map_or_else(
+        || "Invalid date".to_string(),
+        |datetime| datetime.format("%A, %B %d, %Y at %I:%M %p").to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> u64 {
+        u64::try_from(
+            Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+                .unwrap()
+                .timestamp(),
+        )
+        .unwrap()
+    }
+
+    fn local(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn time_skipped_by_spring_forward_moves_past_the_gap() {
+        // Helsinki skips 03:00-04:00 on 30 March 2025
+        assert_eq!(
+            from_user_time(local(2025, 3, 30, 3, 30), Tz::Europe__Helsinki),
+            Some(utc(2025, 3, 30, 1, 30))
+        );
+        // New York skips 02:00-03:00 on 9 March 2025
+        assert_eq!(
+            from_user_time(local(2025, 3, 9, 2, 30), Tz::America__New_York),
+            Some(utc(2025, 3, 9, 7, 30))
+        );
+    }
+
+    #[test]
+    fn time_repeated_by_fall_back_resolves_to_the_earlier_instant() {
+        // Helsinki repeats 03:00-04:00 on 26 October 2025, first at UTC+3
+        assert_eq!(
+            from_user_time(local(2025, 10, 26, 3, 30), Tz::Europe__Helsinki),
+            Some(utc(2025, 10, 26, 0, 30))
+        );
+        // New York repeats 01:00-02:00 on 2 November 2025, first at UTC-4
+        assert_eq!(
+            from_user_time(local(2025, 11, 2, 1, 30), Tz::America__New_York),
+            Some(utc(2025, 11, 2, 5, 30))
+        );
+    }
+
+    #[test]
+    fn offset_changes_across_the_transition() {
+        let tz = Tz::Europe__Helsinki;
+        let before = to_user_time(utc(2025, 3, 30, 0, 59), tz).unwrap();
+        let after = to_user_time(utc(2025, 3, 30, 1, 0), tz).unwrap();
+
+        assert_eq!(before.naive_local(), local(2025, 3, 30, 2, 59));
+        assert_eq!(after.naive_local(), local(2025, 3, 30, 4, 0));
+    }
+
+    #[test]
+    fn negative_offsets_put_early_utc_times_on_the_previous_day() {
+        let timestamp = utc(2025, 1, 1, 3, 0);
+
+        assert_eq!(
+            user_date(timestamp, Tz::America__New_York),
+            NaiveDate::from_ymd_opt(2024, 12, 31)
+        );
+        assert_eq!(
+            user_date(timestamp, Tz::Pacific__Honolulu),
+            NaiveDate::from_ymd_opt(2024, 12, 31)
+        );
+        assert_eq!(
+            user_date(timestamp, Tz::UTC),
+            NaiveDate::from_ymd_opt(2025, 1, 1)
+        );
+    }
+
+    #[test]
+    fn wall_clock_time_round_trips_with_a_negative_offset() {
+        let tz = Tz::America__Los_Angeles;
+        let entered = local(2025, 7, 4, 23, 15);
+
+        let timestamp = from_user_time(entered, tz).unwrap();
+
+        assert_eq!(timestamp, utc(2025, 7, 5, 6, 15));
+        assert_eq!(to_user_time(timestamp, tz).unwrap().naive_local(), entered);
+        assert_eq!(
+            format_due_date(timestamp, tz),
+            "Friday, July 04, 2025 at 11:15 PM"
+        );
+    }
+
+    #[test]
+    fn timestamps_out_of_range_are_rejected() {
+        assert_eq!(to_user_time(u64::MAX, Tz::UTC), None);
+        assert_eq!(format_due_date(u64::MAX, Tz::UTC), "Invalid date");
+        assert_eq!(
+            from_user_time(local(1969, 12, 31, 12, 0), Tz::America__New_York),
+            None
+        );
+    }
+}
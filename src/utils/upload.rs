@@ -0,0 +1,120 @@
+use leptos::web_sys::HtmlInputElement;
+
+/// A file read from a file `<input>`, base64 encoded for sending to the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectedFile {
+    pub name: String,
+    pub content_type: String,
+    pub data_base64: String,
+}
+
+/// Reads the file chosen in a file `<input>` as text.
+///
+/// `on_load` is called with the contents once the browser has read the file.
+/// Nothing happens if no file is selected.
+///
+/// # Errors
+///
+/// Returns an error message if the file cannot be opened for reading.
+#[cfg(feature = "hydrate")]
+pub fn read_file_text(
+    input: &HtmlInputElement,
+    on_load: impl FnOnce(Result<String, String>) + 'static,
+) -> Result<(), String> {
+    read_selected_file(
+        input,
+        |reader, file| reader.read_as_text(file),
+        move |_, contents| on_load(contents),
+    )
+}
+
+/// Reads the file chosen in a file `<input>` as base64.
+///
+/// `on_load` is called with the file once the browser has read it. Nothing
+/// happens if no file is selected.
+///
+/// # Errors
+///
+/// Returns an error message if the file cannot be opened for reading.
+#[cfg(feature = "hydrate")]
+pub fn read_file_base64(
+    input: &HtmlInputElement,
+    on_load: impl FnOnce(Result<SelectedFile, String>) + 'static,
+) -> Result<(), String> {
+    read_selected_file(
+        input,
+        |reader, file| reader.read_as_data_url(file),
+        move |file, contents| {
+            // A data URL looks like `data:<type>;base64,<data>`
+            let selected = contents.and_then(|data_url| {
+                let (_, data_base64) = data_url
+                    .split_once(";base64,")
+                    .ok_or_else(|| format!("Failed to read {}", file.name()))?;
+                Ok(SelectedFile {
+                    name: file.name(),
+                    content_type: file.type_(),
+                    data_base64: data_base64.to_string(),
+                })
+            });
+            on_load(selected);
+        },
+    )
+}
+
+#[cfg(feature = "hydrate")]
+fn read_selected_file(
+    input: &HtmlInputElement,
+    read: impl FnOnce(
+        &web_sys::FileReader,
+        &web_sys::File,
+    ) -> Result<(), web_sys::wasm_bindgen::JsValue>,
+    on_load: impl FnOnce(web_sys::File, Result<String, String>) + 'static,
+) -> Result<(), String> {
+    use web_sys::FileReader;
+    use web_sys::wasm_bindgen::JsCast;
+    use web_sys::wasm_bindgen::closure::Closure;
+
+    let Some(file) = input.files().and_then(|files| files.get(0)) else {
+        return Ok(());
+    };
+    let reader = FileReader::new().map_err(|e| format!("Failed to read file: {e:?}"))?;
+
+    let loaded = reader.clone();
+    let loaded_file = file.clone();
+    let callback = Closure::once_into_js(move || {
+        let contents = loaded
+            .result()
+            .ok()
+            .and_then(|result| result.as_string())
+            .ok_or_else(|| format!("Failed to read {}", loaded_file.name()));
+        on_load(loaded_file, contents);
+    });
+    reader.set_onload(Some(callback.unchecked_ref()));
+    read(&reader, &file).map_err(|e| format!("Failed to read {}: {e:?}", file.name()))
+}
+
+/// No-op outside the browser.
+///
+/// # Errors
+///
+/// Never fails.
+#[cfg(not(feature = "hydrate"))]
+pub fn read_file_text(
+    _input: &HtmlInputElement,
+    _on_load: impl FnOnce(Result<String, String>) + 'static,
+) -> Result<(), String> {
+    Ok(())
+}
+
+/// No-op outside the browser.
+///
+/// # Errors
+///
+/// Never fails.
+#[cfg(not(feature = "hydrate"))]
+pub fn read_file_base64(
+    _input: &HtmlInputElement,
+    _on_load: impl FnOnce(Result<SelectedFile, String>) + 'static,
+) -> Result<(), String> {
+    Ok(())
+}
@@ -1,4 +1,6 @@
-use crate::domain::auth::UserInfo;
+use validator::ValidateEmail;
+
+use crate::domain::auth::{DEFAULT_GROUP_ID, READ_TODOS, UserInfo, WRITE_TODOS};
 
 /// Validates user credentials and returns user information if valid.
 ///
@@ -14,12 +16,29 @@ pub fn validate_credentials(username: &str, password: &str) -> Result<UserInfo,
             username: "Mikko".to_string(),
             email: "mikko@familyleppanen.com".to_string(),
             display_name: "Mikko Leppänen".to_string(),
+            permissions: vec![READ_TODOS.to_string(), WRITE_TODOS.to_string()],
+            group_id: DEFAULT_GROUP_ID.to_string(),
         }),
         ("Niina", "password123") => Ok(UserInfo {
             username: "Niina".to_string(),
             email: "niina@familyleppanen.com".to_string(),
             display_name: "Niina Leppänen".to_string(),
+            permissions: vec![READ_TODOS.to_string(), WRITE_TODOS.to_string()],
+            group_id: DEFAULT_GROUP_ID.to_string(),
         }),
         _ => Err("Invalid credentials".to_string()),
     }
 }
+
+/// Checks that `email` is a well-formed address such as `anna@example.com`.
+///
+/// # Errors
+///
+/// Returns an error string naming the address if it is empty or malformed.
+pub fn validate_email(email: &str) -> Result<(), String> {
+    if email.validate_email() {
+        Ok(())
+    } else {
+        Err(format!("Invalid email address: '{email}'"))
+    }
+}
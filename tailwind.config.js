@@ -1,6 +1,7 @@
 /** @type {import('tailwindcss').Config} */
 module.exports = {
     content: ["*.html", "./src/**/*.rs",],
+    darkMode: "class",
     theme: {
       extend: {},
     },